@@ -0,0 +1,328 @@
+//!
+//! adams.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Variable-step variable-order Adams-Bashforth-Moulton integrator. History
+//! is kept as the Nordsieck array of solvers::nordsieck, so a step size
+//! change is a rescale and an order change adds or drops a row, with no
+//! restart. Runs in PECE mode with the order q AB/AM pair, choosing q in
+//! 1..=12, and controls the corrector's local error step by step
+//!
+
+#![allow(dead_code)]
+
+use solvers::nordsieck::{adams_error, adams_l, Nordsieck};
+use solvers::{CorrectorFailure, Limits, SolveStats};
+
+use crate::system::OdeSystem;
+use crate::tolerance::Tolerance;
+
+pub const MAX_ORDER: usize = 12;
+
+///
+/// Tolerances and step limits for the adaptive Adams solver, and what it
+/// does when the corrector fails
+///
+#[derive(Clone, Copy, Debug)]
+pub struct AdamsOptions<const N: usize> {
+    pub tol: Tolerance<N>,
+    pub max_order: usize,
+    pub h0: Option<f64>,
+    pub h_min: f64,
+    pub h_max: f64,
+    pub on_failure: CorrectorFailure,
+    pub limits: Limits,
+}
+
+impl<const N: usize> Default for AdamsOptions<N> {
+    fn default() -> AdamsOptions<N> {
+        AdamsOptions {
+            tol: Tolerance::uniform(1e-6, 1e-9),
+            max_order: MAX_ORDER,
+            h0: None,
+            h_min: 1e-12,
+            h_max: f64::INFINITY,
+            on_failure: CorrectorFailure::Halve,
+            limits: Limits::default(),
+        }
+    }
+}
+
+///
+/// Accepted step times and states, the order used to reach each point
+/// (0 for the initial condition), every rejected attempt as the (t, h)
+/// it tried from, and the solve statistics
+///
+pub struct AdamsSolution<const N: usize> {
+    pub t: Vec<f64>,
+    pub y: Vec<[f64; N]>,
+    pub order: Vec<usize>,
+    pub rejected: Vec<(f64, f64)>,
+    pub stats: SolveStats,
+}
+
+impl<const N: usize> AdamsSolution<N> {
+    pub fn max_order_used(&self) -> usize {
+        self.order.iter().copied().max().unwrap_or(0)
+    }
+}
+
+///
+/// Adaptive VSVO Adams PECE integration over ts on the Nordsieck array.
+/// The order q array predicts with q step Adams-Bashforth and corrects
+/// with order q Adams-Moulton, and every step is accepted or rejected on
+/// the corrector's own local error, |C*_q| (q + 1)! z_{q+1} with z_{q+1}
+/// estimated from the correction, in the weighted norm of the tolerance.
+/// Step changes rescale the array. Once q + 1 steps have been taken at an
+/// order, the order among q - 1, q, q + 1 permitting the largest next step
+/// is selected, the neighbours' errors read from z_q and the difference of
+/// successive z_{q+1} estimates. A corrector that leaves the finite
+/// numbers is handled by opts.on_failure: Halve (the default) retries at
+/// half the step, as does Accept, having no finite iterate to keep
+///
+pub fn vsvo_adams<F, const N: usize>(rate: &F, ic: [f64; N], ts: [f64; 2], opts: AdamsOptions<N>)
+    -> Result<AdamsSolution<N>, Box<dyn std::error::Error>>
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    let max_order = opts.max_order.clamp(1, MAX_ORDER);
+    let [t0, tf] = ts;
+
+    // l vectors and |C*_q| (q + 1)! for orders 1..=max_order + 1
+    let ls: Vec<Vec<f64>> = (1..=max_order + 1).map(adams_l).collect();
+    let mut factorial = 1.0;
+    let consts: Vec<f64> = (1..=max_order + 1)
+        .map(|q| {
+            factorial *= (q + 1) as f64;
+            adams_error(q).1.abs() * factorial
+        })
+        .collect();
+
+    let stats = SolveStats { on_failure: opts.on_failure, ..SolveStats::default() };
+    let mut out = AdamsSolution { t: vec![t0], y: vec![ic], order: vec![0], rejected: Vec::new(), stats };
+    let mut f0 = [0.0; N];
+    rate(t0, &ic, &mut f0);
+    out.stats.rate_evals += 1;
+
+    // initial step from the weighted size of y over its rate; when either
+    // is negligible against the tolerance, as for a start from y = 0, the
+    // ratio says nothing and a small fixed step is tried instead
+    let ynorm = opts.tol.norm(&ic, &ic, &ic);
+    let fnorm = opts.tol.norm(&f0, &ic, &ic);
+    let guess = if ynorm < 1e-5 || fnorm < 1e-5 { 1e-6 } else { 0.01 * opts.tol.rtol_max().powf(0.5) * ynorm.max(1.0) / fnorm };
+    let mut h = opts.h0.unwrap_or(guess).min(opts.h_max);
+    let mut nd = Nordsieck::new(t0, ic, f0, h);
+    let mut q = 1;
+    let mut failures = 0;
+    // steps since the step or order last changed
+    let mut steady = 0;
+    // the last accepted z_{q+1} estimate and the step it was taken with
+    let mut previous: Option<([f64; N], f64)> = None;
+    let mut guard = opts.limits.start::<N>();
+
+    while nd.t < tf {
+        let (t, y) = (nd.t, nd.value());
+        guard.step("vsvo adams", t)?;
+        if h < opts.h_min {
+            return Err(format!("step size {h:.3e} below h_min at t = {t}").into());
+        }
+        let last = h >= tf - t;
+        nd.rescale(h.min(tf - t));
+        let saved = nd.clone();
+
+        // P, E, C at order q
+        let (_, delta, converged) = nd.adams_step(rate, &ls[q - 1], 1, false);
+        if last {
+            nd.t = tf;
+        }
+        out.stats.rate_evals += 1;
+        if !converged {
+            out.stats.corrector_failures += 1;
+            match opts.on_failure {
+                CorrectorFailure::Abort => return Err(format!("adams corrector did not converge at t = {t}").into()),
+                CorrectorFailure::Accept if nd.value().iter().all(|v| v.is_finite()) => {}
+                _ => {
+                    out.stats.rejected += 1;
+                    out.rejected.push((t, saved.h));
+                    nd = saved;
+                    h = 0.5 * nd.h;
+                    steady = 0;
+                    continue;
+                }
+            }
+        }
+        let a: [f64; N] = std::array::from_fn(|i| ls[q - 1][q] * delta[i] / (q + 1) as f64);
+        let err = opts.tol.norm(&a.map(|x| consts[q - 1] * x), &y, &nd.value());
+
+        if !err.is_finite() || err > 1.0 {
+            out.stats.rejected += 1;
+            out.rejected.push((t, saved.h));
+            nd = saved;
+            failures += 1;
+            let mut factor = if err.is_finite() { 0.9 * err.powf(-1.0 / (q as f64 + 1.0)) } else { 0.1 };
+            // one order down when z_q says it would allow the larger step,
+            // and always after repeated failures
+            let lower = q > 1 && {
+                let e = opts.tol.norm(&nd.z[q].map(|x| consts[q - 2] * x), &y, &y);
+                let down = 0.9 * e.max(1e-10).powf(-1.0 / q as f64);
+                if down > factor || failures >= 2 {
+                    factor = down.max(factor);
+                    true
+                } else {
+                    false
+                }
+            };
+            if lower {
+                nd.lower_order();
+                q -= 1;
+                previous = None;
+            }
+            h = nd.h * factor.clamp(0.1, 0.5);
+            steady = 0;
+            continue;
+        }
+        failures = 0;
+
+        // E: final evaluation at the corrected value
+        let mut fc = [0.0; N];
+        rate(nd.t, &nd.value(), &mut fc);
+        nd.evaluate(&ls[q - 1], &fc);
+        out.stats.rate_evals += 1;
+        out.stats.accepted += 1;
+        out.t.push(nd.t);
+        out.y.push(nd.value());
+        out.order.push(q);
+        steady += 1;
+
+        // after q + 1 steps on one step and order, estimate the step each
+        // neighbouring order would allow next; changes under 10% are not
+        // worth the perturbation of the array
+        if steady > q {
+            let mut best = (q, 0.9 * err.max(1e-10).powf(-1.0 / (q as f64 + 1.0)));
+            let mut candidates = Vec::with_capacity(2);
+            if q > 1 {
+                let e = opts.tol.norm(&nd.z[q].map(|x| consts[q - 2] * x), &y, &nd.value());
+                candidates.push((q - 1, e));
+            }
+            if let (true, Some((a_prev, h_prev))) = (q < max_order, previous) {
+                let r = (nd.h / h_prev).powi(q as i32 + 1);
+                let d: [f64; N] = std::array::from_fn(|i| consts[q] * (a[i] - a_prev[i] * r) / (q + 2) as f64);
+                candidates.push((q + 1, opts.tol.norm(&d, &y, &nd.value())));
+            }
+            for (j, e) in candidates {
+                let factor = 0.9 * e.max(1e-10).powf(-1.0 / (j as f64 + 1.0));
+                if factor > best.1 * 1.1 {
+                    best = (j, factor);
+                }
+            }
+            let factor = best.1.min(5.0).min(opts.h_max / nd.h);
+            if factor >= 1.1 || best.0 != q {
+                if best.0 > q {
+                    nd.raise_order(&delta, ls[q - 1][q]);
+                } else if best.0 < q {
+                    nd.lower_order();
+                }
+                if best.0 != q {
+                    q = best.0;
+                    previous = None;
+                }
+                h = nd.h * factor.max(0.2);
+                steady = 0;
+            }
+        }
+        if q == out.order[out.order.len() - 1] {
+            previous = Some((a, nd.h));
+        }
+    }
+
+    Ok(out)
+}
+
+///
+/// vsvo_adams on a system, its size checked against the state
+///
+pub fn vsvo_adams_system<const N: usize>(system: &impl OdeSystem, ic: [f64; N], ts: [f64; 2], opts: AdamsOptions<N>)
+    -> Result<AdamsSolution<N>, Box<dyn std::error::Error>> {
+    vsvo_adams(&system.rhs::<N>()?, ic, ts, opts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decay(_t: f64, y: &[f64; 1], dy: &mut [f64; 1]) {
+        dy[0] = -y[0];
+    }
+
+    fn oscillator(_t: f64, y: &[f64; 2], dy: &mut [f64; 2]) {
+        dy[0] = y[1];
+        dy[1] = -y[0];
+    }
+
+    #[test]
+    fn meets_the_tolerance_on_decay() {
+        for tol in [1e-4, 1e-6, 1e-8] {
+            let opts = AdamsOptions { tol: Tolerance::uniform(tol, 1e-3 * tol), ..Default::default() };
+            let sol = vsvo_adams(&decay, [1.0], [0.0, 5.0], opts).unwrap();
+            assert_eq!(sol.t.last(), Some(&5.0));
+            let err = (sol.y.last().unwrap()[0] - (-5.0_f64).exp()).abs();
+            assert!(err < 10.0 * tol, "tol {tol:e}: error {err:e}");
+        }
+    }
+
+    #[test]
+    fn order_rises_on_a_smooth_problem_and_respects_the_cap() {
+        let opts = AdamsOptions { tol: Tolerance::uniform(1e-10, 1e-12), ..Default::default() };
+        let free = vsvo_adams(&oscillator, [1.0, 0.0], [0.0, 20.0], opts).unwrap();
+        assert!(free.max_order_used() > 4, "{}", free.max_order_used());
+        let capped = vsvo_adams(&oscillator, [1.0, 0.0], [0.0, 20.0], AdamsOptions { max_order: 4, ..opts }).unwrap();
+        assert_eq!(capped.max_order_used(), 4);
+        assert!(capped.stats.accepted > free.stats.accepted);
+        for sol in [free, capped] {
+            let y = sol.y.last().unwrap();
+            assert!((y[0] - 20.0_f64.cos()).abs() < 1e-7 && (y[1] + 20.0_f64.sin()).abs() < 1e-7);
+        }
+    }
+
+    #[test]
+    fn a_corrector_that_is_not_finite_follows_the_policy() {
+        for on_failure in CorrectorFailure::ALL {
+            // the first evaluation past t = 1 fails, as a bad iterate would
+            let failed = std::cell::Cell::new(false);
+            let rate = |t: f64, y: &[f64; 1], dy: &mut [f64; 1]| {
+                dy[0] = if t > 1.0 && !failed.replace(true) { f64::NAN } else { -y[0] };
+            };
+            let run = vsvo_adams(&rate, [1.0], [0.0, 2.0], AdamsOptions { on_failure, ..Default::default() });
+            if on_failure == CorrectorFailure::Abort {
+                assert!(run.is_err());
+                continue;
+            }
+            let sol = run.unwrap();
+            assert_eq!(sol.stats.corrector_failures, 1, "{}", on_failure.name());
+            assert!((sol.y.last().unwrap()[0] - (-2.0_f64).exp()).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn order_drops_at_a_kink_and_climbs_back() {
+        // the forcing switches off at t = 2, a jump in y'
+        let kinked = |t: f64, y: &[f64; 1], dy: &mut [f64; 1]| {
+            dy[0] = if t < 2.0 { (3.0 * t).cos() } else { -y[0] };
+        };
+        let opts = AdamsOptions { tol: Tolerance::uniform(1e-9, 1e-11), ..Default::default() };
+        let sol = vsvo_adams(&kinked, [0.0], [0.0, 6.0], opts).unwrap();
+        let order_over = |a: f64, b: f64| sol.t.iter().zip(&sol.order).filter(move |(t, _)| **t > a && **t <= b).map(|(_, q)| *q);
+        let before = order_over(1.0, 2.0).max().unwrap();
+        let at = order_over(2.0, 2.1).min().unwrap();
+        let after = order_over(4.0, 6.0).max().unwrap();
+        assert!(at < before && at < after, "orders {before}, {at}, {after}");
+        assert!(sol.rejected.iter().any(|(t, h)| *t < 2.0 && t + h > 2.0), "{:?}", sol.rejected);
+    }
+
+    #[test]
+    fn matches_fixed_step_abm4_at_order_four() {
+        let ((_, fixed), _) = solvers::multistep::solve_with_mode(
+            &oscillator, [1.0, 0.0], [0.0, 10.0], 1e-3, solvers::multistep::Startup::Rk4, solvers::multistep::Mode::Pece(1), CorrectorFailure::Abort, Limits::default()).unwrap();
+        let opts = AdamsOptions { tol: Tolerance::uniform(1e-11, 1e-13), max_order: 4, ..Default::default() };
+        let sol = vsvo_adams(&oscillator, [1.0, 0.0], [0.0, 10.0], opts).unwrap();
+        let (a, b) = (sol.y.last().unwrap(), fixed.last().unwrap());
+        assert!((a[0] - b[0]).abs() < 1e-8 && (a[1] - b[1]).abs() < 1e-8);
+    }
+}
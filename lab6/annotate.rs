@@ -0,0 +1,97 @@
+//!
+//! annotate.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Event markers for the lab figures: dashed vertical and horizontal lines
+//! at a time or threshold, shaded bands over a span of x, and free text at
+//! a data point, each with an optional label. They draw onto any f64 by f64
+//! chart after its series, in the run's PlotStyle, so equilibrium times,
+//! bifurcation thresholds and stability limits read the same everywhere
+//!
+
+#![allow(dead_code)]
+
+use plotters::coord::types::RangedCoordf64;
+use plotters::prelude::*;
+
+use crate::theme::PlotStyle;
+
+/// Pixel gap between a marker and its label
+const GAP: i32 = 4;
+
+#[derive(Clone, Debug)]
+pub enum Annotation {
+    /// dashed line across the chart at x, label along the top
+    Vertical { x: f64, label: String },
+    /// dashed line across the chart at y, label above it on the left
+    Horizontal { y: f64, label: String },
+    /// band between two x values, label along the bottom
+    Region { x: [f64; 2], label: String },
+    /// label with its top left corner at a data point
+    Text { at: (f64, f64), label: String },
+}
+
+impl Annotation {
+    pub fn vertical(x: f64, label: impl Into<String>) -> Annotation {
+        Annotation::Vertical { x, label: label.into() }
+    }
+
+    pub fn horizontal(y: f64, label: impl Into<String>) -> Annotation {
+        Annotation::Horizontal { y, label: label.into() }
+    }
+
+    pub fn region(lo: f64, hi: f64, label: impl Into<String>) -> Annotation {
+        Annotation::Region { x: [lo, hi], label: label.into() }
+    }
+
+    pub fn text(x: f64, y: f64, label: impl Into<String>) -> Annotation {
+        Annotation::Text { at: (x, y), label: label.into() }
+    }
+}
+
+///
+/// Draws every annotation onto chart, regions translucent so the curves
+/// beneath stay visible. Markers outside the chart's ranges are skipped
+///
+pub fn draw<DB>(
+    chart: &mut ChartContext<'_, DB, Cartesian2d<RangedCoordf64, RangedCoordf64>>,
+    theme: &PlotStyle,
+    annotations: &[Annotation],
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
+    let (xs, ys) = (chart.x_range(), chart.y_range());
+    let line = BLACK.mix(0.7).stroke_width(theme.thin);
+    let dash = 3 * theme.label_size as i32 / 4;
+    let below = -(theme.label_size as i32) - GAP;
+    let label = |at: (f64, f64), offset: (i32, i32), text: &str| {
+        EmptyElement::at(at) + Text::new(text.to_string(), offset, theme.label())
+    };
+
+    for annotation in annotations {
+        match annotation {
+            Annotation::Vertical { x, label: text } if xs.contains(x) => {
+                chart.draw_series(DashedLineSeries::new([(*x, ys.start), (*x, ys.end)], dash, dash / 2, line))?;
+                chart.draw_series([label((*x, ys.end), (GAP, GAP), text)])?;
+            }
+            Annotation::Horizontal { y, label: text } if ys.contains(y) => {
+                chart.draw_series(DashedLineSeries::new([(xs.start, *y), (xs.end, *y)], dash, dash / 2, line))?;
+                chart.draw_series([label((xs.start, *y), (GAP, below), text)])?;
+            }
+            Annotation::Region { x: [lo, hi], label: text } => {
+                let (lo, hi) = (lo.max(xs.start), hi.min(xs.end));
+                if lo >= hi {
+                    continue;
+                }
+                chart.draw_series([Rectangle::new([(lo, ys.start), (hi, ys.end)], BLACK.mix(0.08).filled())])?;
+                chart.draw_series([label((lo, ys.start), (GAP, below), text)])?;
+            }
+            Annotation::Text { at, label: text } if xs.contains(&at.0) && ys.contains(&at.1) => {
+                chart.draw_series([label(*at, (0, 0), text)])?;
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
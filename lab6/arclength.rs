@@ -0,0 +1,87 @@
+//!
+//! arclength.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Integration in arc length instead of time. With s the length of the
+//! curve (t, y / scale) in the extended space,
+//!
+//!   dt/ds = 1 / w,  dy/ds = f(t, y) / w,  w = sqrt(1 + |f / scale|^2)
+//!
+//! so a fixed ds takes short time steps exactly where the state moves
+//! fast, such as the fronts of a relaxation oscillation, and long ones on
+//! the slow branches, without any error estimate or global small dt
+//!
+
+#![allow(dead_code)]
+
+use solvers::{rk4_step, Limits, Solution};
+
+#[derive(Clone, Copy, Debug)]
+pub struct ArcOptions<const N: usize> {
+    pub ds: f64,
+    /// per-component length scale of the state in the arc length metric
+    pub scale: [f64; N],
+    pub limits: Limits,
+}
+
+impl<const N: usize> ArcOptions<N> {
+    pub fn new(ds: f64) -> ArcOptions<N> {
+        ArcOptions { ds, scale: [1.0; N], limits: Limits::default() }
+    }
+
+    pub fn scaled(ds: f64, scale: [f64; N]) -> ArcOptions<N> {
+        ArcOptions { scale, ..ArcOptions::new(ds) }
+    }
+}
+
+///
+/// Rk4 in s on the extended system, stopping exactly at ts[1] with a final
+/// time step over whatever remains. The points come back in t like every
+/// other solver, just unevenly spaced
+///
+pub fn solve<F, const N: usize>(rate: &F, ic: [f64; N], ts: [f64; 2], opts: ArcOptions<N>)
+    -> Result<Solution<N>, Box<dyn std::error::Error>>
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    let positive = |x: f64| x.is_finite() && x > 0.0;
+    if !positive(opts.ds) || opts.scale.iter().any(|s| !positive(*s)) {
+        return Err("arc length step and scales must be positive".into());
+    }
+
+    // the extended rate, time carried alongside the state
+    let extended = |t: f64, y: &[f64; N]| -> (f64, [f64; N]) {
+        let mut dy = [0.0; N];
+        rate(t, y, &mut dy);
+        let w = (1.0 + dy.iter().zip(&opts.scale).map(|(d, s)| (d / s).powi(2)).sum::<f64>()).sqrt();
+        (1.0 / w, dy.map(|d| d / w))
+    };
+    let shift = |t: f64, y: &[f64; N], h: f64, k: &(f64, [f64; N])| -> (f64, [f64; N]) {
+        (t + h * k.0, std::array::from_fn(|i| y[i] + h * k.1[i]))
+    };
+
+    let (t0, tf) = (ts[0], ts[1]);
+    let h = opts.ds;
+    let mut t = vec![t0];
+    let mut y = vec![ic];
+    let mut guard = opts.limits.start::<N>();
+    while t[t.len() - 1] < tf {
+        let (tc, yc) = (t[t.len() - 1], y[y.len() - 1]);
+        guard.step("arc length rk4", tc)?;
+
+        let k1 = extended(tc, &yc);
+        let (ta, ya) = shift(tc, &yc, 0.5 * h, &k1);
+        let k2 = extended(ta, &ya);
+        let (tb, yb) = shift(tc, &yc, 0.5 * h, &k2);
+        let k3 = extended(tb, &yb);
+        let (td, yd) = shift(tc, &yc, h, &k3);
+        let k4 = extended(td, &yd);
+
+        let tn = tc + (h / 6.0) * (k1.0 + 2.0 * k2.0 + 2.0 * k3.0 + k4.0);
+        if tn >= tf {
+            y.push(rk4_step(rate, tc, &yc, tf - tc));
+            t.push(tf);
+            break;
+        }
+        y.push(std::array::from_fn(|i| yc[i] + (h / 6.0) * (k1.1[i] + 2.0 * k2.1[i] + 2.0 * k3.1[i] + k4.1[i])));
+        t.push(tn);
+    }
+    Ok((t, y))
+}
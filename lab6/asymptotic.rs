@@ -0,0 +1,136 @@
+//!
+//! asymptotic.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Long-term behavior of each component, read from a trajectory the way
+//! the labs ask it to be read off a plot. Everything before t_skip is
+//! taken as transient and dropped; over the rest each component gets its
+//! time average (trapezoid rule, so uneven adaptive steps weigh by their
+//! length), its bounds, and either
+//!
+//!   - an oscillation: period, amplitude and envelope growth rate, or
+//!   - an exponential approach y(t) ~ y_inf + A exp(-lambda (t - t_skip)),
+//!     with y_inf from Aitken's extrapolation of three equally spaced
+//!     values and lambda from a least squares line through ln|y - y_inf|
+//!
+
+#![allow(dead_code)]
+
+use crate::peaks;
+use crate::trajectory::Trajectory;
+
+///
+/// |y - limit| ~ amplitude exp(-rate (t - t_skip)), r2 the fit's
+/// coefficient of determination in the log
+///
+#[derive(Clone, Copy, Debug)]
+pub struct Approach {
+    pub limit: f64,
+    pub rate: f64,
+    pub amplitude: f64,
+    pub r2: f64,
+}
+
+impl Approach {
+    pub fn time_constant(&self) -> f64 {
+        1.0 / self.rate
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Summary {
+    pub mean: f64,
+    pub min: f64,
+    pub max: f64,
+    /// Some for a component with at least two peaks after t_skip
+    pub period: Option<f64>,
+    pub amplitude: Option<f64>,
+    /// ln(amplitude) per unit time, negative for decay
+    pub envelope_rate: Option<f64>,
+    /// None for an oscillating component or one already settled
+    pub approach: Option<Approach>,
+}
+
+///
+/// Least squares line through (x, y), as (slope, intercept, r2)
+///
+fn line(x: &[f64], y: &[f64]) -> (f64, f64, f64) {
+    let n = x.len() as f64;
+    let (xbar, ybar) = (x.iter().sum::<f64>() / n, y.iter().sum::<f64>() / n);
+    let sxy: f64 = x.iter().zip(y).map(|(a, b)| (a - xbar) * (b - ybar)).sum();
+    let sxx: f64 = x.iter().map(|a| (a - xbar).powi(2)).sum();
+    let syy: f64 = y.iter().map(|b| (b - ybar).powi(2)).sum();
+    let slope = sxy / sxx;
+    let r2 = if syy > 0.0 { sxy * sxy / (sxx * syy) } else { 1.0 };
+    (slope, ybar - slope * xbar, r2)
+}
+
+///
+/// Exponential approach of (t, y) after t0, None when the three point
+/// extrapolation is degenerate (a settled or non-monotone component) or
+/// too few points remain clear of roundoff about the limit
+///
+fn approach(traj: &Trajectory<1>, t0: f64) -> Result<Option<Approach>, Box<dyn std::error::Error>> {
+    let (t, y) = (traj.time(), traj.component(0));
+    let tf = t[t.len() - 1];
+    let at = |s: f64| traj.at(s).map(|v| v[0]);
+    let (y0, y1, y2) = (at(t0)?, at(0.5 * (t0 + tf))?, at(tf)?);
+    let scale = y.iter().fold(0.0_f64, |m, v| m.max(v.abs())).max(f64::MIN_POSITIVE);
+    let den = y0 - 2.0 * y1 + y2;
+    // monotone geometric decay has successive gaps of one sign, shrinking
+    if den.abs() <= 1e-12 * scale || (y1 - y0) * (y2 - y1) <= 0.0 || (y2 - y1).abs() >= (y1 - y0).abs() {
+        return Ok(None);
+    }
+    let limit = y2 - (y2 - y1).powi(2) / den;
+
+    let floor = 1e-9 * scale;
+    let (x, l): (Vec<f64>, Vec<f64>) = t.iter().zip(y)
+        .filter(|(s, v)| **s >= t0 && (**v - limit).abs() > floor)
+        .map(|(s, v)| (s - t0, (v - limit).abs().ln()))
+        .unzip();
+    if x.len() < 3 {
+        return Ok(None);
+    }
+    let (slope, intercept, r2) = line(&x, &l);
+    Ok(Some(Approach { limit, rate: -slope, amplitude: intercept.exp(), r2 }))
+}
+
+///
+/// Summary of every component of traj over [t_skip, tf]. window is the
+/// number of trailing cycles the period and amplitude average
+///
+pub fn summarize<const N: usize>(traj: &Trajectory<N>, t_skip: f64, window: usize)
+    -> Result<[Summary; N], Box<dyn std::error::Error>> {
+    let t = traj.time();
+    let start = t.partition_point(|s| *s < t_skip);
+    if t.len() < start + 3 {
+        return Err(format!("fewer than three points after t_skip = {t_skip} to summarize").into());
+    }
+    let t = &t[start..];
+    let span = t[t.len() - 1] - t[0];
+
+    let mut out = Vec::with_capacity(N);
+    for j in 0..N {
+        let y = &traj.component(j)[start..];
+        let area: f64 = t.windows(2).zip(y.windows(2)).map(|(s, v)| 0.5 * (s[1] - s[0]) * (v[0] + v[1])).sum();
+        let (min, max) = y.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| (lo.min(*v), hi.max(*v)));
+        let osc = peaks::oscillation(t, y, window);
+
+        let approach = match osc.period {
+            Some(_) => None,
+            None => {
+                let rows: Vec<[f64; 1]> = y.iter().map(|v| [*v]).collect();
+                approach(&Trajectory::from_rows(t, &rows), t[0])?
+            }
+        };
+        out.push(Summary {
+            mean: area / span,
+            min,
+            max,
+            period: osc.period,
+            amplitude: osc.amplitude,
+            envelope_rate: osc.rate,
+            approach,
+        });
+    }
+    Ok(std::array::from_fn(|j| out[j]))
+}
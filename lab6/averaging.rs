@@ -0,0 +1,100 @@
+//!
+//! averaging.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! First order method of averaging for weakly nonlinear oscillators
+//! x'' + x = h(x, x'), with the small parameter folded into h. Writing
+//! x = r cos(t + phi), x' = -r sin(t + phi) and averaging over one fast
+//! cycle theta = t + phi gives the slow flow
+//!
+//!   r'   = -<h(r cos, -r sin) sin theta>
+//!   phi' = -<h(r cos, -r sin) cos theta> / r
+//!
+//! The averages are taken by the trapezoid rule in theta, which is
+//! spectrally accurate for periodic integrands, so any h works without
+//! working the integrals by hand
+//!
+
+#![allow(dead_code)]
+
+use solvers::{self, Solution};
+
+///
+/// Quadrature points per fast cycle
+///
+pub const SAMPLES: usize = 64;
+
+///
+/// (r', phi') of the averaged equations at amplitude r
+///
+pub fn slow_flow<H>(h: &H, r: f64) -> (f64, f64)
+where H: Fn(f64, f64) -> f64 {
+    let dtheta = 2.0 * std::f64::consts::PI / SAMPLES as f64;
+    let (mut s, mut c) = (0.0, 0.0);
+    for k in 0..SAMPLES {
+        let theta = k as f64 * dtheta;
+        let force = h(r * theta.cos(), -r * theta.sin());
+        s += force * theta.sin();
+        c += force * theta.cos();
+    }
+    let (s, c) = (s / SAMPLES as f64, c / SAMPLES as f64);
+    (-s, if r > 0.0 { -c / r } else { 0.0 })
+}
+
+///
+/// Slow variables (r, phi) over ts by rk4 on the averaged equations. The
+/// slow flow varies on the scale of the nonlinearity, so dt can be far
+/// larger than the fast period
+///
+pub fn amplitude<H>(h: &H, r0: f64, phi0: f64, ts: [f64; 2], dt: f64) -> Result<Solution<2>, Box<dyn std::error::Error>>
+where H: Fn(f64, f64) -> f64 {
+    let rate = |_t: f64, z: &[f64; 2], dz: &mut [f64; 2]| {
+        let (dr, dphi) = slow_flow(h, z[0]);
+        dz[0] = dr;
+        dz[1] = dphi;
+    };
+    Ok(solvers::Method::Rk4.solve(&rate, [r0, phi0], ts, dt)?.0)
+}
+
+///
+/// Slow variables for a start at (x0, v0), r = |(x0, v0)| and
+/// phi chosen so x = r cos(t + phi) passes through the point at t = 0
+///
+pub fn polar(x0: f64, v0: f64) -> (f64, f64) {
+    (x0.hypot(v0), (-v0).atan2(x0))
+}
+
+///
+/// x(t) = r cos(t + phi) evaluated at the times of the slow solution, or
+/// at any times given the slow variables there
+///
+pub fn reconstruct(t: &[f64], slow: &[[f64; 2]]) -> Vec<f64> {
+    t.iter().zip(slow).map(|(ti, z)| z[0] * (ti + z[1]).cos()).collect()
+}
+
+///
+/// Nonzero root of r' = 0 on [lo, hi] by bisection, the averaged limit
+/// cycle amplitude. None when r' does not change sign on the bracket
+///
+pub fn limit_amplitude<H>(h: &H, lo: f64, hi: f64) -> Option<f64>
+where H: Fn(f64, f64) -> f64 {
+    let g = |r: f64| slow_flow(h, r).0;
+    let (mut a, mut b) = (lo, hi);
+    let (mut ga, gb) = (g(a), g(b));
+    if ga * gb > 0.0 {
+        return None;
+    }
+    for _ in 0..200 {
+        let m = 0.5 * (a + b);
+        let gm = g(m);
+        if ga * gm <= 0.0 {
+            b = m;
+        } else {
+            a = m;
+            ga = gm;
+        }
+        if b - a <= 1e-14 * m.abs() {
+            break;
+        }
+    }
+    Some(0.5 * (a + b))
+}
@@ -0,0 +1,103 @@
+//!
+//! axes.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Axis settings for the lab figures: how tick labels are written, fixed
+//! limits in place of the data range, and the number of minor gridlines
+//! between major ticks. Auto labels switch to scientific notation past 1e4
+//! (and below 1e-2), so a population axis reads 1.2e5 rather than
+//! 120000.0, and carry just enough digits to tell neighbouring ticks apart
+//!
+
+#![allow(dead_code)]
+
+use std::ops::Range;
+
+/// Roughly how many major ticks plotters puts on an axis
+const MAJOR_TICKS: f64 = 10.0;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Ticks {
+    /// plain or scientific by magnitude, digits from the tick spacing
+    Auto,
+    /// fixed point with this many decimals
+    Plain(usize),
+    /// mantissa with this many decimals, then e and the exponent
+    Scientific(usize),
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Axis {
+    pub ticks: Ticks,
+    pub limits: Option<(f64, f64)>,
+    /// light gridlines between two major ones
+    pub minor: usize,
+}
+
+impl Default for Axis {
+    fn default() -> Axis {
+        Axis { ticks: Ticks::Auto, limits: None, minor: 4 }
+    }
+}
+
+/// Decimals that separate ticks step apart on numbers of size 10^exponent
+fn decimals(exponent: f64, step: f64) -> usize {
+    (exponent - step.log10().floor()).clamp(0.0, 6.0) as usize
+}
+
+impl Axis {
+    pub fn ticks(mut self, ticks: Ticks) -> Axis {
+        self.ticks = ticks;
+        self
+    }
+
+    pub fn limits(mut self, lo: f64, hi: f64) -> Axis {
+        self.limits = Some((lo, hi));
+        self
+    }
+
+    pub fn minor(mut self, minor: usize) -> Axis {
+        self.minor = minor;
+        self
+    }
+
+    ///
+    /// The fixed limits if set, otherwise lo..hi from the data
+    ///
+    pub fn range(&self, lo: f64, hi: f64) -> Range<f64> {
+        let (lo, hi) = self.limits.unwrap_or((lo, hi));
+        lo..hi
+    }
+
+    ///
+    /// Ticks resolved for an axis spanning range, Auto replaced by what it
+    /// picks there
+    ///
+    pub fn resolve(&self, range: &Range<f64>) -> Ticks {
+        if self.ticks != Ticks::Auto {
+            return self.ticks;
+        }
+        let magnitude = range.start.abs().max(range.end.abs());
+        let step = (range.end - range.start).abs() / MAJOR_TICKS;
+        if !(magnitude > 0.0 && step > 0.0 && magnitude.is_finite()) {
+            return Ticks::Plain(1);
+        }
+        if !(1e-2..1e4).contains(&magnitude) {
+            Ticks::Scientific(decimals(magnitude.log10().floor(), step))
+        } else {
+            Ticks::Plain(decimals(0.0, step))
+        }
+    }
+
+    ///
+    /// Tick label writer for an axis spanning range, to hand to plotters'
+    /// x_label_formatter or y_label_formatter
+    ///
+    pub fn formatter(&self, range: &Range<f64>) -> impl Fn(&f64) -> String {
+        let ticks = self.resolve(range);
+        move |v: &f64| match ticks {
+            Ticks::Plain(d) => format!("{v:.d$}"),
+            Ticks::Scientific(d) => format!("{v:.d$e}"),
+            Ticks::Auto => format!("{v}"),
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//!
+//! batch.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Fixed step rk4 over M copies of one system at once, typically the same
+//! model at M parameter values. The batch is stored structure-of-arrays,
+//! one Vec of M lanes per state component, and every stage is a plain loop
+//! over lanes, so the per-step overhead is paid once for the batch and the
+//! lane loops vectorize. The rate fills whole components at a time; lane k
+//! of the result is bitwise the state Method::Rk4 gives copy k alone
+//!
+
+#![allow(dead_code)]
+
+use solvers::{self, Limits};
+
+use crate::system::OdeSystem;
+
+///
+/// M states of an N component system, component i of lane k in y[i][k]
+///
+#[derive(Clone, Debug)]
+pub struct Batch<const N: usize> {
+    pub y: [Vec<f64>; N],
+}
+
+impl<const N: usize> Batch<N> {
+    pub fn from_rows(rows: &[[f64; N]]) -> Batch<N> {
+        Batch { y: std::array::from_fn(|i| rows.iter().map(|r| r[i]).collect()) }
+    }
+
+    pub fn lanes(&self) -> usize {
+        self.y.first().map_or(0, Vec::len)
+    }
+
+    pub fn lane(&self, k: usize) -> [f64; N] {
+        std::array::from_fn(|i| self.y[i][k])
+    }
+
+    pub fn rows(&self) -> Vec<[f64; N]> {
+        (0..self.lanes()).map(|k| self.lane(k)).collect()
+    }
+}
+
+/// out = y + h k, lane by lane
+fn axpy(out: &mut [Vec<f64>], y: &[Vec<f64>], h: f64, k: &[Vec<f64>]) {
+    for ((o, y), k) in out.iter_mut().zip(y).zip(k) {
+        for ((o, y), k) in o.iter_mut().zip(y).zip(k) {
+            *o = y + h * k;
+        }
+    }
+}
+
+///
+/// Every lane through ts by classical rk4 with a fixed dt. The rate maps
+/// a whole batch state to its derivative; every point, the initial one
+/// included, is handed to observe. The step count is checked against
+/// limits first and the wall clock as it runs. Returns the final batch
+///
+pub fn solve_with<F, O, const N: usize>(rate: &F, ics: &[[f64; N]], ts: [f64; 2], dt: f64, limits: Limits, mut observe: O)
+    -> Result<Batch<N>, Box<dyn std::error::Error>>
+where
+    F: Fn(f64, &[Vec<f64>; N], &mut [Vec<f64>; N]),
+    O: FnMut(f64, &Batch<N>)
+{
+    solvers::planned_steps("batch", ts, dt, limits)?;
+    let mut guard = limits.memory(usize::MAX).start::<N>();
+    let mut batch = Batch::from_rows(ics);
+    let lanes = batch.lanes();
+    let zeros = || -> [Vec<f64>; N] { std::array::from_fn(|_| vec![0.0; lanes]) };
+    let (mut k1, mut k2, mut k3, mut k4, mut stage) = (zeros(), zeros(), zeros(), zeros(), zeros());
+    observe(ts[0], &batch);
+
+    for (t, h, next) in solvers::grid(ts, dt) {
+        guard.step("batch", t)?;
+        let y = &mut batch.y;
+        rate(t, y, &mut k1);
+        axpy(&mut stage, y, 0.5 * h, &k1);
+        rate(t + 0.5 * h, &stage, &mut k2);
+        axpy(&mut stage, y, 0.5 * h, &k2);
+        rate(t + 0.5 * h, &stage, &mut k3);
+        axpy(&mut stage, y, h, &k3);
+        rate(t + h, &stage, &mut k4);
+        // grouped as in solvers::rk4_step so the lanes round identically
+        let w = h / 6.0;
+        for i in 0..N {
+            for ((((y, a), b), c), d) in y[i].iter_mut().zip(&k1[i]).zip(&k2[i]).zip(&k3[i]).zip(&k4[i]) {
+                *y += w * (a + 2.0 * b + 2.0 * c + d);
+            }
+        }
+        observe(next, &batch);
+    }
+    Ok(batch)
+}
+
+///
+/// solve_with on a system, every lane a copy of it. The rate is taken lane
+/// by lane, so this pays the per lane call a batch rate avoids
+///
+pub fn solve_with_system<O, const N: usize>(system: &impl OdeSystem, ics: &[[f64; N]], ts: [f64; 2], dt: f64, limits: Limits, observe: O)
+    -> Result<Batch<N>, Box<dyn std::error::Error>>
+where O: FnMut(f64, &Batch<N>) {
+    let rhs = system.rhs::<N>()?;
+    let rate = |t: f64, y: &[Vec<f64>; N], dy: &mut [Vec<f64>; N]| {
+        let mut d = [0.0; N];
+        for k in 0..y[0].len() {
+            rhs(t, &std::array::from_fn(|i| y[i][k]), &mut d);
+            for (di, dk) in dy.iter_mut().zip(d) {
+                di[k] = dk;
+            }
+        }
+    };
+    solve_with(&rate, ics, ts, dt, limits, observe)
+}
@@ -0,0 +1,312 @@
+//!
+//! boundary_value.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Engineering boundary value problems with exact solutions, each solved by
+//! shooting and by finite differences: the temperature along a pin fin
+//! with an adiabatic tip, and the deflection of a simply supported beam
+//! under a uniform load, the latter split into two second order problems
+//! (moment, then deflection) for the finite difference solver. Last, the
+//! inverse problem: recovering the heat source along a rod from noisy
+//! temperatures, regularized by Tikhonov with the L-curve
+//!
+
+#![allow(clippy::cast_possible_truncation)]
+#![allow(clippy::cast_sign_loss)]
+#![allow(clippy::cast_precision_loss)]
+#![allow(clippy::missing_panics_doc)]
+#![allow(clippy::missing_errors_doc)]
+
+use plotters::prelude::*;
+use plotters_bitmap::BitMapBackend;
+
+mod bvp;
+mod linalg;
+mod noise;
+mod problems;
+mod regularize;
+mod report;
+mod run_context;
+mod state;
+mod theme;
+
+use bvp::{finite_difference, shoot, Boundary, ShootingOptions};
+use linalg::Matrix;
+use noise::Noise;
+use problems::{Beam, Fin};
+use regularize::Tikhonov;
+use run_context::RunContext;
+
+///
+/// Aluminium pin, 5 mm diameter, in air
+///
+fn fin(length: f64) -> Fin {
+    let d: f64 = 5e-3;
+    Fin {
+        h: 25.0,
+        k: 200.0,
+        perimeter: std::f64::consts::PI * d,
+        area: 0.25 * std::f64::consts::PI * d * d,
+        length,
+        theta_base: 80.0,
+    }
+}
+
+///
+/// Observed order between successive grid doublings
+///
+fn order(errors: &[f64], k: usize) -> String {
+    if k == 0 { "-".to_string() } else { format!("{:.2}", (errors[k - 1] / errors[k]).log2()) }
+}
+
+///
+/// Fin temperature by finite differences at doubling n and by shooting on
+/// the unknown base slope, with the base heat rate from each against the
+/// exact one. Profiles for a few lengths are plotted against the exact
+/// curve
+///
+fn fin_study(ctx: &mut RunContext, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let f = fin(0.1);
+    let m = f.m();
+    ctx.config("fin", format!("{f:?}, m = {m:.4} 1/m"));
+    ctx.solver("bvp::finite_difference", "central, ghost node slope");
+    ctx.solver("bvp::shoot", "newton on theta'(0), rk4");
+
+    let ns = [10, 20, 40, 80, 160];
+    let mut errors = Vec::new();
+    println!("fin, m L = {:.3}, efficiency {:.4}, exact base heat {:.6} W", m * f.length, f.efficiency(), f.heat_rate());
+    println!("{:>8} {:>12} {:>8} {:>14}", "n", "max error", "order", "heat rate");
+    for (k, &n) in ns.iter().enumerate() {
+        let (x, theta) = ctx.time("fin fd", || {
+            finite_difference(|_| 0.0, |_| m * m, |_| 0.0, [0.0, f.length], n, Boundary::Value(f.theta_base), Boundary::Slope(0.0))
+        })?;
+        errors.push(x.iter().zip(&theta).fold(0.0_f64, |e, (xi, t)| e.max((t - f.exact(*xi)).abs())));
+        // one sided second order slope at the base
+        let h = x[1] - x[0];
+        let slope = (-3.0 * theta[0] + 4.0 * theta[1] - theta[2]) / (2.0 * h);
+        println!("{:>8} {:>12.4e} {:>8} {:>14.6}", n, errors[k], order(&errors, k), -f.k * f.area * slope);
+    }
+
+    let rate = |_x: f64, y: &[f64; 2], dy: &mut [f64; 2]| {
+        dy[0] = y[1];
+        dy[1] = m * m * y[0];
+    };
+    let (s, (x, y)) = ctx.time("fin shoot", || {
+        shoot(&rate, [0.0, f.length], |s: &[f64]| [f.theta_base, s[0]], |end: &[f64; 2]| vec![end[1]], &[0.0], ShootingOptions::default())
+    })?;
+    let err = x.iter().zip(&y).fold(0.0_f64, |e, (xi, t)| e.max((t[0] - f.exact(*xi)).abs()));
+    println!("{:>8} {:>12.4e} {:>8} {:>14.6}", "shooting", err, "-", -f.k * f.area * s[0]);
+
+    let lengths = [0.02, 0.05, 0.1, 0.2];
+    let path = ctx.path(name);
+    let theme = ctx.style();
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?;
+    let root = theme.stamp(&root)?;
+    let mut chart = ChartBuilder::on(&root)
+        .caption(format!("Pin fin temperature excess, m = {m:.2} 1/m, fd n = 40 (solid), exact (dashed)"), theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 55)
+        .set_label_area_size(LabelAreaPosition::Bottom, 50)
+        .build_cartesian_2d(0.0..lengths[lengths.len() - 1], 0.0..f.theta_base)?;
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("x (m)").y_desc("T - T_inf (K)").draw()?;
+
+    for (i, &l) in lengths.iter().enumerate() {
+        let g = fin(l);
+        let color = theme.color(i);
+        let (x, theta) = finite_difference(|_| 0.0, |_| m * m, |_| 0.0, [0.0, l], 40, Boundary::Value(g.theta_base), Boundary::Slope(0.0))?;
+        chart.draw_series(LineSeries::new(x.iter().copied().zip(theta), color.stroke_width(theme.line)))?
+            .label(format!("L = {l} m, efficiency {:.3}", g.efficiency()))
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(theme.line)));
+        chart.draw_series(DashedLineSeries::new(x.iter().map(|xi| (*xi, g.exact(*xi))), 6, 4, color.mix(0.6).into()))?;
+    }
+
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?;
+
+    root.present()?;
+    Ok(())
+}
+
+///
+/// Beam deflection by finite differences in two passes, M'' = q then
+/// EI w'' = M, each with zero end values, and by shooting on the fourth
+/// order system for the unknown w'(0) and w'''(0)
+///
+fn beam_study(ctx: &mut RunContext) -> Result<(), Box<dyn std::error::Error>> {
+    // 3 m steel W-section under 10 kN/m
+    let beam = Beam { e: 200e9, i: 8.0e-6, q: 1e4, length: 3.0 };
+    let (l, ei, q) = (beam.length, beam.rigidity(), beam.q);
+    ctx.config("beam", format!("{beam:?}"));
+
+    println!("beam, exact midspan deflection {:.6e} m", beam.max_deflection());
+    println!("{:>8} {:>12} {:>8} {:>14}", "n", "max error", "order", "midspan");
+    let ns = [10, 20, 40, 80, 160];
+    let mut errors = Vec::new();
+    for (k, &n) in ns.iter().enumerate() {
+        let (x, moment) = finite_difference(|_| 0.0, |_| 0.0, |_| q, [0.0, l], n, Boundary::Value(0.0), Boundary::Value(0.0))?;
+        let h = x[1] - x[0];
+        let (_, w) = finite_difference(|_| 0.0, |_| 0.0, |xi| moment[((xi / h).round() as usize).min(n)] / ei, [0.0, l], n,
+            Boundary::Value(0.0), Boundary::Value(0.0))?;
+        errors.push(x.iter().zip(&w).fold(0.0_f64, |e, (xi, wi)| e.max((wi - beam.exact(*xi)).abs())));
+        println!("{:>8} {:>12.4e} {:>8} {:>14.6e}", n, errors[k], order(&errors, k), w[n / 2]);
+    }
+
+    let rate = |_x: f64, y: &[f64; 4], dy: &mut [f64; 4]| {
+        dy[0] = y[1];
+        dy[1] = y[2];
+        dy[2] = y[3];
+        dy[3] = q / ei;
+    };
+    let (s, (x, y)) = ctx.time("beam shoot", || {
+        shoot(&rate, [0.0, l], |s: &[f64]| [0.0, s[0], 0.0, s[1]], |end: &[f64; 4]| vec![end[0], end[2]], &[0.0, 0.0], ShootingOptions::default())
+    })?;
+    let err = x.iter().zip(&y).fold(0.0_f64, |e, (xi, w)| e.max((w[0] - beam.exact(*xi)).abs()));
+    let mid = y[y.len() / 2][0];
+    println!("{:>8} {:>12.4e} {:>8} {:>14.6e}", "shooting", err, "-", mid);
+    println!("shooting slopes w'(0) = {:.6e}, exact {:.6e}; w'''(0) = {:.6e}, exact {:.6e}",
+        s[0], q * l.powi(3) / (24.0 * ei), s[1], -q * l / (2.0 * ei));
+    Ok(())
+}
+
+///
+/// Source recovery: u'' = f on [0, 1] with u = 0 at both ends, so
+/// temperatures u are the source f integrated twice and f is their second
+/// derivative, which amplifies measurement noise without bound as the grid
+/// refines. Temperatures from the finite difference solver get 1% noise
+/// and f is recovered by Tikhonov with a first difference penalty, lambda
+/// from the L-curve corner and compared against the best lambda in
+/// hindsight and against no regularization
+///
+fn source_study(ctx: &mut RunContext, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let n = 100;
+    let sigma = 0.01;
+    let source = |x: f64| 40.0 * (-((x - 0.3) / 0.06).powi(2)).exp() - 25.0 * (-((x - 0.7) / 0.1).powi(2)).exp();
+    ctx.solver("regularize::Tikhonov", "normal equations, first difference penalty");
+    ctx.config("source", format!("n = {n}, relative noise {sigma}, seed 11"));
+
+    let (x, u) = finite_difference(|_| 0.0, |_| 0.0, source, [0.0, 1.0], n, Boundary::Value(0.0), Boundary::Value(0.0))?;
+    let h = x[1] - x[0];
+    let interior = &x[1..n];
+    let truth: Vec<f64> = interior.iter().map(|xi| source(*xi)).collect();
+    let scale = u.iter().fold(0.0_f64, |m, v| m.max(v.abs()));
+    let mut noise = Noise::new(11);
+    let observed: Vec<f64> = u[1..n].iter().map(|v| v + sigma * scale * noise.normal()).collect();
+
+    // forward map f -> u, h^2 T^-1 with T the interior second difference
+    let m = n - 1;
+    let mut t = Matrix::zeros(m, m);
+    for i in 0..m {
+        t[(i, i)] = -2.0;
+        if i > 0 {
+            t[(i, i - 1)] = 1.0;
+            t[(i - 1, i)] = 1.0;
+        }
+    }
+    let lu = t.lu()?;
+    let mut a = Matrix::zeros(m, m);
+    for j in 0..m {
+        let mut e = vec![0.0; m];
+        e[j] = h * h;
+        a.set_column(j, &lu.solve(&e));
+    }
+
+    let problem = Tikhonov::new(a, &observed, regularize::difference_operator(m, 1)?)?;
+    // 8 lambdas a decade, so 16 steps along the sweep is a factor of 100
+    let per_decade = 8;
+    let curve = ctx.time("l-curve", || problem.l_curve(1e-8, 1e2, 10 * per_decade + 1))?;
+    let error = |f: &[f64]| (f.iter().zip(&truth).map(|(a, b)| (a - b).powi(2)).sum::<f64>() / m as f64).sqrt();
+    let knee = regularize::corner(&curve).ok_or("no corner on the l-curve")?;
+    let best = (0..curve.len()).min_by(|&i, &j| error(&curve[i].x).total_cmp(&error(&curve[j].x))).unwrap_or(knee);
+    let (naive, bare) = problem.solve(0.0)?;
+
+    println!("source recovery, n = {n}, noise {:.3e} ({}% of max u)", sigma * scale, sigma * 100.0);
+    println!("{:>14} {:>12} {:>12} {:>12} {:>12} {:>12}", "choice", "lambda", "residual", "|L f|", "condition", "rms error");
+    println!("{:>14} {:>12} {:>12} {:>12} {:>12.4e} {:>12.4e}", "none", 0, "-", "-", bare.condition, error(&naive));
+    let choices = [("l-curve", knee), ("l-curve / 100", knee.saturating_sub(2 * per_decade)), ("l-curve * 100", (knee + 2 * per_decade).min(curve.len() - 1))];
+    for (label, i) in [choices[0], ("best", best), choices[1], choices[2]] {
+        let p = &curve[i];
+        println!("{:>14} {:>12.4e} {:>12.4e} {:>12.4e} {:>12.4e} {:>12.4e}", label, p.lambda, p.residual, p.seminorm, p.condition, error(&p.x));
+    }
+
+    let path = ctx.path(name);
+    let theme = ctx.style();
+    let root = BitMapBackend::new(&path, theme.panels).into_drawing_area();
+    root.fill(&WHITE)?;
+    let root = theme.stamp(&root)?;
+    let panels = root.split_evenly((1, 2));
+
+    let log: Vec<(f64, f64)> = curve.iter().map(|p| (p.residual.log10(), p.seminorm.max(1e-16).log10())).collect();
+    let (xlo, xhi) = log.iter().fold((f64::MAX, f64::MIN), |(lo, hi), p| (lo.min(p.0), hi.max(p.0)));
+    let (ylo, yhi) = log.iter().fold((f64::MAX, f64::MIN), |(lo, hi), p| (lo.min(p.1), hi.max(p.1)));
+    let mut chart = ChartBuilder::on(&panels[0])
+        .caption("L-curve [log-log scale]", theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 70)
+        .set_label_area_size(LabelAreaPosition::Bottom, 50)
+        .build_cartesian_2d(xlo - 0.2..xhi + 0.2, ylo - 0.5..yhi + 0.5)?;
+    chart.configure_mesh()
+        .label_style(theme.label())
+        .axis_desc_style(theme.label())
+        .x_desc("|A f - u|")
+        .y_desc("|L f|")
+        .x_label_formatter(&|v| format!("1e{:.1}", v))
+        .y_label_formatter(&|v| format!("1e{:.0}", v))
+        .draw()?;
+    chart.draw_series(LineSeries::new(log.iter().copied(), BLACK.stroke_width(theme.thin)))?;
+    chart.draw_series(log.iter().map(|p| Circle::new(*p, 2, BLACK.filled())))?;
+    for (k, (label, i)) in [("l-curve corner", knee), ("best", best)].into_iter().enumerate() {
+        let color = theme.color(k);
+        chart.draw_series(std::iter::once(Circle::new(log[i], 6, color.filled())))?
+            .label(format!("{label}, lambda = {:.2e}", curve[i].lambda))
+            .legend(move |(x, y)| Circle::new((x + 10, y), 5, color.filled()));
+    }
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?;
+
+    let (lo, hi) = truth.iter().fold((0.0_f64, 0.0_f64), |(lo, hi), v| (lo.min(*v), hi.max(*v)));
+    let pad = 0.3 * (hi - lo);
+    let mut chart = ChartBuilder::on(&panels[1])
+        .caption("Recovered source f", theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 55)
+        .set_label_area_size(LabelAreaPosition::Bottom, 50)
+        .build_cartesian_2d(0.0..1.0, lo - pad..hi + pad)?;
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("x").y_desc("f").draw()?;
+    let clip = |v: f64| v.clamp(lo - pad, hi + pad);
+    chart.draw_series(LineSeries::new(interior.iter().zip(&naive).map(|(xi, f)| (*xi, clip(*f))), BLACK.mix(0.25)))?
+        .label("no regularization")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLACK.mix(0.25)));
+    for (k, (label, i)) in choices.into_iter().enumerate() {
+        let color = theme.color(k);
+        chart.draw_series(LineSeries::new(interior.iter().copied().zip(curve[i].x.iter().copied()), color.stroke_width(theme.line)))?
+            .label(format!("{label}, lambda = {:.2e}", curve[i].lambda))
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(theme.line)));
+    }
+    chart.draw_series(DashedLineSeries::new(interior.iter().copied().zip(truth.iter().copied()), 6, 4, BLACK.stroke_width(theme.line)))?
+        .label("exact")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLACK.stroke_width(theme.line)));
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?;
+
+    root.present()?;
+    Ok(())
+}
+
+fn main() {
+    let mut ctx = RunContext::new("output", "boundary_value")
+        .expect("failed to create output directory");
+    let _ = fin_study(&mut ctx, "fin.png");
+    let _ = beam_study(&mut ctx);
+    let _ = source_study(&mut ctx, "source.png");
+    let _ = ctx.write_report();
+    let _ = ctx.write_manifest();
+}
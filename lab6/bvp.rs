@@ -0,0 +1,182 @@
+//!
+//! bvp.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Two point boundary value problems on [a, b]. Shooting turns the BVP into
+//! an initial value problem: the unknown initial components are chosen by
+//! Newton (finite difference Jacobian) so the state reached at b satisfies
+//! the right boundary conditions, with every trial integrated by rk4. The
+//! finite difference solver handles the linear second order case
+//!
+//!   y'' = p(x) y' + q(x) y + r(x)
+//!
+//! with central differences on a uniform grid, a value or slope condition
+//! at each end (slopes through a ghost node, so still second order), and
+//! one tridiagonal solve
+//!
+
+#![allow(dead_code)]
+
+use solvers::{rk4_step, Limits, Method, Solution};
+
+use crate::linalg::{self, Matrix};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Boundary {
+    /// y = v
+    Value(f64),
+    /// y' = v
+    Slope(f64),
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ShootingOptions {
+    pub steps: usize,
+    pub tol: f64,
+    pub max_iter: usize,
+    /// each trial integration, and the Newton iterations, checked against these
+    pub limits: Limits,
+}
+
+impl Default for ShootingOptions {
+    fn default() -> ShootingOptions {
+        ShootingOptions { steps: 1000, tol: 1e-10, max_iter: 30, limits: Limits::default() }
+    }
+}
+
+///
+/// Unknown initial parameters s, so that ic(s) integrated over span gives
+/// residual(y(b)) = 0. Returns s and the trajectory it produces. Every
+/// trial runs under opts.limits, and each Newton iteration counts against
+/// them too
+///
+pub fn shoot<F, I, R, const N: usize>(rate: &F, span: [f64; 2], ic: I, residual: R, guess: &[f64], opts: ShootingOptions)
+    -> Result<(Vec<f64>, Solution<N>), Box<dyn std::error::Error>>
+where
+    F: Fn(f64, &[f64; N], &mut [f64; N]),
+    I: Fn(&[f64]) -> [f64; N],
+    R: Fn(&[f64; N]) -> Vec<f64>
+{
+    let dt = (span[1] - span[0]) / opts.steps as f64;
+    let miss = |s: &[f64]| {
+        let end = solvers::integrate_with(rate, rk4_step, ic(s), span, dt, opts.limits, |_, _| {})?;
+        Ok::<_, Box<dyn std::error::Error>>(residual(&end))
+    };
+    let k = guess.len();
+    let mut s = guess.to_vec();
+    let mut guard = opts.limits.start::<0>();
+    for _ in 0..opts.max_iter {
+        guard.iteration("shooting newton")?;
+        let r = miss(&s)?;
+        if r.len() != k {
+            return Err(format!("shooting: {} residuals for {k} unknowns", r.len()).into());
+        }
+        if r.iter().all(|v| v.abs() < opts.tol) {
+            return Ok((s.clone(), Method::Rk4.solve_limited(rate, ic(&s), span, dt, opts.limits)?.0));
+        }
+        let mut jac = Matrix::zeros(k, k);
+        for j in 0..k {
+            let h = 1e-7 * (1.0 + s[j].abs());
+            let mut sp = s.clone();
+            sp[j] += h;
+            let col: Vec<f64> = miss(&sp)?.iter().zip(&r).map(|(a, b)| (a - b) / h).collect();
+            jac.set_column(j, &col);
+        }
+        let (delta, report) = jac.solve_report(&r)?;
+        linalg::check_condition("shooting newton", report.condition);
+        for (x, d) in s.iter_mut().zip(delta) {
+            *x -= d;
+        }
+        if s.iter().any(|x| !x.is_finite()) {
+            return Err("shooting: newton left the finite numbers".into());
+        }
+    }
+    Err(format!("shooting: residual above {} after {} newton iterations", opts.tol, opts.max_iter).into())
+}
+
+///
+/// Nodes x_0 = a .. x_n = b and y at each, for y'' = p y' + q y + r. Slope
+/// conditions at both ends need q != 0, else y is only fixed up to a
+/// constant
+///
+pub fn finite_difference<P, Q, R>(p: P, q: Q, r: R, span: [f64; 2], n: usize, left: Boundary, right: Boundary)
+    -> Result<(Vec<f64>, Vec<f64>), Box<dyn std::error::Error>>
+where
+    P: Fn(f64) -> f64,
+    Q: Fn(f64) -> f64,
+    R: Fn(f64) -> f64
+{
+    if n < 2 || span[1] <= span[0] {
+        return Err(format!("finite difference: need n >= 2 and a < b, got n = {n}, span {span:?}").into());
+    }
+    // the grid, three diagonals and the right hand side
+    Limits::default().check::<4>("finite difference", n)?;
+    let h = (span[1] - span[0]) / n as f64;
+    let x: Vec<f64> = (0..=n).map(|i| span[0] + i as f64 * h).collect();
+
+    // row i: (1 + h p / 2) y_{i-1} - (2 + h^2 q) y_i + (1 - h p / 2) y_{i+1} = h^2 r
+    let mut lo = vec![0.0; n + 1];
+    let mut diag = vec![0.0; n + 1];
+    let mut hi = vec![0.0; n + 1];
+    let mut rhs = vec![0.0; n + 1];
+    for i in 0..=n {
+        lo[i] = 1.0 + 0.5 * h * p(x[i]);
+        diag[i] = -(2.0 + h * h * q(x[i]));
+        hi[i] = 1.0 - 0.5 * h * p(x[i]);
+        rhs[i] = h * h * r(x[i]);
+    }
+    match left {
+        Boundary::Value(v) => {
+            (diag[0], hi[0], rhs[0]) = (1.0, 0.0, v);
+        }
+        Boundary::Slope(v) => {
+            // ghost y_{-1} = y_1 - 2 h v
+            rhs[0] += lo[0] * 2.0 * h * v;
+            hi[0] += lo[0];
+        }
+    }
+    match right {
+        Boundary::Value(v) => {
+            (lo[n], diag[n], rhs[n]) = (0.0, 1.0, v);
+        }
+        Boundary::Slope(v) => {
+            // ghost y_{n+1} = y_{n-1} + 2 h v
+            rhs[n] -= hi[n] * 2.0 * h * v;
+            lo[n] += hi[n];
+        }
+    }
+    let y = tridiagonal(&lo, &diag, &hi, &rhs)?;
+
+    // transpose has lo_t[i] = hi[i - 1] and hi_t[i] = lo[i + 1]
+    let lo_t: Vec<f64> = (0..=n).map(|i| if i > 0 { hi[i - 1] } else { 0.0 }).collect();
+    let hi_t: Vec<f64> = (0..=n).map(|i| if i < n { lo[i + 1] } else { 0.0 }).collect();
+    let norm = (0..=n).map(|j| lo_t[j].abs() + diag[j].abs() + hi_t[j].abs()).fold(0.0, f64::max);
+    let failed = || vec![f64::INFINITY; n + 1];
+    let inverse = linalg::inverse_norm_estimate(n + 1,
+        |b| tridiagonal(&lo, &diag, &hi, b).unwrap_or_else(|_| failed()),
+        |b| tridiagonal(&lo_t, &diag, &hi_t, b).unwrap_or_else(|_| failed()));
+    linalg::check_condition("finite difference system", norm * inverse);
+    Ok((x, y))
+}
+
+///
+/// Thomas algorithm, lo[0] and hi[n] unused. Fails on a zero pivot
+///
+fn tridiagonal(lo: &[f64], diag: &[f64], hi: &[f64], rhs: &[f64]) -> Result<Vec<f64>, Box<dyn std::error::Error>> {
+    let n = diag.len();
+    let mut c = vec![0.0; n];
+    let mut d = vec![0.0; n];
+    for i in 0..n {
+        let pivot = diag[i] - if i > 0 { lo[i] * c[i - 1] } else { 0.0 };
+        if pivot.abs() < 1e-300 {
+            return Err(format!("finite difference: zero pivot in row {i}").into());
+        }
+        c[i] = hi[i] / pivot;
+        d[i] = (rhs[i] - if i > 0 { lo[i] * d[i - 1] } else { 0.0 }) / pivot;
+    }
+    let mut y = vec![0.0; n];
+    y[n - 1] = d[n - 1];
+    for i in (0..n - 1).rev() {
+        y[i] = d[i] - c[i] * y[i + 1];
+    }
+    Ok(y)
+}
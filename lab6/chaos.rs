@@ -0,0 +1,141 @@
+//!
+//! chaos.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Sensitive dependence on initial conditions in the Lorenz system and the
+//! double pendulum. The largest Lyapunov exponent of each is estimated by
+//! renormalized separation and set against the raw divergence of two
+//! trajectories started 1e-8 apart
+//!
+
+#![allow(clippy::cast_possible_truncation)]
+#![allow(clippy::cast_sign_loss)]
+#![allow(clippy::cast_precision_loss)]
+#![allow(clippy::missing_panics_doc)]
+#![allow(clippy::missing_errors_doc)]
+
+use plotters::prelude::*;
+use plotters_bitmap::BitMapBackend;
+use solvers::{Limits, Method};
+
+mod chaotic;
+mod lyapunov;
+mod report;
+mod run_context;
+mod state;
+mod theme;
+
+use chaotic::{DoublePendulum, Lorenz};
+use lyapunov::{largest_lyapunov, separation, LyapunovOptions};
+use run_context::RunContext;
+
+///
+/// Largest Lyapunov exponent of each example with rk4, beside the value
+/// expected for it, and the double pendulum's energy drift over the run
+/// as a check that the step resolves the motion
+///
+fn lyapunov_study(ctx: &mut RunContext, tf: f64, dt: f64) -> Result<Vec<(&'static str, f64)>, Box<dyn std::error::Error>> {
+    let lorenz = Lorenz::classic();
+    let pendulum = DoublePendulum::unit();
+    let opts = LyapunovOptions::default();
+    ctx.config("lorenz", format!("{lorenz:?}"));
+    ctx.config("double_pendulum", format!("{pendulum:?}"));
+    ctx.config("lyapunov", format!("{opts:?}, dt = {dt}, tf = {tf}"));
+    ctx.solver("rk4", "fixed-step");
+
+    let lorenz_rate = |t: f64, s: &[f64; 3], ds: &mut [f64; 3]| lorenz.rate(t, s, ds);
+    let pendulum_rate = |t: f64, s: &[f64; 4], ds: &mut [f64; 4]| pendulum.rate(t, s, ds);
+    let low = [0.1, 0.1, 0.0, 0.0];
+    let high = [2.0, 2.5, 0.0, 0.0];
+
+    println!("{:>26} {:>12} {:>12} {:>14}", "system", "lambda", "expected", "energy drift");
+    let estimate = ctx.time("lorenz", || largest_lyapunov(&lorenz_rate, Method::Rk4, [1.0, 1.0, 1.0], [0.0, tf], dt, opts))?;
+    println!("{:>26} {:>12.4} {:>12} {:>14}", "lorenz", estimate.exponent, "0.9056", "-");
+    let mut exponents = vec![("lorenz", estimate.exponent)];
+    for (label, ic) in [("double pendulum, low", low), ("double pendulum, high", high)] {
+        let estimate = ctx.time(label, || largest_lyapunov(&pendulum_rate, Method::Rk4, ic, [0.0, tf], dt, opts))?;
+        let end = Method::Rk4.solve_with(&pendulum_rate, ic, [0.0, tf], dt, |_, _| {})?;
+        let drift = (pendulum.energy(&end) - pendulum.energy(&ic)).abs() / pendulum.energy(&ic).abs();
+        let expected = if ic == low { "0 (regular)" } else { "> 0" };
+        println!("{:>26} {:>12.4} {:>12} {:>14.3e}", label, estimate.exponent, expected, drift);
+        exponents.push((label, estimate.exponent));
+    }
+    Ok(exponents)
+}
+
+///
+/// Raw separation of trajectories started 1e-8 apart in the first
+/// component, on a log scale with the slope each clearly positive
+/// Lyapunov estimate predicts drawn from the same start
+///
+fn divergence_plot(ctx: &mut RunContext, tf: f64, dt: f64, exponents: &[(&'static str, f64)], name: &str)
+    -> Result<(), Box<dyn std::error::Error>> {
+    let lorenz = Lorenz::classic();
+    let pendulum = DoublePendulum::unit();
+    let lorenz_rate = |t: f64, s: &[f64; 3], ds: &mut [f64; 3]| lorenz.rate(t, s, ds);
+    let pendulum_rate = |t: f64, s: &[f64; 4], ds: &mut [f64; 4]| pendulum.rate(t, s, ds);
+    let d0 = 1e-8;
+    // start Lorenz on the attractor, as the exponent estimate does
+    let settled = Method::Rk4.solve_with(&lorenz_rate, [1.0, 1.0, 1.0], [0.0, LyapunovOptions::default().transient], dt, |_, _| {})?;
+
+    let curves = [
+        separation(&lorenz_rate, Method::Rk4, settled, [d0, 0.0, 0.0], [0.0, tf], dt, Limits::default())?,
+        separation(&pendulum_rate, Method::Rk4, [0.1, 0.1, 0.0, 0.0], [d0, 0.0, 0.0, 0.0], [0.0, tf], dt, Limits::default())?,
+        separation(&pendulum_rate, Method::Rk4, [2.0, 2.5, 0.0, 0.0], [d0, 0.0, 0.0, 0.0], [0.0, tf], dt, Limits::default())?,
+    ];
+
+    let path = ctx.path(name);
+    let theme = ctx.style();
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?;
+    let root = theme.stamp(&root)?;
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Separation of trajectories started 1e-8 apart [semilogy-scale]", theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 70)
+        .set_label_area_size(LabelAreaPosition::Bottom, 50)
+        .build_cartesian_2d(0.0..tf, -10.0..2.0)?;
+
+    chart.configure_mesh()
+        .label_style(theme.label())
+        .axis_desc_style(theme.label())
+        .x_desc("t")
+        .y_desc("|y - z|")
+        .y_label_formatter(&|v| format!("1e{:.0}", v))
+        .draw()?;
+
+    for (i, (curve, (label, lambda))) in curves.iter().zip(exponents).enumerate() {
+        let color = theme.color(i);
+        chart.draw_series(LineSeries::new(curve.iter().map(|(t, d)| (*t, d.max(1e-16).log10())), &color))?
+            .label(*label)
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+        if *lambda > 0.05 {
+            let slope = lambda / std::f64::consts::LN_10;
+            let t_end = ((2.0 - d0.log10()) / slope).min(tf);
+            chart.draw_series(DashedLineSeries::new(
+                [(0.0, d0.log10()), (t_end, d0.log10() + slope * t_end)],
+                6, 4, color.mix(0.6).into(),
+            ))?
+            .label(format!("{label}, slope lambda = {lambda:.3}"))
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color.mix(0.6)));
+        }
+    }
+
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?;
+
+    root.present()?;
+    Ok(())
+}
+
+fn main() {
+    let mut ctx = RunContext::new("output", "chaos")
+        .expect("failed to create output directory");
+    if let Ok(exponents) = lyapunov_study(&mut ctx, 1000.0, 0.005) {
+        let _ = divergence_plot(&mut ctx, 40.0, 0.005, &exponents, "divergence.png");
+    }
+    let _ = ctx.write_report();
+    let _ = ctx.write_manifest();
+}
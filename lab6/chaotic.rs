@@ -0,0 +1,90 @@
+//!
+//! chaotic.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Textbook chaotic systems with recognizable Lyapunov exponents: the
+//! Lorenz system (largest exponent about 0.906 at the classic parameters)
+//! and the planar double pendulum, regular at low energy and chaotic once
+//! both arms can swing over
+//!
+
+#![allow(dead_code)]
+
+use crate::state::StateVar;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Lorenz {
+    pub sigma: f64,
+    pub rho: f64,
+    pub beta: f64,
+}
+
+impl Lorenz {
+    pub const STATES: [StateVar; 3] = [
+        StateVar::new("x", "1"),
+        StateVar::new("y", "1"),
+        StateVar::new("z", "1"),
+    ];
+
+    ///
+    /// sigma = 10, rho = 28, beta = 8/3
+    ///
+    pub fn classic() -> Lorenz {
+        Lorenz { sigma: 10.0, rho: 28.0, beta: 8.0 / 3.0 }
+    }
+
+    pub fn rate(&self, _t: f64, s: &[f64; 3], ds: &mut [f64; 3]) {
+        ds[0] = self.sigma * (s[1] - s[0]);
+        ds[1] = s[0] * (self.rho - s[2]) - s[1];
+        ds[2] = s[0] * s[1] - self.beta * s[2];
+    }
+}
+
+///
+/// Two point masses on rigid massless arms, angles from the downward
+/// vertical, state [theta1, theta2, omega1, omega2]
+///
+#[derive(Clone, Copy, Debug)]
+pub struct DoublePendulum {
+    pub m: [f64; 2],
+    pub l: [f64; 2],
+    pub g: f64,
+}
+
+impl DoublePendulum {
+    pub const STATES: [StateVar; 4] = [
+        StateVar::new("theta1", "rad"),
+        StateVar::new("theta2", "rad"),
+        StateVar::new("omega1", "rad/s"),
+        StateVar::new("omega2", "rad/s"),
+    ];
+
+    ///
+    /// Unit masses on unit arms under g = 9.81
+    ///
+    pub fn unit() -> DoublePendulum {
+        DoublePendulum { m: [1.0, 1.0], l: [1.0, 1.0], g: 9.81 }
+    }
+
+    pub fn rate(&self, _t: f64, s: &[f64; 4], ds: &mut [f64; 4]) {
+        let ([m1, m2], [l1, l2], g) = (self.m, self.l, self.g);
+        let (w1, w2) = (s[2], s[3]);
+        let d = s[1] - s[0];
+        let (sd, cd) = d.sin_cos();
+        let den1 = (m1 + m2) * l1 - m2 * l1 * cd * cd;
+        let den2 = (l2 / l1) * den1;
+        ds[0] = w1;
+        ds[1] = w2;
+        ds[2] = (m2 * l1 * w1 * w1 * sd * cd + m2 * g * s[1].sin() * cd + m2 * l2 * w2 * w2 * sd
+            - (m1 + m2) * g * s[0].sin()) / den1;
+        ds[3] = (-m2 * l2 * w2 * w2 * sd * cd
+            + (m1 + m2) * (g * s[0].sin() * cd - l1 * w1 * w1 * sd - g * s[1].sin())) / den2;
+    }
+
+    pub fn energy(&self, s: &[f64; 4]) -> f64 {
+        let ([m1, m2], [l1, l2], g) = (self.m, self.l, self.g);
+        let kinetic = 0.5 * (m1 + m2) * l1 * l1 * s[2] * s[2] + 0.5 * m2 * l2 * l2 * s[3] * s[3]
+            + m2 * l1 * l2 * s[2] * s[3] * (s[0] - s[1]).cos();
+        let potential = -(m1 + m2) * g * l1 * s[0].cos() - m2 * g * l2 * s[1].cos();
+        kinetic + potential
+    }
+}
@@ -0,0 +1,218 @@
+//!
+//! chunked.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Disk-backed trajectory storage for runs too long to hold in memory. Rows
+//! (t, y0 .. y{N-1}) are buffered and appended to a scratch file one chunk
+//! at a time, so resident memory is a single chunk regardless of the step
+//! count. Reading back is by LTTB downsampled or ranged views, which is
+//! all the plotting and resampling code needs
+//!
+
+#![allow(dead_code)]
+
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::ops::Range;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::downsample::{self, Series};
+use crate::trajectory::Trajectory;
+
+static SCRATCH_ID: AtomicUsize = AtomicUsize::new(0);
+
+///
+/// Streams rows to the scratch file, flushing every `chunk_rows` rows
+///
+pub struct ChunkedWriter<const N: usize> {
+    file: BufWriter<File>,
+    path: PathBuf,
+    buf: Vec<u8>,
+    chunk_rows: usize,
+    rows: usize,
+    done: bool,
+}
+
+///
+/// Finished on-disk trajectory. The scratch file is removed on drop
+///
+pub struct ChunkedTrajectory<const N: usize> {
+    path: PathBuf,
+    rows: usize,
+}
+
+impl<const N: usize> ChunkedWriter<N> {
+    const ROW_BYTES: usize = 8 * (N + 1);
+
+    ///
+    /// Writer on a fresh file in the system temp directory
+    ///
+    pub fn temp(chunk_rows: usize) -> Result<ChunkedWriter<N>, Box<dyn std::error::Error>> {
+        let id = SCRATCH_ID.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("trajectory-{}-{id}.bin", std::process::id()));
+        let chunk_rows = chunk_rows.max(1);
+        Ok(ChunkedWriter {
+            file: BufWriter::new(File::create(&path)?),
+            path,
+            buf: Vec::with_capacity(chunk_rows * Self::ROW_BYTES),
+            chunk_rows,
+            rows: 0,
+            done: false,
+        })
+    }
+
+    pub fn push(&mut self, t: f64, y: &[f64; N]) -> Result<(), Box<dyn std::error::Error>> {
+        self.buf.extend_from_slice(&t.to_le_bytes());
+        for x in y {
+            self.buf.extend_from_slice(&x.to_le_bytes());
+        }
+        self.rows += 1;
+        if self.buf.len() >= self.chunk_rows * Self::ROW_BYTES {
+            self.file.write_all(&self.buf)?;
+            self.buf.clear();
+        }
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result<ChunkedTrajectory<N>, Box<dyn std::error::Error>> {
+        self.file.write_all(&self.buf)?;
+        self.file.flush()?;
+        self.done = true;
+        Ok(ChunkedTrajectory { path: self.path.clone(), rows: self.rows })
+    }
+}
+
+///
+/// An abandoned writer removes its partial file, a finished one hands the
+/// file over to the ChunkedTrajectory
+///
+impl<const N: usize> Drop for ChunkedWriter<N> {
+    fn drop(&mut self) {
+        if !self.done {
+            let _ = std::fs::remove_file(&self.path);
+        }
+    }
+}
+
+impl<const N: usize> ChunkedTrajectory<N> {
+    const ROW_BYTES: usize = 8 * (N + 1);
+
+    pub fn len(&self) -> usize {
+        self.rows
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows == 0
+    }
+
+    fn decode(bytes: &[u8]) -> (f64, [f64; N]) {
+        let at = |k: usize| f64::from_le_bytes(bytes[8 * k..8 * k + 8].try_into().unwrap());
+        (at(0), std::array::from_fn(|j| at(j + 1)))
+    }
+
+    ///
+    /// Rows start..end read with a single seek
+    ///
+    pub fn range(&self, start: usize, end: usize) -> Result<Trajectory<N>, Box<dyn std::error::Error>> {
+        let end = end.min(self.rows);
+        let mut file = File::open(&self.path)?;
+        file.seek(SeekFrom::Start((start * Self::ROW_BYTES) as u64))?;
+        let mut bytes = vec![0u8; end.saturating_sub(start) * Self::ROW_BYTES];
+        file.read_exact(&mut bytes)?;
+
+        let mut out = Trajectory::with_capacity(end.saturating_sub(start));
+        for row in bytes.chunks_exact(Self::ROW_BYTES) {
+            let (t, y) = Self::decode(row);
+            out.push(t, &y);
+        }
+        Ok(out)
+    }
+
+    ///
+    /// Every row in order, one sequential pass
+    ///
+    fn for_each_row(&self, mut f: impl FnMut(usize, f64, &[f64; N])) -> Result<(), Box<dyn std::error::Error>> {
+        let mut reader = BufReader::with_capacity(1 << 20, File::open(&self.path)?);
+        let mut row = vec![0u8; Self::ROW_BYTES];
+        for i in 0..self.rows {
+            reader.read_exact(&mut row)?;
+            let (t, y) = Self::decode(&row);
+            f(i, t, &y);
+        }
+        Ok(())
+    }
+
+    ///
+    /// At most threshold (t, y_j) points of every component chosen by LTTB
+    /// as in downsample::lttb, in two sequential passes: the first takes
+    /// each bucket's mean, the second picks each bucket's point. Only the
+    /// bucket means are held in memory
+    ///
+    pub fn downsampled(&self, threshold: usize) -> Result<[Series; N], Box<dyn std::error::Error>> {
+        if threshold < 3 || self.rows <= threshold {
+            let all = self.range(0, self.rows)?;
+            return Ok(std::array::from_fn(|j| all.points(j).collect()));
+        }
+        let buckets: Vec<Range<usize>> = (0..threshold - 2).map(|b| downsample::bucket(self.rows, threshold, b)).collect();
+        let bucket_of = |i: usize, b: &mut usize| {
+            while *b < buckets.len() && i >= buckets[*b].end {
+                *b += 1;
+            }
+            (*b < buckets.len() && i >= buckets[*b].start).then_some(*b)
+        };
+
+        // bucket means, then the last row as the mean past the final bucket
+        let mut means = vec![(0.0, [0.0; N]); buckets.len() + 1];
+        let mut b = 0;
+        self.for_each_row(|i, t, y| {
+            match bucket_of(i, &mut b) {
+                Some(k) => {
+                    means[k].0 += t;
+                    for (m, x) in means[k].1.iter_mut().zip(y) {
+                        *m += x;
+                    }
+                }
+                None if i + 1 == self.rows => means[buckets.len()] = (t, *y),
+                None => {}
+            }
+        })?;
+        for (m, range) in means.iter_mut().zip(&buckets) {
+            let n = range.len() as f64;
+            m.0 /= n;
+            for x in &mut m.1 {
+                *x /= n;
+            }
+        }
+
+        let mut kept: [Series; N] = std::array::from_fn(|_| Vec::with_capacity(threshold));
+        let mut best = [(f64::NEG_INFINITY, (0.0, 0.0)); N];
+        let mut b = 0;
+        self.for_each_row(|i, t, y| {
+            let Some(k) = bucket_of(i, &mut b) else {
+                // first and last rows
+                for (kept, x) in kept.iter_mut().zip(y) {
+                    kept.push((t, *x));
+                }
+                return;
+            };
+            for (j, (kept, x)) in kept.iter_mut().zip(y).enumerate() {
+                let target = (means[k + 1].0, means[k + 1].1[j]);
+                let area = downsample::area(kept[kept.len() - 1], (t, *x), target);
+                if area >= best[j].0 {
+                    best[j] = (area, (t, *x));
+                }
+                if i + 1 == buckets[k].end {
+                    kept.push(best[j].1);
+                    best[j].0 = f64::NEG_INFINITY;
+                }
+            }
+        })?;
+        Ok(kept)
+    }
+}
+
+impl<const N: usize> Drop for ChunkedTrajectory<N> {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
@@ -0,0 +1,193 @@
+//!
+//! circuit.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Lumped circuits as ODE systems. Nodes are numbered from 1, node 0 is
+//! ground. The state is every node voltage followed by every inductor
+//! current, in the order the inductors were added, and the equations are
+//! nodal analysis with capacitor currents on the left,
+//!
+//!   C v' = -(currents leaving each node through R, L, G, sources)
+//!   L i' = v_a - v_b
+//!
+//! C is the capacitance matrix, so every node needs a capacitive path to
+//! ground; build() factors it once and rejects the circuit otherwise.
+//! Nonlinear conductances take any current-voltage law i = g(v), e.g. the
+//! cubic negative resistance of a tunnel diode
+//!
+
+#![allow(dead_code)]
+
+use std::sync::Arc;
+
+use crate::linalg::{Lu, Matrix};
+use crate::system::OdeSystem;
+
+///
+/// Current from a to b as a function of v_a - v_b (conductances) or of
+/// time (sources)
+///
+pub type Law = Arc<dyn Fn(f64) -> f64 + Send + Sync>;
+
+#[derive(Clone)]
+pub enum Element {
+    Resistor { a: usize, b: usize, r: f64 },
+    Capacitor { a: usize, b: usize, c: f64 },
+    Inductor { a: usize, b: usize, l: f64 },
+    Conductance { a: usize, b: usize, law: Law },
+    CurrentSource { a: usize, b: usize, law: Law },
+}
+
+///
+/// Elements added by name. build() checks node numbers, positive finite
+/// R, L, C, and that the capacitance matrix can be factored
+///
+#[derive(Clone, Default)]
+pub struct CircuitBuilder {
+    nodes: usize,
+    elements: Vec<Element>,
+}
+
+impl CircuitBuilder {
+    pub fn resistor(mut self, a: usize, b: usize, r: f64) -> CircuitBuilder {
+        self.elements.push(Element::Resistor { a, b, r });
+        self
+    }
+
+    pub fn capacitor(mut self, a: usize, b: usize, c: f64) -> CircuitBuilder {
+        self.elements.push(Element::Capacitor { a, b, c });
+        self
+    }
+
+    pub fn inductor(mut self, a: usize, b: usize, l: f64) -> CircuitBuilder {
+        self.elements.push(Element::Inductor { a, b, l });
+        self
+    }
+
+    ///
+    /// Nonlinear element carrying i = law(v_a - v_b) from a to b
+    ///
+    pub fn conductance(mut self, a: usize, b: usize, law: impl Fn(f64) -> f64 + Send + Sync + 'static) -> CircuitBuilder {
+        self.elements.push(Element::Conductance { a, b, law: Arc::new(law) });
+        self
+    }
+
+    ///
+    /// Drives law(t) from a to b through the source, into node b
+    ///
+    pub fn current_source(mut self, a: usize, b: usize, law: impl Fn(f64) -> f64 + Send + Sync + 'static) -> CircuitBuilder {
+        self.elements.push(Element::CurrentSource { a, b, law: Arc::new(law) });
+        self
+    }
+
+    pub fn build(self) -> Result<Circuit, Box<dyn std::error::Error>> {
+        let n = self.nodes;
+        let mut capacitance = Matrix::zeros(n, n);
+        let mut inductors = 0;
+        for (k, e) in self.elements.iter().enumerate() {
+            let (a, b, value, name) = match e {
+                Element::Resistor { a, b, r } => (*a, *b, Some(*r), "resistance"),
+                Element::Capacitor { a, b, c } => (*a, *b, Some(*c), "capacitance"),
+                Element::Inductor { a, b, l } => (*a, *b, Some(*l), "inductance"),
+                Element::Conductance { a, b, .. } | Element::CurrentSource { a, b, .. } => (*a, *b, None, ""),
+            };
+            if a > n || b > n || a == b {
+                return Err(format!("circuit: element {k} joins nodes {a} and {b}, need two distinct nodes in 0..={n}").into());
+            }
+            if let Some(v) = value {
+                if !v.is_finite() || v <= 0.0 {
+                    return Err(format!("circuit: element {k} has {name} {v}, it must be finite and positive").into());
+                }
+            }
+            match e {
+                Element::Capacitor { c, .. } => stamp(&mut capacitance, a, b, *c),
+                Element::Inductor { .. } => inductors += 1,
+                _ => {}
+            }
+        }
+        let capacitance = capacitance.lu()
+            .map_err(|_| "circuit: capacitance matrix is singular, every node needs a capacitive path to ground")?;
+        Ok(Circuit { nodes: n, inductors, elements: self.elements, capacitance })
+    }
+}
+
+///
+/// Adds a two terminal conductance-like value to a nodal matrix, ground
+/// rows and columns dropped
+///
+fn stamp(m: &mut Matrix, a: usize, b: usize, value: f64) {
+    if a > 0 {
+        m[(a - 1, a - 1)] += value;
+    }
+    if b > 0 {
+        m[(b - 1, b - 1)] += value;
+    }
+    if a > 0 && b > 0 {
+        m[(a - 1, b - 1)] -= value;
+        m[(b - 1, a - 1)] -= value;
+    }
+}
+
+pub struct Circuit {
+    pub nodes: usize,
+    pub inductors: usize,
+    pub elements: Vec<Element>,
+    capacitance: Lu,
+}
+
+impl Circuit {
+    ///
+    /// A circuit on nodes 1..=nodes plus ground
+    ///
+    pub fn builder(nodes: usize) -> CircuitBuilder {
+        CircuitBuilder { nodes, elements: Vec::new() }
+    }
+
+    pub fn states(&self) -> usize {
+        self.nodes + self.inductors
+    }
+
+    ///
+    /// v_1.. then i_L1.. as display names
+    ///
+    pub fn state_names(&self) -> Vec<String> {
+        (1..=self.nodes).map(|k| format!("v{k}"))
+            .chain((1..=self.inductors).map(|k| format!("iL{k}")))
+            .collect()
+    }
+}
+
+impl OdeSystem for Circuit {
+    fn dimension(&self) -> usize {
+        self.states()
+    }
+
+    fn rate(&self, t: f64, x: &[f64], dx: &mut [f64]) {
+        let v = |node: usize| if node == 0 { 0.0 } else { x[node - 1] };
+        // current leaving each node
+        let mut out = vec![0.0; self.nodes];
+        let mut leave = |a: usize, b: usize, i: f64| {
+            if a > 0 {
+                out[a - 1] += i;
+            }
+            if b > 0 {
+                out[b - 1] -= i;
+            }
+        };
+        let mut inductor = self.nodes;
+        for e in &self.elements {
+            match e {
+                Element::Resistor { a, b, r } => leave(*a, *b, (v(*a) - v(*b)) / r),
+                Element::Capacitor { .. } => {}
+                Element::Inductor { a, b, l } => {
+                    leave(*a, *b, x[inductor]);
+                    dx[inductor] = (v(*a) - v(*b)) / l;
+                    inductor += 1;
+                }
+                Element::Conductance { a, b, law } => leave(*a, *b, law(v(*a) - v(*b))),
+                Element::CurrentSource { a, b, law } => leave(*a, *b, law(t)),
+            }
+        }
+        let minus: Vec<f64> = out.iter().map(|i| -i).collect();
+        dx[..self.nodes].copy_from_slice(&self.capacitance.solve(&minus));
+    }
+}
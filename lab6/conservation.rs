@@ -0,0 +1,148 @@
+//!
+//! conservation.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Conservation checks for PDE solutions on a uniform cell grid. A Ledger
+//! is fed the solution every step and keeps, per species,
+//!
+//!   - the mass M = sum u dx, against what sources and the boundaries
+//!     supplied since the start (a trapezoid in time of the supply rate
+//!     the caller gives, zero for a closed system), and
+//!   - the energy E = sum u^2 dx, which pure diffusion may only dissipate.
+//!
+//! The drift M(t) - M(0) - supplied is roundoff for a conservative scheme
+//! and its boundary handling. A scheme or an end that loses mass shows up
+//! as drift, one that is unstable as a rise in the energy. check() flags
+//! either past a tolerance and gives the first time it happened
+//!
+
+#![allow(dead_code)]
+
+pub fn mass<const S: usize>(u: &[[f64; S]], dx: f64) -> [f64; S] {
+    std::array::from_fn(|s| u.iter().map(|c| c[s]).sum::<f64>() * dx)
+}
+
+pub fn energy<const S: usize>(u: &[[f64; S]], dx: f64) -> [f64; S] {
+    std::array::from_fn(|s| u.iter().map(|c| c[s] * c[s]).sum::<f64>() * dx)
+}
+
+#[derive(Clone, Debug)]
+pub struct Ledger<const S: usize> {
+    dx: f64,
+    pub t: Vec<f64>,
+    pub mass: Vec<[f64; S]>,
+    pub energy: Vec<[f64; S]>,
+    /// mass supplied since the first record
+    pub supplied: Vec<[f64; S]>,
+    /// supply rate at the last record
+    rate: [f64; S],
+}
+
+///
+/// Worst drift and energy rise over a run, each relative to its initial
+/// value
+///
+#[derive(Clone, Copy, Debug)]
+pub struct Check<const S: usize> {
+    pub mass_drift: [f64; S],
+    /// largest one step rise, zero for a run that only dissipates
+    pub energy_growth: [f64; S],
+    /// first time either passed tol
+    pub violation: Option<f64>,
+    pub tol: f64,
+}
+
+impl<const S: usize> Check<S> {
+    pub fn conserves_mass(&self) -> bool {
+        self.mass_drift.iter().all(|d| *d <= self.tol)
+    }
+
+    pub fn dissipates(&self) -> bool {
+        self.energy_growth.iter().all(|g| *g <= self.tol)
+    }
+
+    pub fn verdict(&self) -> &'static str {
+        match (self.conserves_mass(), self.dissipates()) {
+            (true, true) => "conserved",
+            (false, true) => "mass drifts",
+            (true, false) => "energy grows",
+            (false, false) => "both",
+        }
+    }
+}
+
+impl<const S: usize> Ledger<S> {
+    pub fn new(dx: f64) -> Ledger<S> {
+        Ledger { dx, t: Vec::new(), mass: Vec::new(), energy: Vec::new(), supplied: Vec::new(), rate: [0.0; S] }
+    }
+
+    ///
+    /// The state at t of a closed system
+    ///
+    pub fn record(&mut self, t: f64, u: &[[f64; S]]) {
+        self.record_with(t, u, [0.0; S]);
+    }
+
+    ///
+    /// The state at t, with the rate mass is supplied at: sources inside
+    /// minus the outflow through the ends
+    ///
+    pub fn record_with(&mut self, t: f64, u: &[[f64; S]], supply: [f64; S]) {
+        let supplied = match (self.t.last(), self.supplied.last()) {
+            (Some(t0), Some(s0)) => std::array::from_fn(|s| s0[s] + 0.5 * (t - t0) * (self.rate[s] + supply[s])),
+            _ => [0.0; S],
+        };
+        self.t.push(t);
+        self.mass.push(mass(u, self.dx));
+        self.energy.push(energy(u, self.dx));
+        self.supplied.push(supplied);
+        self.rate = supply;
+    }
+
+    ///
+    /// M(t) - M(0) - supplied at every record
+    ///
+    pub fn drift(&self) -> Vec<[f64; S]> {
+        let Some(m0) = self.mass.first() else {
+            return Vec::new();
+        };
+        self.mass.iter().zip(&self.supplied).map(|(m, p)| std::array::from_fn(|s| m[s] - m0[s] - p[s])).collect()
+    }
+
+    ///
+    /// |drift| relative to the initial mass, or absolute for a species
+    /// that starts with none
+    ///
+    pub fn relative_drift(&self) -> Vec<[f64; S]> {
+        let Some(m0) = self.mass.first() else {
+            return Vec::new();
+        };
+        let scale: [f64; S] = std::array::from_fn(|s| if m0[s] != 0.0 { m0[s].abs() } else { 1.0 });
+        self.drift().iter().map(|d| std::array::from_fn(|s| d[s].abs() / scale[s])).collect()
+    }
+
+    ///
+    /// The run against tol. A non-finite state fails both checks
+    ///
+    pub fn check(&self, tol: f64) -> Check<S> {
+        let drift = self.relative_drift();
+        let e0: [f64; S] = match self.energy.first() {
+            Some(e) => std::array::from_fn(|s| if e[s] != 0.0 { e[s] } else { 1.0 }),
+            None => [1.0; S],
+        };
+        let mut check = Check { mass_drift: [0.0; S], energy_growth: [0.0; S], violation: None, tol };
+        for (k, d) in drift.iter().enumerate() {
+            let mut bad = false;
+            for s in 0..S {
+                let rise = if k == 0 { 0.0 } else { (self.energy[k][s] - self.energy[k - 1][s]) / e0[s] };
+                let (d, rise) = (if d[s].is_finite() { d[s] } else { f64::INFINITY }, if rise.is_nan() { f64::INFINITY } else { rise });
+                check.mass_drift[s] = check.mass_drift[s].max(d);
+                check.energy_growth[s] = check.energy_growth[s].max(rise);
+                bad |= d > tol || rise > tol;
+            }
+            if bad && check.violation.is_none() {
+                check.violation = Some(self.t[k]);
+            }
+        }
+        check
+    }
+}
@@ -0,0 +1,120 @@
+//!
+//! continuation.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Warm-started fixed-step runs. A Continuation is where a run stopped:
+//! the method, its step grid and the state on it. Extending a finished
+//! trajectory to a later tf steps on from there and appends, so asking
+//! what happens by t = 500 costs only the steps past the first run's end,
+//! and lands on the same points a single run to t = 500 would have. A
+//! run whose dt does not divide its span ends on a shortened step and is
+//! continued on a fresh grid from there
+//!
+
+#![allow(dead_code)]
+
+use solvers::{final_step, step_count, Limits, Method};
+
+use crate::system::OdeSystem;
+use crate::trajectory::Trajectory;
+
+///
+/// Final solver state of a fixed-step one-step run. Times are kept as the
+/// grid index from t0, not accumulated, so a continued run evaluates the
+/// rate at exactly the times an uninterrupted one does
+///
+#[derive(Clone, Copy, Debug)]
+pub struct Continuation<const N: usize> {
+    pub method: Method,
+    pub t0: f64,
+    pub dt: f64,
+    /// steps taken since t0
+    pub steps: usize,
+    pub y: [f64; N],
+    /// checked by every advance, across continuations
+    pub limits: Limits,
+}
+
+impl<const N: usize> Continuation<N> {
+    pub fn start(method: Method, ic: [f64; N], t0: f64, dt: f64) -> Continuation<N> {
+        Continuation { method, t0, dt, steps: 0, y: ic, limits: Limits::default() }
+    }
+
+    pub fn with_limits(self, limits: Limits) -> Continuation<N> {
+        Continuation { limits, ..self }
+    }
+
+    pub fn t(&self) -> f64 {
+        self.t0 + (self.steps as f64) * self.dt
+    }
+
+    ///
+    /// Steps on to tf, handing each new point to observe, the last step
+    /// shortened to land on it when tf is off the grid. The grid then
+    /// restarts at tf. Nothing happens for a tf already behind. The new
+    /// steps are checked against limits first and the wall clock as they run
+    ///
+    pub fn advance<F, O>(&mut self, rate: &F, tf: f64, mut observe: O) -> Result<(), Box<dyn std::error::Error>>
+    where
+        F: Fn(f64, &[f64; N], &mut [f64; N]),
+        O: FnMut(f64, &[f64; N]),
+    {
+        if tf <= self.t() {
+            return Ok(());
+        }
+        solvers::planned_steps("continuation", [self.t(), tf], self.dt, self.limits)?;
+        let mut guard = self.limits.memory(usize::MAX).start::<N>();
+        let n = step_count([self.t0, tf], self.dt);
+        for i in self.steps + 1..=n {
+            guard.step("continuation", self.t())?;
+            self.y = self.method.step(rate, self.t0 + ((i - 1) as f64) * self.dt, &self.y, self.dt);
+            observe(self.t0 + (i as f64) * self.dt, &self.y);
+        }
+        self.steps = self.steps.max(n);
+        if let Some(h) = final_step([self.t(), tf], self.dt) {
+            self.y = self.method.step(rate, self.t(), &self.y, h);
+            observe(tf, &self.y);
+            (self.t0, self.steps) = (tf, 0);
+        }
+        Ok(())
+    }
+
+    ///
+    /// Solves over ts from scratch, returning the trajectory and the state
+    /// to continue it from. The trajectory is checked against limits before
+    /// it is allocated
+    ///
+    pub fn solve<F>(method: Method, rate: &F, ic: [f64; N], ts: [f64; 2], dt: f64, limits: Limits)
+        -> Result<(Trajectory<N>, Continuation<N>), Box<dyn std::error::Error>>
+    where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+        let n = solvers::planned_steps("continuation", ts, dt, limits)?;
+        limits.check::<N>("continuation", n)?;
+        let mut traj = Trajectory::with_capacity(n + 1);
+        traj.push(ts[0], &ic);
+        let mut state = Continuation::start(method, ic, ts[0], dt).with_limits(limits);
+        state.extend(rate, &mut traj, ts[1])?;
+        Ok((traj, state))
+    }
+
+    ///
+    /// Continues traj, which must have ended at this state, to tf
+    ///
+    pub fn extend<F>(&mut self, rate: &F, traj: &mut Trajectory<N>, tf: f64) -> Result<(), Box<dyn std::error::Error>>
+    where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+        self.advance(rate, tf, |t, y| traj.push(t, y))
+    }
+
+    ///
+    /// solve on a system, its size checked against the state
+    ///
+    pub fn solve_system(method: Method, system: &impl OdeSystem, ic: [f64; N], ts: [f64; 2], dt: f64, limits: Limits)
+        -> Result<(Trajectory<N>, Continuation<N>), Box<dyn std::error::Error>> {
+        Continuation::solve(method, &system.rhs::<N>()?, ic, ts, dt, limits)
+    }
+
+    ///
+    /// extend on a system, its size checked against the state
+    ///
+    pub fn extend_system(&mut self, system: &impl OdeSystem, traj: &mut Trajectory<N>, tf: f64) -> Result<(), Box<dyn std::error::Error>> {
+        self.extend(&system.rhs::<N>()?, traj, tf)
+    }
+}
@@ -0,0 +1,299 @@
+//!
+//! correlation.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Two-point correlation of uniformly sampled trajectories. The sample
+//! cross-covariance of x and y at every lag comes from one FFT product,
+//! the series zero padded to twice their length so the circular product
+//! is the linear one: O(n log n) instead of O(n max_lag). Correlations are
+//! the biased estimates (divided by n, not n - k), which keeps the
+//! sequence positive definite
+//!
+//! Significance follows Bartlett: under white noise |rho_k| < 1.96 /
+//! sqrt(n) at 95%, and for a correlated series the band at lag k widens to
+//! 1.96 sqrt((1 + 2 sum_{j<k} rho_j^2) / n). From the correlation come the
+//! decorrelation time of a stochastic trajectory, the first lag where rho
+//! falls through 1/e, and the period of a deterministic one, the first
+//! peak after rho's first zero
+//!
+
+#![allow(dead_code)]
+
+use plotters::prelude::*;
+use plotters_bitmap::BitMapBackend;
+
+use crate::fourier::fft;
+use crate::peaks::{self, Kind};
+use crate::theme::PlotStyle;
+
+/// two sided 95% normal quantile
+const Z95: f64 = 1.96;
+
+///
+/// sum_i (x_i - xbar)(y_{i+k} - ybar) for k in 0..=max_lag
+///
+fn covariance_sums(x: &[f64], y: &[f64], max_lag: usize) -> Vec<f64> {
+    let n = x.len();
+    let (xbar, ybar) = (x.iter().sum::<f64>() / n as f64, y.iter().sum::<f64>() / n as f64);
+    let m = (2 * n).next_power_of_two();
+    let mut a = vec![(0.0, 0.0); m];
+    let mut b = vec![(0.0, 0.0); m];
+    for i in 0..n {
+        a[i].0 = x[i] - xbar;
+        b[i].0 = y[i] - ybar;
+    }
+    fft(&mut a, false);
+    fft(&mut b, false);
+    // conj(A) B transforms back to the lagged products
+    for (p, q) in a.iter_mut().zip(&b) {
+        *p = (p.0 * q.0 + p.1 * q.1, p.0 * q.1 - p.1 * q.0);
+    }
+    fft(&mut a, true);
+    a.iter().take(max_lag + 1).map(|p| p.0 / m as f64).collect()
+}
+
+#[derive(Clone, Debug)]
+pub struct Correlation {
+    /// lag in time units, k dt
+    pub lag: Vec<f64>,
+    pub rho: Vec<f64>,
+    /// samples behind each estimate, times the members averaged
+    pub samples: usize,
+}
+
+impl Correlation {
+    ///
+    /// 95% band about zero for white noise
+    ///
+    pub fn white_bound(&self) -> f64 {
+        Z95 / (self.samples as f64).sqrt()
+    }
+
+    ///
+    /// Bartlett's 95% band at every lag, assuming rho is zero from there
+    /// on but not before
+    ///
+    pub fn bartlett_bounds(&self) -> Vec<f64> {
+        let mut sum = 0.0;
+        self.rho.iter().map(|r| {
+            let bound = Z95 * ((1.0 + 2.0 * sum) / self.samples as f64).sqrt();
+            sum += r * r;
+            bound
+        }).collect()
+    }
+
+    ///
+    /// First lag where rho falls through 1/e, interpolated between lags
+    ///
+    pub fn decorrelation_time(&self) -> Option<f64> {
+        let level = (-1.0_f64).exp();
+        let k = self.rho.iter().position(|r| *r < level)?;
+        if k == 0 {
+            return Some(0.0);
+        }
+        let (r0, r1) = (self.rho[k - 1], self.rho[k]);
+        Some(self.lag[k - 1] + (self.lag[k] - self.lag[k - 1]) * (r0 - level) / (r0 - r1))
+    }
+
+    ///
+    /// First lag at which rho is inside its Bartlett band
+    ///
+    pub fn first_insignificant(&self) -> Option<f64> {
+        self.rho.iter().zip(self.bartlett_bounds()).zip(&self.lag)
+            .find(|((r, b), _)| r.abs() < *b)
+            .map(|(_, lag)| *lag)
+    }
+
+    ///
+    /// Lag of rho's first peak past its first zero, refined between lags
+    /// as peaks does, None for a series that never swings back
+    ///
+    pub fn period(&self) -> Option<f64> {
+        let zero = self.rho.iter().position(|r| *r < 0.0)?;
+        peaks::extrema(&self.lag[zero..], &self.rho[zero..]).into_iter()
+            .find(|e| e.kind == Kind::Peak && e.value > 0.0)
+            .map(|e| e.t)
+    }
+}
+
+///
+/// Normalized cross-correlation of x and y, samples dt apart, at lags
+/// 0..=max_lag: corr(x_i, y_{i+k})
+///
+pub fn correlation(x: &[f64], y: &[f64], dt: f64, max_lag: usize) -> Result<Correlation, Box<dyn std::error::Error>> {
+    if x.len() != y.len() || x.len() < 2 {
+        return Err(format!("correlation: need two series of equal length, got {} and {}", x.len(), y.len()).into());
+    }
+    if max_lag >= x.len() {
+        return Err(format!("correlation: lag {max_lag} needs more than the {} samples given", x.len()).into());
+    }
+    let sums = covariance_sums(x, y, max_lag);
+    let (sxx, syy) = (covariance_sums(x, x, 0)[0], covariance_sums(y, y, 0)[0]);
+    if sxx <= 0.0 || syy <= 0.0 {
+        return Err("correlation: a constant series has no correlation".into());
+    }
+    let norm = (sxx * syy).sqrt();
+    Ok(Correlation {
+        lag: (0..=max_lag).map(|k| k as f64 * dt).collect(),
+        rho: sums.iter().map(|s| s / norm).collect(),
+        samples: x.len(),
+    })
+}
+
+pub fn autocorrelation(y: &[f64], dt: f64, max_lag: usize) -> Result<Correlation, Box<dyn std::error::Error>> {
+    correlation(y, y, dt, max_lag)
+}
+
+///
+/// Autocorrelation averaged over the members of an ensemble, each an
+/// independent realization on the same grid. The band narrows with the
+/// total sample count
+///
+pub fn ensemble_autocorrelation(members: &[Vec<f64>], dt: f64, max_lag: usize) -> Result<Correlation, Box<dyn std::error::Error>> {
+    let Some(first) = members.first() else {
+        return Err("correlation: empty ensemble".into());
+    };
+    let mut mean = autocorrelation(first, dt, max_lag)?;
+    for m in &members[1..] {
+        let c = autocorrelation(m, dt, max_lag)?;
+        for (a, b) in mean.rho.iter_mut().zip(&c.rho) {
+            *a += b;
+        }
+        mean.samples += c.samples;
+    }
+    for r in mean.rho.iter_mut() {
+        *r /= members.len() as f64;
+    }
+    Ok(mean)
+}
+
+///
+/// One panel of plot, with an optional exact correlation on the same lags
+///
+pub struct Panel<'a> {
+    pub title: String,
+    pub correlation: &'a Correlation,
+    pub reference: Option<(&'a str, Vec<f64>)>,
+}
+
+///
+/// rho against lag, one panel each, the Bartlett band shaded and the
+/// white noise band dashed
+///
+pub fn plot(theme: &PlotStyle, panels: &[Panel], path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    if panels.is_empty() {
+        return Err(format!("{path}: no correlations to plot").into());
+    }
+    let root = BitMapBackend::new(path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?;
+    let root = theme.stamp(&root)?;
+    let areas = root.split_evenly((panels.len(), 1));
+
+    for (p, area) in panels.iter().zip(&areas) {
+        let c = p.correlation;
+        let span = c.lag[0]..c.lag[c.lag.len() - 1];
+        let mut chart = ChartBuilder::on(area)
+            .caption(&p.title, theme.caption())
+            .margin(theme.margin)
+            .set_label_area_size(LabelAreaPosition::Left, 70)
+            .set_label_area_size(LabelAreaPosition::Bottom, 40)
+            .build_cartesian_2d(span.clone(), -1.05..1.05)?;
+        chart.configure_mesh()
+            .label_style(theme.label())
+            .axis_desc_style(theme.label())
+            .x_desc("lag")
+            .y_desc("rho")
+            .draw()?;
+
+        let bounds = c.bartlett_bounds();
+        let band: Vec<(f64, f64)> = c.lag.iter().zip(&bounds).map(|(l, b)| (*l, *b))
+            .chain(c.lag.iter().zip(&bounds).rev().map(|(l, b)| (*l, -b)))
+            .collect();
+        chart.draw_series([Polygon::new(band, BLACK.mix(0.12).filled())])?
+            .label("bartlett 95%")
+            .legend(|(x, y)| Rectangle::new([(x, y - 5), (x + 20, y + 5)], BLACK.mix(0.12).filled()));
+        let white = c.white_bound();
+        for level in [white, -white] {
+            chart.draw_series(DashedLineSeries::new([(span.start, level), (span.end, level)], 6, 4, BLACK.stroke_width(theme.thin)))?;
+        }
+
+        let color = theme.color(0);
+        chart.draw_series(LineSeries::new(c.lag.iter().copied().zip(c.rho.iter().copied()), color.stroke_width(theme.line)))?
+            .label("estimate")
+            .legend(move |(x, y)| PathElement::new([(x, y), (x + 20, y)], color.stroke_width(2)));
+        if let Some((label, exact)) = &p.reference {
+            let color = theme.color(1);
+            chart.draw_series(DashedLineSeries::new(
+                c.lag.iter().copied().zip(exact.iter().copied()), 6, 4, color.stroke_width(theme.line)))?
+                .label(*label)
+                .legend(move |(x, y)| PathElement::new([(x, y), (x + 20, y)], color.stroke_width(2)));
+        }
+        chart.configure_series_labels()
+            .label_font(theme.label())
+            .border_style(BLACK)
+            .background_style(WHITE.mix(0.85))
+            .draw()?;
+    }
+
+    root.present()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    ///
+    /// The biased correlation summed directly, O(n max_lag)
+    ///
+    fn direct(x: &[f64], y: &[f64], max_lag: usize) -> Vec<f64> {
+        let n = x.len();
+        let (xbar, ybar) = (x.iter().sum::<f64>() / n as f64, y.iter().sum::<f64>() / n as f64);
+        let sum = |a: &[f64], abar: f64, b: &[f64], bbar: f64, k: usize| -> f64 {
+            (0..n - k).map(|i| (a[i] - abar) * (b[i + k] - bbar)).sum()
+        };
+        let norm = (sum(x, xbar, x, xbar, 0) * sum(y, ybar, y, ybar, 0)).sqrt();
+        (0..=max_lag).map(|k| sum(x, xbar, y, ybar, k) / norm).collect()
+    }
+
+    fn series(n: usize, seed: f64) -> Vec<f64> {
+        (0..n).map(|i| ((i as f64 + 1.0) * seed) % 1.0 + 0.3 * (0.2 * i as f64).sin()).collect()
+    }
+
+    #[test]
+    fn fft_autocorrelation_matches_the_direct_sum() {
+        // 300 is not a power of two, so the padding is exercised
+        for n in [64, 300] {
+            let y = series(n, 0.618);
+            let c = autocorrelation(&y, 0.5, 40).unwrap();
+            assert_eq!(c.lag[40], 20.0);
+            assert!((c.rho[0] - 1.0).abs() < 1e-12);
+            for (k, (got, want)) in c.rho.iter().zip(direct(&y, &y, 40)).enumerate() {
+                assert!((got - want).abs() < 1e-12, "n {n} lag {k}: {got} vs {want}");
+            }
+        }
+    }
+
+    #[test]
+    fn cross_correlation_matches_the_direct_sum() {
+        let (x, y) = (series(200, 0.618), series(200, 0.414));
+        let c = correlation(&x, &y, 1.0, 30).unwrap();
+        for (got, want) in c.rho.iter().zip(direct(&x, &y, 30)) {
+            assert!((got - want).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn period_of_a_sampled_sine() {
+        let dt = 0.05;
+        let y: Vec<f64> = (0..2000).map(|i| (2.0 * std::f64::consts::PI * i as f64 * dt / 3.0).sin()).collect();
+        let period = autocorrelation(&y, dt, 200).unwrap().period().unwrap();
+        assert!((period - 3.0).abs() < 0.01, "period {period}");
+    }
+
+    #[test]
+    fn bad_inputs_are_refused() {
+        assert!(correlation(&[1.0, 2.0], &[1.0], 1.0, 0).is_err());
+        assert!(autocorrelation(&[1.0, 2.0, 3.0], 1.0, 3).is_err());
+        assert!(autocorrelation(&[2.0; 10], 1.0, 2).is_err());
+    }
+}
@@ -0,0 +1,198 @@
+//!
+//! derived.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Post-processing of trajectories into derived series. A Pipeline holds
+//! named closures of (t, y), the common ones (per-capita rates, ratios
+//! of two components, logs) built from the system's StateVars, and maps a
+//! trajectory through all of them at once. The named series that come out
+//! are plotted one panel each and written as CSV beside the figure
+//!
+
+#![allow(dead_code)]
+
+use std::io::{BufWriter, Write};
+
+use plotters::prelude::*;
+use plotters_bitmap::BitMapBackend;
+
+use crate::downsample;
+use crate::state::StateVar;
+use crate::theme::PlotStyle;
+use crate::trajectory::Trajectory;
+
+///
+/// One derived quantity sampled on the trajectory's times. A value the
+/// transform cannot give (the log of an extinct species) is NaN
+///
+#[derive(Clone, Debug)]
+pub struct Derived {
+    pub name: String,
+    pub unit: String,
+    pub t: Vec<f64>,
+    pub values: Vec<f64>,
+}
+
+impl Derived {
+    ///
+    /// Axis or legend text, as StateVar::label
+    ///
+    pub fn label(&self) -> String {
+        if self.unit.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{} [{}]", self.name, self.unit)
+        }
+    }
+
+    ///
+    /// Column header, as StateVar::column
+    ///
+    pub fn column(&self) -> String {
+        self.name.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect()
+    }
+
+    ///
+    /// (t, value) at every finite value
+    ///
+    pub fn points(&self) -> Vec<(f64, f64)> {
+        self.t.iter().copied().zip(self.values.iter().copied()).filter(|(_, v)| v.is_finite()).collect()
+    }
+}
+
+type Transform<'a, const N: usize> = Box<dyn Fn(f64, &[f64; N]) -> f64 + 'a>;
+
+pub struct Pipeline<'a, const N: usize> {
+    states: [StateVar; N],
+    transforms: Vec<(String, String, Transform<'a, N>)>,
+}
+
+impl<'a, const N: usize> Pipeline<'a, N> {
+    pub fn new(states: [StateVar; N]) -> Pipeline<'a, N> {
+        Pipeline { states, transforms: Vec::new() }
+    }
+
+    ///
+    /// Any derived series f(t, y)
+    ///
+    pub fn map(mut self, name: &str, unit: &str, f: impl Fn(f64, &[f64; N]) -> f64 + 'a) -> Pipeline<'a, N> {
+        self.transforms.push((name.to_string(), unit.to_string(), Box::new(f)));
+        self
+    }
+
+    ///
+    /// (dy_j/dt) / y_j from the model's rate, the growth rate per member
+    ///
+    pub fn per_capita<F>(self, rate: &'a F, j: usize) -> Pipeline<'a, N>
+    where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+        let name = format!("{} per capita", self.states[j].name);
+        self.map(&name, "1/t", move |t, y| {
+            let mut dy = [0.0; N];
+            rate(t, y, &mut dy);
+            dy[j] / y[j]
+        })
+    }
+
+    ///
+    /// y_i / y_j
+    ///
+    pub fn ratio(self, i: usize, j: usize) -> Pipeline<'a, N> {
+        let name = format!("{} / {}", self.states[i].name, self.states[j].name);
+        let unit = if self.states[i].unit == self.states[j].unit { "" } else { "mixed" };
+        self.map(&name, unit, move |_, y| y[i] / y[j])
+    }
+
+    ///
+    /// log10 y_j, NaN where y_j is not positive
+    ///
+    pub fn log(self, j: usize) -> Pipeline<'a, N> {
+        let name = format!("log10 {}", self.states[j].name);
+        self.map(&name, "", move |_, y| if y[j] > 0.0 { y[j].log10() } else { f64::NAN })
+    }
+
+    pub fn len(&self) -> usize {
+        self.transforms.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.transforms.is_empty()
+    }
+
+    ///
+    /// Every transform over every point of traj
+    ///
+    pub fn apply(&self, traj: &Trajectory<N>) -> Vec<Derived> {
+        let rows = traj.to_rows();
+        self.transforms.iter().map(|(name, unit, f)| Derived {
+            name: name.clone(),
+            unit: unit.clone(),
+            t: traj.time().to_vec(),
+            values: traj.time().iter().zip(&rows).map(|(t, y)| f(*t, y)).collect(),
+        }).collect()
+    }
+}
+
+///
+/// CSV with a t column and one column per series, which must share their
+/// times. Values are written at full precision
+///
+pub fn write_csv(path: &str, series: &[Derived]) -> Result<(), Box<dyn std::error::Error>> {
+    let Some(first) = series.first() else {
+        return Err(format!("{path}: no derived series to write").into());
+    };
+    if let Some(s) = series.iter().find(|s| s.t != first.t) {
+        return Err(format!("{path}: {} is on different times from {}", s.name, first.name).into());
+    }
+    let mut out = BufWriter::new(std::fs::File::create(path).map_err(|e| format!("{path}: {e}"))?);
+    let header: Vec<String> = series.iter().map(Derived::column).collect();
+    writeln!(out, "t,{}", header.join(","))?;
+    for (i, t) in first.t.iter().enumerate() {
+        let row: Vec<String> = series.iter().map(|s| format!("{:e}", s.values[i])).collect();
+        writeln!(out, "{t:e},{}", row.join(","))?;
+    }
+    out.flush()?;
+    Ok(())
+}
+
+///
+/// One panel per series over a shared t range, stacked, or in two
+/// columns past three series
+///
+pub fn plot(theme: &PlotStyle, series: &[Derived], path: &str, title: &str) -> Result<(), Box<dyn std::error::Error>> {
+    if series.is_empty() {
+        return Err(format!("{path}: no derived series to plot").into());
+    }
+    let root = BitMapBackend::new(path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?;
+    let root = theme.stamp(&root)?;
+    let cols = if series.len() > 3 { 2 } else { 1 };
+    let panels = root.split_evenly((series.len().div_ceil(cols), cols));
+
+    for (k, (s, panel)) in series.iter().zip(&panels).enumerate() {
+        let finite = s.points();
+        if finite.is_empty() {
+            return Err(format!("{path}: {} has no finite values", s.name).into());
+        }
+        let (t, v): (Vec<f64>, Vec<f64>) = finite.into_iter().unzip();
+        let points = downsample::points(&t, &v, downsample::PLOT_POINTS);
+        let (lo, hi) = v.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), x| (lo.min(*x), hi.max(*x)));
+        let pad = 0.05 * (hi - lo).max(1e-12 * hi.abs().max(1.0));
+
+        let mut chart = ChartBuilder::on(panel)
+            .caption(format!("{title}, {}", s.name), theme.caption())
+            .margin(theme.margin)
+            .set_label_area_size(LabelAreaPosition::Left, 70)
+            .set_label_area_size(LabelAreaPosition::Bottom, 40)
+            .build_cartesian_2d(s.t[0]..s.t[s.t.len() - 1], (lo - pad)..(hi + pad))?;
+        chart.configure_mesh()
+            .label_style(theme.label())
+            .axis_desc_style(theme.label())
+            .x_desc("t")
+            .y_desc(s.label())
+            .draw()?;
+        let color = theme.color(k);
+        chart.draw_series(LineSeries::new(points, color.stroke_width(theme.line)))?;
+    }
+
+    root.present()?;
+    Ok(())
+}
@@ -0,0 +1,76 @@
+//!
+//! downsample.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Largest-Triangle-Three-Buckets downsampling for plotting. The interior
+//! points are split into equal buckets and each bucket keeps the point
+//! forming the largest triangle with the point kept before it and the mean
+//! of the next bucket, so peaks and troughs survive where taking every k-th
+//! point would alias them away and flatten an oscillation's envelope. The
+//! first and last points are always kept
+//!
+
+#![allow(dead_code)]
+
+use std::ops::Range;
+
+///
+/// Points kept per series in the 1200 px figures, two per pixel column
+///
+pub const PLOT_POINTS: usize = 2400;
+
+/// (x, y) points of one plotted line
+pub type Series = Vec<(f64, f64)>;
+
+///
+/// Index range of interior bucket b of threshold - 2, over len points
+///
+pub fn bucket(len: usize, threshold: usize, b: usize) -> Range<usize> {
+    let every = (len - 2) as f64 / (threshold - 2) as f64;
+    let edge = |b: usize| (1 + (b as f64 * every) as usize).min(len - 1);
+    edge(b)..edge(b + 1)
+}
+
+///
+/// Twice the area of the triangle a, b, c, which ranks candidates the same
+///
+pub fn area(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> f64 {
+    ((a.0 - c.0) * (b.1 - a.1) - (a.0 - b.0) * (c.1 - a.1)).abs()
+}
+
+fn centroid(x: &[f64], y: &[f64], range: Range<usize>) -> (f64, f64) {
+    let n = range.len() as f64;
+    (x[range.clone()].iter().sum::<f64>() / n, y[range].iter().sum::<f64>() / n)
+}
+
+///
+/// Indices of at most threshold points of (x, y) chosen by LTTB, in order.
+/// Every index when the series is already short enough
+///
+pub fn lttb(x: &[f64], y: &[f64], threshold: usize) -> Vec<usize> {
+    let len = x.len().min(y.len());
+    if threshold < 3 || len <= threshold {
+        return (0..len).collect();
+    }
+    let buckets = threshold - 2;
+    let mut kept = Vec::with_capacity(threshold);
+    kept.push(0);
+    for b in 0..buckets {
+        // the last point stands in for the bucket after the final one
+        let next = if b + 1 < buckets { bucket(len, threshold, b + 1) } else { len - 1..len };
+        let target = centroid(x, y, next);
+        let prev = kept[kept.len() - 1];
+        let best = bucket(len, threshold, b)
+            .max_by(|&i, &j| area((x[prev], y[prev]), (x[i], y[i]), target).total_cmp(&area((x[prev], y[prev]), (x[j], y[j]), target)))
+            .unwrap_or(prev);
+        kept.push(best);
+    }
+    kept.push(len - 1);
+    kept
+}
+
+///
+/// The (x, y) pairs lttb keeps, ready for a LineSeries
+///
+pub fn points(x: &[f64], y: &[f64], threshold: usize) -> Series {
+    lttb(x, y, threshold).into_iter().map(|i| (x[i], y[i])).collect()
+}
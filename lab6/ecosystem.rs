@@ -14,6 +14,169 @@
 
 use plotters::prelude::*; 
 use plotters_bitmap::BitMapBackend;
+use std::sync::Arc;
+use std::time::Instant;
+use solvers::competition::{self, Competition};
+use solvers::{CorrectorFailure, Limits, Method, SolveStats};
+
+mod adams;
+mod annotate;
+mod asymptotic;
+mod axes;
+mod batch;
+mod chunked;
+mod conservation;
+mod continuation;
+mod correlation;
+mod derived;
+mod downsample;
+mod ensemble;
+#[cfg(feature = "gpu")]
+mod ensemble_gpu;
+mod experiment;
+mod export;
+mod fourier;
+mod graph;
+mod ic_sweep;
+mod imex;
+mod impulse;
+mod linalg;
+mod metapopulation;
+mod metric;
+mod multistep;
+mod noise;
+mod peaks;
+mod picard;
+mod piecewise;
+mod reference;
+mod report;
+mod residual;
+mod richardson;
+mod run_context;
+mod running;
+mod scaling;
+mod signal;
+mod spline;
+mod splitting;
+mod stages;
+mod state;
+mod system;
+mod taylor;
+mod theme;
+mod tolerance;
+mod trajectory;
+mod units;
+
+use annotate::Annotation;
+use axes::Axis;
+use chunked::ChunkedWriter;
+use conservation::Ledger;
+use continuation::Continuation;
+use ensemble::Sample;
+use experiment::Experiment;
+use graph::Graph;
+use imex::NewtonOptions;
+use linalg::Matrix;
+use metapopulation::Metapopulation;
+use metric::{Convergence, ErrorMetric};
+use multistep::Abm4;
+use noise::Noise;
+use piecewise::Piecewise;
+use reference::Reference;
+use report::Table;
+use run_context::RunContext;
+use running::RunningStats;
+use scaling::Scales;
+use spline::{Interpolation, Spline};
+use state::StateVar;
+use system::OdeSystem;
+use theme::PlotStyle;
+use taylor::{Series, SeriesSystem};
+use tolerance::Tolerance;
+use trajectory::Trajectory;
+use units::{Day, Interaction, Population, Rate, Time, TimeUnit};
+
+///
+/// Non-dimensional groups of the competition model. In u_i = N_i / K_i and 
+/// s = a_1 t the rate is u_i' = rho_i u_i (1 - u_i - alpha_i u_j) 
+///
+#[derive(Clone, Copy, Debug)]
+struct Groups {
+    /// carrying capacities K_i = a_i / b_i 
+    capacity: [f64; 2], 
+    /// competition ratios alpha_1 = c_1 K_2 / a_1 and alpha_2 = c_2 K_1 / a_2, 
+    /// the pressure of the other species at its capacity against own growth 
+    competition: [f64; 2], 
+    /// growth rates relative to species 1, rho = a / a_1 
+    growth: [f64; 2], 
+    /// starting populations as fractions of capacity 
+    start: [f64; 2], 
+    /// span in growth times of the faster species 
+    span: f64, 
+}
+
+impl Groups {
+    ///
+    /// Outcome predicted by the competition ratios alone 
+    ///
+    fn regime(&self) -> &'static str {
+        match (self.competition[0] < 1.0, self.competition[1] < 1.0) {
+            (true, true) => "coexistence", 
+            (true, false) => "N1 wins", 
+            (false, true) => "N2 wins", 
+            (false, false) => "bistable", 
+        }
+    }
+}
+
+///
+/// A model parameter given as a function of time, e.g. seasonal growth. 
+/// Shared so builders stay Clone and sweeps can run on several threads 
+///
+#[derive(Clone)]
+struct Schedule(Arc<dyn Fn(f64) -> f64 + Send + Sync>); 
+
+impl std::fmt::Debug for Schedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Schedule")
+    }
+}
+
+impl Schedule {
+    pub fn new(f: impl Fn(f64) -> f64 + Send + Sync + 'static) -> Schedule {
+        Schedule(Arc::new(f))
+    }
+
+    ///
+    /// base (1 + eps sin(2 pi t / period)) 
+    ///
+    pub fn seasonal(base: f64, eps: f64, period: f64) -> Schedule {
+        Schedule::new(move |t| base * (1.0 + eps * (2.0 * std::f64::consts::PI * t / period).sin()))
+    }
+
+    ///
+    /// A tabulated response read at an input that varies in time, e.g. a 
+    /// measured growth rate against temperature with temperature(t) 
+    ///
+    pub fn tabulated(table: Spline, input: impl Fn(f64) -> f64 + Send + Sync + 'static) -> Schedule {
+        Schedule::new(move |t| table.eval(input(t)))
+    }
+
+    pub fn at(&self, t: f64) -> f64 {
+        (self.0)(t)
+    }
+}
+
+///
+/// Which coefficient array a schedule replaces an entry of 
+///
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Param {
+    Growth, 
+    SelfLimitation, 
+    Competition, 
+}
 
 /// 
 /// Metadata for ecosystem stored in static arrays 
@@ -23,131 +186,547 @@ struct Ecosystem {
     a:  [f64; 2], 
     b:  [f64; 2], 
     c:  [f64; 2],
-    ts: [f64; 2] 
+    ts: [f64; 2], 
+    forcing: Vec<(Param, usize, Schedule)>, 
+    /// method and dt checked by the builder, what run solves with 
+    step: Option<(Method, f64)>, 
+}
+
+///
+/// Named construction of an Ecosystem. build() checks every parameter is 
+/// set and finite, populations and c are non-negative, b is positive so 
+/// every carrying capacity a / b exists, the time span is increasing, and 
+/// a method and dt, when given, fit the span and are stable on the system 
+///
+#[derive(Clone, Debug, Default)]
+struct EcosystemBuilder {
+    ic: Option<[f64; 2]>, 
+    a:  Option<[f64; 2]>, 
+    b:  Option<[f64; 2]>, 
+    c:  Option<[f64; 2]>, 
+    ts: Option<[f64; 2]>, 
+    forcing: Vec<(Param, usize, Schedule)>, 
+    step: Option<(Method, f64)>, 
+}
+
+impl EcosystemBuilder {
+    ///
+    /// Initial populations [N1, N2] 
+    ///
+    pub fn populations(mut self, ic: [f64; 2]) -> EcosystemBuilder {
+        self.ic = Some(ic); 
+        self
+    }
+
+    ///
+    /// Per-capita growth rates a 
+    ///
+    pub fn growth(mut self, a: [f64; 2]) -> EcosystemBuilder {
+        self.a = Some(a); 
+        self
+    }
+
+    ///
+    /// Intraspecific coefficients b, the effect of a species on itself 
+    ///
+    pub fn self_limitation(mut self, b: [f64; 2]) -> EcosystemBuilder {
+        self.b = Some(b); 
+        self
+    }
+
+    ///
+    /// Interspecific coefficients c, c[i] is the effect of the other 
+    /// species on species i 
+    ///
+    pub fn competition(mut self, c: [f64; 2]) -> EcosystemBuilder {
+        self.c = Some(c); 
+        self
+    }
+
+    pub fn span(mut self, t0: f64, tf: f64) -> EcosystemBuilder {
+        self.ts = Some([t0, tf]); 
+        self
+    }
+
+    ///
+    /// Method and dt the ecosystem is run with, checked by build against 
+    /// the span and the method's stability near the equilibria 
+    ///
+    pub fn step(mut self, method: Method, dt: f64) -> EcosystemBuilder {
+        self.step = Some((method, dt)); 
+        self
+    }
+
+    ///
+    /// Replaces entry i of a coefficient array by a schedule in t inside 
+    /// the rate. The constant value set for it stays the base used by the 
+    /// frozen-time analyses (capacities, equilibria, scaling) 
+    ///
+    pub fn forced(mut self, param: Param, i: usize, schedule: Schedule) -> EcosystemBuilder {
+        self.forcing.push((param, i, schedule)); 
+        self
+    }
+
+    pub fn build(self) -> Result<Ecosystem, Box<dyn std::error::Error>> {
+        let get = |v: Option<[f64; 2]>, name: &str| {
+            v.ok_or_else(|| format!("ecosystem: {name} not set"))
+        }; 
+        let ic = get(self.ic, "populations")?; 
+        let a = get(self.a, "growth")?; 
+        let b = get(self.b, "self_limitation")?; 
+        let c = get(self.c, "competition")?; 
+        let ts = get(self.ts, "span")?; 
+
+        for (name, v) in [("populations", ic), ("growth", a), ("self_limitation", b), ("competition", c), ("span", ts)] {
+            if let Some(i) = v.iter().position(|x| !x.is_finite()) {
+                return Err(format!("ecosystem: {name}[{i}] = {} is not finite", v[i]).into()); 
+            }
+        }
+        for (name, v) in [("populations", ic), ("competition", c)] {
+            if let Some(i) = v.iter().position(|x| *x < 0.0) {
+                return Err(format!(
+                    "ecosystem: {name}[{i}] = {} is negative, the competition model needs {name} >= 0", 
+                    v[i]).into()); 
+            }
+        }
+        if let Some(i) = b.iter().position(|x| *x <= 0.0) {
+            return Err(format!(
+                "ecosystem: self_limitation[{i}] = {} must be positive, the carrying capacity a / b needs b > 0", 
+                b[i]).into()); 
+        }
+        if ts[1] <= ts[0] {
+            return Err(format!("ecosystem: span [{}, {}] must have t0 < tf", ts[0], ts[1]).into()); 
+        }
+        if let Some((param, i, _)) = self.forcing.iter().find(|(_, i, _)| *i >= 2) {
+            return Err(format!("ecosystem: forcing of {param:?}[{i}] is out of range for two species").into()); 
+        }
+
+        let eco = Ecosystem { ic, a, b, c, ts, forcing: self.forcing, step: self.step }; 
+        if let Some((method, dt)) = eco.step {
+            eco.check_dt(dt)?; 
+            eco.check_stability(method, dt)?; 
+        }
+        Ok(eco)
+    }
+}
+
+///
+/// Linear stability of an equilibrium from its Jacobian eigenvalues 
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Stability {
+    Stable, 
+    Unstable, 
+    Saddle, 
+    /// an eigenvalue with zero real part, linearization decides nothing 
+    Degenerate, 
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Equilibrium {
+    name: &'static str, 
+    point: [f64; 2], 
+    /// inside the closed positive quadrant, so populations can reach it 
+    feasible: bool, 
+    /// (re, im) pairs 
+    eigenvalues: [(f64, f64); 2], 
+    stability: Stability, 
 }
 
 impl Ecosystem {
-    pub fn new(
-        ic: [f64; 2], 
-        a:  [f64; 2], 
-        b:  [f64; 2], 
-        c:  [f64; 2], 
-        ts: [f64; 2]) -> Ecosystem 
-    { 
-        Ecosystem { ic, a, b, c, ts }
+    pub const STATES: [StateVar; 2] = [
+        StateVar::new("N1", "individuals"), 
+        StateVar::new("N2", "individuals"), 
+    ]; 
+
+    pub fn builder() -> EcosystemBuilder {
+        EcosystemBuilder::default()
     }
 
     ///
-    /// Rate function as a system of first order ODE's 
+    /// Builder holding the lab's system: 1e5 of each species, a = 0.1, 
+    /// b = 8e-7 and c = [1e-6, 1e-7]. Only the span is left to set, and 
+    /// any parameter can still be replaced before build 
     ///
-    fn rate(&self, pop: &[f64; 2], d_pop: &mut [f64; 2]) {
-        d_pop[0] = pop[0] * (self.a[0] - self.b[0] * pop[0] - self.c[0] * pop[1]);  
-        d_pop[1] = pop[1] * (self.a[1] - self.b[1] * pop[1] - self.c[1] * pop[0]);  
+    pub fn lab() -> EcosystemBuilder {
+        Ecosystem::builder()
+            .populations([1e5, 1e5])
+            .growth([0.1, 0.1])
+            .self_limitation([8e-7, 8e-7])
+            .competition([1e-6, 1e-7])
     }
- 
+
     ///
-    /// Solve the Ecosystem at the given initial conditions given some step size 
-    /// using Runge-Kutta 4th Order 
-    ///
-    pub fn solve(&self, dt: f64) -> (Vec<f64>, Vec<[f64; 2]>) {
-        let n = ((self.ts[1] - self.ts[0]) / dt).floor() as usize;  
-        let mut t: Vec<f64> = Vec::with_capacity(n + 1); 
-        let mut y: Vec<[f64; 2]> = Vec::with_capacity(n + 1);
-
-        t.push(self.ts[0]);
-        y.push(self.ic);
-
-        // initialize local per step states
-        let mut k1: [f64; 2] = [0.0, 0.0];
-        let mut k2: [f64; 2] = [0.0, 0.0];
-        let mut k3: [f64; 2] = [0.0, 0.0];
-        let mut k4: [f64; 2] = [0.0, 0.0];
-
-        let mut w1: [f64; 2];
-        let mut w2: [f64; 2] = [0.0, 0.0];
-        let mut w3: [f64; 2] = [0.0, 0.0];
-        let mut w4: [f64; 2] = [0.0, 0.0];
-
-        // update helper 
-        let update = |w: &[f64; 2], k: &[f64; 2], u: &mut [f64; 2], h: f64| {
-             u[0] = w[0] + h * k[0]; 
-             u[1] = w[1] + h * k[1]; 
+    /// Checks dt is positive, no longer than the span, and divides it, so 
+    /// every point lies on one uniform grid. The generic fixed-step solvers 
+    /// would otherwise land on tf with a shortened last step, AB/AM4 on an 
+    /// rk4 step of the remainder, and this lab's own rk4 would stop short 
+    ///
+    pub fn check_dt(&self, dt: f64) -> Result<(), Box<dyn std::error::Error>> {
+        let span = self.ts[1] - self.ts[0]; 
+        if !dt.is_finite() || dt <= 0.0 {
+            return Err(format!("dt = {dt} must be positive").into()); 
+        }
+        if dt > span {
+            return Err(format!("dt = {dt} exceeds the span {span}").into()); 
+        }
+        if solvers::final_step(self.ts, dt).is_some() {
+            let end = self.ts[0] + solvers::step_count(self.ts, dt) as f64 * dt; 
+            return Err(format!("dt = {dt} does not divide the span {span}, the last step would end at t = {end}").into()); 
+        }
+        Ok(())
+    }
+
+    ///
+    /// Refuses a method and dt outside the method's real stability interval 
+    /// for the fastest decaying mode at any feasible equilibrium, where the 
+    /// populations settle. Growing modes are left alone, an explicit method 
+    /// follows growth at any dt 
+    ///
+    pub fn check_stability(&self, method: Method, dt: f64) -> Result<(), Box<dyn std::error::Error>> {
+        // where each method's stability region crosses the negative real axis 
+        let bound = match method {
+            Method::Euler | Method::Heun | Method::Midpoint => 2.0, 
+            Method::Rk4 => 2.785, 
         }; 
+        for e in self.equilibrium_analysis().iter().filter(|e| e.feasible) {
+            for (re, im) in e.eigenvalues.iter().filter(|(re, _)| *re < 0.0) {
+                let z = dt * re.hypot(*im); 
+                if z > bound {
+                    return Err(format!(
+                        "{} at dt = {dt} is unstable near {}: dt |lambda| = {z:.3} exceeds its bound of {bound}", 
+                        method.name(), e.name).into()); 
+                }
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Coefficients [a, b, c] at time t, the scheduled entries evaluated 
+    ///
+    fn parameters(&self, t: f64) -> [[f64; 2]; 3] {
+        let mut p = [self.a, self.b, self.c]; 
+        for (param, i, schedule) in &self.forcing {
+            p[*param as usize][*i] = schedule.at(t); 
+        }
+        p
+    }
+
+    ///
+    /// Rate function as a system of first order ODE's 
+    ///
+    fn rate(&self, t: f64, pop: &[f64; 2], d_pop: &mut [f64; 2]) {
+        let [a, b, c] = self.parameters(t); 
+        competition::rate(&a, &b, &c, pop, d_pop); 
+    }
+
+    ///
+    /// Constructor from typed parameters. Every rate and the time span must 
+    /// share one time unit U, which becomes the model's unit of t; values 
+    /// given in another unit need an explicit .to::<U>() 
+    ///
+    pub fn with_units<U: TimeUnit>(
+        ic: [Population; 2], 
+        a:  [Rate<U>; 2], 
+        b:  [Interaction<U>; 2], 
+        c:  [Interaction<U>; 2], 
+        ts: [Time<U>; 2]) -> Result<Ecosystem, Box<dyn std::error::Error>> 
+    {
+        Ecosystem::builder()
+            .populations(ic.map(|p| p.0))
+            .growth(a.map(Rate::value))
+            .self_limitation(b.map(Interaction::value))
+            .competition(c.map(Interaction::value))
+            .span(ts[0].value(), ts[1].value())
+            .build()
+    }
 
-        // compute next values 
-        let next = |w1: &[f64; 2], ks: &[[f64; 2]; 4]| -> [f64; 2] {
-            let mut wnext: [f64; 2] = [0.0, 0.0]; 
-            let pool0 = ks[0][0] + 2.0 * ks[1][0] + 2.0 * ks[2][0] + ks[3][0];
-            let pool1 = ks[0][1] + 2.0 * ks[1][1] + 2.0 * ks[2][1] + ks[3][1];
+    ///
+    /// Characteristic scales: the single-species carrying capacity a / b 
+    /// for each population and the growth time 1 / a of the faster species 
+    ///
+    fn scales(&self) -> Scales<2> {
+        let time = 1.0 / self.a[0].max(self.a[1]); 
+        Scales::new([self.a[0] / self.b[0], self.a[1] / self.b[1]], time, self.ts[0])
+    }
+
+    ///
+    /// Coefficients of the scaled rate u_i' = u_i (a_i T - b_i Y_i T u_i - c_i Y_j T u_j), 
+    /// rows are [a, b, c] per species 
+    ///
+    fn scaled_coefficients(&self) -> [[f64; 3]; 2] {
+        let sc = self.scales(); 
+        let (y, t) = (sc.state, sc.time); 
+        [
+            [self.a[0] * t, self.b[0] * y[0] * t, self.c[0] * y[1] * t], 
+            [self.a[1] * t, self.b[1] * y[1] * t, self.c[1] * y[0] * t], 
+        ]
+    }
+
+    ///
+    /// Non-dimensional groups 
+    ///
+    fn groups(&self) -> Groups {
+        let capacity = [self.a[0] / self.b[0], self.a[1] / self.b[1]]; 
+        Groups {
+            capacity, 
+            competition: [self.c[0] * capacity[1] / self.a[0], self.c[1] * capacity[0] / self.a[1]], 
+            growth: [1.0, self.a[1] / self.a[0]], 
+            start: [self.ic[0] / capacity[0], self.ic[1] / capacity[1]], 
+            span: (self.ts[1] - self.ts[0]) * self.a[0].abs().max(self.a[1].abs()), 
+        }
+    }
 
-            wnext[0] = w1[0] + (dt / 6.0) * pool0; 
-            wnext[1] = w1[1] + (dt / 6.0) * pool1; 
-            wnext 
-        };
+    ///
+    /// Prints the non-dimensional groups and returns a warning for each 
+    /// parameter that looks mistyped or outside the regime the lab assumes 
+    ///
+    fn report(&self) -> Vec<String> {
+        let g = self.groups(); 
+        println!("carrying capacity K = [{:.4e}, {:.4e}]", g.capacity[0], g.capacity[1]); 
+        println!("competition ratios alpha = [{:.4}, {:.4}], predicted {}", g.competition[0], g.competition[1], g.regime()); 
+        println!("growth ratio a2 / a1 = {:.4}", g.growth[1]); 
+        println!("start N0 / K = [{:.4}, {:.4}]", g.start[0], g.start[1]); 
+        println!("span = {:.1} growth times", g.span); 
+
+        let mut warnings = Vec::new(); 
+        for i in 0..2 {
+            if self.a[i] <= 0.0 {
+                warnings.push(format!("a[{i}] = {} <= 0, N{} declines whatever the competition", self.a[i], i + 1)); 
+            }
+            if g.start[i] > 10.0 {
+                warnings.push(format!("N{} starts at {:.1} times its capacity, check the units of ic and b", i + 1, g.start[i])); 
+            }
+            if g.competition[i].is_finite() && (g.competition[i] > 1e3 || (g.competition[i] > 0.0 && g.competition[i] < 1e-3)) {
+                warnings.push(format!("alpha[{i}] = {:.3e} is far from 1, check the scale of c[{i}] against b", g.competition[i])); 
+            }
+        }
+        if g.growth[1].is_finite() && (g.growth[1] > 1e3 || g.growth[1] < 1e-3) {
+            warnings.push(format!("growth ratio {:.3e}, the system is stiff for explicit solvers", g.growth[1])); 
+        }
+        if g.span < 5.0 {
+            warnings.push(format!("span covers {:.2} growth times, too short to see the long-term outcome", g.span)); 
+        }
+        for w in &warnings {
+            println!("warning: {w}"); 
+        }
+        warnings
+    }
 
-        // main loop
-        for i in 1..=n {
-            w1 = *y.last().unwrap(); 
-            self.rate(&w1, &mut k1);
-            update(&w1, &k1, &mut w2, 0.5_f64 * dt); 
-            self.rate(&w2, &mut k2); 
-            update(&w2, &k2, &mut w3, 0.5_f64 * dt); 
-            self.rate(&w3, &mut k3); 
-            update(&w3, &k3, &mut w4, dt);
-            self.rate(&w4, &mut k4); 
-            y.push(next(&w1, &[k1, k2, k3, k4]));
+    ///
+    /// Jacobian of the rate at pop 
+    ///
+    fn jacobian(&self, pop: &[f64; 2]) -> [[f64; 2]; 2] {
+        [
+            [self.a[0] - 2.0 * self.b[0] * pop[0] - self.c[0] * pop[1], -self.c[0] * pop[0]], 
+            [-self.c[1] * pop[1], self.a[1] - 2.0 * self.b[1] * pop[1] - self.c[1] * pop[0]], 
+        ]
+    }
 
-            let ti = self.ts[0] + (i as f64) * dt; 
-            t.push(ti);
+    ///
+    /// The equilibria of the competition model from a, b, c alone: both 
+    /// extinct, either species alone at its carrying capacity a / b, and 
+    /// the coexistence point of the two nullclines, which is dropped when 
+    /// they are parallel and marked infeasible when it leaves the positive 
+    /// quadrant. Stability comes from the closed form eigenvalues of the 
+    /// 2 x 2 Jacobian 
+    ///
+    fn equilibrium_analysis(&self) -> Vec<Equilibrium> {
+        let mut points = vec![
+            ("extinction", [0.0, 0.0]), 
+            ("N1 wins", [self.a[0] / self.b[0], 0.0]), 
+            ("N2 wins", [0.0, self.a[1] / self.b[1]]), 
+        ]; 
+        let det = self.b[0] * self.b[1] - self.c[0] * self.c[1]; 
+        if det != 0.0 {
+            let n1 = (self.a[0] * self.b[1] - self.c[0] * self.a[1]) / det; 
+            let n2 = (self.b[0] * self.a[1] - self.c[1] * self.a[0]) / det; 
+            points.push(("coexistence", [n1, n2])); 
         }
 
-        // return time vector and solution 
-        (t, y)
+        points.into_iter().map(|(name, point)| {
+            let j = self.jacobian(&point); 
+            let (tr, dt) = (j[0][0] + j[1][1], j[0][0] * j[1][1] - j[0][1] * j[1][0]); 
+            let disc = tr * tr - 4.0 * dt; 
+            let eigenvalues = if disc >= 0.0 {
+                [(0.5 * (tr + disc.sqrt()), 0.0), (0.5 * (tr - disc.sqrt()), 0.0)]
+            } else {
+                [(0.5 * tr, 0.5 * (-disc).sqrt()), (0.5 * tr, -0.5 * (-disc).sqrt())]
+            }; 
+            let scale = tr.abs().max(dt.abs().sqrt()).max(f64::MIN_POSITIVE); 
+            let re = eigenvalues.map(|e| if e.0.abs() < 1e-12 * scale { 0.0 } else { e.0 }); 
+            let stability = if re.contains(&0.0) {
+                Stability::Degenerate
+            } else if re.iter().all(|r| *r < 0.0) {
+                Stability::Stable
+            } else if re.iter().all(|r| *r > 0.0) {
+                Stability::Unstable
+            } else {
+                Stability::Saddle
+            }; 
+            Equilibrium { name, point, feasible: point.iter().all(|p| *p >= 0.0), eigenvalues, stability }
+        }).collect()
+    }
+
+    ///
+    /// Feasible equilibria with their outcome names, in the order of 
+    /// equilibrium_analysis 
+    ///
+    fn equilibria(&self) -> Vec<(&'static str, [f64; 2])> {
+        self.equilibrium_analysis().into_iter()
+            .filter(|e| e.feasible)
+            .map(|e| (e.name, e.point))
+            .collect()
+    }
+
+    ///
+    /// Goh's Lyapunov candidate about the equilibrium e, 
+    /// V = sum w_i (N_i - e_i - e_i ln(N_i / e_i)) with w = (c2, c1), the 
+    /// log term dropped for an absent species. Along solutions 
+    /// dV/dt = sum w_i (N_i - e_i) g_i(N) with g_i the per-capita growth, 
+    /// negative off e throughout the quadrant when c1 c2 < b1 b2 and e 
+    /// cannot be invaded 
+    ///
+    fn lyapunov(&self, e: &[f64; 2], pop: &[f64; 2]) -> f64 {
+        let w = [self.c[1], self.c[0]]; 
+        (0..2).map(|i| {
+            let log = if e[i] > 0.0 { e[i] * (pop[i] / e[i]).ln() } else { 0.0 }; 
+            w[i] * (pop[i] - e[i] - log)
+        }).sum()
+    }
+
+    fn lyapunov_rate(&self, e: &[f64; 2], pop: &[f64; 2]) -> f64 {
+        let w = [self.c[1], self.c[0]]; 
+        let g = [
+            self.a[0] - self.b[0] * pop[0] - self.c[0] * pop[1], 
+            self.a[1] - self.b[1] * pop[1] - self.c[1] * pop[0], 
+        ]; 
+        (0..2).map(|i| w[i] * (pop[i] - e[i]) * g[i]).sum()
+    }
+
+    ///
+    /// Rate function on truncated power series for the Taylor integrator, 
+    /// autonomous at the base coefficients 
+    ///
+    fn rate_series(&self, pop: &[Series; 2]) -> [Series; 2] {
+        let g0 = Series::constant(self.a[0], pop[0].len()) 
+            - self.b[0] * &pop[0] - self.c[0] * &pop[1]; 
+        let g1 = Series::constant(self.a[1], pop[1].len()) 
+            - self.b[1] * &pop[1] - self.c[1] * &pop[0]; 
+        [&pop[0] * &g0, &pop[1] * &g1]
+    }
+ 
+    ///
+    /// Solve the Ecosystem at the given initial conditions given some step size 
+    /// using Runge-Kutta 4th Order, the shared solvers::rk4_step, over the 
+    /// whole span. check_dt says whether dt divides it 
+    ///
+    pub fn solve(&self, dt: f64) -> Result<solvers::Solution<2>, Box<dyn std::error::Error>> {
+        solvers::integrate(&self.rhs()?, solvers::rk4_step, self.ic, self.ts, dt, Limits::default())
+    }
+
+    ///
+    /// Solves over the span with the method and dt the builder checked, 
+    /// with the work it took 
+    ///
+    pub fn run(&self) -> Result<(solvers::Solution<2>, SolveStats), Box<dyn std::error::Error>> {
+        let (method, dt) = self.step.ok_or("ecosystem: no method and dt set, see EcosystemBuilder::step")?; 
+        method.solve(&self.rhs()?, self.ic, self.ts, dt)
+    }
+}
+
+///
+/// The parameters stay in the struct, so the ecosystem plugs into any 
+/// solver that takes an OdeSystem 
+///
+impl OdeSystem for Ecosystem {
+    fn dimension(&self) -> usize {
+        2
+    }
+
+    fn rate(&self, t: f64, y: &[f64], dy: &mut [f64]) {
+        let mut d = [0.0; 2]; 
+        Ecosystem::rate(self, t, &[y[0], y[1]], &mut d); 
+        dy.copy_from_slice(&d); 
+    }
+}
+
+///
+/// The Taylor integrator's view, autonomous at the base coefficients 
+///
+impl SeriesSystem for Ecosystem {
+    fn rate_series(&self, y: &[Series], dy: &mut [Series]) {
+        dy.clone_from_slice(&Ecosystem::rate_series(self, &[y[0].clone(), y[1].clone()])); 
     }
 }
 
 ///
-/// Plot each element of solution from rk4 against time vector 
+/// Plot each element of solution from rk4 against time vector, LTTB 
+/// downsampled to downsample::PLOT_POINTS per species 
 ///
-pub fn plot(t: &[f64], y: &Vec<[f64; 2]>, path: &str, title: &str)
+pub fn plot(theme: &PlotStyle, t: &[f64], y: &[[f64; 2]], states: &[StateVar; 2], path: &str, title: &str)
     -> Result<(), Box<dyn std::error::Error>> {
+    let series = [0, 1].map(|j| {
+        let yj: Vec<f64> = y.iter().map(|p| p[j]).collect(); 
+        downsample::points(t, &yj, downsample::PLOT_POINTS)
+    }); 
+    plot_series(theme, &series, &[Axis::default(); 2], states, &[], path, title)
+}
 
-    let n = t.len(); 
-    let (tmin, tmax) = (t[0], t[n - 1]); 
+///
+/// plot for series already reduced to (t, N) points, one per species, 
+/// with the time and population axes set by axes and marks drawn over them 
+///
+pub fn plot_series(theme: &PlotStyle, series: &[downsample::Series; 2], axes: &[Axis; 2], states: &[StateVar; 2], marks: &[Annotation], 
+    path: &str, title: &str)
+    -> Result<(), Box<dyn std::error::Error>> {
+
+    let (tmin, tmax) = (series[0][0].0, series[0][series[0].len() - 1].0); 
     
     let (mut ymin, mut ymax) = (f64::INFINITY, f64::NEG_INFINITY);
-    for yi in y {
-        ymin = ymin.min(yi[0]).min(yi[1]);
-        ymax = ymax.max(yi[0]).max(yi[1]); 
+    for (_, yi) in series.iter().flatten() {
+        ymin = ymin.min(*yi);
+        ymax = ymax.max(*yi); 
     }
     let pad = (ymax - ymin) * 0.05; 
     ymax += pad; 
+    let (x_range, y_range) = (axes[0].range(tmin, tmax), axes[1].range(0.0, ymax)); 
 
-    let root = BitMapBackend::new(path, (1200,700)).into_drawing_area();
+    let root = BitMapBackend::new(path, theme.size).into_drawing_area();
     root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
     let mut chart = ChartBuilder::on(&root)
-        .caption(title, ("sans-serif", 24))
-        .margin(10)
-        .set_label_area_size(LabelAreaPosition::Left, 55)
+        .caption(title, theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 70)
         .set_label_area_size(LabelAreaPosition::Bottom, 50)
-        .build_cartesian_2d(tmin..tmax, 0.0..ymax)?; 
+        .build_cartesian_2d(x_range.clone(), y_range.clone())?; 
 
-    chart.configure_mesh().x_desc("t").y_desc("population").draw()?; 
+    chart.configure_mesh()
+        .label_style(theme.label())
+        .axis_desc_style(theme.label())
+        .x_desc("t")
+        .y_desc(format!("population [{}]", states[0].unit))
+        .x_label_formatter(&axes[0].formatter(&x_range))
+        .y_label_formatter(&axes[1].formatter(&y_range))
+        .x_max_light_lines(axes[0].minor)
+        .y_max_light_lines(axes[1].minor)
+        .draw()?; 
 
-    chart.draw_series(LineSeries::new(
-        (0..n).map(|i| (t[i], y[i][0])),
-            &RED,
-        ))?
-        .label("N1")
-        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
-    chart.draw_series(LineSeries::new(
-        (0..n).map(|i| (t[i], y[i][1])),
-            &BLUE,
-        ))?
-        .label("N2")
-        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
+    for (k, state) in states.iter().enumerate() {
+        let color = theme.color(k);
+        chart.draw_series(LineSeries::new(
+            series[k].iter().copied(),
+                color.stroke_width(theme.thin),
+            ))?
+            .label(state.name)
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+    }
+    annotate::draw(&mut chart, theme, marks)?; 
 
     chart.configure_series_labels()
+        .label_font(theme.label())
         .border_style(BLACK)
         .background_style(WHITE.mix(0.85))
         .draw()?; 
@@ -157,72 +736,114 @@ pub fn plot(t: &[f64], y: &Vec<[f64; 2]>, path: &str, title: &str)
 } 
 
 ///
-/// Compares larger timesteps to dt = 1e-4 (which I've qualitatively determined 
-/// to be exact
-/// Plots on semilogy, outputs to hardcoded, error.png 
+/// Two quantities of very different size against time, left on the primary 
+/// y axis and right on a secondary one with its own range and tick labels, 
+/// e.g. N1 near 1e5 beside its per-capita growth rate near 0.1. Each is 
+/// LTTB downsampled; labels name the two curves and their axes 
+///
+pub fn plot_dual(theme: &PlotStyle, t: &[f64], left: &[f64], right: &[f64], labels: [&str; 2], path: &str, title: &str)
+    -> Result<(), Box<dyn std::error::Error>> {
+    let series = [left, right].map(|v| downsample::points(t, v, downsample::PLOT_POINTS)); 
+    let bounds = series.each_ref().map(|s| {
+        let (lo, hi) = s.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), p| (lo.min(p.1), hi.max(p.1))); 
+        let pad = 0.05 * (hi - lo).max(hi.abs() * 1e-3).max(f64::MIN_POSITIVE); 
+        (lo - pad)..(hi + pad)
+    }); 
+    let x_range = t[0]..t[t.len() - 1]; 
+    let axis = Axis::default(); 
+    let formats = [axis.formatter(&x_range), axis.formatter(&bounds[0]), axis.formatter(&bounds[1])]; 
+
+    let root = BitMapBackend::new(path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let mut chart = ChartBuilder::on(&root)
+        .caption(title, theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 70)
+        .set_label_area_size(LabelAreaPosition::Right, 70)
+        .set_label_area_size(LabelAreaPosition::Bottom, 50)
+        .build_cartesian_2d(x_range.clone(), bounds[0].clone())?
+        .set_secondary_coord(x_range, bounds[1].clone()); 
+
+    chart.configure_mesh()
+        .label_style(theme.label())
+        .axis_desc_style(theme.label())
+        .x_desc("t")
+        .y_desc(labels[0])
+        .x_label_formatter(&formats[0])
+        .y_label_formatter(&formats[1])
+        .x_max_light_lines(axis.minor)
+        .y_max_light_lines(axis.minor)
+        .draw()?; 
+    chart.configure_secondary_axes()
+        .label_style(theme.label())
+        .axis_desc_style(theme.label())
+        .y_desc(labels[1])
+        .y_label_formatter(&formats[2])
+        .draw()?; 
+
+    let styles = [theme.color(0), theme.color(3)].map(|c| c.stroke_width(theme.line)); 
+    chart.draw_series(LineSeries::new(series[0].iter().copied(), styles[0]))?
+        .label(format!("{} (left)", labels[0]))
+        .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], styles[0]));
+    chart.draw_secondary_series(LineSeries::new(series[1].iter().copied(), styles[1]))?
+        .label(format!("{} (right)", labels[1]))
+        .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], styles[1]));
+
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?; 
+
+    root.present()?; 
+    Ok(())
+}
+
+///
+/// Compares dt and four doublings of it against reference, measured by 
+/// metric on each run's own points. Plots on semilogy to path, naming the 
+/// reference, and returns (dt, errors) from the largest dt down, the 
+/// errors in the order of metric.labels 
 ///
-pub fn compare(dt: f64) -> Result<(), Box<dyn std::error::Error>> {
+pub fn compare(theme: &PlotStyle, dt: f64, metric: &ErrorMetric, reference: &Reference<2>, path: &str) 
+    -> Result<Convergence, Box<dyn std::error::Error>> {
     let dtarr = [dt, 2.0 * dt, 4.0 * dt, 8.0 * dt, 16.0 * dt]; 
-    let eco = Ecosystem::new(
-        [1e5, 1e5], 
-        [0.1, 0.1], 
-        [8e-7, 8e-7], 
-        [1e-6, 1e-7], 
-        [0.0, 10.0]
-    ); 
-    let mut solutions = Vec::with_capacity(dtarr.len()); 
-    
-    for dti in dtarr {
-        let (_, yi) = eco.solve(dti); 
-        solutions.push(*yi.last().unwrap()); 
-    }  
-    
-    // plot inverse timestep value against difference from exact 
-    let inv_dt: Vec<f64> = dtarr[1..].iter().rev().map(|&dti| 1.0 / dti ).collect();
-    let exact = solutions.first().unwrap(); 
-    let rel_err0: Vec<f64> = solutions[1..]
-        .iter()
-        .rev()
-        .map(|s| {
-            (s[0] - exact[0]).abs() / exact[0].abs()
-        })
-        .collect(); 
-    let rel_err1: Vec<f64> = solutions[1..]
-        .iter()
-        .rev()
-        .map(|s| {
-        (s[1] - exact[1]).abs() / exact[1].abs()
-        })
-        .collect(); 
+    let eco = Ecosystem::lab()
+        .span(0.0, 10.0)
+        .build()?; 
+    let rate = |t: f64, p: &[f64; 2], dp: &mut [f64; 2]| eco.rate(t, p, dp); 
 
-    let logerr0: Vec<f64> = rel_err0
-        .iter()
-        .map(|&er| (er.max(1e-16)).log10())
-        .collect(); 
-    let logerr1: Vec<f64> = rel_err1
-        .iter()
-        .map(|&er| (er.max(1e-16)).log10())
+    // the finest grid holds every coarser one, so one set of reference 
+    // values serves every dt 
+    let (tfine, _) = eco.solve(dt)?; 
+    let exact = reference.values(&rate, eco.ic, eco.ts, &tfine)?; 
+    let mut errors: Convergence = Vec::with_capacity(dtarr.len()); 
+    for (k, dti) in dtarr.iter().enumerate().rev() {
+        let (_, yi) = eco.solve(*dti)?; 
+        let aligned: Vec<[f64; 2]> = exact.iter().step_by(1 << k).copied().collect(); 
+        errors.push((*dti, metric.relative(&yi, &aligned)?)); 
+    }
+    let labels = metric.labels(&Ecosystem::STATES.map(|s| s.name)); 
+
+    // plot inverse timestep value against difference from exact 
+    let inv_dt: Vec<f64> = errors.iter().map(|(dti, _)| 1.0 / dti).collect();
+    let logerr: Vec<Vec<f64>> = (0..labels.len())
+        .map(|j| errors.iter().map(|(_, e)| e[j].log10()).collect())
         .collect(); 
 
-    let mut ymin = logerr0
-        .iter()
-        .chain(logerr1.iter())
-        .copied()
-        .fold(f64::INFINITY, f64::min);
-    let mut ymax = logerr0
-        .iter() 
-        .chain(logerr1.iter())
-        .copied()
-        .fold(f64::NEG_INFINITY, f64::max); 
+    let mut ymin = logerr.iter().flatten().copied().fold(f64::INFINITY, f64::min);
+    let mut ymax = logerr.iter().flatten().copied().fold(f64::NEG_INFINITY, f64::max); 
 
     ymin = ymin.floor(); 
     ymax = ymax.ceil(); 
 
-    let root = BitMapBackend::new("errors.png", (1200,700)).into_drawing_area();
+    let root = BitMapBackend::new(path, theme.size).into_drawing_area();
     root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
     let mut chart = ChartBuilder::on(&root)
-        .caption("Relative Error vs 1/dt [semilogy-scale]", ("sans-serif", 22))
-        .margin(10)
+        .caption(format!("Relative Error ({}) vs 1/dt, reference {}", metric.norm.name(), reference.describe()), theme.caption())
+        .margin(theme.margin)
         .set_label_area_size(LabelAreaPosition::Left, 70)
         .set_label_area_size(LabelAreaPosition::Bottom, 60)
         .build_cartesian_2d(
@@ -230,51 +851,2290 @@ pub fn compare(dt: f64) -> Result<(), Box<dyn std::error::Error>> {
             ymin..ymax)?; 
 
     chart.configure_mesh()
+        .label_style(theme.label())
+        .axis_desc_style(theme.label())
         .x_desc("1/dt")
         .y_desc("relative error")
         .y_label_formatter(&|v| format!("1e{:.0}", v))
         .draw()?; 
 
-    chart.draw_series(LineSeries::new(
-        (0..inv_dt.len()).map(|i| (inv_dt[i], logerr0[i])),
-        &RED, 
-    ))? 
-    .label("N1")
-    .legend(|(x,y)| PathElement::new(vec![(x,y), (x + 20, y)], RED));
-
-    chart.draw_series(LineSeries::new(
-        (0..inv_dt.len()).map(|i| (inv_dt[i], logerr1[i])),
-        &BLUE, 
-    ))? 
-    .label("N2")
-    .legend(|(x,y)| PathElement::new(vec![(x,y), (x + 20, y)], BLUE));
+    for (j, (label, logerr)) in labels.iter().zip(&logerr).enumerate() {
+        let color = theme.color(j); 
+        chart.draw_series(LineSeries::new(
+            inv_dt.iter().copied().zip(logerr.iter().copied()),
+            color.stroke_width(theme.thin), 
+        ))? 
+        .label(label.as_str())
+        .legend(move |(x,y)| PathElement::new(vec![(x,y), (x + 20, y)], color));
+    }
 
     chart.configure_series_labels()
+        .label_font(theme.label())
         .border_style(BLACK)
         .background_style(WHITE.mix(0.85))
         .draw()?;
 
     root.present()?; 
+    Ok(errors)
+}
+
+///
+/// Uses an order 20 Taylor solution as a reference in place of the assumed 
+/// exact dt = 1e-4 RK4 run, printing the final state error of RK4 per dt 
+///
+pub fn taylor_reference(ctx: &mut RunContext, dt: f64) -> Result<(), Box<dyn std::error::Error>> {
+    let eco = Ecosystem::lab()
+        .span(0.0, 10.0)
+        .build()?; 
+    ctx.solver("taylor", "order 20, fixed-step");
+    let ((_, reference), _) = ctx.time("taylor reference", || {
+        taylor::taylor_system(&eco, 20, eco.ic, eco.ts[0], eco.ts[1], 0.05, Limits::default())
+    })?; 
+    let exact = reference.last().unwrap(); 
+
+    let [s0, s1] = Ecosystem::STATES.map(|s| s.name); 
+    println!("{:>10} {:>14} {:>14}", "dt", format!("rel err {s0}"), format!("rel err {s1}")); 
+    for dti in [dt, 2.0 * dt, 4.0 * dt, 8.0 * dt, 16.0 * dt] {
+        let (_, y) = eco.solve(dti)?; 
+        let s = y.last().unwrap(); 
+        println!(
+            "{:>10.1e} {:>14.6e} {:>14.6e}", dti, 
+            (s[0] - exact[0]).abs() / exact[0].abs(), 
+            (s[1] - exact[1]).abs() / exact[1].abs()
+        ); 
+    }
     Ok(())
 }
 
 ///
-/// Runs for a given timestep, calling correct constructor, solving system 
-/// Then passing solution to be plotted. 
-///
-pub fn run(dt: f64, path: &str, title: &str) {
-    let eco = Ecosystem::new(
-        [1e5, 1e5], 
-        [0.1, 0.1],
-        [8e-7, 8e-7],
-        [1e-6, 1e-7], 
-        [0.0, 10.0]
+/// Plots N1 for successive Picard iterates against the RK4 solution and 
+/// prints how many sweeps (and rate evaluations) convergence took 
+///
+pub fn picard_iterates(ctx: &mut RunContext, dt: f64, name: &str) 
+    -> Result<(), Box<dyn std::error::Error>> {
+    let eco = Ecosystem::lab()
+        .span(0.0, 10.0)
+        .build()?; 
+    ctx.solver("picard", "cumulative trapezoid");
+    let result = ctx.time("picard", || {
+        picard::picard(
+            &|t: f64, p: &[f64; 2], dp: &mut [f64; 2]| eco.rate(t, p, dp), 
+            eco.ic, eco.ts, dt, 1e-3, 50, Limits::default())
+    })?; 
+    let (t, y) = eco.solve(dt)?; 
+
+    let n = t.len(); 
+    println!(
+        "picard: {} sweeps, {} rate evals, final update {:.3e} (rk4: {} rate evals)", 
+        result.updates.len(), result.updates.len() * n, 
+        result.updates.last().copied().unwrap_or(0.0), 4 * (n - 1)
     ); 
-    let (t, y) = eco.solve(dt);
-    let _ = plot(&t, &y, path, title);
-    let _ = compare(dt);
-}
 
-fn main() {
-    run(1e-4, "rk4_ecosystem.png", "Ecosystem over Time, h=1e-4");
+    let shown = result.iterates.len().min(8); 
+    let mut ymax = f64::NEG_INFINITY; 
+    for iterate in &result.iterates[..shown] {
+        ymax = iterate.iter().fold(ymax, |m, p| m.max(p[0])); 
+    }
+    ymax = ymax.max(y.iter().fold(f64::NEG_INFINITY, |m, p| m.max(p[0]))); 
+    ymax += ymax * 0.05; 
+
+    let path = ctx.path(name); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let mut chart = ChartBuilder::on(&root)
+        .caption(format!("Picard Iterates for {}", Ecosystem::STATES[0].name), theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 55)
+        .set_label_area_size(LabelAreaPosition::Bottom, 50)
+        .build_cartesian_2d(t[0]..t[n - 1], 0.0..ymax)?; 
+
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("t").y_desc("population").draw()?; 
+
+    for (k, iterate) in result.iterates[..shown].iter().enumerate() {
+        let color = theme.color(k); 
+        chart.draw_series(LineSeries::new(
+            (0..n).map(|i| (t[i], iterate[i][0])),
+            &color,
+        ))?
+        .label(format!("iterate {k}"))
+        .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+    }
+    chart.draw_series(LineSeries::new(
+        (0..n).map(|i| (t[i], y[i][0])),
+        &BLACK,
+    ))?
+    .label("rk4")
+    .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLACK));
+
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?; 
+
+    root.present()?; 
+    Ok(())
+}
+
+///
+/// Least squares slope of log(err) against log(dt), the observed order 
+///
+fn observed_order(dts: &[f64], errs: &[f64]) -> f64 {
+    let pts: Vec<(f64, f64)> = dts
+        .iter()
+        .zip(errs.iter())
+        .filter(|(_, &e)| e > 0.0)
+        .map(|(&h, &e)| (h.ln(), e.ln()))
+        .collect(); 
+    let m = pts.len() as f64; 
+    let (sx, sy) = pts.iter().fold((0.0, 0.0), |(a, b), p| (a + p.0, b + p.1)); 
+    let (mx, my) = (sx / m, sy / m); 
+    let sxy: f64 = pts.iter().map(|p| (p.0 - mx) * (p.1 - my)).sum(); 
+    let sxx: f64 = pts.iter().map(|p| (p.0 - mx).powi(2)).sum(); 
+    sxy / sxx
+}
+
+///
+/// Final-time relative error of every method in the solver family against 
+/// the Taylor reference over a range of dt, on loglog axes with the 
+/// observed orders in the legend 
+///
+pub fn order_study(ctx: &mut RunContext, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let eco = Ecosystem::lab()
+        .span(0.0, 10.0)
+        .build()?; 
+    let rate = |t: f64, p: &[f64; 2], dp: &mut [f64; 2]| eco.rate(t, p, dp); 
+    let ((_, reference), _) = taylor::taylor(
+        &|p: &[Series; 2]| eco.rate_series(p), 20, eco.ic, eco.ts[0], eco.ts[1], 0.05, Limits::default())?; 
+    let exact = *reference.last().unwrap(); 
+    let rel = |s: &[f64; 2]| {
+        ((s[0] - exact[0]).abs() / exact[0].abs()).max((s[1] - exact[1]).abs() / exact[1].abs())
+    };
+
+    let dts = [0.4, 0.2, 0.1, 0.05, 0.025]; 
+    let mut curves: Vec<(String, Vec<f64>)> = Vec::new(); 
+    for method in Method::ALL {
+        ctx.solver(method.name(), "fixed-step explicit");
+        let errs: Vec<f64> = dts
+            .iter()
+            .map(|&dt| Ok(rel(method.solve(&rate, eco.ic, eco.ts, dt)?.0.1.last().unwrap())))
+            .collect::<Result<_, Box<dyn std::error::Error>>>()?; 
+        curves.push((method.name().to_string(), errs)); 
+    }
+    let errs: Vec<f64> = dts
+        .iter()
+        .map(|&dt| Ok(rel(solvers::extrapolated_euler(&rate, eco.ic, eco.ts, dt)?.extrapolated.last().unwrap())))
+        .collect::<Result<_, Box<dyn std::error::Error>>>()?; 
+    curves.push(("extrapolated euler".to_string(), errs)); 
+    let errs: Vec<f64> = dts.iter().map(|&dt| Ok(rel(eco.solve(dt)?.1.last().unwrap()))).collect::<Result<_, Box<dyn std::error::Error>>>()?; 
+    curves.push(("Ecosystem::solve".to_string(), errs)); 
+
+    let (mut ymin, mut ymax) = (f64::INFINITY, f64::NEG_INFINITY); 
+    for (_, errs) in &curves {
+        for e in errs {
+            let le = e.max(1e-16).log10(); 
+            ymin = ymin.min(le); 
+            ymax = ymax.max(le); 
+        }
+    }
+
+    let path = ctx.path(name); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Relative Error vs dt [loglog-scale]", theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 70)
+        .set_label_area_size(LabelAreaPosition::Bottom, 60)
+        .build_cartesian_2d(
+            dts[dts.len() - 1].log10()..dts[0].log10(), 
+            ymin.floor()..ymax.ceil())?; 
+
+    chart.configure_mesh()
+        .label_style(theme.label())
+        .axis_desc_style(theme.label())
+        .x_desc("dt")
+        .y_desc("relative error")
+        .x_label_formatter(&|v| format!("1e{:.1}", v))
+        .y_label_formatter(&|v| format!("1e{:.0}", v))
+        .draw()?; 
+
+    let header: Vec<String> = std::iter::once("method".to_string())
+        .chain(dts.iter().map(|dt| format!("dt = {dt}")))
+        .chain(std::iter::once("order".to_string()))
+        .collect(); 
+    let mut table = Table::new("Relative error at t = 10 against the Taylor reference", &header); 
+    for (k, (label, errs)) in curves.iter().enumerate() {
+        let order = observed_order(&dts, errs); 
+        println!("{label:>18}: observed order {order:.2}"); 
+        table.row(std::iter::once(label.clone()).chain(errs.iter().map(|e| format!("{e:.3e}"))).chain(std::iter::once(format!("{order:.2}")))); 
+
+        let color = theme.color(k); 
+        chart.draw_series(LineSeries::new(
+            dts.iter().zip(errs.iter()).map(|(h, e)| (h.log10(), e.max(1e-16).log10())),
+            &color,
+        ))?
+        .label(format!("{label} (p = {order:.2})"))
+        .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+    }
+
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?; 
+
+    root.present()?; 
+    ctx.write_table("order_study.tex", table)?; 
+    ctx.caption(name, "Relative error against dt on log-log axes, observed orders in the legend"); 
+    Ok(())
+}
+
+///
+/// Global Richardson extrapolation of Euler and Heun runs at dt and dt / 2: 
+/// the estimated error of the dt / 2 run against its actual error, and 
+/// the error of the extrapolated trajectory, all over time and relative 
+/// to the initial population. Actual errors are against rk4 at dt / 64 
+///
+pub fn richardson_study(ctx: &mut RunContext, dt: f64, tf: f64, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let eco = Ecosystem::lab()
+        .span(0.0, tf)
+        .step(Method::Rk4, dt)
+        .build()?; 
+    let rate = |t: f64, p: &[f64; 2], dp: &mut [f64; 2]| eco.rate(t, p, dp); 
+    ctx.solver("richardson::richardson", "whole trajectories at dt and dt / 2 combined pointwise"); 
+    let reference = Reference::Solver { method: Method::Rk4, dt: dt / 64.0 }; 
+    ctx.config("richardson_reference", reference.describe()); 
+    let gap = |a: &[f64; 2], b: &[f64; 2]| (a[0] - b[0]).abs().max((a[1] - b[1]).abs()) / eco.ic[0]; 
+
+    // (method, t, [fine error, estimate, extrapolated error]) 
+    let mut runs = Vec::new(); 
+    let mut table = Table::new(format!("Global Richardson extrapolation, dt = {dt} and {}, largest error relative to N(0)", 0.5 * dt), 
+        &["method", "fine run", "estimate", "extrapolated"]); 
+    for method in [Method::Euler, Method::Heun] {
+        let (coarse, _) = method.solve(&rate, eco.ic, eco.ts, dt)?; 
+        let (fine, _) = method.solve(&rate, eco.ic, eco.ts, 0.5 * dt)?; 
+        let r = richardson::richardson(method.order(), &coarse, &fine)?; 
+        let exact = reference.values(&rate, eco.ic, eco.ts, &r.t)?; 
+        let curves: [Vec<f64>; 3] = [
+            fine.1.iter().step_by(2).zip(&exact).map(|(y, e)| gap(y, e)).collect(), 
+            r.estimate.iter().map(|e| e / eco.ic[0]).collect(), 
+            r.extrapolated.iter().zip(&exact).map(|(y, e)| gap(y, e)).collect(), 
+        ]; 
+        let worst = |v: &[f64]| v.iter().copied().fold(0.0, f64::max); 
+        table.row([method.name().to_string(), format!("{:.4e}", worst(&curves[0])), 
+            format!("{:.4e}", worst(&curves[1])), format!("{:.4e}", worst(&curves[2]))]); 
+        runs.push((method, r.t, curves)); 
+    }
+    println!("{}", table.caption); 
+    println!("{:>8} {:>12} {:>12} {:>13}", table.header[0], table.header[1], table.header[2], table.header[3]); 
+    for row in &table.rows {
+        println!("{:>8} {:>12} {:>12} {:>13}", row[0], row[1], row[2], row[3]); 
+    }
+    ctx.table(table); 
+
+    let path = ctx.path(name); 
+    ctx.caption(name, format!("Estimated and actual error of Euler and Heun at dt = {}, and of their Richardson extrapolation", 0.5 * dt)); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area(); 
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let panels = root.split_evenly((2, 1)); 
+    let labels = [format!("actual, dt = {}", 0.5 * dt), "richardson estimate".to_string(), "extrapolated".to_string()]; 
+    for (panel, (method, t, curves)) in panels.iter().zip(&runs) {
+        // the first point is the initial condition, exact for every run 
+        let logs: Vec<Vec<f64>> = curves.iter().map(|c| c.iter().skip(1).map(|e| e.max(1e-16).log10()).collect()).collect(); 
+        let lo = logs.iter().flatten().copied().fold(f64::INFINITY, f64::min).floor(); 
+        let hi = logs.iter().flatten().copied().fold(f64::NEG_INFINITY, f64::max).ceil(); 
+        let mut chart = ChartBuilder::on(panel)
+            .caption(format!("Global Richardson on {}, dt = {dt} [semilogy-scale]", method.name()), theme.caption())
+            .margin(theme.margin)
+            .set_label_area_size(LabelAreaPosition::Left, 70)
+            .set_label_area_size(LabelAreaPosition::Bottom, 40)
+            .build_cartesian_2d(0.0..tf, lo..hi)?; 
+        chart.configure_mesh()
+            .label_style(theme.label())
+            .axis_desc_style(theme.label())
+            .x_desc("t [days]")
+            .y_desc("error / N(0)")
+            .y_label_formatter(&|v| format!("1e{:.0}", v))
+            .draw()?; 
+        // the estimate dashed over the actual error it should match 
+        for (k, (label, log)) in labels.iter().zip(&logs).enumerate() {
+            let style = theme.color(k).stroke_width(theme.line); 
+            let points = t.iter().skip(1).copied().zip(log.iter().copied()); 
+            let series = if k == 1 {
+                chart.draw_series(DashedLineSeries::new(points, 8, 6, style))?
+            } else {
+                chart.draw_series(LineSeries::new(points, style))?
+            }; 
+            series.label(label.as_str())
+                .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], style)); 
+        }
+        chart.configure_series_labels()
+            .label_font(theme.label())
+            .position(SeriesLabelPosition::LowerRight)
+            .border_style(BLACK)
+            .background_style(WHITE.mix(0.85))
+            .draw()?; 
+    }
+    root.present()?; 
+    Ok(())
+}
+
+///
+/// VSVO Adams on the long-time ecosystem, where N1 dies out, under a 
+/// scalar atol sized for O(0.1) states against atol scaled to each 
+/// population (1e-3 individuals at rtol = 1e-8). Steps taken and absolute 
+/// error against the Taylor reference, relative error being meaningless 
+/// for an extinct species 
+///
+pub fn tolerance_study(ctx: &mut RunContext, tf: f64) -> Result<(), Box<dyn std::error::Error>> {
+    let eco = Ecosystem::lab()
+        .span(0.0, tf)
+        .build()?; 
+    let rate = |t: f64, p: &[f64; 2], dp: &mut [f64; 2]| eco.rate(t, p, dp); 
+    let ((_, reference), _) = taylor::taylor(
+        &|p: &[Series; 2]| eco.rate_series(p), 20, eco.ic, eco.ts[0], eco.ts[1], 0.05, Limits::default())?; 
+    let exact = reference.last().unwrap(); 
+    ctx.solver("vsvo_adams", "PECE, orders 1-12"); 
+
+    let rtol = 1e-8; 
+    let cases = [
+        ("scalar", Tolerance::uniform(rtol, 1e-9)), 
+        ("per-state", Tolerance::scaled(rtol, &eco.ic)), 
+    ]; 
+    let [s0, s1] = Ecosystem::STATES.map(|s| s.name); 
+    println!(
+        "{:>10} {:>8} {:>10} {:>14} {:>14}", "atol", "steps", "rate evals", 
+        format!("abs err {s0}"), format!("abs err {s1}")); 
+    for (label, tol) in cases {
+        let opts = adams::AdamsOptions { tol, ..Default::default() }; 
+        match ctx.time("vsvo adams", || adams::vsvo_adams(&rate, eco.ic, eco.ts, opts)) {
+            Ok(sol) => {
+                let s = sol.y.last().unwrap(); 
+                println!(
+                    "{:>10} {:>8} {:>10} {:>14.6e} {:>14.6e}", label, sol.stats.accepted, 
+                    sol.stats.rate_evals, 
+                    (s[0] - exact[0]).abs(), (s[1] - exact[1]).abs()
+                ); 
+            }
+            Err(e) => println!("{:>10} {e}", label), 
+        }
+    }
+    Ok(())
+}
+
+///
+/// Coefficient spread of the dimensional and nondimensional ecosystem, 
+/// then the same solvers on both: rk4 at fixed dt, and VSVO Adams under 
+/// one scalar tolerance, which only means the same thing for every state 
+/// once the states are O(1) 
+///
+pub fn scaling_study(ctx: &mut RunContext, tf: f64) -> Result<(), Box<dyn std::error::Error>> {
+    let eco = Ecosystem::lab()
+        .span(0.0, tf)
+        .build()?; 
+    let sc = eco.scales(); 
+    let rate = |t: f64, p: &[f64; 2], dp: &mut [f64; 2]| eco.rate(t, p, dp); 
+    let ((_, reference), _) = taylor::taylor(
+        &|p: &[Series; 2]| eco.rate_series(p), 20, eco.ic, eco.ts[0], eco.ts[1], 0.05, Limits::default())?; 
+    let exact = reference.last().unwrap(); 
+    ctx.config("scales", format!("N = {:?}, T = {}", sc.state, sc.time)); 
+
+    let spread = |v: &[f64]| {
+        let (lo, hi) = v.iter().fold((f64::INFINITY, 0.0_f64), |(lo, hi), x| (lo.min(x.abs()), hi.max(x.abs()))); 
+        hi / lo
+    }; 
+    let dimensional = [eco.a[0], eco.a[1], eco.b[0], eco.b[1], eco.c[0], eco.c[1], eco.ic[0], eco.ic[1]]; 
+    let k = eco.scaled_coefficients(); 
+    let u0 = sc.scale(&eco.ic); 
+    let scaled = [k[0][0], k[1][0], k[0][1], k[1][1], k[0][2], k[1][2], u0[0], u0[1]]; 
+    println!("coefficient spread: dimensional {:.3e}, scaled {:.3e}", spread(&dimensional), spread(&scaled)); 
+    println!("scaled coefficients [a, b, c]: {:?}", k); 
+
+    let rel = |s: &[f64; 2]| [
+        (s[0] - exact[0]).abs() / exact[0].abs(), (s[1] - exact[1]).abs() / exact[1].abs()
+    ]; 
+    let [s0, s1] = Ecosystem::STATES.map(|s| s.name); 
+    println!(
+        "{:>16} {:>10} {:>14} {:>14}", "solve", "rate evals", 
+        format!("rel err {s0}"), format!("rel err {s1}")); 
+    for dt in [1e-1, 1e-2] {
+        let ((_, y), stats) = Method::Rk4.solve(&rate, eco.ic, eco.ts, dt)?; 
+        let e = rel(y.last().unwrap()); 
+        let evals = stats.rate_evals; 
+        println!("{:>16} {:>10} {:>14.6e} {:>14.6e}", format!("rk4 {dt:.0e}"), evals, e[0], e[1]); 
+        let (_, y) = sc.solve(&rate, eco.ic, eco.ts, dt, |r, u0, span, h| Ok(Method::Rk4.solve(&r, u0, span, h)?.0))?; 
+        let e = rel(y.last().unwrap()); 
+        println!("{:>16} {:>10} {:>14.6e} {:>14.6e}", format!("rk4 {dt:.0e} scaled"), evals, e[0], e[1]); 
+    }
+
+    // the tolerance is the same number in both runs 
+    let opts = adams::AdamsOptions { tol: Tolerance::uniform(1e-8, 1e-8), ..Default::default() }; 
+    for scaled in [false, true] {
+        let result = if scaled {
+            let f = sc.rate(&rate); 
+            adams::vsvo_adams(&f, u0, [sc.scale_time(eco.ts[0]), sc.scale_time(eco.ts[1])], opts)
+                .map(|sol| (sol.stats.rate_evals, sc.unscale(sol.y.last().unwrap())))
+        } else {
+            adams::vsvo_adams(&rate, eco.ic, eco.ts, opts)
+                .map(|sol| (sol.stats.rate_evals, *sol.y.last().unwrap()))
+        }; 
+        let label = if scaled { "vsvo scaled" } else { "vsvo" }; 
+        match result {
+            Ok((evals, y)) => {
+                let e = rel(&y); 
+                println!("{:>16} {:>10} {:>14.6e} {:>14.6e}", label, evals, e[0], e[1]); 
+            }
+            Err(e) => println!("{:>16} {e}", label), 
+        }
+    }
+    Ok(())
+}
+
+///
+/// Long fixed-step run streamed to a scratch file instead of memory; only an 
+/// LTTB downsampled view is read back for the plot. Peak populations and their 
+/// times come from a running accumulator over every step, not the view. The 
+/// time the run settles to within 1% of the stable equilibrium is marked 
+///
+pub fn long_run(ctx: &mut RunContext, dt: f64, tf: f64, name: &str) 
+    -> Result<(), Box<dyn std::error::Error>> {
+    let eco = Ecosystem::lab()
+        .span(0.0, tf)
+        .step(Method::Rk4, dt)
+        .build()?; 
+    let rate = |t: f64, p: &[f64; 2], dp: &mut [f64; 2]| eco.rate(t, p, dp); 
+    ctx.solver("rk4", "fixed-step, streamed to disk"); 
+
+    let mut writer: ChunkedWriter<2> = ChunkedWriter::temp(1 << 16)?; 
+    let mut stats: RunningStats<2> = RunningStats::new(eco.ts[0]); 
+    let mut failed = None; 
+    let stable = eco.equilibrium_analysis().into_iter().find(|e| e.feasible && e.stability == Stability::Stable); 
+    // start of the last stretch spent inside the band about it 
+    let mut settled = None; 
+    ctx.time("rk4 streamed", || {
+        Method::Rk4.solve_with(&rate, eco.ic, eco.ts, dt, |t, y| {
+            stats.observe(t, y); 
+            if let Some(e) = &stable {
+                let band = 0.01 * e.point[0].max(e.point[1]); 
+                let inside = (0..2).all(|i| (y[i] - e.point[i]).abs() <= band); 
+                settled = if inside { settled.or(Some(t)) } else { None }; 
+            }
+            if failed.is_none() {
+                failed = writer.push(t, y).err(); 
+            }
+        })
+    })?; 
+    if let Some(e) = failed {
+        return Err(e); 
+    }
+    let stored = writer.finish()?; 
+    let view = ctx.time("downsampled read", || stored.downsampled(downsample::PLOT_POINTS))?; 
+    println!("streamed {} points to disk, plotting {} per species", stored.len(), view[0].len()); 
+    for (i, s) in Ecosystem::STATES.iter().enumerate() {
+        println!(
+            "{}: peak {:.6e} at t = {:.3}, min {:.6e} at t = {:.3}, mean {:.6e}", 
+            s.name, stats.max[i], stats.t_max[i], stats.min[i], stats.t_min[i], stats.mean()[i]); 
+    }
+
+    let mut marks = Vec::new(); 
+    if let (Some(e), Some(t)) = (&stable, settled) {
+        println!("settled within 1% of {} {:?} at t = {t:.3}", e.name, e.point); 
+        marks.push(Annotation::vertical(t, format!("within 1% of {} from t = {t:.1}", e.name))); 
+    }
+
+    let path = ctx.path(name); 
+    plot_series(&ctx.style(), &view, &[Axis::default(); 2], &Ecosystem::STATES, &marks, &path, 
+        &format!("Ecosystem over Time, h={dt}, {} steps streamed", stored.len() - 1))
+}
+
+///
+/// Runs to t1, then continues the same trajectory to t2 from its final
+/// state, and checks the result against one rk4 run straight to t2. The
+/// continued run only pays for the steps past t1 and should agree to the bit
+///
+pub fn continuation_study(ctx: &mut RunContext, dt: f64, t1: f64, t2: f64, name: &str) 
+    -> Result<(), Box<dyn std::error::Error>> {
+    let eco = Ecosystem::lab()
+        .span(0.0, t1)
+        .step(Method::Rk4, dt)
+        .build()?; 
+    let rate = |t: f64, p: &[f64; 2], dp: &mut [f64; 2]| eco.rate(t, p, dp); 
+    ctx.solver("rk4", "fixed-step, warm-started continuation"); 
+
+    let (mut traj, mut state) = ctx.time("rk4 to t1", || Continuation::solve_system(Method::Rk4, &eco, eco.ic, eco.ts, dt, Limits::default()))?; 
+    let first = state.steps; 
+    ctx.time("rk4 continued to t2", || state.extend_system(&eco, &mut traj, t2))?; 
+    let ((t, y), _) = ctx.time("rk4 straight to t2", || Method::Rk4.solve(&rate, eco.ic, [eco.ts[0], t2], dt))?; 
+
+    let worst = y.iter().enumerate()
+        .flat_map(|(i, yi)| (0..2).map(move |j| (i, j, yi[j])))
+        .map(|(i, j, x)| (traj.component(j)[i] - x).abs())
+        .fold(0.0, f64::max); 
+    let same = traj.len() == t.len() && traj.time() == t.as_slice() && worst == 0.0; 
+    println!(
+        "continued t = {t1} -> {t2}: {} new steps on top of {first}, {} points, max |continued - straight| = {worst:.3e}{}", 
+        state.steps - first, traj.len(), if same { " (bitwise equal)" } else { "" }); 
+
+    let series: [downsample::Series; 2] = std::array::from_fn(|j| 
+        downsample::points(traj.time(), traj.component(j), downsample::PLOT_POINTS)); 
+    let marks = [Annotation::vertical(t1, format!("continued from t = {t1}"))]; 
+    let path = ctx.path(name); 
+    ctx.caption(name, format!("Run to t = {t1} continued to t = {t2} from its final state, rk4 h = {dt}")); 
+    plot_series(&ctx.style(), &series, &[Axis::default(); 2], &Ecosystem::STATES, &marks, &path, 
+        &format!("Ecosystem continued from t={t1} to t={t2}, h={dt}"))
+}
+
+///
+/// AB/AM4 run to t1 at dt, then resumed to t2 at each new step size two 
+/// ways: history rebuilt from the first leg's dense output, and a fresh 
+/// rk4 bootstrap from the state at t1. Errors at t2 are against rk4 at a 
+/// small dt; resuming at the same dt from the dense output reproduces the 
+/// uninterrupted run to rounding 
+///
+pub fn restart_study(ctx: &mut RunContext, dt: f64, t1: f64, t2: f64) -> Result<(), Box<dyn std::error::Error>> {
+    let eco = Ecosystem::lab()
+        .span(0.0, t1)
+        .build()?; 
+    let rate = |t: f64, p: &[f64; 2], dp: &mut [f64; 2]| eco.rate(t, p, dp); 
+    ctx.solver("multistep::Abm4", "AB/AM4 PECE, history from dense output on restart"); 
+
+    let exact = Method::Rk4.solve_with(&rate, eco.ic, [eco.ts[0], t2], 1e-3, |_, _| {})?; 
+    let error = |p: &[f64; 2]| (p[0] - exact[0]).abs().max((p[1] - exact[1]).abs()) / eco.ic[0]; 
+    let (first, stepper) = Abm4::solve(&rate, eco.ic, eco.ts, dt)?; 
+    let (_, straight) = Abm4::solve(&rate, eco.ic, [eco.ts[0], t2], dt)?; 
+    let t1 = stepper.t(); 
+
+    let mut table = Table::new(format!("AB/AM4 from t = 0 at dt = {dt}, resumed at t = {t1} and run to t = {t2}"), 
+        &["dt after", "history", "error at t2"]); 
+    table.row([format!("{dt}"), "uninterrupted".to_string(), format!("{:.4e}", error(&straight.y()))]); 
+    for h in [dt, 0.5 * dt, 0.25 * dt, 2.0 * dt] {
+        let mut dense = Abm4::restart_from(&rate, &first, t1, h)?; 
+        dense.advance(&rate, t2, |_, _| {})?; 
+        let mut fresh = Abm4::start(&rate, stepper.y(), t1, h, |_, _| {}); 
+        fresh.advance(&rate, t2, |_, _| {})?; 
+        if h == dt {
+            let gap = (dense.y()[0] - straight.y()[0]).abs().max((dense.y()[1] - straight.y()[1]).abs()); 
+            println!("resumed at the same dt: max |dense - uninterrupted| = {gap:.3e}{}", 
+                if gap == 0.0 { " (bitwise equal)" } else { "" }); 
+        }
+        table.row([format!("{h}"), "dense output".to_string(), format!("{:.4e}", error(&dense.y()))]); 
+        table.row([format!("{h}"), "rk4 bootstrap".to_string(), format!("{:.4e}", error(&fresh.y()))]); 
+    }
+
+    println!("{}", table.caption); 
+    println!("{:>10} {:>16} {:>14}", table.header[0], table.header[1], table.header[2]); 
+    for row in &table.rows {
+        println!("{:>10} {:>16} {:>14}", row[0], row[1], row[2]); 
+    }
+    ctx.table(table); 
+    Ok(())
+}
+
+const HABITAT_KM: f64 = 100.0; 
+const CELLS: usize = 100; 
+
+///
+/// Habitat of the spatial studies: 100 km in 1 km cells with closed ends,
+/// diffusivities in km^2 / day, N1 seeded over the left fifth and N2 over
+/// the right fifth 
+///
+fn habitat(eco: &Ecosystem) -> (splitting::Diffusion<2>, Vec<f64>, Vec<[f64; 2]>) {
+    let diff = splitting::Diffusion::crank_nicolson([1.0, 0.5], HABITAT_KM / CELLS as f64); 
+    let x: Vec<f64> = (0..CELLS).map(|i| (i as f64 + 0.5) * diff.dx).collect(); 
+    let u0: Vec<[f64; 2]> = x.iter()
+        .map(|xi| [
+            eco.ic[0] * 0.5 * (1.0 - ((xi - 20.0) / 2.0).tanh()), 
+            eco.ic[1] * 0.5 * (1.0 + ((xi - 80.0) / 2.0).tanh()), 
+        ])
+        .collect(); 
+    (diff, x, u0)
+}
+
+///
+/// Unsplit method of lines under rk4 at dt = 1e-3, well inside the 
+/// explicit diffusion limit, as the reference for the spatial studies 
+///
+fn mol_reference<F>(rate: &F, diff: &splitting::Diffusion<2>, u0: &[[f64; 2]], tf: f64) -> Vec<[f64; 2]>
+where F: Fn(f64, &[f64; 2], &mut [f64; 2]) {
+    let dt = 1e-3; 
+    let mut u = u0.to_vec(); 
+    for (t, h, _) in solvers::grid([0.0, tf], dt) {
+        splitting::mol_step(rate, diff, t, &mut u, h); 
+    }
+    u
+}
+
+///
+/// Defect of Euler and rk4 at dt and dt / 2: the 
+/// spline through each run is put back into the rate and the mismatch 
+/// r = s' - f(t, s) plotted per species relative to the largest |f|. A 
+/// solver-independent check, no reference run is involved 
+///
+pub fn defect_study(ctx: &mut RunContext, dt: f64, tf: f64, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let eco = Ecosystem::lab()
+        .span(0.0, tf)
+        .step(Method::Rk4, dt)
+        .build()?; 
+    let rate = |t: f64, p: &[f64; 2], dp: &mut [f64; 2]| eco.rate(t, p, dp); 
+    ctx.solver("residual::defect", "natural cubic spline through the run, defect at step midpoints"); 
+
+    let run = |solver: &str, h: f64| -> Result<Trajectory<2>, Box<dyn std::error::Error>> {
+        let ((t, y), _) = match solver {
+            "euler" => Method::Euler.solve(&rate, eco.ic, eco.ts, h), 
+            _ => Method::Rk4.solve(&rate, eco.ic, eco.ts, h), 
+        }?; 
+        Ok(Trajectory::from_rows(&t, &y))
+    }; 
+    let solvers = ["euler", "rk4"]; 
+    // the natural end conditions' error decays by 2 - sqrt(3) a knot, 
+    // below rk4's defect only some 20 coarse steps in 
+    let within = [eco.ts[0] + 20.0 * dt, eco.ts[1] - 20.0 * dt]; 
+    let mut defects = Vec::with_capacity(solvers.len()); 
+    let mut table = Table::new(format!("Largest relative defect over both species, t in [{}, {}]", within[0], within[1]), 
+        &["solver", &format!("dt = {dt}"), &format!("dt = {}", 0.5 * dt), "observed order"]); 
+    for solver in solvers {
+        let coarse = residual::defect(&rate, &run(solver, dt)?)?; 
+        let fine = residual::defect(&rate, &run(solver, 0.5 * dt)?)?; 
+        let worst = |d: &residual::Defect<2>| (0..2).map(|j| d.max_relative(j, within)).fold(0.0, f64::max); 
+        let (a, b) = (worst(&coarse), worst(&fine)); 
+        table.row([solver.to_string(), format!("{a:.4e}"), format!("{b:.4e}"), format!("{:.2}", (a / b).log2())]); 
+        defects.push(coarse); 
+    }
+    println!("{}", table.caption); 
+    println!("{:>8} {:>14} {:>14} {:>15}", table.header[0], table.header[1], table.header[2], table.header[3]); 
+    for row in &table.rows {
+        println!("{:>8} {:>14} {:>14} {:>15}", row[0], row[1], row[2], row[3]); 
+    }
+    ctx.table(table); 
+
+    let path = ctx.path(name); 
+    ctx.caption(name, format!("Relative defect |s' - f(t, s)| / max|f| of the spline through each run, dt = {dt}")); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area(); 
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let panels = root.split_evenly((2, 1)); 
+    for (j, panel) in panels.iter().enumerate() {
+        let logs: Vec<Vec<f64>> = defects.iter()
+            .map(|d| d.relative(j).iter().map(|r| r.max(1e-16).log10()).collect())
+            .collect(); 
+        let lo = logs.iter().flatten().copied().fold(f64::INFINITY, f64::min).floor(); 
+        let hi = logs.iter().flatten().copied().fold(f64::NEG_INFINITY, f64::max).ceil(); 
+        let mut chart = ChartBuilder::on(panel)
+            .caption(format!("Defect in {}, dt = {dt} [semilogy-scale]", Ecosystem::STATES[j].name), theme.caption())
+            .margin(theme.margin)
+            .set_label_area_size(LabelAreaPosition::Left, 70)
+            .set_label_area_size(LabelAreaPosition::Bottom, 40)
+            .build_cartesian_2d(0.0..tf, lo..hi)?; 
+        chart.configure_mesh()
+            .label_style(theme.label())
+            .axis_desc_style(theme.label())
+            .x_desc("t [days]")
+            .y_desc("relative defect")
+            .y_label_formatter(&|v| format!("1e{:.0}", v))
+            .draw()?; 
+        for (k, (solver, (d, log))) in solvers.iter().zip(defects.iter().zip(&logs)).enumerate() {
+            let color = theme.color(k); 
+            chart.draw_series(LineSeries::new(downsample::points(&d.t, log, downsample::PLOT_POINTS), color.stroke_width(theme.thin)))?
+                .label(*solver)
+                .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color)); 
+        }
+        chart.configure_series_labels()
+            .label_font(theme.label())
+            .position(SeriesLabelPosition::UpperRight)
+            .border_style(BLACK)
+            .background_style(WHITE.mix(0.85))
+            .draw()?; 
+    }
+    root.present()?; 
+    Ok(())
+}
+
+///
+/// Spatial ecosystem: both species diffuse along a 1D habitat with closed 
+/// ends, N1 seeded at the left and N2 at the right, so competition plays 
+/// out across the two invasion fronts. Lie and Strang splitting (Crank-
+/// Nicolson diffusion, rk4 reaction) are measured against the unsplit 
+/// method of lines under rk4 at a small dt, which shares the spatial grid 
+/// so only the splitting and time errors remain. The defect column is the 
+/// largest one-step commutator difference from the initial state 
+///
+pub fn spatial_study(ctx: &mut RunContext, tf: f64, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let eco = Ecosystem::lab()
+        .span(0.0, tf)
+        .build()?; 
+    let rate = |t: f64, p: &[f64; 2], dp: &mut [f64; 2]| eco.rate(t, p, dp); 
+
+    let (diff, x, u0) = habitat(&eco); 
+    let length = HABITAT_KM; 
+    ctx.config("habitat", format!("{length} km, {CELLS} cells, D = {:?}", diff.coeff)); 
+    ctx.solver("splitting", "lie and strang, crank-nicolson diffusion, rk4 reaction"); 
+
+    let reference = ctx.time("method of lines reference", || mol_reference(&rate, &diff, &u0, tf)); 
+    let error = |u: &[[f64; 2]]| u.iter().zip(&reference)
+        .flat_map(|(a, b)| [(a[0] - b[0]).abs(), (a[1] - b[1]).abs()])
+        .fold(0.0_f64, f64::max) / eco.ic[0]; 
+
+    let dts = [2.0, 1.0, 0.5, 0.25, 0.125]; 
+    println!("{:>8} {:>12} {:>12} {:>12}", "dt", "lie", "strang", "defect"); 
+    let mut errs = [Vec::new(), Vec::new()]; 
+    for dt in dts {
+        for (k, scheme) in [splitting::Splitting::Lie, splitting::Splitting::Strang].into_iter().enumerate() {
+            let u = ctx.time(scheme.name(), || splitting::integrate_with(scheme, &rate, &diff, &u0, [0.0, tf], dt, Limits::default(), |_, _| {}))?; 
+            errs[k].push(error(&u)); 
+        }
+        let defect = splitting::commutator_defect(&rate, &diff, 0.0, &u0, dt)
+            .iter()
+            .flat_map(|d| d.iter().map(|v| v.abs()))
+            .fold(0.0_f64, f64::max) / eco.ic[0]; 
+        let i = errs[0].len() - 1; 
+        println!("{:>8} {:>12.4e} {:>12.4e} {:>12.4e}", dt, errs[0][i], errs[1][i], defect); 
+    }
+    println!("observed order: lie {:.2}, strang {:.2}", observed_order(&dts, &errs[0]), observed_order(&dts, &errs[1])); 
+
+    let path = ctx.path(name); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let top = u0.iter().chain(&reference).fold(0.0_f64, |m, p| m.max(p[0]).max(p[1])) * 1.1; 
+    let mut chart = ChartBuilder::on(&root)
+        .caption(format!("Spatial Competition, t = 0 and t = {tf}"), theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 70)
+        .set_label_area_size(LabelAreaPosition::Bottom, 50)
+        .build_cartesian_2d(0.0..length, 0.0..top)?; 
+
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("x (km)").y_desc(state::axis_label(&Ecosystem::STATES)).draw()?; 
+
+    for (s, state) in Ecosystem::STATES.iter().enumerate() {
+        let color = theme.color(s); 
+        chart.draw_series(LineSeries::new(x.iter().zip(&u0).map(|(xi, u)| (*xi, u[s])), color.mix(0.4)))?; 
+        chart.draw_series(LineSeries::new(x.iter().zip(&reference).map(|(xi, u)| (*xi, u[s])), color.stroke_width(theme.line)))?
+            .label(state.name)
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+    }
+
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?; 
+
+    root.present()?; 
+    Ok(())
+}
+
+///
+/// IMEX Runge-Kutta on the spatial ecosystem: diffusion is the stiff part 
+/// taken implicitly, competition the nonstiff part taken explicitly, on 
+/// the flattened state [N1, N2] per cell. Explicit rk4 on the same method 
+/// of lines system is shown beside it; its diffusion limit is near 
+/// dt = 0.7 here, so the larger steps only the IMEX schemes survive 
+///
+pub fn imex_study(ctx: &mut RunContext, tf: f64) -> Result<(), Box<dyn std::error::Error>> {
+    const STATE: usize = 2 * CELLS; 
+    let eco = Ecosystem::lab()
+        .span(0.0, tf)
+        .build()?; 
+    let rate = |t: f64, p: &[f64; 2], dp: &mut [f64; 2]| eco.rate(t, p, dp); 
+    let (diff, _, u0) = habitat(&eco); 
+    let reference = ctx.time("method of lines reference", || mol_reference(&rate, &diff, &u0, tf)); 
+
+    let flatten = |u: &[[f64; 2]]| -> [f64; STATE] { std::array::from_fn(|k| u[k / 2][k % 2]) }; 
+    let cells = |y: &[f64; STATE]| -> Vec<[f64; 2]> { y.chunks_exact(2).map(|c| [c[0], c[1]]).collect() }; 
+    let reaction = |t: f64, y: &[f64; STATE], dy: &mut [f64; STATE]| {
+        for (p, d) in y.chunks_exact(2).zip(dy.chunks_exact_mut(2)) {
+            let mut r = [0.0; 2]; 
+            eco.rate(t, &[p[0], p[1]], &mut r); 
+            d.copy_from_slice(&r); 
+        }
+    }; 
+    let diffusion = |_t: f64, y: &[f64; STATE], dy: &mut [f64; STATE]| {
+        *dy = flatten(&diff.apply(&cells(y))); 
+    }; 
+    let full = |t: f64, y: &[f64; STATE], dy: &mut [f64; STATE]| {
+        let (mut a, mut b) = ([0.0; STATE], [0.0; STATE]); 
+        reaction(t, y, &mut a); 
+        diffusion(t, y, &mut b); 
+        *dy = std::array::from_fn(|k| a[k] + b[k]); 
+    }; 
+    let error = |y: &[f64; STATE]| y.chunks_exact(2).zip(&reference)
+        .flat_map(|(a, b)| [(a[0] - b[0]).abs(), (a[1] - b[1]).abs()])
+        .fold(0.0_f64, f64::max) / eco.ic[0]; 
+
+    let dts = [2.0, 1.0, 0.5, 0.25, 0.125]; 
+    let tableaus = imex::Tableau::all(); 
+    print!("{:>8}", "dt"); 
+    for tab in &tableaus {
+        print!(" {:>12}", tab.name); 
+    }
+    println!(" {:>12}", "rk4"); 
+
+    let ic = flatten(&u0); 
+    let mut errs: Vec<Vec<f64>> = vec![Vec::new(); tableaus.len()]; 
+    for dt in dts {
+        print!("{:>8}", dt); 
+        for (k, tab) in tableaus.iter().enumerate() {
+            ctx.solver(tab.name, "imex, implicit diffusion, explicit reaction"); 
+            let ((_, y), _) = ctx.time(tab.name, || imex::solve(tab, &reaction, &diffusion, ic, [0.0, tf], dt, NewtonOptions::default()))?; 
+            let e = error(&y[y.len() - 1]); 
+            errs[k].push(e); 
+            print!(" {:>12.4e}", e); 
+        }
+        let ((_, y), _) = Method::Rk4.solve(&full, ic, [0.0, tf], dt)?; 
+        let last = &y[y.len() - 1]; 
+        if last.iter().all(|v| v.is_finite()) && error(last) < 1.0 {
+            println!(" {:>12.4e}", error(last)); 
+        } else {
+            println!(" {:>12}", "unstable"); 
+        }
+    }
+    for (tab, e) in tableaus.iter().zip(&errs) {
+        println!("{} observed order {:.2} (design {})", tab.name, observed_order(&dts, e), tab.order); 
+    }
+    Ok(())
+}
+
+///
+/// Diffusion alone on the habitat of the spatial studies, each scheme's 
+/// run kept in a conservation ledger: Crank-Nicolson and backward Euler, 
+/// the explicit rk4 method of lines inside and past its stability limit, 
+/// and imex ars222 with the diffusion implicit, all with closed ends; then 
+/// Crank-Nicolson with absorbing ends, the outflow left out of the ledger 
+/// and counted in it. Rows are flagged once the relative mass drift or a 
+/// one step energy rise passes tol 
+///
+pub fn conservation_study(ctx: &mut RunContext, tf: f64, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    const STATE: usize = 2 * CELLS; 
+    let tol = 1e-12; 
+    let eco = Ecosystem::lab()
+        .span(0.0, tf)
+        .build()?; 
+    let (diff, _, u0) = habitat(&eco); 
+    ctx.solver("conservation::Ledger", "mass and energy every step"); 
+    ctx.config("conservation", format!("diffusion only, D = {:?}, dx = {}, tf = {tf}, tol = {tol:e}", diff.coeff, diff.dx)); 
+
+    let theta = |d: &splitting::Diffusion<2>, dt: f64, counted: bool| -> Ledger<2> {
+        let supply = |u: &[[f64; 2]]| if counted { d.outflow(u).map(|f| -f) } else { [0.0; 2] }; 
+        let mut ledger = Ledger::new(d.dx); 
+        let mut u = u0.clone(); 
+        ledger.record_with(0.0, &u, supply(&u)); 
+        for (_, h, t) in solvers::grid([0.0, tf], dt) {
+            d.step(&mut u, h); 
+            ledger.record_with(t, &u, supply(&u)); 
+        }
+        ledger
+    }; 
+    let none = |_t: f64, _p: &[f64; 2], dp: &mut [f64; 2]| *dp = [0.0; 2]; 
+    let mol = |dt: f64| -> Ledger<2> {
+        let mut ledger = Ledger::new(diff.dx); 
+        let mut u = u0.clone(); 
+        ledger.record(0.0, &u); 
+        for (t, h, next) in solvers::grid([0.0, tf], dt) {
+            splitting::mol_step(&none, &diff, t, &mut u, h); 
+            ledger.record(next, &u); 
+        }
+        ledger
+    }; 
+    let imex = |dt: f64| -> Result<Ledger<2>, Box<dyn std::error::Error>> {
+        let cells = |y: &[f64; STATE]| -> Vec<[f64; 2]> { y.chunks_exact(2).map(|c| [c[0], c[1]]).collect() }; 
+        let nothing = |_t: f64, _y: &[f64; STATE], dy: &mut [f64; STATE]| *dy = [0.0; STATE]; 
+        let diffusion = |_t: f64, y: &[f64; STATE], dy: &mut [f64; STATE]| {
+            let d = diff.apply(&cells(y)); 
+            *dy = std::array::from_fn(|k| d[k / 2][k % 2]); 
+        }; 
+        let ic: [f64; STATE] = std::array::from_fn(|k| u0[k / 2][k % 2]); 
+        let ((t, y), _) = imex::solve(&imex::Tableau::ars222(), &nothing, &diffusion, ic, [0.0, tf], dt, NewtonOptions::default())?; 
+        let mut ledger = Ledger::new(diff.dx); 
+        for (ti, yi) in t.iter().zip(&y) {
+            ledger.record(*ti, &cells(yi)); 
+        }
+        Ok(ledger)
+    }; 
+
+    let open = diff.with_ends(splitting::Ends::Absorbing); 
+    let backward = splitting::Diffusion::backward_euler(diff.coeff, diff.dx); 
+    let runs = [
+        ("crank-nicolson", diff.ends, 1.0, "-", theta(&diff, 1.0, false)), 
+        ("backward euler", backward.ends, 1.0, "-", theta(&backward, 1.0, false)), 
+        ("rk4 mol", diff.ends, 0.5, "-", mol(0.5)), 
+        ("rk4 mol", diff.ends, 1.0, "-", mol(1.0)), 
+        ("imex ars222", diff.ends, 1.0, "-", imex(1.0)?), 
+        ("crank-nicolson", open.ends, 1.0, "ignored", theta(&open, 1.0, false)), 
+        ("crank-nicolson", open.ends, 1.0, "counted", theta(&open, 1.0, true)), 
+    ]; 
+
+    let headers = ["scheme", "ends", "outflow", "dt", "mass drift", "energy rise", "flagged at", "verdict"]; 
+    let mut table = Table::new(format!("Conservation of diffusion on the habitat to t = {tf}, tol = {tol:e}"), &headers); 
+    println!("{:>15} {:>10} {:>8} {:>5} {:>12} {:>12} {:>11} {:>13}", headers[0], headers[1], headers[2], headers[3], headers[4], headers[5], headers[6], headers[7]); 
+    for (scheme, ends, dt, outflow, ledger) in &runs {
+        let check = ledger.check(tol); 
+        let row = [
+            scheme.to_string(), ends.name().to_string(), outflow.to_string(), format!("{dt}"), 
+            format!("{:.3e}", check.mass_drift.iter().fold(0.0_f64, |m, d| m.max(*d))), 
+            format!("{:.3e}", check.energy_growth.iter().fold(0.0_f64, |m, g| m.max(*g))), 
+            check.violation.map_or("-".to_string(), |t| format!("t = {t}")), check.verdict().to_string(), 
+        ]; 
+        println!("{:>15} {:>10} {:>8} {:>5} {:>12} {:>12} {:>11} {:>13}", row[0], row[1], row[2], row[3], row[4], row[5], row[6], row[7]); 
+        table.row(row); 
+    }
+    ctx.table(table); 
+
+    let path = ctx.path(name); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    // exact conservation plots at the floor of the log axis 
+    let floor = 1e-18; 
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Relative Mass Drift of Diffusion Schemes", theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 70)
+        .set_label_area_size(LabelAreaPosition::Bottom, 40)
+        .build_cartesian_2d(0.0..tf, (floor..10.0).log_scale())?; 
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).y_label_formatter(&|v| format!("{v:.0e}"))
+        .x_desc("t").y_desc("|drift| / M(0)").draw()?; 
+    for (j, (scheme, ends, dt, outflow, ledger)) in runs.iter().enumerate() {
+        let color = theme.color(j); 
+        let drift = ledger.relative_drift(); 
+        let points = ledger.t.iter().zip(&drift).map(|(t, d)| (*t, d[0].max(d[1]).clamp(floor, 10.0))); 
+        let label = if *outflow == "-" { format!("{scheme}, {}, dt = {dt}", ends.name()) } else { format!("{scheme}, {}, outflow {outflow}", ends.name()) }; 
+        chart.draw_series(LineSeries::new(points, color.stroke_width(theme.line)))?
+            .label(label)
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(2)));
+    }
+    chart.draw_series(DashedLineSeries::new([(0.0, tol), (tf, tol)], 6, 4, BLACK.stroke_width(theme.thin)))?; 
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .position(SeriesLabelPosition::UpperLeft)
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?; 
+
+    root.present()?; 
+    ctx.caption(name, format!("Relative mass drift of each diffusion scheme and boundary over time, tolerance {tol:e} dashed")); 
+    Ok(())
+}
+
+///
+/// Long-term summary of a competition run after a transient, set beside 
+/// what the linearization predicts: each species' fitted limit against 
+/// the stable equilibrium, and its approach rate against the slowest 
+/// decay rate there, -max Re(lambda) 
+///
+pub fn asymptotic_study(ctx: &mut RunContext, dt: f64, tf: f64, t_skip: f64) -> Result<(), Box<dyn std::error::Error>> {
+    let eco = Ecosystem::lab()
+        .span(0.0, tf)
+        .step(Method::Rk4, dt)
+        .build()?; 
+    ctx.solver("asymptotic::summarize", "trapezoid mean, Aitken limit, log-linear rate"); 
+    ctx.config("asymptotic_t_skip", t_skip); 
+    let ((t, y), _) = eco.run()?; 
+    let summary = asymptotic::summarize(&Trajectory::from_rows(&t, &y), t_skip, 3)?; 
+
+    let stable = eco.equilibrium_analysis().into_iter().find(|e| e.feasible && e.stability == Stability::Stable); 
+    let slowest = stable.map(|e| -e.eigenvalues.iter().map(|ev| ev.0).fold(f64::NEG_INFINITY, f64::max)); 
+    let show = |v: Option<f64>, precision: usize| v.map_or("-".to_string(), |x| format!("{x:.precision$e}")); 
+
+    let caption = format!("Competition after t = {t_skip}, rk4 at dt = {dt} to t = {tf}"); 
+    let mut table = Table::new(caption.clone(), 
+        &["species", "mean", "min", "max", "limit", "equilibrium", "rate", "predicted", "r2"]); 
+    println!("{caption}"); 
+    println!("{:>8} {:>12} {:>12} {:>12} {:>12} {:>12} {:>10} {:>10} {:>10}", 
+        "species", "mean", "min", "max", "limit", "equilibrium", "rate", "predicted", "r2"); 
+    for (j, (s, sum)) in Ecosystem::STATES.iter().zip(&summary).enumerate() {
+        let fit = sum.approach; 
+        let row = [
+            s.name.to_string(), format!("{:.5e}", sum.mean), format!("{:.5e}", sum.min), format!("{:.5e}", sum.max), 
+            show(fit.map(|a| a.limit), 5), show(stable.map(|e| e.point[j]), 5), 
+            show(fit.map(|a| a.rate), 3), show(slowest, 3), fit.map_or("-".to_string(), |a| format!("{:.6}", a.r2)), 
+        ]; 
+        println!("{:>8} {:>12} {:>12} {:>12} {:>12} {:>12} {:>10} {:>10} {:>10}", 
+            row[0], row[1], row[2], row[3], row[4], row[5], row[6], row[7], row[8]); 
+        table.row(row); 
+    }
+    ctx.table(table); 
+    Ok(())
+}
+
+///
+/// Derived views of a competition run: each species' per-capita growth 
+/// rate, the ratio of the two populations, and both on a log scale, 
+/// plotted and written to CSV under the same stem 
+///
+pub fn derived_study(ctx: &mut RunContext, dt: f64, tf: f64, stem: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let eco = Ecosystem::lab()
+        .span(0.0, tf)
+        .step(Method::Rk4, dt)
+        .build()?; 
+    let rate = |t: f64, p: &[f64; 2], dp: &mut [f64; 2]| eco.rate(t, p, dp); 
+    ctx.solver("rk4", "fixed-step, solvers::Method"); 
+    let ((t, y), _) = eco.run()?; 
+    let traj = Trajectory::from_rows(&t, &y); 
+
+    let series = derived::Pipeline::new(Ecosystem::STATES)
+        .per_capita(&rate, 0)
+        .per_capita(&rate, 1)
+        .ratio(0, 1)
+        .log(0)
+        .log(1)
+        .apply(&traj); 
+    let (png, csv) = (format!("{stem}.png"), format!("{stem}.csv")); 
+    derived::write_csv(&ctx.path(&csv), &series)?; 
+    derived::plot(&ctx.style(), &series, &ctx.path(&png), &format!("Competition, dt = {dt}"))?; 
+    for s in &series {
+        println!("{:>18} at t = {tf}: {:.6e}", s.name, s.values[s.values.len() - 1]); 
+    }
+    ctx.caption(&png, format!("Per-capita growth, population ratio and log populations of the competition run, rk4 at dt = {dt}")); 
+    ctx.caption(&csv, "the same derived series, one column each"); 
+    Ok(())
+}
+
+///
+/// Autocorrelation of the surviving species under environmental noise, 
+/// dN_i = f_i dt + sigma N_i dW_i by Euler-Maruyama, over an ensemble of 
+/// independently seeded runs. Once N1 is gone N2 fluctuates about its 
+/// carrying capacity, where the logistic linearizes to an Ornstein- 
+/// Uhlenbeck process with rho(lag) = exp(-a2 lag): the estimated 
+/// decorrelation time is checked against 1 / a2 
+///
+pub fn correlation_study(ctx: &mut RunContext, tf: f64, t_skip: f64, members: usize, name: &str) 
+    -> Result<(), Box<dyn std::error::Error>> {
+    let (dt, every, sigma, max_lag) = (0.05, 10, 0.02, 60.0); 
+    let eco = Ecosystem::lab()
+        .span(0.0, tf)
+        .step(Method::Rk4, dt)
+        .build()?; 
+    ctx.solver("euler-maruyama", "fixed-step, multiplicative environmental noise"); 
+    ctx.solver("correlation::ensemble_autocorrelation", "fft, biased estimate, bartlett bounds"); 
+    ctx.config("correlation", format!("{members} members, sigma {sigma}, dt {dt}, sampled every {}, after t = {t_skip}", dt * every as f64)); 
+
+    let times: Vec<f64> = (0..=solvers::step_count(eco.ts, dt)).map(|k| eco.ts[0] + k as f64 * dt).collect(); 
+    let (steps, skip) = (times.len() - 1, times.partition_point(|s| *s < t_skip)); 
+    let h = dt * every as f64; 
+    let lags = (max_lag / h).round() as usize; 
+    let paths: Vec<Vec<f64>> = ctx.time("euler-maruyama ensemble", || (0..members).map(|m| {
+        let mut noise = Noise::new(11 + m as u64); 
+        let (mut p, mut dp) = (eco.ic, [0.0; 2]); 
+        let mut samples = Vec::with_capacity((times.len() - skip) / every + 1); 
+        for k in 0..steps {
+            eco.rate(eco.ts[0] + k as f64 * dt, &p, &mut dp); 
+            for i in 0..2 {
+                p[i] = (p[i] + dp[i] * dt + sigma * p[i] * dt.sqrt() * noise.normal()).max(0.0); 
+            }
+            if k + 1 >= skip && (k + 1 - skip).is_multiple_of(every) {
+                samples.push(p[1]); 
+            }
+        }
+        samples
+    }).collect()); 
+
+    let ensemble = correlation::ensemble_autocorrelation(&paths, h, lags)?; 
+    let single: Vec<f64> = paths.iter()
+        .map(|y| correlation::autocorrelation(y, h, lags).map(|c| c.decorrelation_time().unwrap_or(f64::NAN)))
+        .collect::<Result<_, _>>()?; 
+    let mean = single.iter().sum::<f64>() / members as f64; 
+    let spread = (single.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (members - 1) as f64).sqrt(); 
+    let predicted = 1.0 / eco.a[1]; 
+    let show = |v: Option<f64>| v.map_or("-".to_string(), |x| format!("{x:.4}")); 
+
+    let caption = format!("Decorrelation of N2, {members} runs of {} samples after t = {t_skip}", paths[0].len()); 
+    let mut table = Table::new(caption.clone(), &["estimate", "decorrelation time", "predicted 1/a2", "first insignificant lag", "white bound"]); 
+    println!("{caption}"); 
+    println!("{:>18} {:>20} {:>16} {:>24} {:>12}", "estimate", "decorrelation time", "predicted 1/a2", "first insignificant lag", "white bound"); 
+    let rows = [
+        ["ensemble".to_string(), show(ensemble.decorrelation_time()), format!("{predicted:.4}"), 
+            show(ensemble.first_insignificant()), format!("{:.4}", ensemble.white_bound())], 
+        ["single run".to_string(), format!("{mean:.4} +- {spread:.2}"), format!("{predicted:.4}"), "-".to_string(), 
+            format!("{:.4}", ensemble.white_bound() * (members as f64).sqrt())], 
+    ]; 
+    for row in rows {
+        println!("{:>18} {:>20} {:>16} {:>24} {:>12}", row[0], row[1], row[2], row[3], row[4]); 
+        table.row(row); 
+    }
+    ctx.table(table); 
+
+    let exact: Vec<f64> = ensemble.lag.iter().map(|l| (-eco.a[1] * l).exp()).collect(); 
+    correlation::plot(&ctx.style(), &[correlation::Panel {
+        title: format!("N2 autocorrelation, {members} noisy runs, sigma = {sigma}"), 
+        correlation: &ensemble, 
+        reference: Some(("exp(-a2 lag)", exact)), 
+    }], &ctx.path(name))?; 
+    ctx.caption(name, "Ensemble autocorrelation of N2 under environmental noise against the linearized exp(-a2 lag), 95% bands"); 
+    Ok(())
+}
+
+///
+/// Which equilibrium a state has settled on, as an index into 
+/// eco.equilibria(), or None when it is within 1% of none of them (in 
+/// units of each species' carrying capacity) 
+///
+fn classify(eco: &Ecosystem, pop: &[f64; 2]) -> Option<usize> {
+    let eq = eco.equilibria(); 
+    let k = [eco.a[0] / eco.b[0], eco.a[1] / eco.b[1]]; 
+    eq.iter().position(|(_, e)| (0..2).all(|i| (pop[i] - e[i]).abs() < 1e-2 * k[i]))
+}
+
+///
+/// Basins of attraction of the competition model. Starting populations 
+/// on a grid up to 1.5 carrying capacities are integrated by rk4 and 
+/// colored by the equilibrium they reach, for the lab's parameters 
+/// (exclusion) beside weak competition (coexistence) and strong 
+/// competition (bistable, the winner set by the starting populations), 
+/// with the mean time to settle in each basin. Goh's Lyapunov function about each attracting equilibrium is checked 
+/// for dV/dt <= 0 on the same grid; it certifies the basin only when the 
+/// attractor is global 
+///
+pub fn basin_study(ctx: &mut RunContext, tf: f64, grid: usize, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let scenarios = [("lab", [1e-6, 1e-7]), ("weak competition", [4e-7, 4e-7]), ("strong competition", [1.2e-6, 1.2e-6])]; 
+    let dt = 1.0; 
+    ctx.solver("rk4", "fixed-step, basin classification"); 
+    ctx.config("basins", format!("{grid} x {grid} grid, tf = {tf}, dt = {dt}")); 
+
+    let path = ctx.path(name); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.panels).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let panels = root.split_evenly((1, scenarios.len())); 
+
+    println!("{:>20} {:>12} {:>8} {:>10} {:>12} {:>12}", "scenario", "outcome", "cells", "fraction", "mean settle", "dV/dt <= 0"); 
+    for ((label, c), panel) in scenarios.iter().zip(&panels) {
+        let eco = Ecosystem::lab()
+            .competition(*c)
+            .span(0.0, tf)
+            .build()?; 
+        let rate = |t: f64, p: &[f64; 2], dp: &mut [f64; 2]| eco.rate(t, p, dp); 
+        let eq = eco.equilibria(); 
+        let top = [1.5 * eco.a[0] / eco.b[0], 1.5 * eco.a[1] / eco.b[1]]; 
+        let cell = [top[0] / grid as f64, top[1] / grid as f64]; 
+
+        let ics = ic_sweep::grid_2d([0.0, 0.0], top, [grid, grid]); 
+        let solver = ic_sweep::SweepSolver { method: Method::Rk4, ts: eco.ts, dt }; 
+        let outcomes = ctx.time("basin grid", || {
+            ic_sweep::sweep_initial_conditions(&rate, &|p: &[f64; 2]| classify(&eco, p), &ics, solver)
+        })?; 
+        let basin: Vec<usize> = outcomes.iter().map(|o| o.class.unwrap_or(eq.len())).collect(); 
+
+        let mut chart = ChartBuilder::on(panel)
+            .caption(format!("{label}, c = {:?}", c), theme.caption())
+            .margin(theme.margin)
+            .set_label_area_size(LabelAreaPosition::Left, 70)
+            .set_label_area_size(LabelAreaPosition::Bottom, 50)
+            .build_cartesian_2d(0.0..top[0], 0.0..top[1])?; 
+        chart.configure_mesh()
+            .label_style(theme.label())
+            .axis_desc_style(theme.label())
+            .x_desc(Ecosystem::STATES[0].label())
+            .y_desc(Ecosystem::STATES[1].label())
+            .draw()?; 
+
+        for class in 0..=eq.len() {
+            let cells: Vec<usize> = (0..basin.len()).filter(|k| basin[*k] == class).collect(); 
+            if cells.is_empty() {
+                continue; 
+            }
+            let outcome = eq.get(class).map_or("unsettled", |e| e.0); 
+            let color = if class < eq.len() { theme.color(class) } else { BLACK.to_rgba() }; 
+            chart.draw_series(cells.iter().map(|k| {
+                let (x, y) = ((k % grid) as f64 * cell[0], (k / grid) as f64 * cell[1]); 
+                Rectangle::new([(x, y), (x + cell[0], y + cell[1])], color.mix(0.6).filled())
+            }))?
+                .label(outcome)
+                .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 15, y + 5)], color.filled()));
+
+            // Lyapunov check about an attracting equilibrium, on the grid 
+            let lyapunov = eq.get(class).map_or("-".to_string(), |(_, e)| {
+                let decreasing = ics.iter().filter(|p| eco.lyapunov_rate(e, p) <= 0.0).count(); 
+                format!("{:.1}%", 100.0 * decreasing as f64 / ics.len() as f64)
+            }); 
+            let times: Vec<f64> = cells.iter().filter_map(|k| outcomes[*k].settle).collect(); 
+            let settle = if times.is_empty() { "-".to_string() } else { format!("{:.1}", times.iter().sum::<f64>() / times.len() as f64) }; 
+            println!("{:>20} {:>12} {:>8} {:>10.3} {:>12} {:>12}", 
+                label, outcome, cells.len(), cells.len() as f64 / basin.len() as f64, settle, lyapunov); 
+        }
+        let reached = ic_sweep::tally(&outcomes, eq.len()).iter().filter(|n| **n > 0).count(); 
+        println!("{:>20} outcome depends on starting populations: {}", label, if reached > 1 { "yes" } else { "no" }); 
+
+        chart.draw_series(eq.iter().map(|(_, e)| Circle::new((e[0], e[1]), 5, BLACK.filled())))?; 
+        chart.configure_series_labels()
+            .label_font(theme.label())
+            .border_style(BLACK)
+            .background_style(WHITE.mix(0.85))
+            .draw()?; 
+
+        // V along the lab's starting populations, for the attractor they reach 
+        let ((_, y), _) = Method::Rk4.solve(&rate, eco.ic, eco.ts, dt)?; 
+        if let Some((outcome, e)) = classify(&eco, &y[y.len() - 1]).and_then(|class| eq.get(class)) {
+            let v: Vec<f64> = y.iter().map(|p| eco.lyapunov(e, p)).collect(); 
+            let monotone = v.windows(2).all(|w| w[1] <= w[0] + 1e-12 * v[0].abs()); 
+            println!("{:>20} from {:?}: {outcome}, V {:.4e} -> {:.4e}, monotone {monotone}", 
+                label, eco.ic, v[0], v[v.len() - 1]); 
+        }
+    }
+
+    root.present()?; 
+    Ok(())
+}
+
+///
+/// Long-term outcomes; the first four follow the order of equilibria() 
+///
+const OUTCOMES: [&str; 6] = ["extinction", "N1 wins", "N2 wins", "coexistence", "oscillation", "unsettled"]; 
+
+///
+/// Index into OUTCOMES for one run: the equilibrium its final state 
+/// settled on as in classify, otherwise oscillation when N1 turned back 
+/// and forth at least four times over the second half of the run, 
+/// otherwise unsettled 
+///
+fn outcome(eco: &Ecosystem, end: &[f64; 2], turns: usize) -> usize {
+    if let Some(class) = classify(eco, end) {
+        return class; 
+    }
+    if turns >= 4 { 4 } else { 5 }
+}
+
+///
+/// Two-parameter outcome map over the competition coefficients c1, c2 
+/// from the lab's starting populations. The dashed lines are the 
+/// invasion thresholds c1 = a1 b2 / a2 and c2 = a2 b1 / a1; below both the 
+/// species coexist, above both the outcome is bistable and the starting 
+/// populations decide it. Near the thresholds the approach to equilibrium 
+/// slows and cells may still read unsettled at tf. The lab's parameters 
+/// are marked 
+///
+pub fn parameter_plane_study(ctx: &mut RunContext, tf: f64, grid: usize, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let base = Ecosystem::lab().span(0.0, tf).build()?; 
+    let (a, b, lab) = (base.a, base.b, base.c); 
+    let top = 2e-6; 
+    let dt = 1.0; 
+    let cell = top / grid as f64; 
+    ctx.solver("batch::solve_with", "classical rk4, one lane per cell, outcome classification"); 
+    ctx.config("parameter_plane", format!("c1, c2 in [0, {top}], {grid} x {grid}, tf = {tf}, dt = {dt}")); 
+
+    let cells: Vec<[f64; 2]> = (0..grid * grid)
+        .map(|k| [((k % grid) as f64 + 0.5) * cell, ((k / grid) as f64 + 0.5) * cell])
+        .collect(); 
+    let ecos = cells.iter()
+        .map(|&c| Ecosystem::lab().competition(c).span(0.0, tf).build())
+        .collect::<Result<Vec<Ecosystem>, _>>()?; 
+
+    // every cell is a lane of one rk4 batch, N1's turns over the second 
+    // half counted as the points stream past 
+    let points = solvers::step_count([0.0, tf], dt) + 1; 
+    let half = points / 2; 
+    let mut seen = 0; 
+    let mut recent = vec![[0.0; 2]; cells.len()]; 
+    let mut turns = vec![0usize; cells.len()]; 
+    let rate = |_t: f64, p: &[Vec<f64>; 2], dp: &mut [Vec<f64>; 2]| {
+        let ([n1, n2], [d1, d2]) = (p, dp); 
+        for (((d, x), y), c) in d1.iter_mut().zip(n1).zip(n2).zip(&cells) {
+            *d = x * (a[0] - b[0] * x - c[0] * y); 
+        }
+        for (((d, x), y), c) in d2.iter_mut().zip(n1).zip(n2).zip(&cells) {
+            *d = y * (a[1] - b[1] * y - c[1] * x); 
+        }
+    }; 
+    let end = ctx.time("parameter plane", || {
+        batch::solve_with(&rate, &vec![[1e5, 1e5]; cells.len()], [0.0, tf], dt, Limits::default(), |_, pop| {
+            if seen >= half {
+                for (k, (r, n)) in recent.iter_mut().zip(turns.iter_mut()).enumerate() {
+                    let x = pop.y[0][k]; 
+                    if seen >= half + 2 && (r[1] - r[0]) * (x - r[1]) < 0.0 {
+                        *n += 1; 
+                    }
+                    *r = [r[1], x]; 
+                }
+            }
+            seen += 1; 
+        })
+    })?; 
+    let map: Vec<usize> = ecos.iter().enumerate().map(|(k, eco)| outcome(eco, &end.lane(k), turns[k])).collect(); 
+
+    let path = ctx.path(name); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let mut chart = ChartBuilder::on(&root)
+        .caption(format!("Competition Outcome from {:?}, a = {a:?}, b = {b:?}", [1e5, 1e5]), theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 70)
+        .set_label_area_size(LabelAreaPosition::Bottom, 50)
+        .build_cartesian_2d(0.0..top, 0.0..top)?; 
+    chart.configure_mesh()
+        .label_style(theme.label())
+        .axis_desc_style(theme.label())
+        .x_desc("c1")
+        .y_desc("c2")
+        .x_label_formatter(&|v| format!("{:.1e}", v))
+        .y_label_formatter(&|v| format!("{:.1e}", v))
+        .draw()?; 
+
+    println!("{:>12} {:>8} {:>10}", "outcome", "cells", "fraction"); 
+    for (class, outcome) in OUTCOMES.iter().enumerate() {
+        let cells: Vec<usize> = (0..map.len()).filter(|k| map[*k] == class).collect(); 
+        if cells.is_empty() {
+            continue; 
+        }
+        println!("{:>12} {:>8} {:>10.3}", outcome, cells.len(), cells.len() as f64 / map.len() as f64); 
+        let color = if class + 1 < OUTCOMES.len() { theme.color(class) } else { BLACK.to_rgba() }; 
+        chart.draw_series(cells.iter().map(|k| {
+            let (x, y) = ((k % grid) as f64 * cell, (k / grid) as f64 * cell); 
+            Rectangle::new([(x, y), (x + cell, y + cell)], color.mix(0.6).filled())
+        }))?
+            .label(*outcome)
+            .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 15, y + 5)], color.filled()));
+    }
+
+    let thresholds = [a[0] * b[1] / a[1], a[1] * b[0] / a[0]]; 
+    annotate::draw(&mut chart, &theme, &[
+        Annotation::vertical(thresholds[0], format!("N1 invasion threshold c1 = a1 b2 / a2 = {:.1e}", thresholds[0])), 
+        Annotation::horizontal(thresholds[1], format!("N2 invasion threshold c2 = a2 b1 / a1 = {:.1e}", thresholds[1])), 
+    ])?; 
+    chart.draw_series([Circle::new((lab[0], lab[1]), 6, BLACK.filled())])?
+        .label("lab parameters")
+        .legend(|(x, y)| Circle::new((x + 10, y), 5, BLACK.filled()));
+
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?; 
+
+    root.present()?; 
+    Ok(())
+}
+
+///
+/// Analytic equilibria and their stability for the lab's parameters and 
+/// the three regimes of the outcome map, cross-checked against rk4 runs 
+/// from a grid of starting populations. A feasible stable equilibrium 
+/// that no run reaches, or a run that ends at an unstable equilibrium or 
+/// none at all, is flagged. Runs ending on a saddle are reported but not 
+/// flagged, they start on its stable manifold 
+///
+pub fn equilibrium_study(ctx: &mut RunContext, tf: f64, grid: usize) -> Result<(), Box<dyn std::error::Error>> {
+    let scenarios = [
+        ("lab", [1e-6, 1e-7]), 
+        ("weak competition", [4e-7, 4e-7]), 
+        ("strong competition", [1.2e-6, 1.2e-6]), 
+        ("N1 dominant", [1e-7, 1e-6]), 
+    ]; 
+    let dt = 1.0; 
+    ctx.solver("rk4", "fixed-step, equilibrium cross-check"); 
+    ctx.config("equilibria", format!("{grid} x {grid} grid, tf = {tf}, dt = {dt}")); 
+
+    let mut flagged = 0; 
+    let mut table = Table::new(format!("Equilibria and their stability, cross-checked by rk4 from a {grid} x {grid} grid to t = {tf}"), 
+        &["scenario", "equilibrium", "point", "eigenvalues", "stability", "reached", "check"]); 
+    for (label, c) in scenarios {
+        let eco = Ecosystem::lab()
+            .competition(c)
+            .span(0.0, tf)
+            .build()?; 
+        let rate = |t: f64, p: &[f64; 2], dp: &mut [f64; 2]| eco.rate(t, p, dp); 
+        let analysis = eco.equilibrium_analysis(); 
+        let feasible: Vec<&Equilibrium> = analysis.iter().filter(|e| e.feasible).collect(); 
+
+        let top = [1.5 * eco.a[0] / eco.b[0], 1.5 * eco.a[1] / eco.b[1]]; 
+        let ics = ic_sweep::grid_2d([0.0, 0.0], top, [grid, grid]); 
+        let solver = ic_sweep::SweepSolver { method: Method::Rk4, ts: eco.ts, dt }; 
+        let outcomes = ctx.time("equilibrium grid", || {
+            ic_sweep::sweep_initial_conditions(&rate, &|p: &[f64; 2]| classify(&eco, p), &ics, solver)
+        })?; 
+        let counts = ic_sweep::tally(&outcomes, feasible.len()); 
+
+        println!("{label}, c = {c:?}"); 
+        println!("{:>14} {:>26} {:>24} {:>11} {:>8}  check", "equilibrium", "point", "eigenvalues", "stability", "reached"); 
+        for e in &analysis {
+            let reached = feasible.iter().position(|f| f.name == e.name).map(|k| counts[k]); 
+            let check = match (e.feasible, e.stability, reached.unwrap_or(0)) {
+                (false, _, _) => "infeasible", 
+                (true, Stability::Stable, 0) => { flagged += 1; "DISAGREE: stable but never reached" }, 
+                (true, Stability::Stable, _) => "ok", 
+                (true, Stability::Saddle, n) if n > 0 => "ok, on the stable manifold", 
+                (true, Stability::Unstable, n) if n > 0 => { flagged += 1; "DISAGREE: unstable but reached" }, 
+                (true, Stability::Degenerate, _) => "non-hyperbolic", 
+                _ => "ok", 
+            }; 
+            let eig = |k: usize| if e.eigenvalues[k].1 == 0.0 { 
+                format!("{:.3e}", e.eigenvalues[k].0) 
+            } else { 
+                format!("{:.2e}{:+.2e}i", e.eigenvalues[k].0, e.eigenvalues[k].1) 
+            }; 
+            let row = [
+                e.name.to_string(), 
+                format!("({:.1}, {:.1})", e.point[0], e.point[1]), 
+                format!("{}, {}", eig(0), eig(1)), 
+                format!("{:?}", e.stability).to_lowercase(), 
+                reached.map_or("-".to_string(), |n| n.to_string()), 
+            ]; 
+            println!("{:>14} {:>26} {:>24} {:>11} {:>8}  {check}", row[0], row[1], row[2], row[3], row[4]); 
+            table.row(std::iter::once(label.to_string()).chain(row).chain(std::iter::once(check.to_string()))); 
+        }
+        let lost = counts[feasible.len()]; 
+        if lost > 0 {
+            flagged += 1; 
+            println!("{:>14} {lost} runs reached no equilibrium by t = {tf}  DISAGREE", "unsettled"); 
+            table.row([label.to_string(), "unsettled".into(), "-".into(), "-".into(), "-".into(), lost.to_string(), "DISAGREE".into()]); 
+        }
+    }
+    println!("equilibrium cross-check: {flagged} disagreement(s)"); 
+    ctx.write_table("equilibria.tex", table)?; 
+    Ok(())
+}
+
+///
+/// Seasonal forcing of N1's growth, a1(t) = a1 (1 + eps sin(2 pi t / 365)), 
+/// under weak competition where the unforced species coexist. The 
+/// populations settle onto a yearly cycle around the coexistence point; 
+/// the table gives the range over the final year for each eps 
+///
+pub fn seasonal_study(ctx: &mut RunContext, years: f64, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let (period, dt) = (365.0, 0.25); 
+    let tf = years * period; 
+    let eps = [0.0, 0.5, 0.9]; 
+    ctx.solver("rk4", "fixed-step, seasonal growth forcing"); 
+    ctx.config("seasonal", format!("a1 (1 + eps sin(2 pi t / {period})), eps = {eps:?}, tf = {tf}")); 
+
+    let mut runs = Vec::new(); 
+    println!("{:>6} {:>12} {:>12} {:>12} {:>12}", "eps", "N1 min", "N1 max", "N2 min", "N2 max"); 
+    for e in eps {
+        let eco = Ecosystem::lab()
+            .competition([4e-7, 4e-7])
+            .span(0.0, tf)
+            .forced(Param::Growth, 0, Schedule::seasonal(0.1, e, period))
+            .step(Method::Rk4, dt)
+            .build()?; 
+        let ((t, y), _) = ctx.time("rk4 seasonal", || eco.run())?; 
+
+        let last: Vec<&[f64; 2]> = t.iter().zip(&y).filter(|(ti, _)| **ti >= tf - period).map(|(_, p)| p).collect(); 
+        let range = |i: usize| last.iter().fold((f64::MAX, f64::MIN), |(lo, hi), p| (lo.min(p[i]), hi.max(p[i]))); 
+        let (r1, r2) = (range(0), range(1)); 
+        println!("{:>6} {:>12.4e} {:>12.4e} {:>12.4e} {:>12.4e}", e, r1.0, r1.1, r2.0, r2.1); 
+        runs.push((e, t, y)); 
+    }
+
+    let path = ctx.path(name); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let top = runs.iter().flat_map(|(_, _, y)| y.iter()).fold(0.0_f64, |m, p| m.max(p[0]).max(p[1])) * 1.1; 
+    let mut chart = ChartBuilder::on(&root)
+        .caption(format!("Seasonal Growth Forcing of N1, period {period} days"), theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 70)
+        .set_label_area_size(LabelAreaPosition::Bottom, 50)
+        .build_cartesian_2d(0.0..tf, 0.0..top)?; 
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("t (days)").y_desc(state::axis_label(&Ecosystem::STATES)).draw()?; 
+
+    for (k, (e, t, y)) in runs.iter().enumerate() {
+        let color = theme.color(k); 
+        for (s, state) in Ecosystem::STATES.iter().enumerate() {
+            let style = if s == 0 { color.stroke_width(theme.line) } else { color.mix(0.5).stroke_width(theme.thin) }; 
+            chart.draw_series(LineSeries::new(t.iter().zip(y).map(|(ti, p)| (*ti, p[s])), style))?
+                .label(format!("{}, eps = {e}", state.name))
+                .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], style));
+        }
+    }
+
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?; 
+
+    root.present()?; 
+    Ok(())
+}
+
+///
+/// Growth driven by measured thermal performance curves: each species' 
+/// growth rate is tabulated against water temperature (N1 warm adapted, 
+/// N2 cool adapted) and read through a spline at a seasonal temperature 
+/// whose mean steps up 3 C halfway through the run. Linear and cubic 
+/// interpolation of the same table are compared 
+///
+pub fn thermal_study(ctx: &mut RunContext, years: f64, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let (period, dt) = (365.0, 0.25); 
+    let tf = years * period; 
+    let celsius = [0.0, 5.0, 10.0, 15.0, 20.0, 25.0, 30.0, 35.0]; 
+    let warm = [0.0, 0.01, 0.04, 0.08, 0.12, 0.14, 0.10, 0.02]; 
+    let cool = [0.02, 0.06, 0.10, 0.12, 0.10, 0.06, 0.02, 0.0]; 
+    let season = move |mean: f64| move |t: f64| mean + 8.0 * (2.0 * std::f64::consts::PI * t / period).sin(); 
+    let temperature = Piecewise::new(&[0.5 * tf], vec![Arc::new(season(12.0)), Arc::new(season(15.0))])?; 
+    ctx.solver("rk4", "fixed-step, tabulated growth forcing"); 
+    ctx.config("thermal", format!("T = 12 (15 after t = {}) + 8 sin(2 pi t / {period}), tables at {celsius:?} C", 0.5 * tf)); 
+
+    let mut runs = Vec::new(); 
+    println!("{:>8} {:>14} {:>14} {:>14} {:>14}", "table", "N1 mean, 12C", "N2 mean, 12C", "N1 mean, 15C", "N2 mean, 15C"); 
+    for kind in [Interpolation::Linear, Interpolation::Cubic] {
+        let (t_warm, t_cool) = (temperature.clone(), temperature.clone()); 
+        let eco = Ecosystem::lab()
+            .competition([4e-7, 4e-7])
+            .span(0.0, tf)
+            .forced(Param::Growth, 0, Schedule::tabulated(Spline::new(&celsius, &warm, kind)?, move |t| t_warm.eval(t)))
+            .forced(Param::Growth, 1, Schedule::tabulated(Spline::new(&celsius, &cool, kind)?, move |t| t_cool.eval(t)))
+            .step(Method::Rk4, dt)
+            .build()?; 
+        let ((t, y), _) = ctx.time("rk4 thermal", || eco.run())?; 
+
+        // means over the last year of each climate 
+        let mean = |end: f64, i: usize| {
+            let pts: Vec<f64> = t.iter().zip(&y).filter(|(ti, _)| **ti > end - period && **ti <= end).map(|(_, p)| p[i]).collect(); 
+            pts.iter().sum::<f64>() / pts.len() as f64
+        }; 
+        println!("{:>8} {:>14.4e} {:>14.4e} {:>14.4e} {:>14.4e}", format!("{kind:?}").to_lowercase(), 
+            mean(0.5 * tf, 0), mean(0.5 * tf, 1), mean(tf, 0), mean(tf, 1)); 
+        runs.push((kind, t, y)); 
+    }
+    let gap = runs[0].2.iter().zip(&runs[1].2).fold(0.0_f64, |m, (a, b)| m.max((a[0] - b[0]).abs()).max((a[1] - b[1]).abs())); 
+    println!("largest linear vs cubic gap: {gap:.4e}"); 
+
+    let path = ctx.path(name); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let top = runs.iter().flat_map(|(_, _, y)| y.iter()).fold(0.0_f64, |m, p| m.max(p[0]).max(p[1])) * 1.1; 
+    let mut chart = ChartBuilder::on(&root)
+        .caption(format!("Tabulated Thermal Growth, mean temperature 12 C -> 15 C at t = {}", 0.5 * tf), theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 70)
+        .set_label_area_size(LabelAreaPosition::Bottom, 50)
+        .build_cartesian_2d(0.0..tf, 0.0..top)?; 
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("t (days)").y_desc(state::axis_label(&Ecosystem::STATES)).draw()?; 
+
+    for (k, (kind, t, y)) in runs.iter().enumerate() {
+        for (s, state) in Ecosystem::STATES.iter().enumerate() {
+            let color = theme.color(s); 
+            let style = if k == 1 { color.stroke_width(theme.line) } else { color.mix(0.4).stroke_width(theme.thin) }; 
+            chart.draw_series(LineSeries::new(t.iter().zip(y).map(|(ti, p)| (*ti, p[s])), style))?
+                .label(format!("{}, {kind:?}", state.name))
+                .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], style));
+        }
+    }
+
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?; 
+
+    root.present()?; 
+    Ok(())
+}
+
+///
+/// Harvesting as a management scenario: under the lab's parameters N2 
+/// excludes N1, so a fraction of N2 is removed every 30 days to see how 
+/// much harvest lets N1 persist. For one fraction the AB/AM4 solution with 
+/// its history reset at every harvest is compared against the same run 
+/// keeping the stale history, both against rk4 at a small dt 
+///
+pub fn harvest_study(ctx: &mut RunContext, tf: f64, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let eco = Ecosystem::lab()
+        .span(0.0, tf)
+        .build()?; 
+    let rate = |t: f64, p: &[f64; 2], dp: &mut [f64; 2]| eco.rate(t, p, dp); 
+    let (period, dt) = (30.0, 0.5); 
+    let count = (tf / period).floor() as usize; 
+    let fractions = [0.0, 0.25, 0.5, 0.75]; 
+    ctx.solver("impulse::integrate_impulsive", "rk4 restarted at each harvest"); 
+    ctx.config("harvest", format!("N2 every {period} days, fractions {fractions:?}, dt = {dt}")); 
+
+    let rk4 = |r: &_, t: f64, y: &[f64; 2], h: f64| Method::Rk4.step(r, t, y, h); 
+    let mut runs = Vec::new(); 
+    println!("{:>9} {:>12} {:>12} {:>12}", "fraction", "N1 at tf", "N2 at tf", "N1 min"); 
+    for fraction in fractions {
+        let events = impulse::periodic_harvest(period, period, count, 1, fraction); 
+        let (t, y) = ctx.time("rk4 harvest", || impulse::integrate_impulsive(&rate, rk4, eco.ic, eco.ts, dt, &events, Limits::default()))?; 
+        let last = y[y.len() - 1]; 
+        let low = y.iter().fold(f64::MAX, |m, p| m.min(p[0])); 
+        println!("{:>9} {:>12.4e} {:>12.4e} {:>12.4e}", fraction, last[0], last[1], low); 
+        runs.push((fraction, t, y)); 
+    }
+
+    // history handling, for the middle fraction 
+    let events = impulse::periodic_harvest(period, period, count, 1, 0.5); 
+    let (_, reference) = impulse::integrate_impulsive(&rate, rk4, eco.ic, eco.ts, 1e-3, &events, Limits::default())?; 
+    let exact = reference[reference.len() - 1]; 
+    let error = |y: &[[f64; 2]]| {
+        let p = y[y.len() - 1]; 
+        (p[0] - exact[0]).abs().max((p[1] - exact[1]).abs()) / eco.ic[0] 
+    }; 
+    ctx.solver("impulse::abm4_impulsive", "AB/AM4 PECE, rk4 restart after each impulse"); 
+    println!("{:>8} {:>14} {:>14}", "dt", "history reset", "history kept"); 
+    for h in [1.0, 0.5, 0.25] {
+        let run = |history| {
+            impulse::abm4_impulsive(&rate, eco.ic, eco.ts, h, &events, history, CorrectorFailure::Abort, Limits::default())
+        }; 
+        let ((_, reset), (_, kept)) = (run(impulse::History::Reset)?, run(impulse::History::Keep)?); 
+        println!("{:>8} {:>14.4e} {:>14.4e}", h, error(&reset), error(&kept)); 
+    }
+
+    let path = ctx.path(name); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let top = runs.iter().flat_map(|(_, _, y)| y.iter()).fold(0.0_f64, |m, p| m.max(p[0]).max(p[1])) * 1.1; 
+    let mut chart = ChartBuilder::on(&root)
+        .caption(format!("Harvesting N2 every {period} days"), theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 70)
+        .set_label_area_size(LabelAreaPosition::Bottom, 50)
+        .build_cartesian_2d(0.0..tf, 0.0..top)?; 
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("t (days)").y_desc(state::axis_label(&Ecosystem::STATES)).draw()?; 
+
+    for (k, (fraction, t, y)) in runs.iter().enumerate() {
+        let color = theme.color(k); 
+        for (s, state) in Ecosystem::STATES.iter().enumerate() {
+            let style = if s == 0 { color.stroke_width(theme.line) } else { color.mix(0.5).stroke_width(theme.thin) }; 
+            chart.draw_series(LineSeries::new(t.iter().zip(y).map(|(ti, p)| (*ti, p[s])), style))?
+                .label(format!("{}, {:.0}% harvest", state.name, 100.0 * fraction))
+                .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], style));
+        }
+    }
+
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?; 
+
+    root.present()?; 
+    Ok(())
+}
+
+///
+/// Juvenile/adult structure on top of the lab's competition: adults compete 
+/// with the lab's b and c, and the vital rates are chosen so the adult 
+/// recruitment m f / (m + d_J) - d_A equals the lab's a, giving the same 
+/// adult capacities. The juvenile stage delays growth, so the low density 
+/// rate is the smaller dominant eigenvalue of each stage block 
+///
+pub fn stage_study(ctx: &mut RunContext, tf: f64, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let eco = Ecosystem::lab()
+        .span(0.0, tf)
+        .build()?; 
+    let vital = stages::VitalRates { fecundity: 0.1875, maturation: 0.2, juvenile_death: 0.05, adult_death: 0.05 }; 
+    let staged = stages::StagedEcosystem::from_vital_rates([vital; 2], eco.b, eco.c)?; 
+    let dt = 0.1; 
+    eco.check_dt(dt)?; 
+    ctx.solver("rk4", "fixed-step, stage-structured 4 state system"); 
+    ctx.config("stages", format!("{vital:?}")); 
+
+    // juveniles start at their stable ratio to adults 
+    let ratio = vital.fecundity / (vital.maturation + vital.juvenile_death); 
+    let ic = [ratio * eco.ic[0], eco.ic[0], ratio * eco.ic[1], eco.ic[1]]; 
+    let rate = |t: f64, p: &[f64; 2], dp: &mut [f64; 2]| eco.rate(t, p, dp); 
+    let staged_rate = |t: f64, y: &[f64; 4], dy: &mut [f64; 4]| staged.rate(t, y, dy); 
+    let ((t, y), _) = ctx.time("rk4 unstructured", || Method::Rk4.solve(&rate, eco.ic, eco.ts, dt))?; 
+    let ((ts, ys), _) = ctx.time("rk4 staged", || Method::Rk4.solve(&staged_rate, ic, eco.ts, dt))?; 
+
+    println!("low density growth rate {:.4}, adult recruitment {:.4}, lab a {:.4}", 
+        vital.growth_rate(), vital.adult_recruitment(), eco.a[0]); 
+    let (last, staged_last) = (y[y.len() - 1], ys[ys.len() - 1]); 
+    println!("{:>8} {:>14} {:>14} {:>14}", "species", "N at tf", "adults at tf", "juveniles at tf"); 
+    for s in 0..2 {
+        println!("{:>8} {:>14.4e} {:>14.4e} {:>14.4e}", s + 1, last[s], staged_last[2 * s + 1], staged_last[2 * s]); 
+    }
+
+    let path = ctx.path(name); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let top = y.iter().flat_map(|p| p.iter()).chain(ys.iter().flat_map(|p| p.iter())).fold(0.0_f64, |m, v| m.max(*v)) * 1.1; 
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Stage-Structured vs Unstructured Competition", theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 70)
+        .set_label_area_size(LabelAreaPosition::Bottom, 50)
+        .build_cartesian_2d(0.0..tf, 0.0..top)?; 
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("t (days)").y_desc("individuals").draw()?; 
+
+    for (s, state) in Ecosystem::STATES.iter().enumerate() {
+        let color = theme.color(s); 
+        let faint = color.mix(0.4); 
+        chart.draw_series(LineSeries::new(t.iter().zip(&y).map(|(ti, p)| (*ti, p[s])), faint.stroke_width(theme.line)))?
+            .label(format!("{}, unstructured", state.name))
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], faint.stroke_width(theme.line)));
+        for (k, style) in [(2 * s + 1, color.stroke_width(theme.line)), (2 * s, color.stroke_width(theme.thin))] {
+            chart.draw_series(LineSeries::new(ts.iter().zip(&ys).map(|(ti, p)| (*ti, p[k])), style))?
+                .label(stages::STATES[k].name)
+                .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], style));
+        }
+    }
+
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?; 
+
+    root.present()?; 
+    Ok(())
+}
+
+///
+/// Runs for a given timestep, calling correct constructor, solving system 
+/// Then passing solution to be plotted. Outputs land in the run directory 
+///
+///
+/// Cleaning a noisy census: the lab ecosystem is sampled every quarter day
+/// with 3% multiplicative noise, then smoothed by a moving average and by
+/// Savitzky-Golay over the same window. Growth rates are estimated from the
+/// noisy samples by central differences and by the Savitzky-Golay
+/// derivative, all against the exact trajectory and rate 
+///
+pub fn smoothing_study(ctx: &mut RunContext, tf: f64, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let (dt, every, sigma) = (1e-3, 250, 0.03); 
+    let (window, order) = (41, 3); 
+    let eco = Ecosystem::lab()
+        .span(0.0, tf)
+        .step(Method::Rk4, dt)
+        .build()?; 
+    ctx.solver("rk4", "fixed-step, sampled with noise"); 
+    ctx.solver("signal::savitzky_golay", &format!("window {window}, order {order}")); 
+    ctx.config("smoothing", format!("sample every {} days, relative noise {sigma}, seed 7", dt * every as f64)); 
+
+    let (t, y) = ctx.time("rk4 smoothing", || eco.solve(dt))?; 
+    let ts: Vec<f64> = t.iter().step_by(every).copied().collect(); 
+    let h = ts[1] - ts[0]; 
+    let mut noise = Noise::new(7); 
+    let rms = |a: &[f64], b: &[f64]| (a.iter().zip(b).map(|(x, y)| (x - y).powi(2)).sum::<f64>() / a.len() as f64).sqrt(); 
+
+    println!("{:>6} {:>12} {:>12} {:>12} {:>12} {:>12} {:>12}", "state", "raw", "moving avg", "sav-golay", "rate scale", "central", "sav-golay'"); 
+    let mut series = Vec::new(); 
+    for (s, state) in Ecosystem::STATES.iter().enumerate() {
+        let exact: Vec<f64> = y.iter().step_by(every).map(|p| p[s]).collect(); 
+        let rate: Vec<f64> = ts.iter().zip(y.iter().step_by(every)).map(|(ti, p)| {
+            let mut dp = [0.0; 2]; 
+            eco.rate(*ti, p, &mut dp); 
+            dp[s]
+        }).collect(); 
+        let noisy = noise.relative(&exact, sigma); 
+        let average = signal::moving_average(&noisy, window)?; 
+        let smooth = signal::savitzky_golay(&noisy, window, order, 0, h)?; 
+        let slope = signal::savitzky_golay(&noisy, window, order, 1, h)?; 
+        // central differences inside, one sided at the ends 
+        let n = noisy.len(); 
+        let central: Vec<f64> = (0..n).map(|i| {
+            let (a, b) = (i.saturating_sub(1), (i + 1).min(n - 1)); 
+            (noisy[b] - noisy[a]) / (ts[b] - ts[a])
+        }).collect(); 
+        let scale = rate.iter().fold(0.0_f64, |m, r| m.max(r.abs())); 
+        println!("{:>6} {:>12.4e} {:>12.4e} {:>12.4e} {:>12.4e} {:>12.4e} {:>12.4e}", state.name, 
+            rms(&noisy, &exact), rms(&average, &exact), rms(&smooth, &exact), scale, rms(&central, &rate), rms(&slope, &rate)); 
+        series.push((state.name, exact, noisy, average, smooth)); 
+    }
+
+    let path = ctx.path(name); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let top = series.iter().flat_map(|(_, _, noisy, _, _)| noisy.iter()).fold(0.0_f64, |m, v| m.max(*v)) * 1.1; 
+    let mut chart = ChartBuilder::on(&root)
+        .caption(format!("Noisy Census ({}% noise), moving average vs Savitzky-Golay, window {window}", sigma * 100.0), theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 70)
+        .set_label_area_size(LabelAreaPosition::Bottom, 50)
+        .build_cartesian_2d(0.0..tf, 0.0..top)?; 
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("t (days)").y_desc(state::axis_label(&Ecosystem::STATES)).draw()?; 
+
+    for (s, (label, exact, noisy, average, smooth)) in series.iter().enumerate() {
+        let color = theme.color(s); 
+        chart.draw_series(ts.iter().zip(noisy).map(|(ti, v)| Circle::new((*ti, *v), 2, color.mix(0.4).filled())))?
+            .label(format!("{label}, samples"))
+            .legend(move |(x, y)| Circle::new((x + 10, y), 3, color.mix(0.4).filled()));
+        chart.draw_series(DashedLineSeries::new(ts.iter().copied().zip(average.iter().copied()), 6, 4, color.stroke_width(theme.thin)))?
+            .label(format!("{label}, moving average"))
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(theme.thin)));
+        chart.draw_series(LineSeries::new(ts.iter().copied().zip(smooth.iter().copied()), color.stroke_width(theme.line)))?
+            .label(format!("{label}, Savitzky-Golay"))
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(theme.line)));
+        chart.draw_series(LineSeries::new(ts.iter().copied().zip(exact.iter().copied()), BLACK.mix(0.6).stroke_width(theme.thin)))?; 
+    }
+
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?; 
+
+    root.present()?; 
+    Ok(())
+}
+
+///
+/// Recovering the lab parameters from a noisy census: Savitzky-Golay gives
+/// N and dN/dt, and the per capita rate (dN_i/dt) / N_i = a_i - b_i N_i -
+/// c_i N_j is linear in the parameters. An overparameterized model adds a
+/// total crowding column N1 + N2, exactly collinear with the others; the
+/// normal equations then lose the fit while pivoted QR drops a column and
+/// keeps the same effective b and c 
+///
+pub fn fit_study(ctx: &mut RunContext, tf: f64) -> Result<(), Box<dyn std::error::Error>> {
+    let (dt, every, sigma) = (1e-3, 250, 0.002); 
+    let (window, order, tol) = (41, 3, 1e-10); 
+    let eco = Ecosystem::lab()
+        .span(0.0, tf)
+        .step(Method::Rk4, dt)
+        .build()?; 
+    ctx.solver("linalg::least_squares", &format!("pivoted householder qr, rank tol {tol}")); 
+    ctx.config("fit", format!("sample every {} days, relative noise {sigma}, seed 5, savitzky-golay {window}/{order}", dt * every as f64)); 
+
+    let (t, y) = ctx.time("rk4 fit", || eco.solve(dt))?; 
+    let h = t[every] - t[0]; 
+    let mut noise = Noise::new(5); 
+    let mut smooth = [Vec::new(), Vec::new()]; 
+    let mut slope = [Vec::new(), Vec::new()]; 
+    for s in 0..2 {
+        let noisy = noise.relative(&y.iter().step_by(every).map(|p| p[s]).collect::<Vec<f64>>(), sigma); 
+        smooth[s] = signal::savitzky_golay(&noisy, window, order, 0, h)?; 
+        slope[s] = signal::savitzky_golay(&noisy, window, order, 1, h)?; 
+    }
+    // drop the ends, where the filter extrapolates 
+    let rows: Vec<usize> = (window / 2..smooth[0].len() - window / 2).collect(); 
+
+    println!("{:>4} {:>10} {:>8} {:>5} {:>12} {:>12} {:>12} {:>12}", "N", "columns", "method", "rank", "a", "b", "c", "total"); 
+    for s in 0..2 {
+        let o = 1 - s; 
+        println!("{:>4} {:>10} {:>8} {:>5} {:>12.4e} {:>12.4e} {:>12.4e} {:>12}", Ecosystem::STATES[s].name, "true", "-", "-", eco.a[s], eco.b[s], eco.c[s], "-"); 
+        let target: Vec<f64> = rows.iter().map(|&i| slope[s][i] / smooth[s][i]).collect(); 
+        for cols in [3, 4] {
+            let mut a = Matrix::zeros(rows.len(), cols); 
+            for (r, &i) in rows.iter().enumerate() {
+                a[(r, 0)] = 1.0; 
+                a[(r, 1)] = -smooth[s][i]; 
+                a[(r, 2)] = -smooth[o][i]; 
+                if cols == 4 {
+                    a[(r, 3)] = -(smooth[s][i] + smooth[o][i]); 
+                }
+            }
+            let normal = a.transpose().mul(&a).solve(&a.transpose().mul_vec(&target)); 
+            let (qr, rank) = linalg::least_squares(&a, &target, tol)?; 
+            let results = [("normal", normal.map(|x| (x, cols)).map_err(|e| e.to_string())), ("qr", Ok((qr, rank)))]; 
+            for (method, result) in results {
+                match result {
+                    Ok((x, rank)) => {
+                        // the total column adds to both b and c 
+                        let total = if cols == 4 { x[3] } else { 0.0 }; 
+                        println!("{:>4} {:>10} {:>8} {:>5} {:>12.4e} {:>12.4e} {:>12.4e} {:>12.4e}", "", cols, method, rank, x[0], x[1] + total, x[2] + total, total); 
+                    }
+                    Err(e) => println!("{:>4} {:>10} {:>8} failed: {e}", "", cols, method), 
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+///
+/// Final state statistics by outcome and species for one backend's run of
+/// the ensemble
+///
+fn ensemble_report(backend: &str, seconds: f64, samples: &[Sample], ends: &[[f64; 2]]) {
+    // a species counts as present above 1% of its own carrying capacity
+    let classify = |s: &Sample, end: &[f64; 2]| {
+        let present = |i: usize| end[i] > 1e-2 * s.params.a[i] / s.params.b[i]; 
+        match (present(0), present(1)) {
+            (true, false) => Some(0),
+            (false, true) => Some(1),
+            (true, true) => Some(2),
+            (false, false) => None,
+        }
+    }; 
+    let counts = ic_sweep::tally(&ensemble::outcomes(samples, ends, classify), 3); 
+    let (summary, lost) = ensemble::summarize(ends); 
+    println!("{:>8} {:>10.3} {:>12.3e} {:>9} {:>9} {:>9} {:>9} {:>9}", backend, seconds, samples.len() as f64 / seconds,
+        counts[0], counts[1], counts[2], counts[3], lost); 
+    for (state, s) in Ecosystem::STATES.iter().zip(summary) {
+        println!("{:>8} {:>10} {:>12.4e} {:>12.4e} {:>12.4e} {:>12.4e} {:>12.4e}", "", state.name, s.mean, s.std, s.q05, s.median, s.q95); 
+    }
+}
+
+///
+/// A large ensemble of the competition model: every rate and starting
+/// population scattered log-normally about b = c, where draws fall on
+/// either side of the exclusion, coexistence and bistable boundaries.
+/// Final states are tallied by outcome and summarized across the ensemble.
+/// With the `gpu` feature the same draws also run on the compute backend
+/// (in f32), go through the same reduction, and are compared with the CPU
+/// final states; without an adapter the study stays on the CPU
+///
+pub fn ensemble_study(ctx: &mut RunContext, tf: f64, count: usize) -> Result<(), Box<dyn std::error::Error>> {
+    let (dt, spread, seed) = (0.5, 0.25, 13); 
+    let eco = Ecosystem::lab()
+        .competition([8e-7, 8e-7])
+        .span(0.0, tf)
+        .step(Method::Rk4, dt)
+        .build()?; 
+    ctx.solver("rk4", "fixed-step, ensemble"); 
+    ctx.config("ensemble", format!("{count} draws, log-normal spread {spread}, seed {seed}, tf = {tf}, dt = {dt}")); 
+
+    let base = Competition { a: eco.a, b: eco.b, c: eco.c }; 
+    let samples = ensemble::draw(&base, eco.ic, spread, count, seed); 
+    println!("{:>8} {:>10} {:>12} {:>9} {:>9} {:>9} {:>9} {:>9}", "backend", "seconds", "draws/s", "N1 only", "N2 only", "both", "neither", "lost"); 
+    println!("{:>8} {:>10} {:>12} {:>12} {:>12} {:>12} {:>12}", "", "state", "mean", "std", "q05", "median", "q95"); 
+
+    let start = Instant::now(); 
+    let cpu = ctx.time("ensemble cpu", || ensemble::integrate_cpu(&samples, eco.ts, dt, Limits::default()))?; 
+    ensemble_report("cpu", start.elapsed().as_secs_f64(), &samples, &cpu); 
+
+    #[cfg(feature = "gpu")]
+    match ensemble_gpu::GpuEnsemble::new() {
+        Ok(gpu) => {
+            ctx.solver("rk4 (gpu)", &format!("fixed-step f32 compute shader, {}", gpu.adapter)); 
+            let start = Instant::now(); 
+            let ends = ctx.time("ensemble gpu", || gpu.integrate(&samples, eco.ts, dt))?; 
+            ensemble_report("gpu", start.elapsed().as_secs_f64(), &samples, &ends); 
+            let scale = eco.a[0] / eco.b[0]; 
+            let diff = cpu.iter().zip(&ends).fold(0.0_f64, |m, (c, g)| m.max((c[0] - g[0]).abs()).max((c[1] - g[1]).abs())); 
+            println!("gpu on {}: max |cpu - gpu| = {diff:.3e}, {:.2e} of a carrying capacity", gpu.adapter, diff / scale); 
+        }
+        Err(e) => println!("gpu backend unavailable, cpu only: {e}"),
+    }
+    Ok(())
+}
+
+///
+/// M patches on a line, each the lab's competition, joined by migration 
+/// at rate m for both species. With identical patches the region is the 
+/// single patch repeated and N2 excludes N1 at every m; with two habitats 
+/// (the right half has the competition coefficients swapped, so N1 wins 
+/// there alone) migration carries each species into the other's patches. 
+/// Each row gives the regional invasion rate of either species against the 
+/// other's resident equilibrium, the rightmost eigenvalue of 
+/// diag(a - c N*) - m L, and the outcome from equal starting populations 
+///
+pub fn metapopulation_study(ctx: &mut RunContext, tf: f64, rates: &[f64], name: &str) 
+    -> Result<(), Box<dyn std::error::Error>> {
+    const PATCHES: usize = 8; 
+    const STATE: usize = 2 * PATCHES; 
+    let dt = 0.5; 
+    let eco = Ecosystem::lab()
+        .span(0.0, tf)
+        .step(Method::Rk4, dt)
+        .build()?; 
+    let home = Competition { a: eco.a, b: eco.b, c: eco.c }; 
+    let away = Competition { c: [eco.c[1], eco.c[0]], ..home }; 
+    // below one individual a species is gone from its patch 
+    let extinct = 1.0; 
+    ctx.solver("rk4", "fixed-step, metapopulation::Metapopulation"); 
+    ctx.config("metapopulation", format!("{PATCHES} patches on a path, tf = {tf}, dt = {dt}, extinct below {extinct}")); 
+
+    let caption = format!("{PATCHES} competing patches on a path, rk4 at dt = {dt} to t = {tf}"); 
+    let headers = ["habitats", "m", "m lambda2", "invade N1", "invade N2", "N1 total", "N2 total", "coexist"]; 
+    let mut table = Table::new(caption.clone(), &headers); 
+    println!("{caption}"); 
+    println!("{:>10} {:>8} {:>10} {:>11} {:>11} {:>12} {:>12} {:>8}", headers[0], headers[1], headers[2], headers[3], headers[4], headers[5], headers[6], headers[7]); 
+    let mut profiles = Vec::new(); 
+    let mut sinks = Vec::new(); 
+    for habitats in ["one", "two"] {
+        for &m in rates {
+            let graph = Graph::path(PATCHES, 1.0); 
+            let lambda2 = graph.algebraic_connectivity()?; 
+            let builder = Metapopulation::builder(PATCHES, home).migration(graph, [m, m]); 
+            let meta = if habitats == "two" {
+                (PATCHES / 2..PATCHES).fold(builder, |b, i| b.patch(i, away))
+            } else {
+                builder
+            }.build()?; 
+
+            // each species alone settles to the resident state the other must invade 
+            let mut invade = [0.0; 2]; 
+            for s in 0..2 {
+                let mut alone = [0.0; 2]; 
+                alone[1 - s] = eco.ic[1 - s]; 
+                let ((_, y), _) = system::solve(&meta, Method::Rk4, meta.uniform::<STATE>(alone), [0.0, tf], dt)?; 
+                invade[s] = meta.invasion_rate(s, &meta.profile(&y[y.len() - 1], 1 - s))?; 
+            }
+            let ((t, y), _) = ctx.time("rk4 metapopulation", || system::solve(&meta, Method::Rk4, meta.uniform::<STATE>(eco.ic), [0.0, tf], dt))?; 
+            let end = y[y.len() - 1]; 
+            let total = meta.totals(&end); 
+            let coexist = (0..PATCHES).filter(|i| end[2 * i] > extinct && end[2 * i + 1] > extinct).count(); 
+
+            let row = [
+                habitats.to_string(), format!("{m}"), format!("{:.4e}", m * lambda2), format!("{:+.4e}", invade[0]), format!("{:+.4e}", invade[1]), 
+                format!("{:.4e}", total[0]), format!("{:.4e}", total[1]), format!("{coexist}/{PATCHES}"), 
+            ]; 
+            println!("{:>10} {:>8} {:>10} {:>11} {:>11} {:>12} {:>12} {:>8}", row[0], row[1], row[2], row[3], row[4], row[5], row[6], row[7]); 
+            table.row(row); 
+            if habitats == "two" {
+                profiles.push((m, [meta.profile(&end, 0), meta.profile(&end, 1)])); 
+                sinks.push((m, t, meta.population(&y, 0, 0))); 
+            }
+        }
+    }
+    ctx.table(table); 
+
+    let path = ctx.path(name); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let panels = root.split_evenly((2, 1)); 
+
+    let top = profiles.iter().flat_map(|(_, p)| p.iter().flatten()).fold(0.0_f64, |m, v| m.max(*v)) * 1.1; 
+    let mut chart = ChartBuilder::on(&panels[0])
+        .caption(format!("Two Habitats, Density by Patch at t = {tf}"), theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 70)
+        .set_label_area_size(LabelAreaPosition::Bottom, 40)
+        .build_cartesian_2d(1.0..PATCHES as f64, 0.0..top)?; 
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("patch").y_desc("population").draw()?; 
+    for (j, (m, p)) in profiles.iter().enumerate() {
+        let color = theme.color(j); 
+        let points = |s: usize| p[s].iter().enumerate().map(|(i, v)| ((i + 1) as f64, *v)).collect::<Vec<(f64, f64)>>(); 
+        chart.draw_series(LineSeries::new(points(0), color.stroke_width(theme.line)))?
+            .label(format!("m = {m}"))
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(2)));
+        chart.draw_series(DashedLineSeries::new(points(1), 6, 4, color.stroke_width(theme.line)))?; 
+    }
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .position(SeriesLabelPosition::UpperRight)
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?; 
+
+    // a log axis needs a floor, well below one individual 
+    let floor = 1e-3; 
+    let top = sinks.iter().flat_map(|(_, _, n)| n.iter()).fold(0.0_f64, |m, v| m.max(*v)) * 2.0; 
+    let mut chart = ChartBuilder::on(&panels[1])
+        .caption("Two Habitats, N1 in Patch 1, where N2 wins alone", theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 70)
+        .set_label_area_size(LabelAreaPosition::Bottom, 40)
+        .build_cartesian_2d(0.0..tf, (floor..top).log_scale())?; 
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("t").y_desc("N1").draw()?; 
+    for (j, (_, t, n)) in sinks.iter().enumerate() {
+        let color = theme.color(j); 
+        chart.draw_series(LineSeries::new(t.iter().zip(n).map(|(ti, v)| (*ti, v.max(floor))), color.stroke_width(theme.line)))?; 
+    }
+    chart.draw_series(DashedLineSeries::new([(0.0, extinct), (tf, extinct)], 6, 4, BLACK.stroke_width(theme.thin)))?; 
+
+    root.present()?; 
+    ctx.caption(name, format!("{PATCHES} patches in two habitats: final densities by patch (N1 solid, N2 dashed) and N1 over time in the patch it loses, per migration rate")); 
+    Ok(())
+}
+
+pub fn run(ctx: &mut RunContext, dt: f64, name: &str, title: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let eco = Ecosystem::with_units::<Day>(
+        [Population(1e5), Population(1e5)], 
+        [Rate::new(0.1), Rate::new(0.1)],
+        [Interaction::new(8e-7), Interaction::new(8e-7)],
+        [Interaction::new(1e-6), Interaction::new(1e-7)], 
+        [Time::new(0.0), Time::new(10.0)]
+    )?; 
+    eco.check_dt(dt)?; 
+    ctx.config("time_unit", Day::NAME);
+    ctx.config("dt", dt);
+    ctx.config("ic", format!("{:?}", eco.ic));
+    ctx.config("a", format!("{:?}", eco.a));
+    ctx.config("b", format!("{:?}", eco.b));
+    ctx.config("c", format!("{:?}", eco.c));
+    ctx.config("ts", format!("{:?}", eco.ts));
+    let groups = eco.groups(); 
+    ctx.config("capacity", format!("{:?}", groups.capacity));
+    ctx.config("competition_ratios", format!("{:?}", groups.competition));
+    ctx.config("regime", groups.regime());
+    ctx.config("parameter_warnings", eco.report().len());
+    ctx.solver("rk4", "fixed-step");
+
+    let (t, y) = ctx.time("rk4 solve", || eco.solve(dt))?;
+    let path = ctx.path(name);
+    let _ = plot(&ctx.style(), &t, &y, &Ecosystem::STATES, &path, title);
+
+    let stem = name.trim_end_matches(".png");
+    // (dN1/dt) / N1, four decades below N1 itself
+    let n1: Vec<f64> = y.iter().map(|p| p[0]).collect();
+    let growth: Vec<f64> = t.iter().zip(&y).map(|(ti, p)| {
+        let mut dp = [0.0; 2];
+        eco.rate(*ti, p, &mut dp);
+        dp[0] / p[0]
+    }).collect();
+    let _ = plot_dual(&ctx.style(), &t, &n1, &growth, ["N1 [individuals]", "N1 per-capita growth [1/day]"],
+        &ctx.path(&format!("{stem}_growth.png")), &format!("N1 and its Per-capita Growth Rate, h={dt}"));
+    let _ = export::write_npz(&ctx.path(&format!("{stem}.npz")), &t, &[("y", &y)]);
+    let _ = export::write_mat(&ctx.path(&format!("{stem}.mat")), &t, &[("y", &y)]);
+
+    let errors = ctx.path("errors.png");
+    let theme = ctx.style(); 
+    let metric = ErrorMetric::default(); 
+    ctx.config("error_metric", format!("{}, {:?}, floor {:e}", metric.norm.name(), metric.scope, metric.floor));
+    let reference = Reference::Solver { method: Method::Rk4, dt: dt / 4.0 }; 
+    ctx.config("error_reference", reference.describe()); 
+    if let Ok(rows) = ctx.time("convergence study", || compare(&theme, dt, &metric, &reference, &errors)) {
+        let labels = metric.labels(&Ecosystem::STATES.map(|s| s.name)); 
+        println!("relative errors against {}", reference.describe()); 
+        println!("{:>10} {}", "dt", labels.iter().map(|l| format!("{l:>14}")).collect::<String>()); 
+        for (dti, e) in rows {
+            println!("{:>10.1e} {}", dti, e.iter().map(|x| format!("{x:>14.4e}")).collect::<String>()); 
+        }
+    }
+    Ok(())
+}
+
+///
+/// Every ecosystem experiment, in the order a bare run goes through them 
+///
+const EXPERIMENTS: &[Experiment] = &[
+    Experiment {
+        name: "rk4", system: "competition, 2 species", solver: "rk4, dt = 1e-4", 
+        outputs: &["rk4_ecosystem.png", "rk4_ecosystem_growth.png", "rk4_ecosystem.npz", "rk4_ecosystem.mat", "errors.png"], 
+        run: |ctx| run(ctx, 1e-4, "rk4_ecosystem.png", "Ecosystem over Time, h=1e-4"), 
+    }, 
+    Experiment {
+        name: "taylor", system: "competition, 2 species", solver: "taylor order 20 against rk4", outputs: &[], 
+        run: |ctx| taylor_reference(ctx, 1e-4), 
+    }, 
+    Experiment {
+        name: "picard", system: "competition, 2 species", solver: "picard iteration", outputs: &["picard_ecosystem.png"], 
+        run: |ctx| picard_iterates(ctx, 1e-3, "picard_ecosystem.png"), 
+    }, 
+    Experiment {
+        name: "order", system: "competition, 2 species", solver: "euler, heun, midpoint, rk4, extrapolation", 
+        outputs: &["order_study.png", "order_study.tex"], 
+        run: |ctx| order_study(ctx, "order_study.png"), 
+    }, 
+    Experiment {
+        name: "tolerance", system: "competition, to t = 500", solver: "vsvo adams, scalar vs per-state atol", outputs: &[], 
+        run: |ctx| tolerance_study(ctx, 500.0), 
+    }, 
+    Experiment {
+        name: "scaling", system: "competition, nondimensionalized", solver: "rk4 and vsvo adams", outputs: &[], 
+        run: |ctx| scaling_study(ctx, 100.0), 
+    }, 
+    Experiment {
+        name: "long_run", system: "competition, to t = 1000", solver: "rk4 streamed to disk", outputs: &["long_run.png"], 
+        run: |ctx| long_run(ctx, 1e-3, 1000.0, "long_run.png"), 
+    }, 
+    Experiment {
+        name: "continue", system: "competition, t = 100 extended to 500", solver: "rk4, warm-started", outputs: &["continued.png"], 
+        run: |ctx| continuation_study(ctx, 1e-3, 100.0, 500.0, "continued.png"), 
+    }, 
+    Experiment {
+        name: "restart", system: "competition, resumed at t = 20", solver: "ab/am4, dense output history vs rk4 bootstrap", outputs: &[], 
+        run: |ctx| restart_study(ctx, 0.5, 20.0, 100.0), 
+    }, 
+    Experiment {
+        name: "richardson", system: "competition, 2 species", solver: "euler, heun at dt = 0.2 and 0.1, extrapolated", outputs: &["richardson.png"], 
+        run: |ctx| richardson_study(ctx, 0.2, 50.0, "richardson.png"), 
+    }, 
+    Experiment {
+        name: "asymptotics", system: "competition, 2 species", solver: "rk4 at dt = 0.1, summarized after t = 100", outputs: &[], 
+        run: |ctx| asymptotic_study(ctx, 0.1, 400.0, 100.0), 
+    }, 
+    Experiment {
+        name: "derived", system: "competition, 2 species", solver: "rk4 at dt = 0.05", outputs: &["derived.png", "derived.csv"], 
+        run: |ctx| derived_study(ctx, 0.05, 100.0, "derived"), 
+    }, 
+    Experiment {
+        name: "correlation", system: "competition with environmental noise", solver: "euler-maruyama ensemble, fft autocorrelation", 
+        outputs: &["correlation.png"], run: |ctx| correlation_study(ctx, 2400.0, 400.0, 16, "correlation.png"), 
+    },
+    Experiment {
+        name: "defect", system: "competition, 2 species", solver: "euler, rk4 at dt = 0.1 and 0.05", outputs: &["defect.png"], 
+        run: |ctx| defect_study(ctx, 0.1, 50.0, "defect.png"), 
+    }, 
+    Experiment {
+        name: "spatial", system: "competition with diffusion, 1D", solver: "lie and strang splitting", outputs: &["spatial.png"], 
+        run: |ctx| spatial_study(ctx, 50.0, "spatial.png"), 
+    }, 
+    Experiment {
+        name: "imex", system: "competition with diffusion, 1D", solver: "imex runge-kutta against rk4", outputs: &[], 
+        run: |ctx| imex_study(ctx, 50.0), 
+    }, 
+    Experiment {
+        name: "conservation", system: "diffusion on the 1D habitat", solver: "theta, rk4 and imex schemes, conservation ledger", 
+        outputs: &["conservation.png"], run: |ctx| conservation_study(ctx, 50.0, "conservation.png"), 
+    }, 
+    Experiment {
+        name: "basins", system: "competition, three regimes", solver: "rk4 over an initial condition grid", outputs: &["basins.png"], 
+        run: |ctx| basin_study(ctx, 1000.0, 120, "basins.png"), 
+    }, 
+    Experiment {
+        name: "parameter_plane", system: "competition over (c1, c2)", solver: "batched rk4", outputs: &["parameter_plane.png"], 
+        run: |ctx| parameter_plane_study(ctx, 1000.0, 100, "parameter_plane.png"), 
+    }, 
+    Experiment {
+        name: "equilibria", system: "competition, four scenarios", solver: "analytic jacobian, rk4 cross-check", outputs: &["equilibria.tex"], 
+        run: |ctx| equilibrium_study(ctx, 1000.0, 20), 
+    }, 
+    Experiment {
+        name: "seasonal", system: "competition, seasonal growth", solver: "rk4", outputs: &["seasonal.png"], 
+        run: |ctx| seasonal_study(ctx, 5.0, "seasonal.png"), 
+    }, 
+    Experiment {
+        name: "harvest", system: "competition, impulsive harvest", solver: "rk4 and ab/am4 with restarts", outputs: &["harvest.png"], 
+        run: |ctx| harvest_study(ctx, 1000.0, "harvest.png"), 
+    }, 
+    Experiment {
+        name: "stages", system: "juvenile/adult competition", solver: "rk4", outputs: &["stages.png"], 
+        run: |ctx| stage_study(ctx, 300.0, "stages.png"), 
+    }, 
+    Experiment {
+        name: "thermal", system: "competition, tabulated growth", solver: "rk4", outputs: &["thermal.png"], 
+        run: |ctx| thermal_study(ctx, 10.0, "thermal.png"), 
+    }, 
+    Experiment {
+        name: "smoothing", system: "competition, noisy census", solver: "rk4, savitzky-golay", outputs: &["smoothing.png"], 
+        run: |ctx| smoothing_study(ctx, 100.0, "smoothing.png"), 
+    }, 
+    Experiment {
+        name: "fit", system: "competition, noisy census", solver: "least squares by pivoted qr", outputs: &[], 
+        run: |ctx| fit_study(ctx, 100.0), 
+    }, 
+    Experiment {
+        name: "metapopulation", system: "competition on 8 patches with migration", solver: "rk4, laplacian invasion rates", 
+        outputs: &["metapopulation.png"], run: |ctx| metapopulation_study(ctx, 2000.0, &[0.0, 1e-4, 1e-3, 1e-2, 1e-1], "metapopulation.png"), 
+    }, 
+    Experiment {
+        name: "ensemble", system: "competition, jittered parameters", solver: "rk4 on cpu threads or gpu", outputs: &[], 
+        run: |ctx| ensemble_study(ctx, 500.0, 65536), 
+    }, 
+]; 
+
+fn main() {
+    experiment::main("ecosystem", EXPERIMENTS, |_| {}); 
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*; 
+
+    #[test]
+    fn builder_refuses_no_self_limitation() {
+        for b in [[0.0, 8e-7], [8e-7, -1e-7]] {
+            assert!(Ecosystem::lab().self_limitation(b).span(0.0, 10.0).build().is_err()); 
+        }
+    }
+
+    #[test]
+    fn builder_checks_the_step() {
+        let eco = Ecosystem::lab().span(0.0, 100.0); 
+        assert!(eco.clone().step(Method::Rk4, 0.1).build().is_ok()); 
+        assert!(eco.clone().step(Method::Rk4, 0.0).build().is_err()); 
+        assert!(eco.clone().step(Method::Rk4, 0.3).build().is_err()); 
+        // the decay at capacity is -a = -0.1, past euler's bound of 2 at dt = 25
+        assert!(eco.clone().span(0.0, 500.0).step(Method::Euler, 25.0).build().is_err()); 
+        assert!(eco.span(0.0, 500.0).step(Method::Rk4, 25.0).build().is_ok()); 
+    }
 }
@@ -0,0 +1,110 @@
+//!
+//! ensemble.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Ensembles of the two species competition model: many independent draws
+//! of the parameters and starting populations, each integrated by rk4 to
+//! the same final time. Final states are reduced to ic_sweep Outcomes, so
+//! they tally like any sweep, and to summary statistics across the
+//! ensemble. The CPU backend splits the draws across scoped threads; with
+//! the `gpu` feature ensemble_gpu integrates them in a compute shader and
+//! hands back final states for the same reduction
+//!
+
+#![allow(dead_code)]
+
+use solvers::competition::{self, Competition};
+use solvers::{self, rk4_step, Limits};
+
+use crate::ic_sweep::Outcome;
+use crate::noise::Noise;
+
+///
+/// One member of the ensemble
+///
+#[derive(Clone, Copy, Debug)]
+pub struct Sample {
+    pub params: Competition,
+    pub ic: [f64; 2],
+}
+
+///
+/// Across-ensemble statistics of one state component over the finite
+/// final states
+///
+#[derive(Clone, Copy, Debug)]
+pub struct Summary {
+    pub mean: f64,
+    pub std: f64,
+    pub q05: f64,
+    pub median: f64,
+    pub q95: f64,
+}
+
+///
+/// count draws around base and ic, every coefficient and population
+/// multiplied by exp(spread z) for a standard normal z, so each stays
+/// positive and spread is the relative scatter for small spreads
+///
+pub fn draw(base: &Competition, ic: [f64; 2], spread: f64, count: usize, seed: u64) -> Vec<Sample> {
+    let mut noise = Noise::new(seed);
+    let mut jitter = |v: f64| v * (spread * noise.normal()).exp();
+    (0..count).map(|_| {
+        let params = Competition {
+            a: [jitter(base.a[0]), jitter(base.a[1])],
+            b: [jitter(base.b[0]), jitter(base.b[1])],
+            c: [jitter(base.c[0]), jitter(base.c[1])],
+        };
+        Sample { params, ic: [jitter(ic[0]), jitter(ic[1])] }
+    }).collect()
+}
+
+///
+/// Final rk4 state of every sample over ts, in sample order, each run
+/// under limits
+///
+pub fn integrate_cpu(samples: &[Sample], ts: [f64; 2], dt: f64, limits: Limits) -> Result<Vec<[f64; 2]>, Box<dyn std::error::Error>> {
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk = samples.len().div_ceil(threads).max(1);
+    std::thread::scope(|scope| {
+        let workers: Vec<_> = samples
+            .chunks(chunk)
+            .map(|part| scope.spawn(move || part.iter().map(|s| {
+                let rate = |_t: f64, p: &[f64; 2], dp: &mut [f64; 2]| competition::rate(&s.params.a, &s.params.b, &s.params.c, p, dp);
+                solvers::integrate_with(&rate, rk4_step, s.ic, ts, dt, limits, |_, _| {}).map_err(|e| e.to_string())
+            }).collect::<Result<Vec<_>, _>>()))
+            .collect();
+        let mut ends = Vec::with_capacity(samples.len());
+        for w in workers {
+            ends.extend(w.join().expect("ensemble worker panicked")?);
+        }
+        Ok(ends)
+    })
+}
+
+///
+/// Outcomes from final states, whichever backend produced them. Only the
+/// end state is known, so settle is None
+///
+pub fn outcomes<C>(samples: &[Sample], ends: &[[f64; 2]], classify: C) -> Vec<Outcome<2>>
+where C: Fn(&Sample, &[f64; 2]) -> Option<usize> {
+    samples.iter().zip(ends).map(|(s, end)| Outcome { ic: s.ic, end: *end, class: classify(s, end), settle: None }).collect()
+}
+
+///
+/// Statistics of each component, and the number of final states that were
+/// not finite and so left out
+///
+pub fn summarize(ends: &[[f64; 2]]) -> ([Summary; 2], usize) {
+    let finite: Vec<&[f64; 2]> = ends.iter().filter(|e| e.iter().all(|v| v.is_finite())).collect();
+    let summary = std::array::from_fn(|i| {
+        let mut v: Vec<f64> = finite.iter().map(|e| e[i]).collect();
+        v.sort_by(f64::total_cmp);
+        let n = v.len() as f64;
+        let mean = v.iter().sum::<f64>() / n;
+        let var = v.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0).max(1.0);
+        // nearest rank
+        let quantile = |q: f64| v.get(((q * (n - 1.0)).round() as usize).min(v.len().saturating_sub(1))).copied().unwrap_or(f64::NAN);
+        Summary { mean, std: var.sqrt(), q05: quantile(0.05), median: quantile(0.5), q95: quantile(0.95) }
+    });
+    (summary, ends.len() - finite.len())
+}
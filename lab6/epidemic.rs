@@ -0,0 +1,287 @@
+//!
+//! epidemic.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! SIR and SEIR compartment models on population fractions, with the basic
+//! reproduction number R0 computed both in closed form and as the spectral
+//! radius of the next generation matrix F V^-1, and the epidemic final size
+//! checked against the final size relation z = 1 - s0 exp(-R0 z)
+//!
+
+#![allow(clippy::cast_possible_truncation)]
+#![allow(clippy::cast_sign_loss)]
+#![allow(clippy::cast_precision_loss)]
+#![allow(clippy::missing_panics_doc)]
+#![allow(clippy::missing_errors_doc)]
+
+use plotters::prelude::*;
+use plotters_bitmap::BitMapBackend;
+use solvers::{CorrectorFailure, Limits, Method};
+
+mod export;
+mod linalg;
+mod multistep;
+mod peaks;
+mod report;
+mod run_context;
+mod spline;
+mod state;
+mod system;
+mod theme;
+mod trajectory;
+
+use linalg::Matrix;
+use multistep::{Mode, Startup};
+use run_context::RunContext;
+use state::StateVar;
+use system::OdeSystem;
+
+///
+/// Transmission rate beta, recovery rate gamma, and birth rate mu equal
+/// to the death rate so the population stays constant (mu = 0 for a
+/// single outbreak)
+///
+#[derive(Clone, Copy, Debug)]
+struct Sir {
+    beta:  f64,
+    gamma: f64,
+    mu:    f64,
+}
+
+///
+/// SIR with a latent stage left at rate sigma
+///
+#[derive(Clone, Copy, Debug)]
+struct Seir {
+    beta:  f64,
+    sigma: f64,
+    gamma: f64,
+    mu:    f64,
+}
+
+impl Sir {
+    pub const STATES: [StateVar; 3] = [
+        StateVar::new("S", "fraction"),
+        StateVar::new("I", "fraction"),
+        StateVar::new("R", "fraction"),
+    ];
+
+    fn r0(&self) -> f64 {
+        self.beta / (self.gamma + self.mu)
+    }
+
+    ///
+    /// F and V of the infected subsystem (I) at the disease free state
+    ///
+    fn next_generation(&self) -> (Matrix, Matrix) {
+        (Matrix::from_rows(&[[self.beta]]), Matrix::from_rows(&[[self.gamma + self.mu]]))
+    }
+}
+
+impl Seir {
+    pub const STATES: [StateVar; 4] = [
+        StateVar::new("S", "fraction"),
+        StateVar::new("E", "fraction"),
+        StateVar::new("I", "fraction"),
+        StateVar::new("R", "fraction"),
+    ];
+
+    fn r0(&self) -> f64 {
+        self.beta * self.sigma / ((self.sigma + self.mu) * (self.gamma + self.mu))
+    }
+
+    ///
+    /// F and V of the infected subsystem (E, I) at the disease free state:
+    /// F holds new infections, V the transfers out of and between stages
+    ///
+    fn next_generation(&self) -> (Matrix, Matrix) {
+        let f = Matrix::from_rows(&[[0.0, self.beta], [0.0, 0.0]]);
+        let v = Matrix::from_rows(&[[self.sigma + self.mu, 0.0], [-self.sigma, self.gamma + self.mu]]);
+        (f, v)
+    }
+}
+
+impl OdeSystem for Sir {
+    fn dimension(&self) -> usize {
+        3
+    }
+
+    fn rate(&self, _t: f64, y: &[f64], dy: &mut [f64]) {
+        let infection = self.beta * y[0] * y[1];
+        dy[0] = self.mu - infection - self.mu * y[0];
+        dy[1] = infection - (self.gamma + self.mu) * y[1];
+        dy[2] = self.gamma * y[1] - self.mu * y[2];
+    }
+}
+
+impl OdeSystem for Seir {
+    fn dimension(&self) -> usize {
+        4
+    }
+
+    fn rate(&self, _t: f64, y: &[f64], dy: &mut [f64]) {
+        let infection = self.beta * y[0] * y[2];
+        dy[0] = self.mu - infection - self.mu * y[0];
+        dy[1] = infection - (self.sigma + self.mu) * y[1];
+        dy[2] = self.sigma * y[1] - (self.gamma + self.mu) * y[2];
+        dy[3] = self.gamma * y[2] - self.mu * y[3];
+    }
+}
+
+///
+/// R0 as the spectral radius of F V^-1
+///
+fn next_generation_r0(f: &Matrix, v: &Matrix) -> Result<f64, Box<dyn std::error::Error>> {
+    let n = v.rows;
+    let lu = v.lu()?;
+    let mut v_inv = Matrix::zeros(n, n);
+    for j in 0..n {
+        let e: Vec<f64> = (0..n).map(|i| if i == j { 1.0 } else { 0.0 }).collect();
+        v_inv.set_column(j, &lu.solve(&e));
+    }
+    let eig = f.mul(&v_inv).eigenvalues()?;
+    Ok(eig.iter().fold(0.0_f64, |m, (re, im)| m.max(re.hypot(*im))))
+}
+
+///
+/// Fraction z infected over a whole outbreak with no vital dynamics, the
+/// root in (0, 1] of z = 1 - s0 exp(-R0 z), by bisection
+///
+fn final_size(r0: f64, s0: f64) -> f64 {
+    let g = |z: f64| z - 1.0 + s0 * (-r0 * z).exp();
+    let (mut lo, mut hi) = (1e-12, 1.0);
+    if g(lo) > 0.0 {
+        return 0.0;
+    }
+    for _ in 0..200 {
+        let mid = 0.5 * (lo + hi);
+        if g(mid) < 0.0 { lo = mid; } else { hi = mid; }
+    }
+    0.5 * (lo + hi)
+}
+
+///
+/// Closed form R0 against the next generation matrix for a few parameter
+/// sets of each model
+///
+fn r0_table() -> Result<(), Box<dyn std::error::Error>> {
+    println!("{:>6} {:>8} {:>8} {:>8} {:>8} {:>12} {:>12}", "model", "beta", "sigma", "gamma", "mu", "R0 formula", "R0 ngm");
+    for (beta, gamma, mu) in [(0.3, 0.1, 0.0), (0.5, 0.2, 0.01), (0.08, 0.1, 0.0)] {
+        let sir = Sir { beta, gamma, mu };
+        let (f, v) = sir.next_generation();
+        println!("{:>6} {:>8} {:>8} {:>8} {:>8} {:>12.6} {:>12.6}", "sir", beta, "-", gamma, mu, sir.r0(), next_generation_r0(&f, &v)?);
+    }
+    for (beta, sigma, gamma, mu) in [(0.3, 0.2, 0.1, 0.0), (0.5, 0.1, 0.2, 0.01), (0.08, 0.5, 0.1, 0.0)] {
+        let seir = Seir { beta, sigma, gamma, mu };
+        let (f, v) = seir.next_generation();
+        println!("{:>6} {:>8} {:>8} {:>8} {:>8} {:>12.6} {:>12.6}", "seir", beta, sigma, gamma, mu, seir.r0(), next_generation_r0(&f, &v)?);
+    }
+    Ok(())
+}
+
+///
+/// One outbreak under SIR and under SEIR with the same beta and gamma, so
+/// the same R0: the latent stage delays and flattens the peak but leaves
+/// the final size, which both match against the final size relation
+///
+fn outbreak(ctx: &mut RunContext, tf: f64, dt: f64, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let sir = Sir { beta: 0.3, gamma: 0.1, mu: 0.0 };
+    let seir = Seir { beta: 0.3, sigma: 0.2, gamma: 0.1, mu: 0.0 };
+    let i0 = 1e-4;
+    ctx.config("sir", format!("{sir:?}"));
+    ctx.config("seir", format!("{seir:?}"));
+    ctx.config("i0", i0);
+    ctx.solver("rk4", "fixed-step");
+
+    let (sir_rate, seir_rate) = (sir.rhs::<3>()?, seir.rhs::<4>()?);
+    let ((t, y), _) = ctx.time("rk4 sir", || Method::Rk4.solve(&sir_rate, [1.0 - i0, i0, 0.0], [0.0, tf], dt))?;
+    let ((te, ye), _) = ctx.time("rk4 seir", || Method::Rk4.solve(&seir_rate, [1.0 - i0, 0.0, i0, 0.0], [0.0, tf], dt))?;
+
+    let peak = |t: &[f64], i: Vec<f64>| {
+        t.iter().zip(i).fold((0.0, 0.0), |(tp, ip), (ti, v)| if v > ip { (*ti, v) } else { (tp, ip) })
+    };
+    let (sir_peak, seir_peak) = (peak(&t, y.iter().map(|p| p[1]).collect()), peak(&te, ye.iter().map(|p| p[2]).collect()));
+    let expected = final_size(sir.r0(), 1.0 - i0);
+    println!("{:>6} {:>8} {:>12} {:>12} {:>12} {:>12}", "model", "R0", "peak t", "peak I", "final size", "relation");
+    println!("{:>6} {:>8.3} {:>12.2} {:>12.4e} {:>12.6} {:>12.6}", "sir", sir.r0(), sir_peak.0, sir_peak.1, 1.0 - y[y.len() - 1][0], expected);
+    println!("{:>6} {:>8.3} {:>12.2} {:>12.4e} {:>12.6} {:>12.6}", "seir", seir.r0(), seir_peak.0, seir_peak.1, 1.0 - ye[ye.len() - 1][0], expected);
+
+    let path = ctx.path(name);
+    let theme = ctx.style();
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?;
+    let root = theme.stamp(&root)?;
+    let mut chart = ChartBuilder::on(&root)
+        .caption(format!("SIR vs SEIR, R0 = {:.2}", sir.r0()), theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 55)
+        .set_label_area_size(LabelAreaPosition::Bottom, 50)
+        .build_cartesian_2d(0.0..tf, 0.0..1.0)?;
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("t (days)").y_desc("fraction of population").draw()?;
+
+    for (k, s) in Sir::STATES.iter().enumerate() {
+        let color = theme.color(k);
+        chart.draw_series(LineSeries::new(t.iter().zip(&y).map(|(ti, p)| (*ti, p[k])), color.stroke_width(theme.line)))?
+            .label(format!("{}, sir", s.name))
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(theme.line)));
+    }
+    for (k, s) in Seir::STATES.iter().enumerate() {
+        // same color as the matching SIR compartment, E gets its own
+        let color = theme.color([0, 3, 1, 2][k]).mix(0.5);
+        chart.draw_series(LineSeries::new(te.iter().zip(&ye).map(|(ti, p)| (*ti, p[k])), color))?
+            .label(format!("{}, seir", s.name))
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+    }
+
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?;
+
+    root.present()?;
+    Ok(())
+}
+
+///
+/// The lab's AB/AM4 on the 3-state SIR and 4-state SEIR: error at tf of
+/// PECE under each startup at dt and dt / 2 against rk4 at dt / 16, and
+/// the observed order
+///
+fn multistep_check(ctx: &mut RunContext, tf: f64, dt: f64) -> Result<(), Box<dyn std::error::Error>> {
+    let sir = Sir { beta: 0.3, gamma: 0.1, mu: 0.0 };
+    let seir = Seir { beta: 0.3, sigma: 0.2, gamma: 0.1, mu: 0.0 };
+    let i0 = 1e-4;
+    ctx.solver("multistep::solve_with_mode", "ab/am4 PECE, three startups");
+    let (sir_rate, seir_rate) = (sir.rhs::<3>()?, seir.rhs::<4>()?);
+    let (sir_ic, seir_ic) = ([1.0 - i0, i0, 0.0], [1.0 - i0, 0.0, i0, 0.0]);
+    let ((_, sir_ref), _) = Method::Rk4.solve(&sir_rate, sir_ic, [0.0, tf], dt / 16.0)?;
+    let ((_, seir_ref), _) = Method::Rk4.solve(&seir_rate, seir_ic, [0.0, tf], dt / 16.0)?;
+    let max_diff = |a: &[f64], b: &[f64]| a.iter().zip(b).fold(0.0_f64, |m, (x, y)| m.max((x - y).abs()));
+
+    println!("{:>6} {:>10} {:>14} {:>14} {:>8}", "model", "startup", format!("err dt = {dt}"), format!("err dt = {}", dt / 2.0), "order");
+    for startup in [Startup::Rk4, Startup::Rk4Refined(10), Startup::Bootstrap] {
+        let mut errs = [0.0; 2];
+        for (err, h) in errs.iter_mut().zip([dt, dt / 2.0]) {
+            let ((_, y), _) = multistep::solve_with_mode(&sir_rate, sir_ic, [0.0, tf], h, startup, Mode::Pece(1), CorrectorFailure::Abort, Limits::default())?;
+            *err = max_diff(&y[y.len() - 1], &sir_ref[sir_ref.len() - 1]);
+        }
+        println!("{:>6} {:>10} {:>14.4e} {:>14.4e} {:>8.2}", "sir", startup.name(), errs[0], errs[1], (errs[0] / errs[1]).log2());
+        let mut errs = [0.0; 2];
+        for (err, h) in errs.iter_mut().zip([dt, dt / 2.0]) {
+            let ((_, y), _) = multistep::solve_with_mode(&seir_rate, seir_ic, [0.0, tf], h, startup, Mode::Pece(1), CorrectorFailure::Abort, Limits::default())?;
+            *err = max_diff(&y[y.len() - 1], &seir_ref[seir_ref.len() - 1]);
+        }
+        println!("{:>6} {:>10} {:>14.4e} {:>14.4e} {:>8.2}", "seir", startup.name(), errs[0], errs[1], (errs[0] / errs[1]).log2());
+    }
+    Ok(())
+}
+
+fn main() {
+    let mut ctx = RunContext::new("output", "epidemic")
+        .expect("failed to create output directory");
+    let _ = r0_table();
+    let _ = outbreak(&mut ctx, 300.0, 0.1, "sir_seir.png");
+    let _ = multistep_check(&mut ctx, 150.0, 0.5);
+    let _ = ctx.write_report();
+    let _ = ctx.write_manifest();
+}
@@ -0,0 +1,109 @@
+//!
+//! experiment.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Named, self-describing experiments for the lab binaries. Each binary
+//! keeps a registry of its experiments (system, solvers, outputs and the
+//! function that runs it) and hands its command line here:
+//!
+//!     cargo run --bin ecosystem -- list
+//!     cargo run --bin ecosystem -- run order long_run
+//!
+//! With no arguments every experiment runs in registry order, as the
+//! binaries always have. A run writes one output directory, report and
+//! manifest however many experiments it covers
+//!
+
+#![allow(dead_code)]
+
+use crate::run_context::RunContext;
+
+pub type Runner = fn(&mut RunContext) -> Result<(), Box<dyn std::error::Error>>;
+
+#[derive(Clone, Copy)]
+pub struct Experiment {
+    pub name: &'static str,
+    pub system: &'static str,
+    pub solver: &'static str,
+    /// files written into the run directory
+    pub outputs: &'static [&'static str],
+    pub run: Runner,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Command {
+    List,
+    /// the named experiments, every one when empty
+    Run(Vec<String>),
+}
+
+///
+/// Command from the arguments after the program name
+///
+pub fn parse(args: &[String]) -> Result<Command, Box<dyn std::error::Error>> {
+    match args.first().map(String::as_str) {
+        None => Ok(Command::Run(Vec::new())),
+        Some("list") if args.len() == 1 => Ok(Command::List),
+        Some("run") if args.len() > 1 => Ok(Command::Run(args[1..].to_vec())),
+        Some("run") => Err("run needs at least one experiment name".into()),
+        Some(other) => Err(format!("unknown command {other:?}, expected list or run <name>...").into()),
+    }
+}
+
+///
+/// The registry as a table: name, system, solver, outputs
+///
+pub fn list(experiments: &[Experiment]) {
+    println!("{:<16} {:<38} {:<44} outputs", "name", "system", "solver");
+    for e in experiments {
+        let outputs = if e.outputs.is_empty() { "-".to_string() } else { e.outputs.join(", ") };
+        println!("{:<16} {:<38} {:<44} {outputs}", e.name, e.system, e.solver);
+    }
+}
+
+///
+/// The experiments named, in the order given, or an error naming the
+/// first unknown one and the choices
+///
+pub fn select<'a>(experiments: &'a [Experiment], names: &[String]) -> Result<Vec<&'a Experiment>, Box<dyn std::error::Error>> {
+    if names.is_empty() {
+        return Ok(experiments.iter().collect());
+    }
+    names.iter().map(|name| {
+        experiments.iter().find(|e| e.name == name).ok_or_else(|| {
+            let choices: Vec<&str> = experiments.iter().map(|e| e.name).collect();
+            format!("no experiment {name:?}, choose from {}", choices.join(", ")).into()
+        })
+    }).collect()
+}
+
+///
+/// Shared main of the lab binaries. setup records run-wide configuration
+/// before the experiments; one experiment failing is reported and the
+/// rest still run. Exits with status 2 on a bad command line
+///
+pub fn main(bin: &str, experiments: &[Experiment], setup: fn(&mut RunContext)) {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let chosen = match parse(&args).and_then(|command| match command {
+        Command::List => Ok(None),
+        Command::Run(names) => select(experiments, &names).map(Some),
+    }) {
+        Ok(Some(chosen)) => chosen,
+        Ok(None) => return list(experiments),
+        Err(e) => {
+            eprintln!("{bin}: {e}");
+            std::process::exit(2);
+        }
+    };
+
+    let mut ctx = RunContext::new("output", bin)
+        .expect("failed to create output directory");
+    ctx.config("experiments", chosen.iter().map(|e| e.name).collect::<Vec<_>>().join(", "));
+    setup(&mut ctx);
+    for e in chosen {
+        if let Err(err) = (e.run)(&mut ctx) {
+            eprintln!("{bin}: experiment {} failed: {err}", e.name);
+        }
+    }
+    let _ = ctx.write_report();
+    let _ = ctx.write_manifest();
+}
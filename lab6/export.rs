@@ -0,0 +1,285 @@
+//!
+//! export.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Lossless binary export of trajectories to NumPy (.npy/.npz) and
+//! MATLAB (MAT v5) files so results transfer without CSV truncation, and
+//! reading .npy trajectories back in, e.g. as a stored reference solution
+//!
+
+#![allow(dead_code)]
+
+use crate::trajectory::Trajectory;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+///
+/// Packs the time vector and a solution into a dense row-major (n, N + 1)
+/// matrix with time in the first column
+///
+fn pack<const N: usize>(t: &[f64], y: &[[f64; N]]) -> Vec<f64> {
+    let n = t.len().min(y.len());
+    let mut data = Vec::with_capacity(n * (N + 1));
+    for i in 0..n {
+        data.push(t[i]);
+        data.extend_from_slice(&y[i]);
+    }
+    data
+}
+
+///
+/// Serializes a row-major f64 array into the npy v1.0 byte format
+///
+fn npy_bytes(data: &[f64], shape: &[usize]) -> Vec<u8> {
+    let dims = match shape {
+        [n] => format!("({n},)"),
+        _ => format!("({})", shape.iter().map(usize::to_string).collect::<Vec<_>>().join(", ")),
+    };
+    let mut header = format!(
+        "{{'descr': '<f8', 'fortran_order': False, 'shape': {dims}, }}"
+    );
+
+    // magic (6) + version (2) + header length (2) + header must align to 64
+    let unpadded = 10 + header.len() + 1;
+    header.push_str(&" ".repeat((64 - unpadded % 64) % 64));
+    header.push('\n');
+
+    let mut bytes = Vec::with_capacity(10 + header.len() + 8 * data.len());
+    bytes.extend_from_slice(b"\x93NUMPY\x01\x00");
+    bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    for x in data {
+        bytes.extend_from_slice(&x.to_le_bytes());
+    }
+    bytes
+}
+
+///
+/// Writes a single trajectory as an (n, N + 1) .npy array, column 0 is time
+///
+pub fn write_npy<const N: usize>(path: &str, t: &[f64], y: &[[f64; N]])
+    -> Result<(), Box<dyn std::error::Error>> {
+    let n = t.len().min(y.len());
+    let mut file = BufWriter::new(File::create(path)?);
+    file.write_all(&npy_bytes(&pack(t, y), &[n, N + 1]))?;
+    file.flush()?;
+    Ok(())
+}
+
+///
+/// Reads back an (n, N + 1) .npy trajectory as write_npy lays it out:
+/// little-endian f64, C order, time in column 0. Anything else is an error
+///
+pub fn read_npy<const N: usize>(path: &str) -> Result<Trajectory<N>, Box<dyn std::error::Error>> {
+    let bytes = std::fs::read(path).map_err(|e| format!("{path}: {e}"))?;
+    if bytes.len() < 10 || &bytes[..6] != b"\x93NUMPY" {
+        return Err(format!("{path} is not a .npy file").into());
+    }
+    // version 1 has a 2 byte header length, versions 2 and 3 a 4 byte one
+    let (start, len) = match bytes[6] {
+        1 => (10, u16::from_le_bytes([bytes[8], bytes[9]]) as usize),
+        2 | 3 if bytes.len() >= 12 => (12, u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize),
+        v => return Err(format!("{path}: unsupported .npy version {v}").into()),
+    };
+    let header = bytes.get(start..start + len)
+        .and_then(|h| std::str::from_utf8(h).ok())
+        .ok_or_else(|| format!("{path}: truncated or unreadable .npy header"))?;
+    if !header.contains("'descr': '<f8'") || !header.contains("'fortran_order': False") {
+        return Err(format!("{path}: expected little-endian f64 in C order, header is {}", header.trim()).into());
+    }
+    let shape: Vec<usize> = header.split("'shape': (").nth(1)
+        .and_then(|s| s.split(')').next())
+        .map(|s| s.split(',').map(str::trim).filter(|d| !d.is_empty()).map(str::parse).collect::<Result<_, _>>())
+        .ok_or_else(|| format!("{path}: .npy header has no shape"))??;
+    let n = match shape[..] {
+        [n, cols] if cols == N + 1 => n,
+        _ => return Err(format!("{path}: shape {shape:?} is not (n, {})", N + 1).into()),
+    };
+    let data = &bytes[start + len..];
+    if data.len() != 8 * n * (N + 1) {
+        return Err(format!("{path}: {} data bytes for shape ({n}, {})", data.len(), N + 1).into());
+    }
+    let mut values = data.chunks_exact(8).map(|b| f64::from_le_bytes(b.try_into().unwrap()));
+    let mut traj = Trajectory::with_capacity(n);
+    for _ in 0..n {
+        let t = values.next().unwrap();
+        traj.push(t, &std::array::from_fn(|_| values.next().unwrap()));
+    }
+    Ok(traj)
+}
+
+///
+/// Writes one flat column as a 1-D .npy array
+///
+pub fn write_npy_column(path: &str, data: &[f64]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut file = BufWriter::new(File::create(path)?);
+    file.write_all(&npy_bytes(data, &[data.len()]))?;
+    file.flush()?;
+    Ok(())
+}
+
+///
+/// Table driven CRC-32 (IEEE) required by the zip container
+///
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut table = [0u32; 256];
+    for (i, entry) in table.iter_mut().enumerate() {
+        let mut c = i as u32;
+        for _ in 0..8 {
+            c = if c & 1 == 1 { 0xEDB8_8320 ^ (c >> 1) } else { c >> 1 };
+        }
+        *entry = c;
+    }
+
+    let mut crc = 0xFFFF_FFFF_u32;
+    for &b in bytes {
+        crc = table[((crc ^ u32::from(b)) & 0xFF) as usize] ^ (crc >> 8);
+    }
+    !crc
+}
+
+///
+/// Writes the shared time vector as `t` and each named solution as an
+/// (n, N) array into an uncompressed .npz archive (np.load compatible)
+///
+pub fn write_npz<const N: usize>(path: &str, t: &[f64], solutions: &[(&str, &[[f64; N]])])
+    -> Result<(), Box<dyn std::error::Error>> {
+    let mut entries: Vec<(String, Vec<u8>)> = Vec::with_capacity(solutions.len() + 1);
+    entries.push(("t.npy".to_string(), npy_bytes(t, &[t.len()])));
+    for (name, y) in solutions {
+        let flat: Vec<f64> = y.iter().flat_map(|row| row.iter().copied()).collect();
+        entries.push((format!("{name}.npy"), npy_bytes(&flat, &[y.len(), N])));
+    }
+
+    let mut archive: Vec<u8> = Vec::new();
+    let mut directory: Vec<u8> = Vec::new();
+
+    // dos date for 1980-01-01, time zero
+    let (dos_time, dos_date) = (0u16, 0x0021u16);
+    for (name, bytes) in &entries {
+        let crc  = crc32(bytes);
+        let size = u32::try_from(bytes.len())?;
+        let offset = u32::try_from(archive.len())?;
+
+        // local file header, method 0 (stored)
+        archive.extend_from_slice(&0x0403_4b50_u32.to_le_bytes());
+        archive.extend_from_slice(&20u16.to_le_bytes());
+        archive.extend_from_slice(&0u16.to_le_bytes());
+        archive.extend_from_slice(&0u16.to_le_bytes());
+        archive.extend_from_slice(&dos_time.to_le_bytes());
+        archive.extend_from_slice(&dos_date.to_le_bytes());
+        archive.extend_from_slice(&crc.to_le_bytes());
+        archive.extend_from_slice(&size.to_le_bytes());
+        archive.extend_from_slice(&size.to_le_bytes());
+        archive.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        archive.extend_from_slice(&0u16.to_le_bytes());
+        archive.extend_from_slice(name.as_bytes());
+        archive.extend_from_slice(bytes);
+
+        // matching central directory record
+        directory.extend_from_slice(&0x0201_4b50_u32.to_le_bytes());
+        directory.extend_from_slice(&20u16.to_le_bytes());
+        directory.extend_from_slice(&20u16.to_le_bytes());
+        directory.extend_from_slice(&0u16.to_le_bytes());
+        directory.extend_from_slice(&0u16.to_le_bytes());
+        directory.extend_from_slice(&dos_time.to_le_bytes());
+        directory.extend_from_slice(&dos_date.to_le_bytes());
+        directory.extend_from_slice(&crc.to_le_bytes());
+        directory.extend_from_slice(&size.to_le_bytes());
+        directory.extend_from_slice(&size.to_le_bytes());
+        directory.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        directory.extend_from_slice(&[0u8; 12]);
+        directory.extend_from_slice(&offset.to_le_bytes());
+        directory.extend_from_slice(name.as_bytes());
+    }
+
+    let cd_offset = u32::try_from(archive.len())?;
+    let cd_size   = u32::try_from(directory.len())?;
+    let count     = entries.len() as u16;
+    archive.extend_from_slice(&directory);
+
+    // end of central directory
+    archive.extend_from_slice(&0x0605_4b50_u32.to_le_bytes());
+    archive.extend_from_slice(&[0u8; 4]);
+    archive.extend_from_slice(&count.to_le_bytes());
+    archive.extend_from_slice(&count.to_le_bytes());
+    archive.extend_from_slice(&cd_size.to_le_bytes());
+    archive.extend_from_slice(&cd_offset.to_le_bytes());
+    archive.extend_from_slice(&0u16.to_le_bytes());
+
+    let mut file = BufWriter::new(File::create(path)?);
+    file.write_all(&archive)?;
+    file.flush()?;
+    Ok(())
+}
+
+///
+/// Appends a MAT v5 tagged data element, padding payload to 8 bytes
+///
+fn mat_element(out: &mut Vec<u8>, dtype: u32, payload: &[u8]) {
+    out.extend_from_slice(&dtype.to_le_bytes());
+    out.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    out.extend_from_slice(payload);
+    out.resize(out.len() + (8 - payload.len() % 8) % 8, 0);
+}
+
+///
+/// Builds a real double miMATRIX element from column-major data
+///
+fn mat_matrix(name: &str, data: &[f64], rows: usize, cols: usize) -> Vec<u8> {
+    const MI_INT8: u32   = 1;
+    const MI_INT32: u32  = 5;
+    const MI_UINT32: u32 = 6;
+    const MI_DOUBLE: u32 = 9;
+    const MI_MATRIX: u32 = 14;
+    const MX_DOUBLE_CLASS: u32 = 6;
+
+    let mut body: Vec<u8> = Vec::new();
+    let mut flags = MX_DOUBLE_CLASS.to_le_bytes().to_vec();
+    flags.extend_from_slice(&0u32.to_le_bytes());
+    mat_element(&mut body, MI_UINT32, &flags);
+
+    let mut dims = (rows as i32).to_le_bytes().to_vec();
+    dims.extend_from_slice(&(cols as i32).to_le_bytes());
+    mat_element(&mut body, MI_INT32, &dims);
+    mat_element(&mut body, MI_INT8, name.as_bytes());
+
+    let real: Vec<u8> = data.iter().flat_map(|x| x.to_le_bytes()).collect();
+    mat_element(&mut body, MI_DOUBLE, &real);
+
+    let mut element = Vec::with_capacity(body.len() + 8);
+    mat_element(&mut element, MI_MATRIX, &body);
+    element
+}
+
+///
+/// Writes `t` as an (n, 1) column and each named solution as an (n, N)
+/// double matrix into a MAT v5 file readable by `load` in MATLAB/Octave.
+/// Names must be valid MATLAB identifiers
+///
+pub fn write_mat<const N: usize>(path: &str, t: &[f64], solutions: &[(&str, &[[f64; N]])])
+    -> Result<(), Box<dyn std::error::Error>> {
+    let mut bytes: Vec<u8> = Vec::new();
+
+    // 116 byte descriptive text, 8 byte subsystem offset, version, endian tag
+    let mut text = b"MATLAB 5.0 MAT-file, written by numeric-methods-engs91 export.rs".to_vec();
+    text.resize(116, b' ');
+    bytes.extend_from_slice(&text);
+    bytes.extend_from_slice(&[0u8; 8]);
+    bytes.extend_from_slice(&0x0100u16.to_le_bytes());
+    bytes.extend_from_slice(b"IM");
+
+    bytes.extend_from_slice(&mat_matrix("t", t, t.len(), 1));
+    for (name, y) in solutions {
+        // MATLAB stores column-major
+        let mut data = Vec::with_capacity(y.len() * N);
+        for j in 0..N {
+            data.extend(y.iter().map(|row| row[j]));
+        }
+        bytes.extend_from_slice(&mat_matrix(name, &data, y.len(), N));
+    }
+
+    let mut file = BufWriter::new(File::create(path)?);
+    file.write_all(&bytes)?;
+    file.flush()?;
+    Ok(())
+}
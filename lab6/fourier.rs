@@ -0,0 +1,78 @@
+//!
+//! fourier.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Discrete Fourier transform for the trajectory analyses: an in place
+//! iterative radix-2 FFT on (re, im) pairs, and the padding and the
+//! analytic signal built on it. Lengths that are not a power of two are
+//! zero padded by the callers
+//!
+
+#![allow(dead_code)]
+
+///
+/// In place radix-2 FFT of (re, im) pairs, inverse unscaled. The length
+/// must be a power of two
+///
+pub fn fft(a: &mut [(f64, f64)], inverse: bool) {
+    let n = a.len();
+    // bit reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let mut len = 2;
+    while len <= n {
+        let angle = sign * 2.0 * std::f64::consts::PI / len as f64;
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let (s, c) = (angle * k as f64).sin_cos();
+                let (u, v) = (a[start + k], a[start + k + len / 2]);
+                let w = (v.0 * c - v.1 * s, v.0 * s + v.1 * c);
+                a[start + k] = (u.0 + w.0, u.1 + w.1);
+                a[start + k + len / 2] = (u.0 - w.0, u.1 - w.1);
+            }
+        }
+        len <<= 1;
+    }
+}
+
+///
+/// Analytic signal y + i H[y] of a uniformly sampled series, its mean
+/// removed. The spectrum is zero padded to twice the length so the
+/// Hilbert kernel does not wrap around; the first and last cycle or so
+/// still carry edge error and are best trimmed by the caller
+///
+pub fn analytic(y: &[f64]) -> Vec<(f64, f64)> {
+    let n = y.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let mean = y.iter().sum::<f64>() / n as f64;
+    let m = (2 * n).next_power_of_two();
+    let mut a = vec![(0.0, 0.0); m];
+    for (ai, yi) in a.iter_mut().zip(y) {
+        ai.0 = yi - mean;
+    }
+    fft(&mut a, false);
+    // keep dc and nyquist, double positive frequencies, drop negative ones
+    for (k, ak) in a.iter_mut().enumerate().skip(1) {
+        let gain = match k.cmp(&(m / 2)) {
+            std::cmp::Ordering::Less => 2.0,
+            std::cmp::Ordering::Equal => 1.0,
+            std::cmp::Ordering::Greater => 0.0,
+        };
+        *ak = (ak.0 * gain, ak.1 * gain);
+    }
+    fft(&mut a, true);
+    a.truncate(n);
+    a.iter().map(|p| (p.0 / m as f64, p.1 / m as f64)).collect()
+}
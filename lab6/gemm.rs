@@ -0,0 +1,190 @@
+//!
+//! gemm.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Dense matrix multiply as a performance study: the textbook i-j-k
+//! triple loop against the i-k-j loop linalg uses for small products, the
+//! cache tiled product, the cache oblivious recursive one, and Strassen's
+//! algorithm with its crossover to the tiled product tuned on this machine.
+//! Rates are in GFLOP/s counted as 2 n^3 flops whatever the algorithm does,
+//! best of a few repetitions, and every variant is checked against the
+//! plain loop
+//!
+
+#![allow(clippy::cast_possible_truncation)]
+#![allow(clippy::cast_sign_loss)]
+#![allow(clippy::cast_precision_loss)]
+#![allow(clippy::missing_panics_doc)]
+#![allow(clippy::missing_errors_doc)]
+
+use plotters::prelude::*;
+use plotters_bitmap::BitMapBackend;
+use std::time::Instant;
+
+mod linalg;
+mod report;
+mod run_context;
+mod theme;
+
+use linalg::Matrix;
+use run_context::RunContext;
+
+fn filled(n: usize, seed: f64) -> Matrix {
+    let mut m = Matrix::zeros(n, n);
+    for i in 0..n {
+        for j in 0..n {
+            m[(i, j)] = (seed + 7.0 * i as f64 + 3.0 * j as f64).sin();
+        }
+    }
+    m
+}
+
+///
+/// Inner product form, the inner loop striding down a column of b
+///
+fn mul_naive(a: &Matrix, b: &Matrix) -> Matrix {
+    let mut out = Matrix::zeros(a.rows, b.cols);
+    for i in 0..a.rows {
+        for j in 0..b.cols {
+            let mut s = 0.0;
+            for k in 0..a.cols {
+                s += a[(i, k)] * b[(k, j)];
+            }
+            out[(i, j)] = s;
+        }
+    }
+    out
+}
+
+///
+/// Best wall time of reps runs, at least one, and the last product
+///
+fn best<F: Fn() -> Matrix>(reps: usize, f: F) -> (f64, Matrix) {
+    let mut fastest = f64::MAX;
+    let mut out = Matrix::zeros(0, 0);
+    for _ in 0..reps.max(1) {
+        let start = Instant::now();
+        out = f();
+        fastest = fastest.min(start.elapsed().as_secs_f64());
+    }
+    (fastest, out)
+}
+
+fn max_diff(a: &Matrix, b: &Matrix) -> f64 {
+    (0..a.rows).flat_map(|i| (0..a.cols).map(move |j| (i, j))).fold(0.0_f64, |m, (i, j)| m.max((a[(i, j)] - b[(i, j)]).abs()))
+}
+
+///
+/// GFLOP/s for each variant as n doubles, plotted against log2 n
+///
+fn size_study(ctx: &mut RunContext, sizes: &[usize], crossover: usize, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    ctx.config("gemm_sizes", format!("{sizes:?}, tile {}, strassen crossover {crossover}", linalg::TILE));
+    let labels = ["naive i-j-k", "plain i-k-j", "tiled", "recursive", "strassen"];
+    let mut rates = vec![Vec::new(); labels.len()];
+
+    println!("{:>6} {:>12} {:>12} {:>12} {:>12} {:>12} {:>12} {:>12}", "n", labels[0], labels[1], labels[2], labels[3], labels[4], "blocked diff", "strassen diff");
+    for &n in sizes {
+        let (a, b) = (filled(n, 0.0), filled(n, 1.0));
+        let reps = (1 << 24) / (n * n * n) + 1;
+        let flops = 2.0 * (n as f64).powi(3);
+        let (t_naive, _) = ctx.time("gemm naive", || best(reps, || mul_naive(&a, &b)));
+        let (t_plain, plain) = ctx.time("gemm plain", || best(reps, || a.mul_plain(&b)));
+        let (t_tiled, tiled) = ctx.time("gemm tiled", || best(reps, || a.mul_tiled(&b, linalg::TILE)));
+        let (t_recursive, recursive) = ctx.time("gemm recursive", || best(reps, || a.mul_recursive(&b, linalg::TILE)));
+        let (t_strassen, strassen) = ctx.time("gemm strassen", || best(reps, || a.mul_strassen(&b, crossover)));
+        let times = [t_naive, t_plain, t_tiled, t_recursive, t_strassen];
+        for (r, t) in rates.iter_mut().zip(times) {
+            r.push(flops / t * 1e-9);
+        }
+        let diff = max_diff(&plain, &tiled).max(max_diff(&plain, &recursive));
+        let rate = |k: usize| rates[k][rates[k].len() - 1];
+        println!("{:>6} {:>12.3} {:>12.3} {:>12.3} {:>12.3} {:>12.3} {:>12.1e} {:>12.1e}", n, rate(0), rate(1), rate(2), rate(3), rate(4),
+            diff, max_diff(&plain, &strassen));
+    }
+
+    let path = ctx.path(name);
+    let theme = ctx.style();
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?;
+    let root = theme.stamp(&root)?;
+    let top = rates.iter().flatten().fold(0.0_f64, |m, r| m.max(*r)) * 1.1;
+    let log2 = |n: usize| (n as f64).log2();
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Dense matrix multiply throughput", theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 55)
+        .set_label_area_size(LabelAreaPosition::Bottom, 50)
+        .build_cartesian_2d(log2(sizes[0])..log2(sizes[sizes.len() - 1]), 0.0..top)?;
+    chart.configure_mesh()
+        .label_style(theme.label())
+        .axis_desc_style(theme.label())
+        .x_desc("n")
+        .y_desc("GFLOP/s")
+        .x_label_formatter(&|v| format!("{:.0}", 2.0_f64.powf(*v)))
+        .draw()?;
+
+    for (k, (label, r)) in labels.iter().zip(&rates).enumerate() {
+        let color = theme.color(k);
+        chart.draw_series(LineSeries::new(sizes.iter().map(|n| log2(*n)).zip(r.iter().copied()), color.stroke_width(theme.line)))?
+            .label(*label)
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(theme.line)));
+        chart.draw_series(sizes.iter().map(|n| log2(*n)).zip(r.iter().copied()).map(|p| Circle::new(p, 3, color.filled())))?;
+    }
+
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?;
+
+    root.present()?;
+    Ok(())
+}
+
+///
+/// Tiled rate against the tile size at a fixed n
+///
+fn tile_study(ctx: &mut RunContext, n: usize, tiles: &[usize]) {
+    let (a, b) = (filled(n, 0.0), filled(n, 1.0));
+    let flops = 2.0 * (n as f64).powi(3);
+    println!("tiled, n = {n}");
+    println!("{:>6} {:>12} {:>12}", "tile", "tiled", "recursive");
+    for &tile in tiles {
+        let (t_tiled, _) = ctx.time("gemm tile sweep", || best(3, || a.mul_tiled(&b, tile)));
+        let (t_recursive, _) = ctx.time("gemm tile sweep", || best(3, || a.mul_recursive(&b, tile)));
+        println!("{:>6} {:>12.3} {:>12.3}", tile, flops / t_tiled * 1e-9, flops / t_recursive * 1e-9);
+    }
+}
+
+///
+/// Crossover with the best Strassen rate at size n, each candidate timed
+/// against the tiled product alone
+///
+fn tune_crossover(ctx: &mut RunContext, n: usize, candidates: &[usize]) -> usize {
+    let (a, b) = (filled(n, 0.0), filled(n, 1.0));
+    let flops = 2.0 * (n as f64).powi(3);
+    let (t_tiled, _) = ctx.time("strassen tuning", || best(3, || a.mul_tiled(&b, linalg::TILE)));
+    println!("strassen crossover, n = {n}, tiled {:.3} GFLOP/s", flops / t_tiled * 1e-9);
+    println!("{:>10} {:>8} {:>12} {:>10}", "crossover", "levels", "strassen", "speedup");
+    let mut tuned = (n, t_tiled);
+    for &c in candidates {
+        let (t, _) = ctx.time("strassen tuning", || best(3, || a.mul_strassen(&b, c)));
+        // halvings before a dimension reaches c
+        let levels = (0..).find(|l| n.div_ceil(1 << l) <= c).unwrap_or(0);
+        println!("{:>10} {:>8} {:>12.3} {:>10.3}", c, levels, flops / t * 1e-9, t_tiled / t);
+        if t < tuned.1 {
+            tuned = (c, t);
+        }
+    }
+    ctx.config("strassen_crossover", tuned.0);
+    tuned.0
+}
+
+fn main() {
+    let mut ctx = RunContext::new("output", "gemm")
+        .expect("failed to create output directory");
+    let crossover = tune_crossover(&mut ctx, 1024, &[32, 64, 128, 256, 512]);
+    let _ = size_study(&mut ctx, &[16, 32, 64, 128, 256, 512, 1024], crossover, "gemm.png");
+    tile_study(&mut ctx, 512, &[8, 16, 32, 64, 128, 256]);
+    let _ = ctx.write_report();
+    let _ = ctx.write_manifest();
+}
@@ -0,0 +1,158 @@
+//!
+//! graph.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Weighted graphs for network-structured systems: coupled oscillators,
+//! patches joined by migration. Node i's adjacency list holds (j, w) for
+//! every j that pulls on it with weight w, so a directed edge is one entry
+//! and an undirected edge two. The graph Laplacian L = D - A, D the
+//! diagonal of in-weights, is what diffusive coupling applies to the
+//! state; its spectrum goes to the linalg eigenvalue solver, and the
+//! second smallest eigenvalue (the algebraic connectivity) sets how much
+//! coupling identical units need to synchronize
+//!
+
+#![allow(dead_code)]
+
+use crate::linalg::Matrix;
+use crate::noise::Noise;
+
+#[derive(Clone, Debug, Default)]
+pub struct Graph {
+    adjacency: Vec<Vec<(usize, f64)>>,
+}
+
+impl Graph {
+    ///
+    /// nodes with no edges
+    ///
+    pub fn new(nodes: usize) -> Graph {
+        Graph { adjacency: vec![Vec::new(); nodes] }
+    }
+
+    ///
+    /// j pulls i with weight w
+    ///
+    pub fn directed(mut self, i: usize, j: usize, w: f64) -> Graph {
+        self.adjacency[i].push((j, w));
+        self
+    }
+
+    pub fn edge(self, i: usize, j: usize, w: f64) -> Graph {
+        self.directed(i, j, w).directed(j, i, w)
+    }
+
+    ///
+    /// 0 - 1 - .. - (nodes - 1)
+    ///
+    pub fn path(nodes: usize, w: f64) -> Graph {
+        (1..nodes).fold(Graph::new(nodes), |g, i| g.edge(i - 1, i, w))
+    }
+
+    ///
+    /// Each node to its two neighbors on a ring; two nodes share a single
+    /// edge
+    ///
+    pub fn ring(nodes: usize, w: f64) -> Graph {
+        let g = Graph::path(nodes, w);
+        if nodes > 2 { g.edge(nodes - 1, 0, w) } else { g }
+    }
+
+    pub fn complete(nodes: usize, w: f64) -> Graph {
+        (0..nodes).flat_map(|i| (i + 1..nodes).map(move |j| (i, j))).fold(Graph::new(nodes), |g, (i, j)| g.edge(i, j, w))
+    }
+
+    ///
+    /// Each pair joined with probability p, Erdos-Renyi, reproducible from
+    /// the seed
+    ///
+    pub fn random(nodes: usize, p: f64, w: f64, seed: u64) -> Graph {
+        let mut noise = Noise::new(seed);
+        (0..nodes).flat_map(|i| (i + 1..nodes).map(move |j| (i, j)))
+            .fold(Graph::new(nodes), |g, (i, j)| if noise.uniform() < p { g.edge(i, j, w) } else { g })
+    }
+
+    pub fn nodes(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    pub fn neighbors(&self, i: usize) -> &[(usize, f64)] {
+        &self.adjacency[i]
+    }
+
+    ///
+    /// Every directed entry as (i, j, w): j pulls i
+    ///
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize, f64)> + '_ {
+        self.adjacency.iter().enumerate().flat_map(|(i, list)| list.iter().map(move |(j, w)| (i, *j, *w)))
+    }
+
+    ///
+    /// Sum of the weights pulling each node
+    ///
+    pub fn degrees(&self) -> Vec<f64> {
+        self.adjacency.iter().map(|list| list.iter().map(|(_, w)| w).sum()).collect()
+    }
+
+    pub fn is_symmetric(&self) -> bool {
+        let a = self.adjacency_matrix();
+        (0..a.rows).all(|i| (0..i).all(|j| a[(i, j)] == a[(j, i)]))
+    }
+
+    ///
+    /// Every node and weight in range, no self loops
+    ///
+    pub fn check(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let n = self.nodes();
+        for (i, j, w) in self.edges() {
+            if j >= n || i == j {
+                return Err(format!("graph: edge {i} <- {j} needs two distinct nodes in 0..{n}").into());
+            }
+            if !w.is_finite() {
+                return Err(format!("graph: edge {i} <- {j} has weight {w}").into());
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// A[i][j] the total weight of j pulling i
+    ///
+    pub fn adjacency_matrix(&self) -> Matrix {
+        let mut a = Matrix::zeros(self.nodes(), self.nodes());
+        for (i, j, w) in self.edges() {
+            a[(i, j)] += w;
+        }
+        a
+    }
+
+    ///
+    /// L = D - A, so diffusive coupling sum_j w_ij (x_j - x_i) is -L x
+    ///
+    pub fn laplacian(&self) -> Matrix {
+        let mut l = Matrix::zeros(self.nodes(), self.nodes());
+        for (i, j, w) in self.edges() {
+            l[(i, j)] -= w;
+            l[(i, i)] += w;
+        }
+        l
+    }
+
+    ///
+    /// Real parts of the Laplacian's eigenvalues, ascending. Real already
+    /// for an undirected graph, the first one zero
+    ///
+    pub fn laplacian_spectrum(&self) -> Result<Vec<f64>, Box<dyn std::error::Error>> {
+        let mut re: Vec<f64> = self.laplacian().eigenvalues()?.iter().map(|(re, _)| *re).collect();
+        re.sort_by(f64::total_cmp);
+        Ok(re)
+    }
+
+    ///
+    /// Second smallest Laplacian eigenvalue: zero for a disconnected graph,
+    /// larger the better connected
+    ///
+    pub fn algebraic_connectivity(&self) -> Result<f64, Box<dyn std::error::Error>> {
+        let spectrum = self.laplacian_spectrum()?;
+        spectrum.get(1).copied().ok_or_else(|| "graph: a single node has no algebraic connectivity".into())
+    }
+}
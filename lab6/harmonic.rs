@@ -0,0 +1,228 @@
+//!
+//! harmonic.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Harmonic balance for steady oscillations of autonomous second order
+//! systems x'' = g(x, x'). The orbit is the truncated Fourier series
+//!
+//!   x(tau) = a0 + sum_k a_k cos(k tau) + b_k sin(k tau),  tau = omega t
+//!
+//! and the residual omega^2 x_tau_tau - g(x, omega x_tau) is projected back
+//! onto the same harmonics by sampling it on a uniform grid in tau. The
+//! free phase is removed by pinning b_1 = 0, which leaves omega as the
+//! extra unknown, and the square system is solved by damped Newton with a
+//! finite difference Jacobian
+//!
+
+#![allow(dead_code)]
+
+use std::f64::consts::PI;
+use solvers::Limits;
+
+use crate::linalg::{self, Matrix};
+
+#[derive(Clone, Copy, Debug)]
+pub struct BalanceOptions {
+    pub harmonics: usize,
+    /// collocation points per period, at least 4 * harmonics + 1 so a cubic
+    /// nonlinearity does not alias onto the retained harmonics
+    pub samples: usize,
+    pub tol: f64,
+    pub max_iter: usize,
+    /// every residual evaluation counts against these
+    pub limits: Limits,
+}
+
+impl Default for BalanceOptions {
+    fn default() -> BalanceOptions {
+        BalanceOptions { harmonics: 15, samples: 128, tol: 1e-10, max_iter: 50, limits: Limits::default() }
+    }
+}
+
+///
+/// Balanced solution: the frequency, the mean a0, and (a_k, b_k) for
+/// k = 1..harmonics, along with the Newton iterations and final residual
+///
+#[derive(Clone, Debug)]
+pub struct Harmonic {
+    pub omega: f64,
+    pub mean: f64,
+    pub coefficients: Vec<(f64, f64)>,
+    pub iterations: usize,
+    pub residual: f64,
+}
+
+impl Harmonic {
+    ///
+    /// Single harmonic start, for instance the averaged amplitude at unit
+    /// frequency
+    ///
+    pub fn guess(amplitude: f64, omega: f64, harmonics: usize) -> Harmonic {
+        let mut coefficients = vec![(0.0, 0.0); harmonics];
+        coefficients[0].0 = amplitude;
+        Harmonic { omega, mean: 0.0, coefficients, iterations: 0, residual: f64::NAN }
+    }
+
+    pub fn period(&self) -> f64 {
+        2.0 * PI / self.omega
+    }
+
+    ///
+    /// (x, x_tau, x_tau_tau) at phase tau
+    ///
+    fn series(&self, tau: f64) -> (f64, f64, f64) {
+        let (mut x, mut dx, mut ddx) = (self.mean, 0.0, 0.0);
+        for (k, (a, b)) in self.coefficients.iter().enumerate() {
+            let k = (k + 1) as f64;
+            let (s, c) = (k * tau).sin_cos();
+            x += a * c + b * s;
+            dx += k * (b * c - a * s);
+            ddx -= k * k * (a * c + b * s);
+        }
+        (x, dx, ddx)
+    }
+
+    ///
+    /// x(t) and x'(t) in physical time
+    ///
+    pub fn eval(&self, t: f64) -> [f64; 2] {
+        let (x, dx, _) = self.series(self.omega * t);
+        [x, self.omega * dx]
+    }
+
+    ///
+    /// Half the peak to peak swing of x over a fine sampling of one period
+    ///
+    pub fn amplitude(&self) -> f64 {
+        let (lo, hi) = (0..1024)
+            .map(|i| self.series(2.0 * PI * i as f64 / 1024.0).0)
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), x| (lo.min(x), hi.max(x)));
+        0.5 * (hi - lo)
+    }
+
+    ///
+    /// Fraction of the signal energy in the harmonics above the first, a
+    /// measure of how far the orbit is from sinusoidal
+    ///
+    pub fn distortion(&self) -> f64 {
+        let energy: Vec<f64> = self.coefficients.iter().map(|(a, b)| a * a + b * b).collect();
+        let total: f64 = energy.iter().sum();
+        if total > 0.0 { energy[1..].iter().sum::<f64>() / total } else { 0.0 }
+    }
+
+    ///
+    /// Magnitude of the highest retained harmonic, which should be small
+    /// against the amplitude for the truncation to be trusted
+    ///
+    pub fn tail(&self) -> f64 {
+        self.coefficients.last().map_or(0.0, |(a, b)| a.hypot(*b))
+    }
+
+    fn unknowns(&self) -> Vec<f64> {
+        let mut u = vec![self.mean];
+        u.extend(self.coefficients.iter().map(|c| c.0));
+        u.extend(self.coefficients.iter().skip(1).map(|c| c.1));
+        u.push(self.omega);
+        u
+    }
+
+    fn from_unknowns(u: &[f64], harmonics: usize) -> Harmonic {
+        let mut coefficients = vec![(0.0, 0.0); harmonics];
+        for (k, c) in coefficients.iter_mut().enumerate() {
+            c.0 = u[1 + k];
+            if k > 0 {
+                c.1 = u[harmonics + k];
+            }
+        }
+        Harmonic { omega: u[2 * harmonics], mean: u[0], coefficients, iterations: 0, residual: f64::NAN }
+    }
+}
+
+///
+/// Fourier projection of the residual: the mean, then cos k for k = 1..K,
+/// then sin k for k = 1..K. The sin 1 equation stays in and the b_1 unknown
+/// is pinned, so the system is square with omega in its place
+///
+fn residual<G>(g: &G, h: &Harmonic, samples: usize) -> Vec<f64>
+where G: Fn(f64, f64) -> f64 {
+    let k_max = h.coefficients.len();
+    let mut out = vec![0.0; 2 * k_max + 1];
+    for i in 0..samples {
+        let tau = 2.0 * PI * i as f64 / samples as f64;
+        let (x, dx, ddx) = h.series(tau);
+        let r = h.omega * h.omega * ddx - g(x, h.omega * dx);
+        out[0] += r;
+        for k in 1..=k_max {
+            let (s, c) = (k as f64 * tau).sin_cos();
+            out[k] += 2.0 * r * c;
+            out[k_max + k] += 2.0 * r * s;
+        }
+    }
+    out.iter().map(|r| r / samples as f64).collect()
+}
+
+fn max_norm(v: &[f64]) -> f64 {
+    v.iter().fold(0.0_f64, |m, x| m.max(x.abs()))
+}
+
+///
+/// Damped Newton from `guess`, halving the step until the residual drops.
+/// Each residual evaluation, Jacobian columns and line search included,
+/// is one iteration of a guard over opts.limits
+///
+pub fn balance<G>(g: &G, guess: &Harmonic, opts: BalanceOptions) -> Result<Harmonic, Box<dyn std::error::Error>>
+where G: Fn(f64, f64) -> f64 {
+    let k_max = opts.harmonics;
+    if opts.samples < 4 * k_max + 1 {
+        return Err(format!("{} samples alias {} harmonics of a cubic", opts.samples, k_max).into());
+    }
+
+    let mut start = guess.clone();
+    start.coefficients.resize(k_max, (0.0, 0.0));
+    start.coefficients[0].1 = 0.0;
+    let mut guard = opts.limits.start::<0>();
+    let mut u = start.unknowns();
+    guard.iteration("harmonic balance")?;
+    let mut r = residual(g, &start, opts.samples);
+    let n = u.len();
+
+    for iter in 0..=opts.max_iter {
+        let size = max_norm(&r);
+        if size < opts.tol {
+            let mut h = Harmonic::from_unknowns(&u, k_max);
+            h.iterations = iter;
+            h.residual = size;
+            return Ok(h);
+        }
+
+        let mut jac = Matrix::zeros(n, n);
+        for j in 0..n {
+            let du = 1e-7 * (1.0 + u[j].abs());
+            let mut up = u.clone();
+            up[j] += du;
+            guard.iteration("harmonic balance")?;
+            let rp = residual(g, &Harmonic::from_unknowns(&up, k_max), opts.samples);
+            let col: Vec<f64> = rp.iter().zip(&r).map(|(a, b)| (a - b) / du).collect();
+            jac.set_column(j, &col);
+        }
+        let (delta, report) = jac.solve_report(&r.iter().map(|x| -x).collect::<Vec<f64>>())?;
+        linalg::check_condition("harmonic balance newton", report.condition);
+
+        let mut lambda = 1.0;
+        loop {
+            let trial: Vec<f64> = u.iter().zip(&delta).map(|(a, d)| a + lambda * d).collect();
+            guard.iteration("harmonic balance")?;
+            let rt = residual(g, &Harmonic::from_unknowns(&trial, k_max), opts.samples);
+            if max_norm(&rt) < size || lambda < 1e-4 {
+                u = trial;
+                r = rt;
+                break;
+            }
+            lambda *= 0.5;
+        }
+        if u[n - 1] <= 0.0 || u.iter().any(|x| !x.is_finite()) {
+            return Err(format!("harmonic balance diverged at iteration {}", iter + 1).into());
+        }
+    }
+
+    Err(format!("harmonic balance did not converge in {} iterations", opts.max_iter).into())
+}
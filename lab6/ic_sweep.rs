@@ -0,0 +1,97 @@
+//!
+//! ic_sweep.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Grid-of-initial-conditions sweeps. Every starting state is integrated
+//! with the same fixed step method and reduced to an outcome: the final
+//! state, the class it settled in (which equilibrium or cycle, as decided
+//! by the caller's classifier) and when it settled there. Starting states
+//! are split evenly across scoped threads, one per available core
+//!
+
+#![allow(dead_code)]
+
+use solvers::Method;
+
+///
+/// Fixed step integration shared by every starting state
+///
+#[derive(Clone, Copy, Debug)]
+pub struct SweepSolver {
+    pub method: Method,
+    pub ts: [f64; 2],
+    pub dt: f64,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Outcome<const N: usize> {
+    pub ic: [f64; N],
+    pub end: [f64; N],
+    /// class of the final state, None if the classifier recognized nothing
+    pub class: Option<usize>,
+    /// first time from which every later state had the final class
+    pub settle: Option<f64>,
+}
+
+///
+/// Cell centered points of a rectangle, the first coordinate varying
+/// fastest
+///
+pub fn grid_2d(lo: [f64; 2], hi: [f64; 2], n: [usize; 2]) -> Vec<[f64; 2]> {
+    let cell = [(hi[0] - lo[0]) / n[0] as f64, (hi[1] - lo[1]) / n[1] as f64];
+    (0..n[0] * n[1])
+        .map(|k| [lo[0] + ((k % n[0]) as f64 + 0.5) * cell[0], lo[1] + ((k / n[0]) as f64 + 0.5) * cell[1]])
+        .collect()
+}
+
+fn outcome<F, C, const N: usize>(rate: &F, classify: &C, ic: [f64; N], solver: &SweepSolver) -> Result<Outcome<N>, String>
+where
+    F: Fn(f64, &[f64; N], &mut [f64; N]),
+    C: Fn(&[f64; N]) -> Option<usize>
+{
+    // last time the class changed, and the class it changed to
+    let mut current = (solver.ts[0], classify(&ic));
+    let end = solver.method.solve_with(rate, ic, solver.ts, solver.dt, |t, y| {
+        let class = classify(y);
+        if class != current.1 {
+            current = (t, class);
+        }
+    }).map_err(|e| e.to_string())?;
+    let class = current.1;
+    Ok(Outcome { ic, end, class, settle: class.map(|_| current.0) })
+}
+
+///
+/// Outcome of every starting state in grid, in grid order
+///
+pub fn sweep_initial_conditions<F, C, const N: usize>(rate: &F, classify: &C, grid: &[[f64; N]], solver: SweepSolver)
+    -> Result<Vec<Outcome<N>>, Box<dyn std::error::Error>>
+where
+    F: Fn(f64, &[f64; N], &mut [f64; N]) + Sync,
+    C: Fn(&[f64; N]) -> Option<usize> + Sync
+{
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk = grid.len().div_ceil(threads).max(1);
+    std::thread::scope(|scope| {
+        let workers: Vec<_> = grid
+            .chunks(chunk)
+            .map(|ics| scope.spawn(move || ics.iter().map(|ic| outcome(rate, classify, *ic, &solver)).collect::<Result<Vec<_>, _>>()))
+            .collect();
+        let mut outcomes = Vec::with_capacity(grid.len());
+        for w in workers {
+            outcomes.extend(w.join().expect("sweep worker panicked")?);
+        }
+        Ok(outcomes)
+    })
+}
+
+///
+/// Count of outcomes per class for a classifier returning indices below
+/// classes, unclassified ones in the last slot
+///
+pub fn tally<const N: usize>(outcomes: &[Outcome<N>], classes: usize) -> Vec<usize> {
+    let mut counts = vec![0; classes + 1];
+    for o in outcomes {
+        counts[o.class.unwrap_or(classes)] += 1;
+    }
+    counts
+}
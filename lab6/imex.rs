@@ -0,0 +1,448 @@
+//!
+//! imex.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Implicit-explicit Runge-Kutta methods of Ascher, Ruuth and Spiteri for
+//! y' = f_E(t, y) + f_I(t, y), with the nonstiff part f_E (reaction,
+//! advection) taken explicitly and the stiff part f_I (diffusion) taken by
+//! a diagonally implicit tableau. The ARS methods put a zero first row on
+//! the implicit side, so each step costs one explicit stage plus one
+//! implicit solve per remaining stage, and are stiffly accurate: the last
+//! stage is the new solution
+//!
+//! Every implicit stage is solved by simplified Newton on the Jacobian of
+//! f_I formed once per step, by finite differences or supplied in closed
+//! form, so a linear stiff part (method of lines diffusion) converges in a
+//! single iteration. A stage where that stalls, as in fast nonlinear
+//! kinetics, falls back to full Newton with the Jacobian refreshed at
+//! every iterate. If that fails too, NewtonOptions::on_failure decides:
+//! halve the step and retry, accept the last iterate, or abort
+//!
+//! The work is counted in a SolveStats as it is done. A stage's f_E and
+//! f_I together count as one rate evaluation and each Newton residual as
+//! another; a finite difference Jacobian adds its N + 1 evaluations of
+//! f_I on top of the Jacobian evaluation itself
+//!
+
+#![allow(dead_code)]
+
+use solvers::{self, CorrectorFailure, Limits, Solution, SolveStats, MAX_HALVINGS};
+
+use crate::linalg::{self, Lu, Matrix};
+use crate::system::OdeSystem;
+
+///
+/// Paired tableaus sharing the abscissae c. Row 0 of both is zero
+///
+#[derive(Clone, Debug)]
+pub struct Tableau {
+    pub name: &'static str,
+    pub order: u32,
+    pub c: Vec<f64>,
+    pub explicit: Vec<Vec<f64>>,
+    pub implicit: Vec<Vec<f64>>,
+    pub b_explicit: Vec<f64>,
+    pub b_implicit: Vec<f64>,
+}
+
+impl Tableau {
+    ///
+    /// ARS(1,1,1), forward Euler on f_E with backward Euler on f_I
+    ///
+    pub fn ars111() -> Tableau {
+        Tableau {
+            name: "ars111",
+            order: 1,
+            c: vec![0.0, 1.0],
+            explicit: vec![vec![0.0, 0.0], vec![1.0, 0.0]],
+            implicit: vec![vec![0.0, 0.0], vec![0.0, 1.0]],
+            b_explicit: vec![1.0, 0.0],
+            b_implicit: vec![0.0, 1.0],
+        }
+    }
+
+    ///
+    /// ARS(2,2,2), L-stable second order
+    ///
+    pub fn ars222() -> Tableau {
+        let g = 1.0 - 1.0 / 2.0_f64.sqrt();
+        let d = 1.0 - 1.0 / (2.0 * g);
+        Tableau {
+            name: "ars222",
+            order: 2,
+            c: vec![0.0, g, 1.0],
+            explicit: vec![vec![0.0, 0.0, 0.0], vec![g, 0.0, 0.0], vec![d, 1.0 - d, 0.0]],
+            implicit: vec![vec![0.0, 0.0, 0.0], vec![0.0, g, 0.0], vec![0.0, 1.0 - g, g]],
+            b_explicit: vec![d, 1.0 - d, 0.0],
+            b_implicit: vec![0.0, 1.0 - g, g],
+        }
+    }
+
+    ///
+    /// ARS(4,4,3), L-stable third order
+    ///
+    pub fn ars443() -> Tableau {
+        Tableau {
+            name: "ars443",
+            order: 3,
+            c: vec![0.0, 0.5, 2.0 / 3.0, 0.5, 1.0],
+            explicit: vec![
+                vec![0.0, 0.0, 0.0, 0.0, 0.0],
+                vec![0.5, 0.0, 0.0, 0.0, 0.0],
+                vec![11.0 / 18.0, 1.0 / 18.0, 0.0, 0.0, 0.0],
+                vec![5.0 / 6.0, -5.0 / 6.0, 0.5, 0.0, 0.0],
+                vec![0.25, 1.75, 0.75, -1.75, 0.0],
+            ],
+            implicit: vec![
+                vec![0.0, 0.0, 0.0, 0.0, 0.0],
+                vec![0.0, 0.5, 0.0, 0.0, 0.0],
+                vec![0.0, 1.0 / 6.0, 0.5, 0.0, 0.0],
+                vec![0.0, -0.5, 0.5, 0.5, 0.0],
+                vec![0.0, 1.5, -1.5, 0.5, 0.5],
+            ],
+            b_explicit: vec![0.25, 1.75, 0.75, -1.75, 0.0],
+            b_implicit: vec![0.0, 1.5, -1.5, 0.5, 0.5],
+        }
+    }
+
+    pub fn all() -> [Tableau; 3] {
+        [Tableau::ars111(), Tableau::ars222(), Tableau::ars443()]
+    }
+
+    pub fn stages(&self) -> usize {
+        self.c.len()
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct NewtonOptions {
+    pub tol: f64,
+    pub max_iter: usize,
+    pub on_failure: CorrectorFailure,
+    /// checked before the run and once per step
+    pub limits: Limits,
+}
+
+impl Default for NewtonOptions {
+    fn default() -> NewtonOptions {
+        NewtonOptions { tol: 1e-10, max_iter: 20, on_failure: CorrectorFailure::Abort, limits: Limits::default() }
+    }
+}
+
+///
+/// Finite difference Jacobian of f_I at (t, y)
+///
+fn jacobian<F, const N: usize>(implicit: &F, t: f64, y: &[f64; N]) -> Matrix
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    let mut f0 = [0.0; N];
+    implicit(t, y, &mut f0);
+    let mut jac = Matrix::zeros(N, N);
+    for j in 0..N {
+        let dy = 1e-7 * (1.0 + y[j].abs());
+        let mut yp = *y;
+        yp[j] += dy;
+        let mut fp = [0.0; N];
+        implicit(t, &yp, &mut fp);
+        let col: Vec<f64> = (0..N).map(|i| (fp[i] - f0[i]) / dy).collect();
+        jac.set_column(j, &col);
+    }
+    jac
+}
+
+///
+/// I - h a_ii J, factored
+///
+fn newton_matrix(jac: &Matrix, ha: f64, stats: &mut SolveStats) -> Result<Lu, Box<dyn std::error::Error>> {
+    stats.factorizations += 1;
+    let n = jac.rows;
+    let mut m = Matrix::identity(n);
+    for r in 0..n {
+        for c in 0..n {
+            m[(r, c)] -= ha * jac[(r, c)];
+        }
+    }
+    let lu = m.lu()?;
+    linalg::check_condition("imex newton matrix", lu.condition());
+    Ok(lu)
+}
+
+///
+/// Newton on Y - ha f_I(Y) = known from Y = known. update gives a new
+/// factorization at each iterate, or None to keep lu. Returns the last
+/// iterate, whether it converged, and whether it is close enough to use:
+/// its last update under sqrt(tol), so that at Newton's quadratic rate
+/// the next would have converged. One that leaves the finite numbers
+/// stops the iteration
+///
+#[allow(clippy::too_many_arguments)]
+fn newton<FI, U, const N: usize>(
+    implicit: &FI, update: &mut U, lu: &Lu, t: f64, known: &[f64; N], ha: f64, opts: NewtonOptions, stats: &mut SolveStats)
+    -> Result<([f64; N], bool, bool), Box<dyn std::error::Error>>
+where
+    FI: Fn(f64, &[f64; N], &mut [f64; N]),
+    U: FnMut(&[f64; N], &mut SolveStats) -> Result<Option<Lu>, Box<dyn std::error::Error>>
+{
+    let mut yi = *known;
+    let mut last = f64::INFINITY;
+    for _ in 0..opts.max_iter {
+        let mut fi = [0.0; N];
+        implicit(t, &yi, &mut fi);
+        stats.rate_evals += 1;
+        stats.newton_iters += 1;
+        stats.linear_solves += 1;
+        let g: Vec<f64> = (0..N).map(|n| known[n] + ha * fi[n] - yi[n]).collect();
+        let delta = match update(&yi, stats)? {
+            Some(fresh) => fresh.solve(&g),
+            None => lu.solve(&g),
+        };
+        let mut size = 0.0_f64;
+        for n in 0..N {
+            yi[n] += delta[n];
+            size = size.max(delta[n].abs() / (1.0 + yi[n].abs()));
+        }
+        if !yi.iter().all(|v| v.is_finite()) {
+            return Ok((yi, false, false));
+        }
+        if size < opts.tol {
+            return Ok((yi, true, true));
+        }
+        last = size;
+    }
+    Ok((yi, false, last < opts.tol.sqrt()))
+}
+
+///
+/// One IMEX step from (t, y) over h, its work added to stats
+///
+#[allow(clippy::too_many_arguments)]
+pub fn step<FE, FI, const N: usize>(
+    tab: &Tableau, explicit: &FE, implicit: &FI, t: f64, y: &[f64; N], h: f64, opts: NewtonOptions, stats: &mut SolveStats)
+    -> Result<[f64; N], Box<dyn std::error::Error>>
+where
+    FE: Fn(f64, &[f64; N], &mut [f64; N]),
+    FI: Fn(f64, &[f64; N], &mut [f64; N])
+{
+    let differenced = std::cell::Cell::new(0);
+    let jac = |t: f64, y: &[f64; N]| {
+        differenced.set(differenced.get() + N + 1);
+        jacobian(implicit, t, y)
+    };
+    let out = step_with_jacobian(tab, explicit, implicit, &jac, t, y, h, opts, stats);
+    stats.rate_evals += differenced.get();
+    out
+}
+
+///
+/// One IMEX step with the Jacobian of f_I supplied, e.g. in closed form.
+/// Under the Halve policy a step whose corrector fails is retried as two
+/// halves, each of which may split again, down to h / 2^MAX_HALVINGS;
+/// every split counts one rejected and one further accepted step. Accept
+/// falls back to the same halving when its last iterate is not finite or
+/// not nearly converged, or the Newton matrix is singular
+///
+#[allow(clippy::too_many_arguments)]
+pub fn step_with_jacobian<FE, FI, J, const N: usize>(
+    tab: &Tableau, explicit: &FE, implicit: &FI, jacobian: &J, t: f64, y: &[f64; N], h: f64, opts: NewtonOptions,
+    stats: &mut SolveStats)
+    -> Result<[f64; N], Box<dyn std::error::Error>>
+where
+    FE: Fn(f64, &[f64; N], &mut [f64; N]),
+    FI: Fn(f64, &[f64; N], &mut [f64; N]),
+    J: Fn(f64, &[f64; N]) -> Matrix
+{
+    let mut pending = vec![(t, h, 0)];
+    let mut out = *y;
+    // depth first, so the halves are taken in time order
+    while let Some((ti, hi, depth)) = pending.pop() {
+        match attempt(tab, explicit, implicit, jacobian, ti, &out, hi, opts, stats)? {
+            Some(next) => out = next,
+            None if depth < MAX_HALVINGS => {
+                stats.rejected += 1;
+                stats.accepted += 1;
+                pending.push((ti + 0.5 * hi, 0.5 * hi, depth + 1));
+                pending.push((ti, 0.5 * hi, depth + 1));
+            }
+            None => return Err(format!("{} newton did not converge at t = {ti} after {MAX_HALVINGS} halvings", tab.name).into()),
+        }
+    }
+    Ok(out)
+}
+
+///
+/// The stages of one step from (t, y) over h. None when a stage's
+/// corrector fails under the Halve policy, or under Accept with no usable
+/// iterate or a singular Newton matrix to show for it
+///
+#[allow(clippy::too_many_arguments)]
+fn attempt<FE, FI, J, const N: usize>(
+    tab: &Tableau, explicit: &FE, implicit: &FI, jacobian: &J, t: f64, y: &[f64; N], h: f64, opts: NewtonOptions,
+    stats: &mut SolveStats)
+    -> Result<Option<[f64; N]>, Box<dyn std::error::Error>>
+where
+    FE: Fn(f64, &[f64; N], &mut [f64; N]),
+    FI: Fn(f64, &[f64; N], &mut [f64; N]),
+    J: Fn(f64, &[f64; N]) -> Matrix
+{
+    // a Newton matrix singular at a bad iterate fails the corrector too;
+    // only Abort stops there, the others halve as there is nothing to keep
+    let singular = |e: Box<dyn std::error::Error>, stats: &mut SolveStats| -> Result<Option<[f64; N]>, Box<dyn std::error::Error>> {
+        stats.corrector_failures += 1;
+        match opts.on_failure {
+            CorrectorFailure::Abort => Err(e),
+            _ => Ok(None),
+        }
+    };
+    let s = tab.stages();
+    let mut ke: Vec<[f64; N]> = Vec::with_capacity(s);
+    let mut ki: Vec<[f64; N]> = Vec::with_capacity(s);
+    let jac = jacobian(t, y);
+    stats.jacobian_evals += 1;
+    // (I - h a_ii J) factored once per distinct diagonal entry
+    let mut factored: Vec<(f64, Lu)> = Vec::new();
+
+    for i in 0..s {
+        let ti = t + tab.c[i] * h;
+        let mut known: [f64; N] = *y;
+        for j in 0..i {
+            for n in 0..N {
+                known[n] += h * (tab.explicit[i][j] * ke[j][n] + tab.implicit[i][j] * ki[j][n]);
+            }
+        }
+
+        let aii = tab.implicit[i][i];
+        let stage = if aii == 0.0 {
+            known
+        } else {
+            if !factored.iter().any(|(a, _)| *a == aii) {
+                match newton_matrix(&jac, h * aii, stats) {
+                    Ok(lu) => factored.push((aii, lu)),
+                    Err(e) => return singular(e, stats),
+                }
+            }
+            let lu = &factored.iter().find(|(a, _)| *a == aii).unwrap().1;
+
+            // solve Y - h a_ii f_I(Y) = known, on the step's Jacobian first,
+            // then refreshing it every iteration if that stalls
+            let mut keep = |_: &[f64; N], _: &mut SolveStats| Ok(None);
+            let (mut yi, mut converged, mut usable) = newton(implicit, &mut keep, lu, ti, &known, h * aii, opts, stats)?;
+            if !converged {
+                let mut refresh = |yi: &[f64; N], stats: &mut SolveStats| {
+                    stats.jacobian_evals += 1;
+                    newton_matrix(&jacobian(ti, yi), h * aii, stats).map(Some)
+                };
+                match newton(implicit, &mut refresh, lu, ti, &known, h * aii, opts, stats) {
+                    Ok(out) => (yi, converged, usable) = out,
+                    Err(e) => return singular(e, stats),
+                }
+            }
+            if !converged {
+                stats.corrector_failures += 1;
+                match opts.on_failure {
+                    CorrectorFailure::Halve => return Ok(None),
+                    CorrectorFailure::Accept if !usable => return Ok(None),
+                    CorrectorFailure::Accept => {
+                        if stats.corrector_failures == 1 {
+                            eprintln!("warning: {} stage {i} newton did not converge at t = {ti}, accepting the last iterate \
+                                (later failures are only counted)", tab.name);
+                        }
+                    }
+                    _ => return Err(format!("{} stage {i} newton did not converge at t = {ti}", tab.name).into()),
+                }
+            }
+            yi
+        };
+
+        let (mut fe, mut fi) = ([0.0; N], [0.0; N]);
+        explicit(ti, &stage, &mut fe);
+        implicit(ti, &stage, &mut fi);
+        stats.rate_evals += 1;
+        ke.push(fe);
+        ki.push(fi);
+    }
+
+    let mut out = *y;
+    for i in 0..s {
+        for n in 0..N {
+            out[n] += h * (tab.b_explicit[i] * ke[i][n] + tab.b_implicit[i] * ki[i][n]);
+        }
+    }
+    Ok(Some(out))
+}
+
+///
+/// Fixed dt IMEX integration over ts, and the work it took
+///
+pub fn solve<FE, FI, const N: usize>(
+    tab: &Tableau, explicit: &FE, implicit: &FI, ic: [f64; N], ts: [f64; 2], dt: f64, opts: NewtonOptions)
+    -> Result<(Solution<N>, SolveStats), Box<dyn std::error::Error>>
+where
+    FE: Fn(f64, &[f64; N], &mut [f64; N]),
+    FI: Fn(f64, &[f64; N], &mut [f64; N])
+{
+    let n = solvers::planned_steps(tab.name, ts, dt, opts.limits)?;
+    opts.limits.check::<N>(tab.name, n)?;
+    let mut guard = opts.limits.start::<N>();
+    let mut t = Vec::with_capacity(n + 1);
+    let mut y = Vec::with_capacity(n + 1);
+    t.push(ts[0]);
+    y.push(ic);
+    let mut stats = SolveStats { on_failure: opts.on_failure, ..SolveStats::default() };
+    for (k, (tk, h, next)) in solvers::grid(ts, dt).enumerate() {
+        guard.step(tab.name, tk)?;
+        let z = step(tab, explicit, implicit, tk, &y[k], h, opts, &mut stats)?;
+        t.push(next);
+        y.push(z);
+        stats.accepted += 1;
+    }
+    Ok(((t, y), stats))
+}
+
+///
+/// Fixed dt IMEX integration with the Jacobian of f_I supplied, and the
+/// work it took
+///
+#[allow(clippy::too_many_arguments)]
+pub fn solve_with_jacobian<FE, FI, J, const N: usize>(
+    tab: &Tableau, explicit: &FE, implicit: &FI, jacobian: &J, ic: [f64; N], ts: [f64; 2], dt: f64, opts: NewtonOptions)
+    -> Result<(Solution<N>, SolveStats), Box<dyn std::error::Error>>
+where
+    FE: Fn(f64, &[f64; N], &mut [f64; N]),
+    FI: Fn(f64, &[f64; N], &mut [f64; N]),
+    J: Fn(f64, &[f64; N]) -> Matrix
+{
+    let n = solvers::planned_steps(tab.name, ts, dt, opts.limits)?;
+    opts.limits.check::<N>(tab.name, n)?;
+    let mut guard = opts.limits.start::<N>();
+    let mut t = Vec::with_capacity(n + 1);
+    let mut y = Vec::with_capacity(n + 1);
+    t.push(ts[0]);
+    y.push(ic);
+    let mut stats = SolveStats { on_failure: opts.on_failure, ..SolveStats::default() };
+    for (k, (tk, h, next)) in solvers::grid(ts, dt).enumerate() {
+        guard.step(tab.name, tk)?;
+        let z = step_with_jacobian(tab, explicit, implicit, jacobian, tk, &y[k], h, opts, &mut stats)?;
+        t.push(next);
+        y.push(z);
+        stats.accepted += 1;
+    }
+    Ok(((t, y), stats))
+}
+
+///
+/// solve with f_E and f_I as systems, both sizes checked against the state
+///
+pub fn solve_system<const N: usize>(
+    tab: &Tableau, explicit: &impl OdeSystem, implicit: &impl OdeSystem, ic: [f64; N], ts: [f64; 2], dt: f64, opts: NewtonOptions)
+    -> Result<(Solution<N>, SolveStats), Box<dyn std::error::Error>> {
+    solve(tab, &explicit.rhs::<N>()?, &implicit.rhs::<N>()?, ic, ts, dt, opts)
+}
+
+///
+/// solve_with_jacobian with f_E and f_I as systems
+///
+#[allow(clippy::too_many_arguments)]
+pub fn solve_system_with_jacobian<J, const N: usize>(
+    tab: &Tableau, explicit: &impl OdeSystem, implicit: &impl OdeSystem, jacobian: &J, ic: [f64; N], ts: [f64; 2], dt: f64,
+    opts: NewtonOptions) -> Result<(Solution<N>, SolveStats), Box<dyn std::error::Error>>
+where J: Fn(f64, &[f64; N]) -> Matrix {
+    solve_with_jacobian(tab, &explicit.rhs::<N>()?, &implicit.rhs::<N>()?, jacobian, ic, ts, dt, opts)
+}
@@ -0,0 +1,232 @@
+//!
+//! impulse.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Scheduled impulsive state changes, such as harvesting a fraction of a
+//! population at fixed times. Integration runs segment by segment between
+//! impulses: the step before an impulse is shortened to land on it, the
+//! state is scaled, and both the pre and post impulse states are kept at
+//! that time, so the solution carries the jump explicitly
+//!
+//! A multistep method has to discard its history at every jump, since the
+//! stored rates belong to the trajectory before it. The AB/AM4 here
+//! restarts with rk4 after each impulse; keeping the stale history is
+//! offered only to show what that costs
+//!
+
+#![allow(dead_code)]
+
+use solvers::nordsieck::{adams_l, Nordsieck};
+use solvers::{self, rk4_step, CorrectorFailure, Limits, Solution, SolveStats};
+
+///
+/// y[component] *= factor at time t
+///
+#[derive(Clone, Copy, Debug)]
+pub struct Impulse {
+    pub t: f64,
+    pub component: usize,
+    pub factor: f64,
+}
+
+impl Impulse {
+    ///
+    /// Removes a fraction of one component
+    ///
+    pub fn harvest(t: f64, component: usize, fraction: f64) -> Impulse {
+        Impulse { t, component, factor: 1.0 - fraction }
+    }
+
+    pub fn apply<const N: usize>(&self, y: &mut [f64; N]) {
+        y[self.component] *= self.factor;
+    }
+}
+
+///
+/// count harvests of the same fraction every period, the first at first
+///
+pub fn periodic_harvest(first: f64, period: f64, count: usize, component: usize, fraction: f64) -> Vec<Impulse> {
+    (0..count).map(|k| Impulse::harvest(first + k as f64 * period, component, fraction)).collect()
+}
+
+///
+/// Impulses inside ts in time order, after checking each one
+///
+fn schedule<const N: usize>(events: &[Impulse], ts: [f64; 2]) -> Result<Vec<Impulse>, Box<dyn std::error::Error>> {
+    for e in events {
+        if e.component >= N {
+            return Err(format!("impulse at t = {} targets component {} of a {N} component state", e.t, e.component).into());
+        }
+        if !e.factor.is_finite() || e.factor < 0.0 {
+            return Err(format!("impulse at t = {} has factor {}, it must be finite and non-negative", e.t, e.factor).into());
+        }
+    }
+    let mut inside: Vec<Impulse> = events.iter().copied().filter(|e| e.t > ts[0] && e.t <= ts[1]).collect();
+    inside.sort_by(|a, b| a.t.total_cmp(&b.t));
+    Ok(inside)
+}
+
+///
+/// Segment ends: every distinct impulse time, then ts[1]
+///
+fn segments(events: &[Impulse], ts: [f64; 2]) -> Vec<f64> {
+    let mut ends: Vec<f64> = events.iter().map(|e| e.t).collect();
+    ends.dedup();
+    if ends.last() != Some(&ts[1]) {
+        ends.push(ts[1]);
+    }
+    ends
+}
+
+///
+/// Any one-step method on a dt grid restarted at each impulse, the last
+/// step of each segment shortened to end on it. The run is checked
+/// against limits before anything is stored
+///
+pub fn integrate_impulsive<F, S, const N: usize>(
+    rate: &F, step: S, ic: [f64; N], ts: [f64; 2], dt: f64, events: &[Impulse], limits: Limits)
+    -> Result<Solution<N>, Box<dyn std::error::Error>>
+where
+    F: Fn(f64, &[f64; N], &mut [f64; N]),
+    S: Fn(&F, f64, &[f64; N], f64) -> [f64; N]
+{
+    let events = schedule::<N>(events, ts)?;
+    let (mut t, mut y) = allocate(ic, ts, dt, events.len(), limits)?;
+    let mut guard = limits.start::<N>();
+    let mut start = ts[0];
+    for end in segments(&events, ts) {
+        let mut tk = start;
+        let mut state = y[y.len() - 1];
+        while remains(tk, end, dt) {
+            guard.step("impulsive", tk)?;
+            let h = dt.min(end - tk);
+            state = step(rate, tk, &state, h);
+            tk = (tk + h).min(end);
+            t.push(tk);
+            y.push(state);
+        }
+        apply_at(&events, end, &mut t, &mut y);
+        start = end;
+    }
+    Ok((t, y))
+}
+
+///
+/// Trajectory Vecs sized for a run of dt steps over ts with `impulses`
+/// restarts, each adding at most a shortened step and a post impulse
+/// point, once the count has passed limits
+///
+fn allocate<const N: usize>(ic: [f64; N], ts: [f64; 2], dt: f64, impulses: usize, limits: Limits)
+    -> Result<Solution<N>, Box<dyn std::error::Error>> {
+    let n = solvers::planned_steps("impulsive", ts, dt, limits)?.saturating_add(2 * impulses);
+    limits.check::<N>("impulsive", n)?;
+    let mut t = Vec::with_capacity(n + 1);
+    let mut y = Vec::with_capacity(n + 1);
+    t.push(ts[0]);
+    y.push(ic);
+    Ok((t, y))
+}
+
+///
+/// More than roundoff left of the segment
+///
+fn remains(t: f64, end: f64, dt: f64) -> bool {
+    end - t > 1e-9 * dt
+}
+
+///
+/// Applies every impulse at time end, keeping the post impulse state as
+/// a second point at the same time
+///
+fn apply_at<const N: usize>(events: &[Impulse], end: f64, t: &mut Vec<f64>, y: &mut Vec<[f64; N]>) {
+    let mut state = y[y.len() - 1];
+    let mut hit = false;
+    for e in events.iter().filter(|e| e.t == end) {
+        e.apply(&mut state);
+        hit = true;
+    }
+    if hit {
+        t.push(end);
+        y.push(state);
+    }
+}
+
+///
+/// What AB/AM4 does with its rate history at an impulse
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum History {
+    /// restart from the new state with three rk4 steps
+    Reset,
+    /// carry the pre impulse derivatives on, wrong, for comparison only
+    Keep,
+}
+
+///
+/// AB4 predictor, AM4 corrector in PECE mode on the order 4 Nordsieck
+/// array, segment by segment between impulses. The array is seeded from
+/// three rk4 steps at dt; a segment whose length is not a multiple of dt
+/// ends with the array rescaled onto the short step, and rescaled back
+/// after. A step whose corrector fails is handled by on_failure. Checked
+/// against limits as integrate_impulsive is
+///
+#[allow(clippy::too_many_arguments)]
+pub fn abm4_impulsive<F, const N: usize>(
+    rate: &F, ic: [f64; N], ts: [f64; 2], dt: f64, events: &[Impulse], history: History, on_failure: CorrectorFailure,
+    limits: Limits)
+    -> Result<Solution<N>, Box<dyn std::error::Error>>
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    let events = schedule::<N>(events, ts)?;
+    let eval = |t: f64, y: &[f64; N]| {
+        let mut d = [0.0; N];
+        rate(t, y, &mut d);
+        d
+    };
+
+    let (mut t, mut y) = allocate(ic, ts, dt, events.len(), limits)?;
+    let mut guard = limits.start::<N>();
+    let l = adams_l(4);
+    // rates on the dt grid while starting up, oldest first, then the array
+    let mut f: Vec<[f64; N]> = Vec::with_capacity(4);
+    let mut nd: Option<Nordsieck<N>> = None;
+    let mut stats = SolveStats::default();
+    let mut start = ts[0];
+    for end in segments(&events, ts) {
+        if history == History::Reset {
+            f.clear();
+            nd = None;
+        }
+        let mut tk = start;
+        let mut state = y[y.len() - 1];
+        if let Some(nd) = nd.as_mut() {
+            nd.z[0] = state;
+        } else if f.is_empty() {
+            f.push(eval(tk, &state));
+        }
+        while remains(tk, end, dt) {
+            guard.step("impulsive abm4", tk)?;
+            let h = dt.min(end - tk);
+            if let Some(nd) = nd.as_mut() {
+                nd.rescale(h);
+                nd.t = tk;
+                nd.adams_step_with(rate, &l, 1, true, on_failure, &mut stats)?;
+                state = nd.value();
+            } else {
+                state = rk4_step(rate, tk, &state, h);
+                // a short step breaks the uniform startup grid
+                if h < dt {
+                    f.clear();
+                }
+                f.push(eval(tk + h, &state));
+                if f.len() == 4 {
+                    nd = Some(Nordsieck::from_uniform_history(tk + h, dt, state, &f));
+                }
+            }
+            tk = (tk + h).min(end);
+            t.push(tk);
+            y.push(state);
+        }
+        apply_at(&events, end, &mut t, &mut y);
+        start = end;
+    }
+    Ok((t, y))
+}
@@ -0,0 +1,193 @@
+//!
+//! inset.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Zoomed inset axes. A figure of 100 time units of oscillation shows the
+//! envelope but not the waveform; an Inset redraws the same series over a
+//! short t window, one period say, in a boxed sub-chart in a corner of the
+//! plot, and frames that window on the main axes so the eye can find it.
+//! The inset covers whatever data is under it, so headroom gives the top
+//! of a y range that keeps the curves clear of an upper corner
+//!
+
+#![allow(dead_code)]
+
+use plotters::coord::Shift;
+use plotters::prelude::*;
+
+use crate::legend::Position;
+use crate::theme::PlotStyle;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Inset {
+    /// t window shown
+    pub window: [f64; 2],
+    /// any corner or MiddleRight, never Below
+    pub position: Position,
+    /// fraction of the plotting area's width and height
+    pub size: [f64; 2],
+}
+
+impl Inset {
+    pub fn new(t0: f64, t1: f64) -> Inset {
+        Inset { window: [t0.min(t1), t0.max(t1)], position: Position::UpperRight, size: [0.45, 0.4] }
+    }
+
+    ///
+    /// The last period of a run ending at tf
+    ///
+    pub fn last_period(tf: f64, period: f64) -> Inset {
+        Inset::new(tf - period, tf)
+    }
+
+    ///
+    /// Window of width 2 half about the time in [0, end] where the curves
+    /// disagree most: values(t) is sampled at samples + 1 even points and
+    /// the spread is its largest entry less its smallest. Returns the inset,
+    /// clipped to [0, end], with the time and the spread found there
+    ///
+    pub fn widest_spread<V>(end: f64, samples: usize, half: f64, values: V)
+        -> Result<(Inset, f64, f64), Box<dyn std::error::Error>>
+    where V: Fn(f64) -> Result<Vec<f64>, Box<dyn std::error::Error>> {
+        if samples == 0 || end.is_nan() || end <= 0.0 {
+            return Err(format!("inset: {samples} samples over [0, {end}]").into());
+        }
+        let mut widest = (0.0, 0.0);
+        for i in 0..=samples {
+            let t = end * i as f64 / samples as f64;
+            let v = values(t)?;
+            let spread = v.iter().copied().fold(f64::NEG_INFINITY, f64::max) - v.iter().copied().fold(f64::INFINITY, f64::min);
+            if spread > widest.1 {
+                widest = (t, spread);
+            }
+        }
+        let inset = Inset::new((widest.0 - half).max(0.0), (widest.0 + half).min(end));
+        Ok((inset, widest.0, widest.1))
+    }
+
+    pub fn position(mut self, position: Position) -> Inset {
+        self.position = position;
+        self
+    }
+
+    pub fn size(mut self, width: f64, height: f64) -> Inset {
+        self.size = [width.clamp(0.1, 0.9), height.clamp(0.1, 0.9)];
+        self
+    }
+
+    ///
+    /// Top of a y range over data spanning [lo, hi] that leaves the upper
+    /// size[1] of the plot, and a little more for the margin, to an inset
+    /// there; hi itself for a lower one
+    ///
+    pub fn headroom(&self, lo: f64, hi: f64) -> f64 {
+        let share = self.size[1] + 0.05;
+        match self.position {
+            Position::UpperLeft | Position::UpperRight => hi + (hi - lo) * share / (1.0 - share),
+            _ => hi,
+        }
+    }
+
+    ///
+    /// Outline of the window on the main chart, over the y range the inset
+    /// shows
+    ///
+    pub fn frame(&self, y: [f64; 2], theme: &PlotStyle) -> Rectangle<(f64, f64)> {
+        Rectangle::new([(self.window[0], y[0]), (self.window[1], y[1])], BLACK.stroke_width(theme.thin))
+    }
+
+    ///
+    /// Draws the inset into area, the main chart's plotting area, with each
+    /// (t, values, style) series cut to the window. Returns the y range
+    /// shown, the series' extremes in the window padded by 10%, for frame
+    ///
+    pub fn draw<DB>(&self, area: &DrawingArea<DB, Shift>, theme: &PlotStyle, series: &[(&[f64], &[f64], ShapeStyle)])
+        -> Result<[f64; 2], Box<dyn std::error::Error>>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        if self.position == Position::Below {
+            return Err("an inset sits inside the plot, it cannot go below it".into());
+        }
+        let [t0, t1] = self.window;
+        if t0.is_nan() || t1.is_nan() || t1 <= t0 {
+            return Err(format!("inset window [{t0}, {t1}] is empty").into());
+        }
+        // index range of each series inside the window
+        let cut = |t: &[f64]| t.partition_point(|s| *s < t0)..t.partition_point(|s| *s <= t1);
+        let (mut lo, mut hi) = (f64::INFINITY, f64::NEG_INFINITY);
+        for (t, v, _) in series {
+            for x in &v[cut(t)] {
+                lo = lo.min(*x);
+                hi = hi.max(*x);
+            }
+        }
+        if lo > hi {
+            return Err(format!("no points inside the inset window [{t0}, {t1}]").into());
+        }
+        let pad = 0.1 * (hi - lo).max(1e-12 * hi.abs().max(1.0));
+        let (lo, hi) = (lo - pad, hi + pad);
+
+        let (aw, ah) = area.dim_in_pixel();
+        let (w, h) = ((aw as f64 * self.size[0]) as u32, (ah as f64 * self.size[1]) as u32);
+        let m = theme.margin;
+        let (x0, y0) = match self.position {
+            Position::UpperLeft => (m, m),
+            Position::UpperRight => (aw.saturating_sub(w + m), m),
+            Position::MiddleRight => (aw.saturating_sub(w + m), ah.saturating_sub(h) / 2),
+            Position::LowerLeft => (m, ah.saturating_sub(h + m)),
+            Position::LowerRight | Position::Below => (aw.saturating_sub(w + m), ah.saturating_sub(h + m)),
+        };
+        let inset = area.clone().shrink((x0, y0), (w, h));
+        inset.fill(&WHITE)?;
+        let mut chart = ChartBuilder::on(&inset)
+            .caption(format!("t in [{t0:.2}, {t1:.2}]"), theme.label())
+            .margin(theme.margin)
+            .set_label_area_size(LabelAreaPosition::Left, 55)
+            .set_label_area_size(LabelAreaPosition::Bottom, 30)
+            .build_cartesian_2d(t0..t1, lo..hi)?;
+        chart.configure_mesh().label_style(theme.label()).draw()?;
+        for (t, v, style) in series {
+            let range = cut(t);
+            chart.draw_series(LineSeries::new(t[range.clone()].iter().copied().zip(v[range].iter().copied()), *style))?;
+        }
+        inset.draw(&Rectangle::new([(0, 0), (w as i32 - 1, h as i32 - 1)], BLACK))?;
+        Ok([lo, hi])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn widest_spread_finds_the_bump() {
+        // two curves that part only near t = 3
+        let values = |t: f64| -> Result<Vec<f64>, Box<dyn std::error::Error>> {
+            Ok(vec![t.sin(), t.sin() + 0.1 * (-(t - 3.0) * (t - 3.0)).exp(), t.sin()])
+        };
+        let (inset, at, spread) = Inset::widest_spread(10.0, 1000, 0.5, values).unwrap();
+        assert!((at - 3.0).abs() < 1e-12, "at {at}");
+        assert!((spread - 0.1).abs() < 1e-12);
+        assert_eq!(inset.window, [2.5, 3.5]);
+    }
+
+    #[test]
+    fn widest_spread_window_is_clipped_to_the_run() {
+        let values = |t: f64| -> Result<Vec<f64>, Box<dyn std::error::Error>> { Ok(vec![0.0, t]) };
+        let (inset, at, _) = Inset::widest_spread(4.0, 40, 1.0, values).unwrap();
+        assert_eq!(at, 4.0);
+        assert_eq!(inset.window, [3.0, 4.0]);
+        assert!(Inset::widest_spread(4.0, 0, 1.0, values).is_err());
+        assert!(Inset::widest_spread(f64::NAN, 10, 1.0, values).is_err());
+    }
+
+    #[test]
+    fn headroom_clears_an_upper_corner_only() {
+        let upper = Inset::new(0.0, 1.0);
+        let top = upper.headroom(0.0, 1.0);
+        // the data ends below the inset's lower edge
+        assert!((1.0 - 0.0) / (top - 0.0) <= 1.0 - upper.size[1]);
+        assert_eq!(upper.position(Position::LowerRight).headroom(0.0, 1.0), 1.0);
+    }
+}
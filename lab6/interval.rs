@@ -0,0 +1,316 @@
+//!
+//! interval.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Interval arithmetic with outward rounding and a simple validated
+//! integrator. Each step first proves an a priori enclosure of the flow
+//! over the step, then bounds the solution at the next step with a Taylor
+//! expansion whose remainder is evaluated over that enclosure. The result
+//! rigorously contains the exact solution, unlike the heuristic estimates
+//!
+
+#![allow(dead_code)]
+
+use std::ops::{Add, Mul, Neg, Sub};
+use solvers::{self, Limits, SolveStats};
+
+use crate::system::OdeSystem;
+
+///
+/// Closed interval [lo, hi] where every operation rounds outward by one ulp
+/// so the true real-valued result is always contained
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Interval {
+    pub lo: f64,
+    pub hi: f64,
+}
+
+impl Interval {
+    pub fn new(lo: f64, hi: f64) -> Interval {
+        assert!(lo <= hi, "interval lower bound exceeds upper bound");
+        Interval { lo, hi }
+    }
+
+    pub fn point(x: f64) -> Interval {
+        Interval { lo: x, hi: x }
+    }
+
+    ///
+    /// Rounds bounds outward, the cheap substitute for directed rounding modes
+    ///
+    fn outward(lo: f64, hi: f64) -> Interval {
+        Interval { lo: lo.next_down(), hi: hi.next_up() }
+    }
+
+    pub fn width(&self) -> f64 {
+        self.hi - self.lo
+    }
+
+    pub fn mid(&self) -> f64 {
+        0.5 * (self.lo + self.hi)
+    }
+
+    pub fn contains(&self, x: f64) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    pub fn subset_of(&self, other: &Interval) -> bool {
+        other.lo <= self.lo && self.hi <= other.hi
+    }
+
+    pub fn hull(&self, other: &Interval) -> Interval {
+        Interval { lo: self.lo.min(other.lo), hi: self.hi.max(other.hi) }
+    }
+
+    ///
+    /// Widens by a relative factor plus an absolute floor about the midpoint
+    ///
+    pub fn inflate(&self, rel: f64, abs: f64) -> Interval {
+        let r = 0.5 * self.width() * (1.0 + rel) + abs;
+        let m = self.mid();
+        Interval::outward(m - r, m + r)
+    }
+
+    ///
+    /// Tight square, nonnegative even when the interval straddles zero
+    ///
+    pub fn sqr(&self) -> Interval {
+        let (a, b) = (self.lo * self.lo, self.hi * self.hi);
+        if self.contains(0.0) {
+            Interval::outward(0.0, a.max(b)).max_zero()
+        } else {
+            Interval::outward(a.min(b), a.max(b))
+        }
+    }
+
+    ///
+    /// Integer power, monotone for odd n and routed through sqr for even n
+    ///
+    pub fn powi(&self, n: u32) -> Interval {
+        match n {
+            0 => Interval::point(1.0),
+            1 => *self,
+            _ if n.is_multiple_of(2) => self.sqr().powi(n / 2),
+            _ => {
+                let (a, b) = (self.lo.powi(n as i32), self.hi.powi(n as i32));
+                Interval::outward(a, b)
+            }
+        }
+    }
+
+    fn max_zero(self) -> Interval {
+        Interval { lo: self.lo.max(0.0), hi: self.hi }
+    }
+}
+
+impl Add for Interval {
+    type Output = Interval;
+    fn add(self, rhs: Interval) -> Interval {
+        Interval::outward(self.lo + rhs.lo, self.hi + rhs.hi)
+    }
+}
+
+impl Sub for Interval {
+    type Output = Interval;
+    fn sub(self, rhs: Interval) -> Interval {
+        Interval::outward(self.lo - rhs.hi, self.hi - rhs.lo)
+    }
+}
+
+impl Neg for Interval {
+    type Output = Interval;
+    fn neg(self) -> Interval {
+        Interval { lo: -self.hi, hi: -self.lo }
+    }
+}
+
+impl Mul for Interval {
+    type Output = Interval;
+    fn mul(self, rhs: Interval) -> Interval {
+        let p = [self.lo * rhs.lo, self.lo * rhs.hi, self.hi * rhs.lo, self.hi * rhs.hi];
+        let lo = p.iter().copied().fold(f64::INFINITY, f64::min);
+        let hi = p.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        Interval::outward(lo, hi)
+    }
+}
+
+impl Mul<Interval> for f64 {
+    type Output = Interval;
+    fn mul(self, rhs: Interval) -> Interval {
+        Interval::point(self) * rhs
+    }
+}
+
+///
+/// An autonomous OdeSystem whose rate also runs on intervals, with its
+/// derivative along the flow (J f, i.e. y''), for validated_taylor2
+///
+pub trait IntervalSystem: OdeSystem {
+    fn rate_interval(&self, y: &[Interval], dy: &mut [Interval]);
+
+    fn rate_interval_deriv(&self, y: &[Interval], d2y: &mut [Interval]);
+}
+
+///
+/// Step times and the rigorous enclosure of the state at each
+///
+pub type Enclosure<const N: usize> = (Vec<f64>, Vec<[Interval; N]>);
+
+///
+/// Finds B with Y0 + [0, h] F(B) contained in B, which by Picard-Lindelof
+/// guarantees the exact flow stays inside B over the whole step
+///
+fn a_priori<F, const N: usize>(f: &F, y0: &[Interval; N], h: f64) -> Option<[Interval; N]>
+where F: Fn(&[Interval; N]) -> [Interval; N] {
+    let step = Interval::new(0.0, h);
+    let fy = f(y0);
+    let mut b: [Interval; N] = std::array::from_fn(|i| (y0[i] + step * fy[i]).inflate(0.1, 1e-12));
+
+    for _ in 0..20 {
+        let fb = f(&b);
+        let next: [Interval; N] = std::array::from_fn(|i| y0[i] + step * fb[i]);
+        if next.iter().zip(b.iter()).all(|(x, bx)| x.subset_of(bx)) {
+            return Some(next);
+        }
+        b = std::array::from_fn(|i| next[i].hull(&b[i]).inflate(0.5, 1e-12));
+    }
+    None
+}
+
+///
+/// Validated second order Taylor integrator. `f` is the interval rate
+/// function and `df` its derivative along the flow (J f, i.e. y''). Returns
+/// the step times and rigorous enclosures of y at each, or an error when
+/// the a priori enclosure cannot be proven (step too large for the horizon).
+/// When h does not divide the span a shortened last step lands on tf.
+/// The run is checked against limits before anything is stored.
+/// rate_evals counts the calls of f and df, a priori sweeps included
+///
+#[allow(clippy::too_many_arguments)]
+pub fn validated_taylor2<F, G, const N: usize>(
+    f: &F,
+    df: &G,
+    y0: [Interval; N],
+    t0: f64,
+    tf: f64,
+    h: f64,
+    limits: Limits) -> Result<(Enclosure<N>, SolveStats), Box<dyn std::error::Error>>
+where
+    F: Fn(&[Interval; N]) -> [Interval; N],
+    G: Fn(&[Interval; N]) -> [Interval; N]
+{
+    let n = solvers::planned_steps("validated taylor", [t0, tf], h, limits)?;
+    // an interval is two f64, so the stored run is about twice n points
+    limits.check::<N>("validated taylor", n.saturating_mul(2))?;
+    let mut guard = limits.start::<N>();
+    let mut t: Vec<f64> = Vec::with_capacity(n + 1);
+    let mut y: Vec<[Interval; N]> = Vec::with_capacity(n + 1);
+    t.push(t0);
+    y.push(y0);
+    let calls = std::cell::Cell::new(0);
+    let f = |z: &[Interval; N]| {
+        calls.set(calls.get() + 1);
+        f(z)
+    };
+    let df = |z: &[Interval; N]| {
+        calls.set(calls.get() + 1);
+        df(z)
+    };
+
+    for (i, (ti, h, next)) in solvers::grid([t0, tf], h).enumerate() {
+        guard.step("validated taylor", ti)?;
+        let w = *y.last().unwrap();
+        let Some(b) = a_priori(&f, &w, h) else {
+            return Err(format!("could not validate step {} at t = {ti}", i + 1).into());
+        };
+
+        // y(t + h) in y + h f(y) + h^2/2 y''(B), remainder over the enclosure
+        let hh = Interval::point(h);
+        let half_h2 = Interval::point(0.5) * hh * hh;
+        let fw = f(&w);
+        let d2 = df(&b);
+        y.push(std::array::from_fn(|j| w[j] + hh * fw[j] + half_h2 * d2[j]));
+        t.push(next);
+    }
+
+    let stats = SolveStats { accepted: t.len() - 1, rate_evals: calls.get(), ..SolveStats::default() };
+    Ok(((t, y), stats))
+}
+
+///
+/// validated_taylor2 on a system, its size checked against the state
+///
+pub fn validated_taylor2_system<const N: usize>(system: &impl IntervalSystem, y0: [Interval; N], t0: f64, tf: f64, h: f64, limits: Limits)
+    -> Result<(Enclosure<N>, SolveStats), Box<dyn std::error::Error>> {
+    system.check_size::<N>()?;
+    let f = |y: &[Interval; N]| -> [Interval; N] {
+        let mut dy = [Interval::point(0.0); N];
+        system.rate_interval(y, &mut dy);
+        dy
+    };
+    let df = |y: &[Interval; N]| -> [Interval; N] {
+        let mut d2y = [Interval::point(0.0); N];
+        system.rate_interval_deriv(y, &mut d2y);
+        d2y
+    };
+    validated_taylor2(&f, &df, y0, t0, tf, h, limits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decay(y: &[Interval; 1]) -> [Interval; 1] {
+        [-y[0]]
+    }
+
+    // y'' = -y' = y
+    fn decay_deriv(y: &[Interval; 1]) -> [Interval; 1] {
+        [y[0]]
+    }
+
+    #[test]
+    fn arithmetic_contains_the_real_result() {
+        let (a, b) = (Interval::new(-1.0, 2.0), Interval::new(-3.0, 0.5));
+        assert_eq!((a * b).lo.next_up(), -6.0);
+        assert_eq!((a * b).hi.next_down(), 3.0);
+        assert!((a - b).contains(5.0) && (a - b).contains(-1.5));
+        // a square is never negative, a product of the same interval can be
+        assert_eq!(a.sqr().lo, 0.0);
+        assert!((a * a).lo < 0.0);
+        let cube = Interval::new(-2.0, 1.0).powi(3);
+        assert!(cube.contains(-8.0) && cube.contains(1.0));
+        let tenth = Interval::point(0.1) + Interval::point(0.2);
+        assert!(tenth.lo < 0.1 + 0.2 && 0.1 + 0.2 < tenth.hi);
+    }
+
+    #[test]
+    fn enclosure_contains_the_exact_decay() {
+        let ((t, y), stats) = validated_taylor2(&decay, &decay_deriv, [Interval::point(1.0)], 0.0, 2.0, 0.05, Limits::default()).unwrap();
+        assert_eq!(t.len(), 41);
+        assert!((t[40] - 2.0).abs() < 1e-15);
+        for (ti, yi) in t.iter().zip(&y) {
+            assert!(yi[0].contains((-ti).exp()), "{yi:?} misses exp(-{ti})");
+        }
+        assert!(y[40][0].width() < 1e-2);
+        assert!(stats.rate_evals >= 2 * 40);
+    }
+
+    #[test]
+    fn enclosure_width_shrinks_at_second_order() {
+        let width = |h: f64| {
+            let ((_, y), _) = validated_taylor2(&decay, &decay_deriv, [Interval::point(1.0)], 0.0, 1.0, h, Limits::default()).unwrap();
+            y[y.len() - 1][0].width()
+        };
+        let (coarse, fine) = (width(0.02), width(0.01));
+        assert!(coarse / fine > 3.5 && coarse / fine < 4.5, "{coarse:e} / {fine:e}");
+    }
+
+    #[test]
+    fn a_step_past_the_blow_up_is_not_validated() {
+        // y' = y^2 from 1 blows up at t = 1
+        let f = |y: &[Interval; 1]| [y[0].sqr()];
+        let df = |y: &[Interval; 1]| [Interval::point(2.0) * y[0].powi(3)];
+        assert!(validated_taylor2(&f, &df, [Interval::point(1.0)], 0.0, 2.0, 1.5, Limits::default()).is_err());
+    }
+}
@@ -0,0 +1,85 @@
+//!
+//! invariants.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Monitoring of conserved quantities during a run. Each invariant is a
+//! named function of the state; the monitor records its value at the first
+//! point, the largest relative drift from it seen since, and a thinned
+//! history of the drift for plotting. Feed it from any observer callback
+//!
+
+#![allow(dead_code)]
+
+pub type InvariantFn<const N: usize> = Box<dyn Fn(&[f64; N]) -> f64>;
+
+pub struct Invariant<const N: usize> {
+    pub name: &'static str,
+    pub f: InvariantFn<N>,
+}
+
+pub struct Monitor<const N: usize> {
+    pub invariants: Vec<Invariant<N>>,
+    /// keep every `every`-th point in the history
+    pub every: usize,
+    initial: Vec<f64>,
+    max_drift: Vec<f64>,
+    history: Vec<(f64, Vec<f64>)>,
+    count: usize,
+}
+
+impl<const N: usize> Monitor<N> {
+    pub fn new(every: usize) -> Monitor<N> {
+        Monitor {
+            invariants: Vec::new(),
+            every: every.max(1),
+            initial: Vec::new(),
+            max_drift: Vec::new(),
+            history: Vec::new(),
+            count: 0,
+        }
+    }
+
+    pub fn with(mut self, name: &'static str, f: impl Fn(&[f64; N]) -> f64 + 'static) -> Monitor<N> {
+        self.invariants.push(Invariant { name, f: Box::new(f) });
+        self
+    }
+
+    ///
+    /// |I - I0| / |I0|, or the absolute change when I0 is zero
+    ///
+    fn drift(&self, k: usize, value: f64) -> f64 {
+        let i0 = self.initial[k];
+        if i0 == 0.0 { value.abs() } else { (value - i0).abs() / i0.abs() }
+    }
+
+    pub fn observe(&mut self, t: f64, y: &[f64; N]) {
+        let values: Vec<f64> = self.invariants.iter().map(|inv| (inv.f)(y)).collect();
+        if self.count == 0 {
+            self.initial.clone_from(&values);
+            self.max_drift = vec![0.0; values.len()];
+        }
+        let drift: Vec<f64> = values.iter().enumerate().map(|(k, v)| self.drift(k, *v)).collect();
+        for (m, d) in self.max_drift.iter_mut().zip(&drift) {
+            // NaN counts as unbounded drift
+            *m = if d.is_nan() { f64::INFINITY } else { m.max(*d) };
+        }
+        if self.count.is_multiple_of(self.every) {
+            self.history.push((t, drift));
+        }
+        self.count += 1;
+    }
+
+    pub fn initial(&self) -> &[f64] {
+        &self.initial
+    }
+
+    pub fn max_drift(&self) -> &[f64] {
+        &self.max_drift
+    }
+
+    ///
+    /// (t, relative drift of each invariant) at the kept points
+    ///
+    pub fn history(&self) -> &[(f64, Vec<f64>)] {
+        &self.history
+    }
+}
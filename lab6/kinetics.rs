@@ -0,0 +1,310 @@
+//!
+//! kinetics.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Chemical kinetics from reaction networks written as text. The mass
+//! action right-hand side and its Jacobian are generated from the network,
+//! and the Robertson problem, the standard stiff kinetics benchmark, is
+//! run through the implicit ARS path on the closed form Jacobian
+//!
+//! The built in stiff benchmarks (Robertson, HIRES) are then run at a
+//! sequence of step counts and checked against their published reference
+//! solutions
+//!
+
+#![allow(clippy::cast_possible_truncation)]
+#![allow(clippy::cast_sign_loss)]
+#![allow(clippy::cast_precision_loss)]
+#![allow(clippy::missing_panics_doc)]
+#![allow(clippy::missing_errors_doc)]
+
+use plotters::prelude::*;
+use plotters_bitmap::BitMapBackend;
+use solvers::{CorrectorFailure, Method};
+
+mod annotate;
+mod export;
+mod imex;
+mod linalg;
+mod multistep;
+mod peaks;
+mod problems;
+mod reaction;
+mod report;
+mod run_context;
+mod spline;
+mod state;
+mod system;
+mod theme;
+mod trajectory;
+
+use annotate::Annotation;
+use imex::NewtonOptions;
+use linalg::Matrix;
+use problems::Problem;
+use reaction::Network;
+use report::Table;
+use run_context::RunContext;
+use system::OdeSystem;
+
+const ROBERTSON: &str = "
+    # Robertson (1966), autocatalytic reaction with rates spanning 9 decades
+    A -> B          @ 0.04
+    2 B -> B + C    @ 3e7
+    B + C -> A + C  @ 1e4
+";
+
+/// Where rk4's stability region meets the negative real axis
+const RK4_REAL_LIMIT: f64 = 2.785;
+
+///
+/// Largest entrywise gap between the closed form Jacobian and central
+/// differences, relative to the largest entry
+///
+fn jacobian_check(net: &Network, y: &[f64]) -> f64 {
+    let n = net.species_count();
+    let exact = net.jacobian(y);
+    let mut fd = Matrix::zeros(n, n);
+    let (mut fp, mut fm) = (vec![0.0; n], vec![0.0; n]);
+    for j in 0..n {
+        let h = 1e-6 * y[j].abs().max(1e-8);
+        let (mut yp, mut ym) = (y.to_vec(), y.to_vec());
+        yp[j] += h;
+        ym[j] -= h;
+        net.rate(&yp, &mut fp);
+        net.rate(&ym, &mut fm);
+        for i in 0..n {
+            fd[(i, j)] = (fp[i] - fm[i]) / (2.0 * h);
+        }
+    }
+    let mut scale: f64 = 0.0;
+    let mut gap: f64 = 0.0;
+    for i in 0..n {
+        for j in 0..n {
+            scale = scale.max(exact[(i, j)].abs());
+            gap = gap.max((exact[(i, j)] - fd[(i, j)]).abs());
+        }
+    }
+    gap / scale
+}
+
+///
+/// Robertson on [0, tf] with every reaction on the implicit side of each
+/// ARS tableau, against rk4 at the same dt. The generated RHS conserves
+/// A + B + C, which is checked at the end of each run
+///
+fn robertson_study(ctx: &mut RunContext, tf: f64, dt: f64, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let net = Network::parse(ROBERTSON)?;
+    for eq in net.equations() {
+        println!("{eq}");
+    }
+    let ic = [1.0, 0.0, 0.0];
+    println!("jacobian vs central differences, relative gap: {:.3e}", jacobian_check(&net, &[0.9, 3e-5, 0.1]));
+    ctx.config("network", ROBERTSON.trim().replace('\n', "; "));
+    ctx.config("dt", dt);
+
+    let rate = net.rhs::<3>()?;
+    let none = |_t: f64, _y: &[f64; 3], dy: &mut [f64; 3]| *dy = [0.0; 3];
+    let jac = |_t: f64, y: &[f64; 3]| net.jacobian(y);
+
+    println!("{:>8} {:>14} {:>14} {:>14} {:>12} {:>10} {:>10} {:>8} {:>8}",
+        "method", "A(tf)", "B(tf)", "C(tf)", "mass drift", "rate evals", "jacobians", "lu", "newton");
+    let mut table = Table::new(format!("Robertson at t = {tf}, dt = {dt}"),
+        &["method", "A(tf)", "B(tf)", "C(tf)", "mass drift", "rate evals", "jacobians", "lu", "newton"]);
+    let mut runs = Vec::new();
+    for tab in &imex::Tableau::all() {
+        ctx.solver(tab.name, "implicit, analytic jacobian");
+        let ((t, y), stats) = ctx.time(tab.name, || imex::solve_with_jacobian(tab, &none, &rate, &jac, ic, [0.0, tf], dt, NewtonOptions::default()))?;
+        let end = y[y.len() - 1];
+        println!("{:>8} {:>14.8e} {:>14.8e} {:>14.8e} {:>12.3e} {:>10} {:>10} {:>8} {:>8}", tab.name, end[0], end[1], end[2],
+            (end.iter().sum::<f64>() - 1.0).abs(), stats.rate_evals, stats.jacobian_evals, stats.factorizations, stats.newton_iters);
+        table.row([tab.name.to_string(), format!("{:.8e}", end[0]), format!("{:.8e}", end[1]), format!("{:.8e}", end[2]),
+            format!("{:.3e}", (end.iter().sum::<f64>() - 1.0).abs()), stats.rate_evals.to_string(),
+            stats.jacobian_evals.to_string(), stats.factorizations.to_string(), stats.newton_iters.to_string()]);
+        runs.push((tab.name, t, y));
+    }
+    // rk4 is stable while dt |lambda| stays inside its real-axis interval
+    let (_, t, y) = &runs[runs.len() - 1];
+    let mut marks = Vec::new();
+    let limit = t.iter().zip(y).find(|(_, p)| {
+        net.jacobian(&p[..]).eigenvalues().is_ok_and(|ev| ev.iter().any(|(re, im)| dt * re.hypot(*im) > RK4_REAL_LIMIT))
+    });
+    if let Some((tl, _)) = limit {
+        println!("dt = {dt} leaves rk4's stability interval at t = {tl:.3}");
+        marks.push(Annotation::region(*tl, tf, format!("dt = {dt} past the rk4 stability limit {RK4_REAL_LIMIT} / |lambda|")));
+    }
+
+    ctx.solver("rk4", "fixed-step");
+    let ((_, y), stats) = ctx.time("rk4", || Method::Rk4.solve(&rate, ic, [0.0, tf], dt))?;
+    let end = y[y.len() - 1];
+    if end.iter().all(|v| v.is_finite()) {
+        println!("{:>8} {:>14.8e} {:>14.8e} {:>14.8e} {:>12.3e} {:>10} {:>10} {:>8} {:>8}", "rk4", end[0], end[1], end[2],
+            (end.iter().sum::<f64>() - 1.0).abs(), stats.rate_evals, 0, 0, 0);
+        table.row(["rk4".to_string(), format!("{:.8e}", end[0]), format!("{:.8e}", end[1]), format!("{:.8e}", end[2]),
+            format!("{:.3e}", (end.iter().sum::<f64>() - 1.0).abs()), stats.rate_evals.to_string(),
+            "0".to_string(), "0".to_string(), "0".to_string()]);
+    } else {
+        let blowup = y.iter().position(|p| p.iter().any(|v| !v.is_finite() || v.abs() > 1e3)).unwrap_or(y.len());
+        println!("{:>8} diverged by t = {:.2}, explicit stability needs dt well below 1e-3", "rk4", blowup as f64 * dt);
+        table.row(["rk4", "diverged", "", "", "", "", "", "", ""]);
+        marks.push(Annotation::vertical(blowup as f64 * dt, "rk4 diverges"));
+    }
+
+    // B is four decades below A and C, so it is drawn scaled by 1e4
+    let path = ctx.path(name);
+    let theme = ctx.style();
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?;
+    let root = theme.stamp(&root)?;
+    let mut chart = ChartBuilder::on(&root)
+        .caption(format!("Robertson kinetics, dt = {dt}"), theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 55)
+        .set_label_area_size(LabelAreaPosition::Bottom, 50)
+        .build_cartesian_2d(0.0..tf, 0.0..1.0)?;
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("t").y_desc("concentration").draw()?;
+
+    let (label, t, y) = &runs[runs.len() - 1];
+    for (k, (species, scale)) in net.species.iter().zip([1.0, 1e4, 1.0]).enumerate() {
+        let color = theme.color(k);
+        let name = if scale == 1.0 { species.clone() } else { format!("{species} x {scale:.0e}") };
+        chart.draw_series(LineSeries::new(t.iter().zip(y).map(|(ti, p)| (*ti, scale * p[k])), color.stroke_width(theme.line)))?
+            .label(format!("{name}, {label}"))
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(theme.line)));
+    }
+    annotate::draw(&mut chart, &theme, &marks)?;
+
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?;
+
+    root.present()?;
+    ctx.write_table("robertson.tex", table)?;
+    ctx.caption(name, format!("Robertson kinetics by {label} at dt = {dt}, B scaled by 1e4"));
+    Ok(())
+}
+
+///
+/// Error against the reference at the end of the interval for each ARS
+/// tableau as the step count grows, with the observed order between
+/// successive counts
+///
+fn benchmark<const N: usize>(ctx: &mut RunContext, problem: &Problem<N>, steps: &[usize]) -> Result<(), Box<dyn std::error::Error>> {
+    let none = |_t: f64, _y: &[f64; N], dy: &mut [f64; N]| *dy = [0.0; N];
+    let span = problem.ts[1] - problem.ts[0];
+    println!("{} on [{}, {}], error relative to the reference", problem.name, problem.ts[0], problem.ts[1]);
+    println!("{:>8} {:>10} {:>12} {:>8}", "method", "steps", "error", "order");
+    let mut table = Table::new(format!("{} on [{}, {}], error relative to the reference", problem.name, problem.ts[0], problem.ts[1]),
+        &["method", "steps", "error", "order"]);
+    for tab in &imex::Tableau::all() {
+        ctx.solver(tab.name, "implicit, analytic jacobian");
+        let mut last: Option<(usize, f64)> = None;
+        for &n in steps {
+            let dt = span / n as f64;
+            let label = format!("{} {} {n}", problem.name, tab.name);
+            let ((_, y), _) = ctx.time(&label, || imex::solve_with_jacobian(tab, &none, &problem.rate, &problem.jacobian, problem.ic, problem.ts, dt, NewtonOptions::default()))?;
+            let err = problem.error(&y[y.len() - 1], 1e-12);
+            let order = last.map_or(String::from("-"), |(m, e)| format!("{:.2}", (e / err).ln() / (n as f64 / m as f64).ln()));
+            println!("{:>8} {:>10} {:>12.3e} {:>8}", tab.name, n, err, order);
+            table.row([tab.name.to_string(), n.to_string(), format!("{err:.3e}"), order]);
+            last = Some((n, err));
+        }
+    }
+    ctx.write_table(&format!("{}_convergence.tex", problem.name), table)?;
+    Ok(())
+}
+
+///
+/// The corrector failure policies on a problem whose Newton iterations
+/// are cut to max_iter, so that some stages fail: how often, what each
+/// policy did about it, and the error it ended with
+///
+fn failure_study<const N: usize>(ctx: &mut RunContext, problem: &Problem<N>, tab: &imex::Tableau, steps: usize, max_iter: usize)
+    -> Result<(), Box<dyn std::error::Error>> {
+    let none = |_t: f64, _y: &[f64; N], dy: &mut [f64; N]| *dy = [0.0; N];
+    let dt = (problem.ts[1] - problem.ts[0]) / steps as f64;
+    let caption = format!("{} by {} at dt = {dt}, newton cut to {max_iter} iterations", problem.name, tab.name);
+    println!("{caption}");
+    println!("{:>8} {:>9} {:>9} {:>9} {:>8} {:>12}", "policy", "failures", "rejected", "accepted", "newton", "error");
+    let mut table = Table::new(caption, &["policy", "failures", "rejected", "accepted", "newton", "error"]);
+    ctx.config("failure_max_iter", max_iter);
+    for policy in CorrectorFailure::ALL {
+        let opts = NewtonOptions { max_iter, on_failure: policy, ..NewtonOptions::default() };
+        let label = format!("{} {} {}", problem.name, tab.name, policy.name());
+        match ctx.time(&label, || imex::solve_with_jacobian(tab, &none, &problem.rate, &problem.jacobian, problem.ic, problem.ts, dt, opts)) {
+            Ok(((_, y), stats)) => {
+                let err = problem.error(&y[y.len() - 1], 1e-12);
+                println!("{:>8} {:>9} {:>9} {:>9} {:>8} {:>12.3e}", policy.name(), stats.corrector_failures, stats.rejected,
+                    stats.accepted, stats.newton_iters, err);
+                table.row([policy.name().to_string(), stats.corrector_failures.to_string(), stats.rejected.to_string(),
+                    stats.accepted.to_string(), stats.newton_iters.to_string(), format!("{err:.3e}")]);
+            }
+            Err(e) => {
+                println!("{:>8} {e}", policy.name());
+                table.row([policy.name(), "", "", "", "", "aborted"]);
+            }
+        }
+    }
+    ctx.write_table(&format!("{}_failure_policy.tex", problem.name), table)?;
+    Ok(())
+}
+
+fn main() {
+    let mut ctx = RunContext::new("output", "kinetics")
+        .expect("failed to create output directory");
+    let _ = robertson_study(&mut ctx, 40.0, 0.1, "robertson.png");
+    let _ = benchmark(&mut ctx, &problems::robertson(), &[1000, 4000, 16000]);
+    let _ = benchmark(&mut ctx, &problems::hires(), &[1000, 4000, 16000]);
+    let _ = failure_study(&mut ctx, &problems::robertson(), &imex::Tableau::ars222(), 400, 3);
+    let _ = ctx.write_report();
+    let _ = ctx.write_manifest();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    ///
+    /// End state error of ARS(2,2,2) over that many equal steps, against
+    /// the problem's published reference
+    ///
+    fn ars222_error<const N: usize>(problem: &Problem<N>, steps: usize) -> f64 {
+        let none = |_t: f64, _y: &[f64; N], dy: &mut [f64; N]| *dy = [0.0; N];
+        let dt = (problem.ts[1] - problem.ts[0]) / steps as f64;
+        let ((_, y), _) = imex::solve_with_jacobian(&imex::Tableau::ars222(), &none, &problem.rate, &problem.jacobian,
+            problem.ic, problem.ts, dt, NewtonOptions::default()).unwrap();
+        problem.error(&y[y.len() - 1], 1e-12)
+    }
+
+    #[test]
+    fn ars_matches_the_robertson_reference() {
+        assert!(ars222_error(&problems::robertson(), 4000) < 1e-7);
+    }
+
+    #[test]
+    fn each_failure_policy_on_a_cut_newton() {
+        let problem = problems::robertson();
+        let none = |_t: f64, _y: &[f64; 3], dy: &mut [f64; 3]| *dy = [0.0; 3];
+        let run = |on_failure| {
+            let opts = NewtonOptions { max_iter: 3, on_failure, ..NewtonOptions::default() };
+            imex::solve_with_jacobian(&imex::Tableau::ars222(), &none, &problem.rate, &problem.jacobian, problem.ic, problem.ts, 0.1, opts)
+        };
+        assert!(run(CorrectorFailure::Abort).is_err());
+        for policy in [CorrectorFailure::Halve, CorrectorFailure::Accept] {
+            let ((_, y), stats) = run(policy).unwrap();
+            assert!(stats.corrector_failures > 0 && stats.rejected > 0, "{}", policy.name());
+            assert!(problem.error(&y[y.len() - 1], 1e-12) < 1e-5, "{}", policy.name());
+        }
+        // accept keeps the nearly converged iterates instead of halving them
+        assert!(run(CorrectorFailure::Accept).unwrap().1.rejected < run(CorrectorFailure::Halve).unwrap().1.rejected);
+    }
+
+    #[test]
+    fn ars_converges_to_the_hires_reference() {
+        let (coarse, fine) = (ars222_error(&problems::hires(), 4000), ars222_error(&problems::hires(), 16000));
+        assert!(coarse < 5e-4);
+        assert!(fine < 5e-5);
+        assert!(fine < coarse / 8.0);
+    }
+}
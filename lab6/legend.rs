@@ -0,0 +1,141 @@
+//!
+//! legend.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Legends for figures with many series. plotters draws its series labels
+//! as one column in a corner of the plot, which on a figure of ten curves
+//! covers the data. A Legend here picks a corner or a strip below the
+//! chart, lays its entries out in several columns (filled top to bottom,
+//! so paired curves line up) and names series to leave out entirely
+//!
+
+#![allow(dead_code)]
+
+use plotters::coord::Shift;
+use plotters::prelude::*;
+
+use crate::theme::PlotStyle;
+
+/// Swatch length, gap after it and padding inside the box, in pixels
+const SWATCH: i32 = 20;
+const GAP: i32 = 6;
+const PAD: i32 = 8;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Position {
+    UpperLeft,
+    UpperRight,
+    MiddleRight,
+    LowerLeft,
+    LowerRight,
+    /// strip under the chart, outside the plotting area
+    Below,
+}
+
+#[derive(Clone, Debug)]
+pub struct Legend {
+    pub position: Position,
+    pub columns: usize,
+    /// labels of series not drawn at all
+    pub hidden: Vec<String>,
+}
+
+impl Default for Legend {
+    fn default() -> Legend {
+        Legend { position: Position::MiddleRight, columns: 1, hidden: Vec::new() }
+    }
+}
+
+impl Legend {
+    pub fn position(mut self, position: Position) -> Legend {
+        self.position = position;
+        self
+    }
+
+    pub fn columns(mut self, columns: usize) -> Legend {
+        self.columns = columns.max(1);
+        self
+    }
+
+    pub fn hide(mut self, label: impl Into<String>) -> Legend {
+        self.hidden.push(label.into());
+        self
+    }
+
+    ///
+    /// Whether the series called label is drawn
+    ///
+    pub fn shows(&self, label: &str) -> bool {
+        !self.hidden.iter().any(|h| h == label)
+    }
+
+    fn rows(&self, entries: usize) -> usize {
+        entries.div_ceil(self.columns)
+    }
+
+    fn row_height(theme: &PlotStyle) -> i32 {
+        theme.label_size as i32 + GAP
+    }
+
+    ///
+    /// Splits root into the chart's area and, for Position::Below, the
+    /// strip holding a legend of entries shown series
+    ///
+    pub fn split<DB: DrawingBackend>(&self, root: &DrawingArea<DB, Shift>, theme: &PlotStyle, entries: usize)
+        -> (DrawingArea<DB, Shift>, Option<DrawingArea<DB, Shift>>) {
+        if self.position != Position::Below || entries == 0 {
+            return (root.clone(), None);
+        }
+        let strip = self.rows(entries) as i32 * Legend::row_height(theme) + 2 * PAD + theme.margin as i32;
+        let (_, height) = root.dim_in_pixel();
+        let (chart, strip_area) = root.split_vertically(height as i32 - strip);
+        (chart, Some(strip_area))
+    }
+
+    ///
+    /// Draws the boxed legend of (label, style) entries into area, which is
+    /// the plotting area for the corner positions and the strip from split
+    /// for Below. Hidden entries are dropped
+    ///
+    pub fn draw<DB>(&self, area: &DrawingArea<DB, Shift>, theme: &PlotStyle, entries: &[(String, ShapeStyle)])
+        -> Result<(), Box<dyn std::error::Error>>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        let shown: Vec<&(String, ShapeStyle)> = entries.iter().filter(|(label, _)| self.shows(label)).collect();
+        if shown.is_empty() {
+            return Ok(());
+        }
+        let font = theme.label();
+        let mut width = 0;
+        for (label, _) in &shown {
+            width = width.max(area.estimate_text_size(label, &font.into_text_style(area))?.0 as i32);
+        }
+        let column = SWATCH + GAP + width + 2 * GAP;
+        let (columns, rows) = (self.columns.min(shown.len()), self.rows(shown.len()));
+        let row = Legend::row_height(theme);
+        let (w, h) = (columns as i32 * column + 2 * PAD - 2 * GAP, rows as i32 * row + 2 * PAD);
+
+        let (aw, ah) = area.dim_in_pixel();
+        let (aw, ah, inset) = (aw as i32, ah as i32, theme.margin as i32);
+        let (x0, y0) = match self.position {
+            Position::UpperLeft => (inset, inset),
+            Position::UpperRight => (aw - w - inset, inset),
+            Position::MiddleRight => (aw - w - inset, (ah - h) / 2),
+            Position::LowerLeft => (inset, ah - h - inset),
+            Position::LowerRight => (aw - w - inset, ah - h - inset),
+            Position::Below => ((aw - w) / 2, (ah - h - inset).max(0)),
+        };
+
+        area.draw(&Rectangle::new([(x0, y0), (x0 + w, y0 + h)], WHITE.mix(0.85).filled()))?;
+        area.draw(&Rectangle::new([(x0, y0), (x0 + w, y0 + h)], BLACK))?;
+        for (k, (label, style)) in shown.iter().enumerate() {
+            // column-major, so consecutive entries stack
+            let x = x0 + PAD + (k / rows) as i32 * column;
+            let y = y0 + PAD + (k % rows) as i32 * row + row / 2;
+            area.draw(&PathElement::new(vec![(x, y), (x + SWATCH, y)], *style))?;
+            area.draw(&Text::new(label.clone(), (x + SWATCH + GAP, y - theme.label_size as i32 / 2), font))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+//!
+//! linalg.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! The dense linear algebra the Newton and stability solvers share. The
+//! matrix, LU, pivoted QR, eigenvalue and blocked product kernels live in
+//! solvers::linalg so they build without std; what is left here is the
+//! ill conditioning warning, which prints
+//!
+
+#![allow(dead_code)]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+pub use solvers::linalg::*;
+
+/// warnings printed so far, across all solvers
+static WARNINGS: AtomicUsize = AtomicUsize::new(0);
+const MAX_WARNINGS: usize = 10;
+
+///
+/// Prints a warning naming the solver when condition is at or past
+/// ILL_CONDITIONED (or not finite), and returns whether it was. Only the
+/// first few warnings print, an implicit integrator can hit the same
+/// system every step
+///
+pub fn check_condition(context: &str, condition: f64) -> bool {
+    if condition < ILL_CONDITIONED {
+        return false;
+    }
+    let count = WARNINGS.fetch_add(1, Ordering::Relaxed);
+    if count < MAX_WARNINGS {
+        eprintln!("warning: {context}: condition estimate {condition:.3e}, expect to lose about {:.0} digits", condition.log10());
+    } else if count == MAX_WARNINGS {
+        eprintln!("warning: further ill conditioning warnings suppressed");
+    }
+    true
+}
@@ -0,0 +1,111 @@
+//!
+//! lyapunov.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Divergence of nearby trajectories. `separation` follows a reference and
+//! a perturbed trajectory side by side and reports their distance, which
+//! grows like d0 exp(lambda t) until it saturates at the attractor size.
+//! `largest_lyapunov` avoids the saturation the way Benettin et al. do:
+//! the perturbation is rescaled back to d0 at fixed intervals and the
+//! exponent is the mean of the logged growth factors
+//!
+
+#![allow(dead_code)]
+
+use solvers::{self, Limits, Method};
+
+#[derive(Clone, Copy, Debug)]
+pub struct LyapunovOptions {
+    /// initial and renormalized separation
+    pub d0: f64,
+    /// steps between renormalizations
+    pub every: usize,
+    /// time spent settling onto the attractor before any growth is logged
+    pub transient: f64,
+    /// the steps of both trajectories count against these
+    pub limits: Limits,
+}
+
+impl Default for LyapunovOptions {
+    fn default() -> LyapunovOptions {
+        LyapunovOptions { d0: 1e-8, every: 10, transient: 10.0, limits: Limits::default() }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LyapunovEstimate {
+    pub exponent: f64,
+    /// (t, running estimate) after each renormalization
+    pub history: Vec<(f64, f64)>,
+}
+
+fn distance<const N: usize>(a: &[f64; N], b: &[f64; N]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| (x - y) * (x - y)).sum::<f64>().sqrt()
+}
+
+///
+/// (t, |y(t) - z(t)|) for y from ic and z from ic + delta, both stepped by
+/// the same method on the same grid, the stored distances checked against
+/// limits first
+///
+pub fn separation<F, const N: usize>(rate: &F, method: Method, ic: [f64; N], delta: [f64; N], ts: [f64; 2], dt: f64, limits: Limits)
+    -> Result<Vec<(f64, f64)>, Box<dyn std::error::Error>>
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    let n = solvers::planned_steps("separation", ts, dt, limits)?;
+    limits.check::<1>("separation", n)?;
+    let mut guard = limits.start::<1>();
+    let (mut y, mut z) = (ic, std::array::from_fn(|i| ic[i] + delta[i]));
+    let mut out = Vec::with_capacity(n + 1);
+    out.push((ts[0], distance(&y, &z)));
+    for (t, h, next) in solvers::grid(ts, dt) {
+        guard.step("separation", t)?;
+        y = method.step(rate, t, &y, h);
+        z = method.step(rate, t, &z, h);
+        out.push((next, distance(&y, &z)));
+    }
+    Ok(out)
+}
+
+///
+/// Largest Lyapunov exponent by repeated renormalization of a single
+/// perturbation, started along the first state component after the
+/// transient
+///
+pub fn largest_lyapunov<F, const N: usize>(rate: &F, method: Method, ic: [f64; N], ts: [f64; 2], dt: f64, opts: LyapunovOptions)
+    -> Result<LyapunovEstimate, Box<dyn std::error::Error>>
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    if opts.d0 <= 0.0 || opts.every == 0 || ts[1] - ts[0] <= opts.transient {
+        return Err(format!("lyapunov: need d0 > 0, every > 0 and a span beyond the transient, got {opts:?} over {ts:?}").into());
+    }
+    solvers::planned_steps("lyapunov", ts, dt, opts.limits)?;
+    let mut guard = opts.limits.memory(usize::MAX).start::<N>();
+    let mut t = ts[0];
+    let mut y = ic;
+    while t < ts[0] + opts.transient {
+        guard.step("lyapunov transient", t)?;
+        y = method.step(rate, t, &y, dt);
+        t += dt;
+    }
+
+    let mut z = y;
+    z[0] += opts.d0;
+    let start = t;
+    let mut sum = 0.0;
+    let mut history = Vec::new();
+    while t + opts.every as f64 * dt <= ts[1] {
+        for _ in 0..opts.every {
+            guard.step("lyapunov", t)?;
+            y = method.step(rate, t, &y, dt);
+            z = method.step(rate, t, &z, dt);
+            t += dt;
+        }
+        let d = distance(&y, &z);
+        if !d.is_finite() || d == 0.0 {
+            return Err(format!("lyapunov: separation became {d} at t = {t}").into());
+        }
+        sum += (d / opts.d0).ln();
+        z = std::array::from_fn(|i| y[i] + (z[i] - y[i]) * opts.d0 / d);
+        history.push((t, sum / (t - start)));
+    }
+    let exponent = history.last().map(|h| h.1).ok_or("lyapunov: span too short for one renormalization")?;
+    Ok(LyapunovEstimate { exponent, history })
+}
@@ -0,0 +1,178 @@
+//!
+//! metapopulation.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! The two species competition model on M patches joined by migration.
+//! Patch i has its own parameters, and each species moves along the
+//! edges of a graph at its own rate m_s,
+//!
+//!   N_si' = N_si (a_si - b_si N_si - c_si N_ri) + m_s sum_j w_ij (N_sj - N_si)
+//!
+//! r the other species, so the migration term is -m_s L N_s with L the
+//! graph Laplacian. The state is (N_11, N_21, N_12, N_22, ..), 2M long.
+//!
+//! Whether a species absent from the region can invade the other's
+//! equilibrium N_r* is linear: its growth near zero is
+//! (diag(a_si - c_si N_ri*) - m_s L) N_s, and it invades when that
+//! matrix's rightmost eigenvalue is positive
+//!
+
+#![allow(dead_code)]
+
+use solvers::competition::{self, Competition};
+
+use crate::graph::Graph;
+use crate::linalg::Matrix;
+use crate::system::OdeSystem;
+
+///
+/// Patches and migration added by name. build() checks the graph has one
+/// node per patch, every parameter is finite, and populations cannot be
+/// fed by negative b, c or migration rates
+///
+#[derive(Clone, Debug)]
+pub struct MetapopulationBuilder {
+    patches: Vec<Competition>,
+    graph: Graph,
+    migration: [f64; 2],
+}
+
+impl MetapopulationBuilder {
+    pub fn patch(mut self, i: usize, params: Competition) -> MetapopulationBuilder {
+        if let Some(p) = self.patches.get_mut(i) {
+            *p = params;
+        }
+        self
+    }
+
+    ///
+    /// Every species moves along the edges of graph, species s at rates[s]
+    /// times the edge weight
+    ///
+    pub fn migration(mut self, graph: Graph, rates: [f64; 2]) -> MetapopulationBuilder {
+        self.graph = graph;
+        self.migration = rates;
+        self
+    }
+
+    pub fn build(self) -> Result<Metapopulation, Box<dyn std::error::Error>> {
+        let m = self.patches.len();
+        if m == 0 {
+            return Err("metapopulation: no patches".into());
+        }
+        if self.graph.nodes() != m {
+            return Err(format!("metapopulation: {m} patches but the migration graph has {} nodes", self.graph.nodes()).into());
+        }
+        self.graph.check()?;
+        for (i, p) in self.patches.iter().enumerate() {
+            if p.a.iter().chain(&p.b).chain(&p.c).any(|v| !v.is_finite()) {
+                return Err(format!("metapopulation: patch {i} has a non-finite parameter, {p:?}").into());
+            }
+            if p.b.iter().chain(&p.c).any(|v| *v < 0.0) {
+                return Err(format!("metapopulation: patch {i} has a negative b or c, {p:?}").into());
+            }
+        }
+        if self.migration.iter().any(|m| !m.is_finite() || *m < 0.0) {
+            return Err(format!("metapopulation: migration rates {:?} must be finite and non-negative", self.migration).into());
+        }
+        Ok(Metapopulation { patches: self.patches, graph: self.graph, migration: self.migration })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Metapopulation {
+    pub patches: Vec<Competition>,
+    pub graph: Graph,
+    /// per species, scaling every edge weight
+    pub migration: [f64; 2],
+}
+
+impl Metapopulation {
+    ///
+    /// count patches with the same parameters and no migration yet
+    ///
+    pub fn builder(count: usize, params: Competition) -> MetapopulationBuilder {
+        MetapopulationBuilder { patches: vec![params; count], graph: Graph::new(count), migration: [0.0; 2] }
+    }
+
+    pub fn patches(&self) -> usize {
+        self.patches.len()
+    }
+
+    pub fn states(&self) -> usize {
+        2 * self.patches()
+    }
+
+    ///
+    /// N1 and N2 of patch 1, then patch 2, .. as display names
+    ///
+    pub fn state_names(&self) -> Vec<String> {
+        (1..=self.patches()).flat_map(|i| [format!("N1 patch {i}"), format!("N2 patch {i}")]).collect()
+    }
+
+    ///
+    /// pop in every patch
+    ///
+    pub fn uniform<const N: usize>(&self, pop: [f64; 2]) -> [f64; N] {
+        core::array::from_fn(|k| pop[k % 2])
+    }
+
+    ///
+    /// Species s in patch i along a trajectory
+    ///
+    pub fn population<const N: usize>(&self, y: &[[f64; N]], i: usize, s: usize) -> Vec<f64> {
+        y.iter().map(|x| x[2 * i + s]).collect()
+    }
+
+    ///
+    /// Species s in each patch of one state
+    ///
+    pub fn profile(&self, x: &[f64], s: usize) -> Vec<f64> {
+        x.iter().skip(s).step_by(2).copied().collect()
+    }
+
+    ///
+    /// Regional total of each species
+    ///
+    pub fn totals(&self, x: &[f64]) -> [f64; 2] {
+        [0, 1].map(|s| self.profile(x, s).iter().sum())
+    }
+
+    ///
+    /// Rightmost eigenvalue of diag(a_si - c_si N_ri) - m_s L, the regional
+    /// growth rate of species s while rare against the other at resident,
+    /// one density per patch
+    ///
+    pub fn invasion_rate(&self, s: usize, resident: &[f64]) -> Result<f64, Box<dyn std::error::Error>> {
+        if resident.len() != self.patches() {
+            return Err(format!("metapopulation: {} resident densities for {} patches", resident.len(), self.patches()).into());
+        }
+        let mut j = Matrix::zeros(self.patches(), self.patches());
+        let l = self.graph.laplacian();
+        for (i, (p, n)) in self.patches.iter().zip(resident).enumerate() {
+            for k in 0..self.patches() {
+                j[(i, k)] = -self.migration[s] * l[(i, k)];
+            }
+            j[(i, i)] += p.a[s] - p.c[s] * n;
+        }
+        Ok(j.eigenvalues()?.iter().map(|(re, _)| *re).fold(f64::NEG_INFINITY, f64::max))
+    }
+}
+
+impl OdeSystem for Metapopulation {
+    fn dimension(&self) -> usize {
+        self.states()
+    }
+
+    fn rate(&self, _t: f64, y: &[f64], dy: &mut [f64]) {
+        for (i, p) in self.patches.iter().enumerate() {
+            let mut d = [0.0; 2];
+            competition::rate(&p.a, &p.b, &p.c, &[y[2 * i], y[2 * i + 1]], &mut d);
+            dy[2 * i..2 * i + 2].copy_from_slice(&d);
+        }
+        for (i, j, w) in self.graph.edges() {
+            for (s, m) in self.migration.iter().enumerate() {
+                dy[2 * i + s] += m * w * (y[2 * j + s] - y[2 * i + s]);
+            }
+        }
+    }
+}
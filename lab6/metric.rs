@@ -0,0 +1,130 @@
+//!
+//! metric.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Relative error of an approximate trajectory against a reference on the
+//! same time points. The norm is taken at the final state, as the maximum
+//! over time, or as the discrete L2 norm over time, either per component
+//! or over the whole state at once. Errors below the floor are raised to
+//! it, so an exact match still has a logarithm to plot
+//!
+
+#![allow(dead_code)]
+
+///
+/// (dt, errors) per step size of a convergence study
+///
+pub type Convergence = Vec<(f64, Vec<f64>)>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Norm {
+    /// |y(tf) - y_ref(tf)| / |y_ref(tf)|
+    Final,
+    /// max_t |y - y_ref| / max_t |y_ref|
+    Max,
+    /// (sum_t |y - y_ref|^2 / sum_t |y_ref|^2)^(1/2) on a uniform grid
+    L2,
+}
+
+impl Norm {
+    pub fn name(self) -> &'static str {
+        match self {
+            Norm::Final => "final state",
+            Norm::Max => "max over time",
+            Norm::L2 => "l2 over time",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Scope {
+    /// one error per component
+    PerComponent,
+    /// one error for the whole state, components pooled in the norm
+    Aggregate,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ErrorMetric {
+    pub norm: Norm,
+    pub scope: Scope,
+    pub floor: f64,
+}
+
+impl Default for ErrorMetric {
+    fn default() -> ErrorMetric {
+        ErrorMetric { norm: Norm::Final, scope: Scope::PerComponent, floor: 1e-16 }
+    }
+}
+
+impl ErrorMetric {
+    pub fn norm(mut self, norm: Norm) -> ErrorMetric {
+        self.norm = norm;
+        self
+    }
+
+    pub fn scope(mut self, scope: Scope) -> ErrorMetric {
+        self.scope = scope;
+        self
+    }
+
+    pub fn floor(mut self, floor: f64) -> ErrorMetric {
+        self.floor = floor;
+        self
+    }
+
+    ///
+    /// Series names for the errors relative returns, the components' own
+    /// names per component
+    ///
+    pub fn labels(&self, components: &[&str]) -> Vec<String> {
+        match self.scope {
+            Scope::PerComponent => components.iter().map(|c| c.to_string()).collect(),
+            Scope::Aggregate => vec!["all states".to_string()],
+        }
+    }
+
+    ///
+    /// Relative errors of y against reference, point for point, one per
+    /// component or a single aggregate. Empty input is an error
+    ///
+    pub fn relative<const N: usize>(&self, y: &[[f64; N]], reference: &[[f64; N]])
+        -> Result<Vec<f64>, Box<dyn std::error::Error>> {
+        let n = y.len().min(reference.len());
+        if n == 0 {
+            return Err("no points to measure an error over".into());
+        }
+        let points = match self.norm {
+            Norm::Final => n - 1..n,
+            Norm::Max | Norm::L2 => 0..n,
+        };
+        // numerator and denominator sums, per component
+        let (mut num, mut den) = ([0.0_f64; N], [0.0_f64; N]);
+        for i in points {
+            for j in 0..N {
+                let (d, r) = ((y[i][j] - reference[i][j]).abs(), reference[i][j].abs());
+                match self.norm {
+                    Norm::Max => {
+                        num[j] = num[j].max(d);
+                        den[j] = den[j].max(r);
+                    }
+                    Norm::Final | Norm::L2 => {
+                        num[j] += d * d;
+                        den[j] += r * r;
+                    }
+                }
+            }
+        }
+        let pool = |v: &[f64; N]| match self.norm {
+            Norm::Max => v.iter().copied().fold(0.0, f64::max),
+            Norm::Final | Norm::L2 => v.iter().sum(),
+        };
+        let ratio = |a: f64, b: f64| {
+            let e = if self.norm == Norm::Max { a / b } else { (a / b).sqrt() };
+            if e.is_nan() { self.floor } else { e.max(self.floor) }
+        };
+        Ok(match self.scope {
+            Scope::PerComponent => (0..N).map(|j| ratio(num[j], den[j])).collect(),
+            Scope::Aggregate => vec![ratio(pool(&num), pool(&den))],
+        })
+    }
+}
@@ -0,0 +1,67 @@
+//!
+//! modified.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Backward error analysis of the low order one-step methods. A method of
+//! order p run at step h follows, far more closely than the ODE it was
+//! given, the solution of a modified equation
+//!
+//!     y' = f(t, y) + h^p f_{p+1}(t, y) + O(h^(p+1))
+//!
+//! For Euler f_2 = -(f_t + J f) / 2, half the solution's curvature taken
+//! away, which is why Euler spirals out of a centre. The leading term is
+//! found here for any system without differentiating f by hand: one step
+//! of the method from (t, y) is set against the exact flow over the same
+//! step, their gap is h^(p+1) f_{p+1} to leading order, and one Richardson
+//! halving removes the next term. The exact flow is rk4 on 16 substeps
+//!
+
+#![allow(dead_code)]
+
+use solvers::Method;
+
+/// rk4 substeps standing in for the exact flow over one step
+const SUBSTEPS: usize = 16;
+
+fn flow<F, const N: usize>(rate: &F, t: f64, y: &[f64; N], h: f64) -> [f64; N]
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    let dt = h / SUBSTEPS as f64;
+    (0..SUBSTEPS).fold(*y, |z, k| Method::Rk4.step(rate, t + (k as f64) * dt, &z, dt))
+}
+
+///
+/// (method step - exact flow) / h^(p+1) from (t, y)
+///
+fn gap<F, const N: usize>(method: Method, rate: &F, t: f64, y: &[f64; N], h: f64) -> [f64; N]
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    let (step, exact) = (method.step(rate, t, y, h), flow(rate, t, y, h));
+    let scale = h.powi(method.order() as i32 + 1);
+    std::array::from_fn(|i| (step[i] - exact[i]) / scale)
+}
+
+///
+/// Leading term f_{p+1}(t, y) of method's modified equation, from steps
+/// of h and h / 2. Only meaningful for methods of order below 4, where
+/// the rk4 stand-in for the exact flow is the more accurate of the two
+///
+pub fn leading_term<F, const N: usize>(method: Method, rate: &F, t: f64, y: &[f64; N], h: f64) -> [f64; N]
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    let (coarse, fine) = (gap(method, rate, t, y, h), gap(method, rate, t, y, 0.5 * h));
+    std::array::from_fn(|i| 2.0 * fine[i] - coarse[i])
+}
+
+///
+/// Rate of the modified equation truncated after its leading term,
+/// f + h^p f_{p+1}, for method run at step h. Solved accurately, it
+/// tracks the method's own points rather than the true solution
+///
+pub fn modified_rate<F, const N: usize>(method: Method, rate: &F, h: f64) -> impl Fn(f64, &[f64; N], &mut [f64; N]) + '_
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    let weight = h.powi(method.order() as i32);
+    move |t: f64, y: &[f64; N], dy: &mut [f64; N]| {
+        rate(t, y, dy);
+        let term = leading_term(method, rate, t, y, h);
+        for (d, c) in dy.iter_mut().zip(term) {
+            *d += weight * c;
+        }
+    }
+}
@@ -0,0 +1,204 @@
+//!
+//! multirate.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Slowest-first multirate rk4. A partition marks which components of the
+//! state are fast. Each macro step H advances the whole system by one rk4
+//! step and keeps only its slow components; the fast components are then
+//! re-integrated with m rk4 micro steps of H / m, reading the slow ones
+//! from the cubic Hermite interpolant between the macro endpoints, so the
+//! coupling is carried at the accuracy of the macro step. That step still
+//! has to be stable and accurate for the slow components, which bounds the
+//! error from below however many micro steps the fast ones take
+//!
+
+#![allow(dead_code)]
+
+use solvers::{self, rk4_step, Limits, Solution, SolveStats};
+
+use crate::system::OdeSystem;
+
+///
+/// Which components of the state are fast
+///
+#[derive(Clone, Copy, Debug)]
+pub struct Partition<const N: usize> {
+    pub fast: [bool; N],
+}
+
+impl<const N: usize> Partition<N> {
+    pub fn new(fast: &[usize]) -> Result<Partition<N>, Box<dyn std::error::Error>> {
+        let mut mask = [false; N];
+        for &i in fast {
+            if i >= N {
+                return Err(format!("fast component {i} out of range for a {N} component state").into());
+            }
+            mask[i] = true;
+        }
+        Ok(Partition { fast: mask })
+    }
+
+    pub fn fast_count(&self) -> usize {
+        self.fast.iter().filter(|f| **f).count()
+    }
+
+    pub fn slow_count(&self) -> usize {
+        N - self.fast_count()
+    }
+}
+
+///
+/// The shared SolveStats, macro steps and full rate calls, and the work in
+/// component evaluations, the cost a rate that evaluated only the
+/// requested components would pay
+///
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MultirateStats {
+    pub solve: SolveStats,
+    pub slow_evals: usize,
+    pub fast_evals: usize,
+}
+
+impl MultirateStats {
+    pub fn component_evals(&self) -> usize {
+        self.slow_evals + self.fast_evals
+    }
+}
+
+///
+/// Cubic Hermite on [0, H] from values and slopes at both ends
+///
+fn hermite(y0: f64, d0: f64, y1: f64, d1: f64, big_h: f64, s: f64) -> f64 {
+    let u = s / big_h;
+    let h00 = (1.0 + 2.0 * u) * (1.0 - u) * (1.0 - u);
+    let h10 = u * (1.0 - u) * (1.0 - u);
+    let h01 = u * u * (3.0 - 2.0 * u);
+    let h11 = u * u * (u - 1.0);
+    h00 * y0 + h10 * big_h * d0 + h01 * y1 + h11 * big_h * d1
+}
+
+///
+/// One macro step of H with m fast micro steps. H must be a positive step
+/// and m at least one
+///
+pub fn step<F, const N: usize>(rate: &F, part: &Partition<N>, t: f64, y: &[f64; N], big_h: f64, m: usize, stats: &mut MultirateStats)
+    -> Result<[f64; N], Box<dyn std::error::Error>>
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    check(big_h, m)?;
+    let (nf, ns) = (part.fast_count(), part.slow_count());
+
+    // slow components from one macro rk4 step, slopes at both ends
+    let predicted = rk4_step(rate, t, y, big_h);
+    let (mut d0, mut d1) = ([0.0; N], [0.0; N]);
+    rate(t, y, &mut d0);
+    rate(t + big_h, &predicted, &mut d1);
+    stats.slow_evals += 6 * ns;
+    stats.solve.rate_evals += 6;
+
+    let slow_at = |s: f64, fast: &[f64; N]| -> [f64; N] {
+        std::array::from_fn(|i| if part.fast[i] { fast[i] } else { hermite(y[i], d0[i], predicted[i], d1[i], big_h, s) })
+    };
+
+    // fast micro steps with the slow components interpolated at each stage
+    let h = big_h / m as f64;
+    let mut z = *y;
+    for k in 0..m {
+        let s = k as f64 * h;
+        let stage = |ds: f64, base: &[f64; N], slope: &[f64; N], scale: f64| -> [f64; N] {
+            let mut p: [f64; N] = std::array::from_fn(|i| base[i] + scale * slope[i]);
+            p = slow_at(s + ds, &p);
+            let mut d = [0.0; N];
+            rate(t + s + ds, &p, &mut d);
+            d
+        };
+        let k1 = stage(0.0, &z, &[0.0; N], 0.0);
+        let k2 = stage(0.5 * h, &z, &k1, 0.5 * h);
+        let k3 = stage(0.5 * h, &z, &k2, 0.5 * h);
+        let k4 = stage(h, &z, &k3, h);
+        for i in 0..N {
+            if part.fast[i] {
+                z[i] += (h / 6.0) * (k1[i] + 2.0 * k2[i] + 2.0 * k3[i] + k4[i]);
+            }
+        }
+        stats.fast_evals += 4 * nf;
+        stats.solve.rate_evals += 4;
+    }
+
+    stats.solve.accepted += 1;
+    Ok(std::array::from_fn(|i| if part.fast[i] { z[i] } else { predicted[i] }))
+}
+
+fn check(big_h: f64, m: usize) -> Result<(), Box<dyn std::error::Error>> {
+    if !(big_h.is_finite() && big_h > 0.0) {
+        return Err(format!("multirate: H must be finite and positive, got {big_h}").into());
+    }
+    if m == 0 {
+        return Err("multirate: m must be at least one micro step per macro step".into());
+    }
+    Ok(())
+}
+
+///
+/// Fixed macro step integration over ts, solution at the macro points.
+/// When H does not divide the span a shortened last macro step, still of
+/// m micro steps, lands on ts[1]. The micro steps and the stored macro
+/// points are checked against limits before the first step
+///
+pub fn solve<F, const N: usize>(rate: &F, part: &Partition<N>, ic: [f64; N], ts: [f64; 2], big_h: f64, m: usize, limits: Limits)
+    -> Result<(Solution<N>, MultirateStats), Box<dyn std::error::Error>>
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    check(big_h, m)?;
+    let n = solvers::planned_steps("multirate", ts, big_h, limits)?;
+    limits.check::<N>("multirate", n)?;
+    limits.check_steps("multirate", n.saturating_mul(m))?;
+    let mut guard = limits.start::<N>();
+    let mut stats = MultirateStats::default();
+    let mut t = Vec::with_capacity(n + 1);
+    let mut y = Vec::with_capacity(n + 1);
+    t.push(ts[0]);
+    y.push(ic);
+    for (k, (tk, h, next)) in solvers::grid(ts, big_h).enumerate() {
+        guard.step("multirate", tk)?;
+        let z = step(rate, part, tk, &y[k], h, m, &mut stats)?;
+        t.push(next);
+        y.push(z);
+    }
+    Ok(((t, y), stats))
+}
+
+///
+/// solve on a system, its size checked against the state
+///
+pub fn solve_system<const N: usize>(
+    system: &impl OdeSystem, part: &Partition<N>, ic: [f64; N], ts: [f64; 2], big_h: f64, m: usize, limits: Limits)
+    -> Result<(Solution<N>, MultirateStats), Box<dyn std::error::Error>> {
+    solve(&system.rhs::<N>()?, part, ic, ts, big_h, m, limits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decay(_t: f64, y: &[f64; 2], dy: &mut [f64; 2]) {
+        dy[0] = -y[0];
+        dy[1] = -10.0 * y[1];
+    }
+
+    #[test]
+    fn refuses_no_micro_steps_and_a_bad_macro_step() {
+        let part = Partition::<2>::new(&[1]).unwrap();
+        assert!(solve(&decay, &part, [1.0, 1.0], [0.0, 1.0], 0.1, 0, Limits::default()).is_err());
+        for big_h in [0.0, -0.1, f64::NAN] {
+            assert!(solve(&decay, &part, [1.0, 1.0], [0.0, 1.0], big_h, 4, Limits::default()).is_err());
+        }
+        let mut stats = MultirateStats::default();
+        assert!(step(&decay, &part, 0.0, &[1.0, 1.0], 0.1, 0, &mut stats).is_err());
+    }
+
+    #[test]
+    fn ends_on_tf_when_h_does_not_divide_the_span() {
+        let part = Partition::<2>::new(&[1]).unwrap();
+        let ((t, y), _) = solve(&decay, &part, [1.0, 1.0], [0.0, 1.0], 0.3, 4, Limits::default()).unwrap();
+        assert_eq!(t.last(), Some(&1.0));
+        assert!((y.last().unwrap()[0] - (-1.0_f64).exp()).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,259 @@
+//!
+//! multistep.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! AB4 predictor, AM4 corrector in PECE mode as a resumable stepper,
+//! carrying its history as the order 4 Nordsieck array. The array is
+//! seeded from the rates at four grid points; a fresh start makes them
+//! with three rk4 steps, but when a run is resumed or its dt changes the
+//! trajectory already covers the points the new history sits on.
+//! restart_from rebuilds the history there from the trajectory's cubic
+//! Hermite dense output instead of bootstrapping again with rk4 from a
+//! single point
+//!
+//! The fixed-step AB/AM4 itself, solve_with_mode with its startups and
+//! evaluation modes, lives in the solvers crate and is re-exported here
+//!
+
+#![allow(dead_code)]
+
+pub use solvers::multistep::{solve_with_mode, Mode, Startup};
+
+use solvers::nordsieck::{adams_l, Nordsieck};
+use solvers::{final_step, rk4_step, step_count, CorrectorFailure, SolveStats};
+
+use crate::trajectory::Trajectory;
+
+///
+/// Stepper state: the dt grid from t0 and the Nordsieck array at the
+/// current point, with the AM4 l vector it corrects by, what to do when
+/// the corrector fails (Abort unless set) and the work of the steps taken
+///
+#[derive(Clone, Debug)]
+pub struct Abm4<const N: usize> {
+    pub t0: f64,
+    pub dt: f64,
+    /// steps taken since t0
+    pub steps: usize,
+    pub on_failure: CorrectorFailure,
+    pub stats: SolveStats,
+    nd: Nordsieck<N>,
+    l: Vec<f64>,
+}
+
+fn eval<F, const N: usize>(rate: &F, t: f64, y: &[f64; N]) -> [f64; N]
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    let mut d = [0.0; N];
+    rate(t, y, &mut d);
+    d
+}
+
+///
+/// Cubic Hermite interpolant of traj at s, from the states and rates at
+/// the two points around it. Fourth order accurate for an rk4 or AB/AM4
+/// trajectory, matching the methods it restarts. At a repeated time (an
+/// impulse) the later state is used
+///
+pub fn dense<F, const N: usize>(rate: &F, traj: &Trajectory<N>, s: f64) -> Result<[f64; N], Box<dyn std::error::Error>>
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    let t = traj.time();
+    if t.is_empty() || s < t[0] || s > t[t.len() - 1] {
+        return Err(format!("t = {s} is outside the trajectory's span").into());
+    }
+    // t[i] <= s < t[i + 1], or s is the last time
+    let i = t.partition_point(|ti| *ti <= s);
+    if i == t.len() {
+        return Ok(traj.row(i - 1));
+    }
+    let i = i - 1;
+    if s == t[i] {
+        return Ok(traj.row(i));
+    }
+    let h = t[i + 1] - t[i];
+    let (y0, y1) = (traj.row(i), traj.row(i + 1));
+    let (f0, f1) = (eval(rate, t[i], &y0), eval(rate, t[i + 1], &y1));
+    let u = (s - t[i]) / h;
+    let (h00, h10) = ((1.0 + 2.0 * u) * (1.0 - u) * (1.0 - u), u * (1.0 - u) * (1.0 - u));
+    let (h01, h11) = (u * u * (3.0 - 2.0 * u), u * u * (u - 1.0));
+    Ok(std::array::from_fn(|j| h00 * y0[j] + h * h10 * f0[j] + h01 * y1[j] + h * h11 * f1[j]))
+}
+
+impl<const N: usize> Abm4<N> {
+    ///
+    /// Fresh start at (t0, ic): three rk4 steps fill the history. Every
+    /// point from ic on is handed to observe
+    ///
+    pub fn start<F, O>(rate: &F, ic: [f64; N], t0: f64, dt: f64, mut observe: O) -> Abm4<N>
+    where
+        F: Fn(f64, &[f64; N], &mut [f64; N]),
+        O: FnMut(f64, &[f64; N]),
+    {
+        let mut f = [[0.0; N]; 4];
+        let mut y = ic;
+        observe(t0, &y);
+        f[0] = eval(rate, t0, &y);
+        for (k, fk) in f.iter_mut().enumerate().skip(1) {
+            y = rk4_step(rate, t0 + ((k - 1) as f64) * dt, &y, dt);
+            observe(t0 + (k as f64) * dt, &y);
+            *fk = eval(rate, t0 + (k as f64) * dt, &y);
+        }
+        let nd = Nordsieck::from_uniform_history(t0 + 3.0 * dt, dt, y, &f);
+        Abm4::new(t0, dt, 3, nd)
+    }
+
+    ///
+    /// Resumes from traj at time t with step dt, which need not be the dt
+    /// traj was made with. The history at t - 3 dt .. t comes from the
+    /// dense output, so t - 3 dt must lie inside traj. The stepper's first
+    /// point is t itself, not reported again by advance
+    ///
+    pub fn restart_from<F>(rate: &F, traj: &Trajectory<N>, t: f64, dt: f64) -> Result<Abm4<N>, Box<dyn std::error::Error>>
+    where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+        if traj.is_empty() || t - 3.0 * dt < traj.time()[0] {
+            return Err(format!("restart at t = {t} with dt = {dt} needs the trajectory from t = {}", t - 3.0 * dt).into());
+        }
+        let mut f = [[0.0; N]; 4];
+        let mut y = [0.0; N];
+        for (k, fk) in f.iter_mut().enumerate() {
+            let s = t - ((3 - k) as f64) * dt;
+            y = dense(rate, traj, s)?;
+            *fk = eval(rate, s, &y);
+        }
+        let nd = Nordsieck::from_uniform_history(t, dt, y, &f);
+        Ok(Abm4::new(t, dt, 0, nd))
+    }
+
+    fn new(t0: f64, dt: f64, steps: usize, nd: Nordsieck<N>) -> Abm4<N> {
+        let on_failure = CorrectorFailure::default();
+        Abm4 { t0, dt, steps, on_failure, stats: SolveStats { on_failure, ..SolveStats::default() }, nd, l: adams_l(4) }
+    }
+
+    pub fn t(&self) -> f64 {
+        self.t0 + (self.steps as f64) * self.dt
+    }
+
+    pub fn y(&self) -> [f64; N] {
+        self.nd.value()
+    }
+
+    ///
+    /// One PECE step along the grid, a corrector failure handled by
+    /// on_failure
+    ///
+    pub fn step<F>(&mut self, rate: &F) -> Result<(), Box<dyn std::error::Error>>
+    where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+        self.nd.t = self.t();
+        self.stats.on_failure = self.on_failure;
+        self.nd.adams_step_with(rate, &self.l, 1, true, self.on_failure, &mut self.stats)?;
+        self.stats.accepted += 1;
+        self.steps += 1;
+        Ok(())
+    }
+
+    ///
+    /// Steps on to tf, handing each new point to observe. A tf off the grid
+    /// is reached by a copy of the array rescaled onto the remainder, whose
+    /// step is observed but not kept: the stepper stays on the last grid
+    /// point before tf. Nothing happens for a tf already behind
+    ///
+    pub fn advance<F, O>(&mut self, rate: &F, tf: f64, mut observe: O) -> Result<(), Box<dyn std::error::Error>>
+    where
+        F: Fn(f64, &[f64; N], &mut [f64; N]),
+        O: FnMut(f64, &[f64; N]),
+    {
+        let n = step_count([self.t0, tf], self.dt);
+        if self.steps > n {
+            return Ok(());
+        }
+        while self.steps < n {
+            self.step(rate)?;
+            observe(self.t(), &self.y());
+        }
+        if let Some(h) = final_step([self.t0, tf], self.dt) {
+            let mut last = self.nd.clone();
+            last.t = self.t();
+            last.rescale(h);
+            last.adams_step_with(rate, &self.l, 1, true, self.on_failure, &mut self.stats)?;
+            observe(tf, &last.value());
+        }
+        Ok(())
+    }
+
+    ///
+    /// Solves over ts from a fresh start, returning the trajectory and the
+    /// stepper to resume it from. A span under three steps still starts
+    /// the stepper at ts[0] + 3 dt, but the trajectory stops at ts[1]
+    ///
+    pub fn solve<F>(rate: &F, ic: [f64; N], ts: [f64; 2], dt: f64) -> Result<(Trajectory<N>, Abm4<N>), Box<dyn std::error::Error>>
+    where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+        let n = step_count(ts, dt);
+        let mut traj = Trajectory::with_capacity(n + 2);
+        let mut stepper = Abm4::start(rate, ic, ts[0], dt, |t, y| {
+            if traj.len() <= n {
+                traj.push(t, y);
+            }
+        });
+        if n < 3 {
+            if let (Some(h), Some(y)) = (final_step(ts, dt), traj.last()) {
+                traj.push(ts[1], &rk4_step(rate, ts[0] + (n as f64) * dt, &y, h));
+            }
+        } else {
+            stepper.advance(rate, ts[1], |t, y| traj.push(t, y))?;
+        }
+        Ok((traj, stepper))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decay(_t: f64, y: &[f64; 1], dy: &mut [f64; 1]) {
+        dy[0] = -y[0];
+    }
+
+    #[test]
+    fn advance_off_the_grid_ends_on_tf() {
+        let mut last = None;
+        let (_, mut stepper) = Abm4::solve(&decay, [1.0], [0.0, 0.5], 0.1).unwrap();
+        stepper.advance(&decay, 1.05, |t, y| last = Some((t, y[0]))).unwrap();
+        let (t, y) = last.unwrap();
+        assert_eq!(t, 1.05);
+        assert!((y - (-1.05_f64).exp()).abs() < 1e-5);
+        assert!((stepper.t() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn stepper_matches_solve_with_mode() {
+        let (traj, stepper) = Abm4::solve(&decay, [1.0], [0.0, 2.0], 0.01).unwrap();
+        assert_eq!(stepper.stats.corrector_failures, 0);
+        let ((_, y), _) = solve_with_mode(&decay, [1.0], [0.0, 2.0], 0.01, Startup::Rk4, Mode::Pece(1), solvers::CorrectorFailure::Abort, solvers::Limits::default()).unwrap();
+        assert_eq!(traj.len(), y.len());
+        for (k, want) in y.iter().enumerate() {
+            assert!((traj.row(k)[0] - want[0]).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    fn stepper_follows_its_failure_policy() {
+        for on_failure in CorrectorFailure::ALL {
+            let failed = std::cell::Cell::new(false);
+            let rate = |t: f64, y: &[f64; 1], dy: &mut [f64; 1]| {
+                dy[0] = if t > 0.5 && !failed.replace(true) { f64::NAN } else { -y[0] };
+            };
+            let mut stepper = Abm4::start(&rate, [1.0], 0.0, 0.1, |_, _| {});
+            stepper.on_failure = on_failure;
+            let run = stepper.advance(&rate, 1.0, |_, _| {});
+            assert_eq!(run.is_err(), on_failure == CorrectorFailure::Abort);
+            if run.is_ok() {
+                assert_eq!((stepper.stats.corrector_failures, stepper.stats.rejected), (1, 1));
+                assert!((stepper.y()[0] - (-1.0_f64).exp()).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn span_under_three_steps_does_not_overshoot() {
+        let (traj, _) = Abm4::solve(&decay, [1.0], [0.0, 0.15], 0.1).unwrap();
+        assert_eq!(traj.time(), [0.0, 0.1, 0.15]);
+    }
+}
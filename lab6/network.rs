@@ -0,0 +1,249 @@
+//!
+//! network.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Networks of semiconductor oscillators. Unit i has its own alpha and the
+//! weighted edges (i, j, k) pull it toward its neighbors through either
+//! the voltage or its rate,
+//!
+//!   V_i'' = a_i V_i' - V_i'^3 - V_i + sum_j k_ij (V_j - V_i)
+//!   V_i'' = a_i V_i' - V_i'^3 - V_i + sum_j k_ij (V_j' - V_i')
+//!
+//! Near the harmonic limit the first is mostly reactive (it shifts the
+//! frequencies more than it aligns the phases) and the second dissipative,
+//! which is what locks a spread of alphas together.
+//!
+//! The coupling term is -k L applied to the coupled component, L the
+//! Laplacian of the network's graph, so its algebraic connectivity says
+//! how strongly the topology pulls the units together.
+//!
+//! The state is (V_1, V_1', V_2, V_2', ..). As with circuit, the size is
+//! known only at run time; the network is an OdeSystem, so rhs() adapts
+//! its rate to a fixed size state after check_size
+//!
+
+#![allow(dead_code)]
+
+use crate::graph::Graph;
+use crate::noise::Noise;
+use crate::system::OdeSystem;
+
+///
+/// The state a coupling acts through
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Coupling {
+    #[default]
+    Voltage,
+    Rate,
+}
+
+impl Coupling {
+    pub const ALL: [Coupling; 2] = [Coupling::Voltage, Coupling::Rate];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Coupling::Voltage => "voltage",
+            Coupling::Rate => "dV/dt",
+        }
+    }
+
+    ///
+    /// Offset of the coupled component within a unit's pair
+    ///
+    fn offset(&self) -> usize {
+        match self {
+            Coupling::Voltage => 0,
+            Coupling::Rate => 1,
+        }
+    }
+}
+
+///
+/// Units and edges added by name. build() checks unit indices, finite
+/// alphas and couplings, and that no unit is coupled to itself
+///
+#[derive(Clone, Debug, Default)]
+pub struct NetworkBuilder {
+    alphas: Vec<f64>,
+    edges: Vec<(usize, usize, f64)>,
+    coupling: Coupling,
+}
+
+impl NetworkBuilder {
+    pub fn alpha(mut self, i: usize, alpha: f64) -> NetworkBuilder {
+        if let Some(a) = self.alphas.get_mut(i) {
+            *a = alpha;
+        }
+        self
+    }
+
+    pub fn through(mut self, coupling: Coupling) -> NetworkBuilder {
+        self.coupling = coupling;
+        self
+    }
+
+    ///
+    /// alpha of every unit, spread evenly over [lo, hi]
+    ///
+    pub fn alphas_between(mut self, lo: f64, hi: f64) -> NetworkBuilder {
+        let n = self.alphas.len();
+        for (i, a) in self.alphas.iter_mut().enumerate() {
+            *a = if n > 1 { lo + (hi - lo) * i as f64 / (n - 1) as f64 } else { lo };
+        }
+        self
+    }
+
+    ///
+    /// One way coupling: j pulls i with strength k
+    ///
+    pub fn directed(mut self, i: usize, j: usize, k: f64) -> NetworkBuilder {
+        self.edges.push((i, j, k));
+        self
+    }
+
+    ///
+    /// Symmetric coupling of i and j
+    ///
+    pub fn edge(self, i: usize, j: usize, k: f64) -> NetworkBuilder {
+        self.directed(i, j, k).directed(j, i, k)
+    }
+
+    ///
+    /// Every edge of graph, its weight scaled by k. The graph's nodes are
+    /// the units
+    ///
+    pub fn graph(self, graph: &Graph, k: f64) -> NetworkBuilder {
+        graph.edges().fold(self, |b, (i, j, w)| b.directed(i, j, k * w))
+    }
+
+    ///
+    /// Each unit to its two neighbors on a ring
+    ///
+    pub fn ring(self, k: f64) -> NetworkBuilder {
+        let n = self.alphas.len();
+        self.graph(&Graph::ring(n, 1.0), k)
+    }
+
+    ///
+    /// Every pair, at k / units so the total pull on a unit does not grow
+    /// with the network (mean field)
+    ///
+    pub fn all_to_all(self, k: f64) -> NetworkBuilder {
+        let n = self.alphas.len();
+        self.graph(&Graph::complete(n, 1.0 / n as f64), k)
+    }
+
+    ///
+    /// Each pair joined with probability p, Erdos-Renyi, reproducible from
+    /// the seed
+    ///
+    pub fn random(self, p: f64, k: f64, seed: u64) -> NetworkBuilder {
+        let n = self.alphas.len();
+        self.graph(&Graph::random(n, p, 1.0, seed), k)
+    }
+
+    pub fn build(self) -> Result<Network, Box<dyn std::error::Error>> {
+        let n = self.alphas.len();
+        if n == 0 {
+            return Err("network: no units".into());
+        }
+        if let Some(i) = self.alphas.iter().position(|a| !a.is_finite()) {
+            return Err(format!("network: unit {i} has alpha {}", self.alphas[i]).into());
+        }
+        for (e, (i, j, k)) in self.edges.iter().enumerate() {
+            if *i >= n || *j >= n || i == j {
+                return Err(format!("network: edge {e} joins units {i} and {j}, need two distinct units in 0..{n}").into());
+            }
+            if !k.is_finite() {
+                return Err(format!("network: edge {e} has coupling {k}").into());
+            }
+        }
+        Ok(Network { alphas: self.alphas, edges: self.edges, coupling: self.coupling })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Network {
+    pub alphas: Vec<f64>,
+    /// (i, j, k): j pulls i with strength k
+    pub edges: Vec<(usize, usize, f64)>,
+    pub coupling: Coupling,
+}
+
+impl Network {
+    ///
+    /// units oscillators at alpha 0, voltage coupled, and no edges yet
+    ///
+    pub fn builder(units: usize) -> NetworkBuilder {
+        NetworkBuilder { alphas: vec![0.0; units], edges: Vec::new(), coupling: Coupling::Voltage }
+    }
+
+    pub fn units(&self) -> usize {
+        self.alphas.len()
+    }
+
+    pub fn states(&self) -> usize {
+        2 * self.units()
+    }
+
+    ///
+    /// V1, dV1/dt, V2, .. as display names
+    ///
+    pub fn state_names(&self) -> Vec<String> {
+        (1..=self.units()).flat_map(|i| [format!("V{i}"), format!("dV{i}/dt")]).collect()
+    }
+
+    ///
+    /// The units as nodes and the couplings as weights, for the Laplacian
+    ///
+    pub fn graph(&self) -> Graph {
+        self.edges.iter().fold(Graph::new(self.units()), |g, (i, j, k)| g.directed(*i, *j, *k))
+    }
+
+    ///
+    /// Sum of the coupling strengths pulling each unit
+    ///
+    pub fn degrees(&self) -> Vec<f64> {
+        self.graph().degrees()
+    }
+
+    ///
+    /// Every unit on a circle of the given radius in its phase plane, at
+    /// phases drawn uniformly from the seed
+    ///
+    pub fn scattered<const N: usize>(&self, radius: f64, seed: u64) -> [f64; N] {
+        let mut noise = Noise::new(seed);
+        let mut x = [0.0; N];
+        for pair in x.chunks_exact_mut(2) {
+            let theta = 2.0 * std::f64::consts::PI * noise.uniform();
+            pair[0] = radius * theta.cos();
+            pair[1] = -radius * theta.sin();
+        }
+        x
+    }
+
+    ///
+    /// Voltage of unit i along a trajectory
+    ///
+    pub fn voltage<const N: usize>(&self, y: &[[f64; N]], i: usize) -> Vec<f64> {
+        y.iter().map(|x| x[2 * i]).collect()
+    }
+}
+
+impl OdeSystem for Network {
+    fn dimension(&self) -> usize {
+        self.states()
+    }
+
+    fn rate(&self, _t: f64, x: &[f64], dx: &mut [f64]) {
+        for (i, a) in self.alphas.iter().enumerate() {
+            let (v, dv) = (x[2 * i], x[2 * i + 1]);
+            dx[2 * i] = dv;
+            dx[2 * i + 1] = a * dv - dv.powi(3) - v;
+        }
+        let c = self.coupling.offset();
+        for (i, j, k) in &self.edges {
+            dx[2 * i + 1] += k * (x[2 * j + c] - x[2 * i + c]);
+        }
+    }
+}
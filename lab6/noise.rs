@@ -0,0 +1,61 @@
+//!
+//! noise.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Reproducible pseudo-random numbers for synthetic measurements: xorshift64*
+//! for uniforms and Box-Muller for normals. Not for anything statistical
+//! beyond adding noise to test data, but the same seed always gives the
+//! same sequence on every platform
+//!
+
+#![allow(dead_code)]
+
+#[derive(Clone, Debug)]
+pub struct Noise {
+    state: u64,
+    /// second Box-Muller normal, kept for the next call
+    spare: Option<f64>,
+}
+
+impl Noise {
+    pub fn new(seed: u64) -> Noise {
+        // zero is a fixed point of xorshift
+        Noise { state: seed.max(1), spare: None }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.state = x;
+        x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    ///
+    /// Uniform on (0, 1)
+    ///
+    pub fn uniform(&mut self) -> f64 {
+        ((self.next_u64() >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+    }
+
+    ///
+    /// Standard normal
+    ///
+    pub fn normal(&mut self) -> f64 {
+        if let Some(z) = self.spare.take() {
+            return z;
+        }
+        let (u, v) = (self.uniform(), self.uniform());
+        let r = (-2.0 * u.ln()).sqrt();
+        let theta = 2.0 * std::f64::consts::PI * v;
+        self.spare = Some(r * theta.sin());
+        r * theta.cos()
+    }
+
+    ///
+    /// y (1 + sigma z) for each entry, multiplicative measurement noise
+    ///
+    pub fn relative(&mut self, y: &[f64], sigma: f64) -> Vec<f64> {
+        y.iter().map(|v| v * (1.0 + sigma * self.normal())).collect()
+    }
+}
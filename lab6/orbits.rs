@@ -0,0 +1,121 @@
+//!
+//! orbits.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Long-term behavior of the two-body problem under a symplectic and a
+//! non-symplectic integrator. rk4 is far more accurate per step, but its
+//! energy error grows steadily and the orbit decays; leapfrog keeps the
+//! energy error bounded and oscillating for all time and conserves the
+//! angular momentum of a central force to roundoff
+//!
+
+#![allow(clippy::cast_possible_truncation)]
+#![allow(clippy::cast_sign_loss)]
+#![allow(clippy::cast_precision_loss)]
+#![allow(clippy::missing_panics_doc)]
+#![allow(clippy::missing_errors_doc)]
+
+use plotters::prelude::*;
+use plotters_bitmap::BitMapBackend;
+use solvers::{integrate_with, leapfrog_step, rk4_step, Limits};
+
+mod invariants;
+mod report;
+mod run_context;
+mod state;
+mod theme;
+mod two_body;
+
+use invariants::Monitor;
+use run_context::RunContext;
+use two_body::TwoBody;
+
+///
+/// An eccentric orbit for `periods` revolutions at `per_period` steps per
+/// revolution with rk4 and leapfrog. Energy, angular momentum and the
+/// eccentricity (the Runge-Lenz vector's length) are monitored through the
+/// observer; the energy drift history is plotted on a log scale
+///
+fn orbit_study(ctx: &mut RunContext, e: f64, periods: usize, per_period: usize, name: &str)
+    -> Result<(), Box<dyn std::error::Error>> {
+    let body = TwoBody { mu: 1.0 };
+    let a = 1.0;
+    let ic = body.periapsis(a, e)?;
+    let tf = periods as f64 * body.period(a);
+    let dt = body.period(a) / per_period as f64;
+    ctx.config("orbit", format!("mu = {}, a = {a}, e = {e}", body.mu));
+    ctx.config("periods", periods);
+    ctx.config("dt", dt);
+
+    let rate = |t: f64, s: &[f64; 4], ds: &mut [f64; 4]| body.rate(t, s, ds);
+    let monitor = || Monitor::new(per_period / 4)
+        .with("energy", move |s: &[f64; 4]| body.energy(s))
+        .with("angular momentum", TwoBody::angular_momentum)
+        .with("eccentricity", move |s: &[f64; 4]| body.eccentricity(s)[0].hypot(body.eccentricity(s)[1]));
+
+    let mut curves = Vec::new();
+    println!("{:>10} {:>16} {:>16} {:>16} {:>12}", "method", "max energy", "max ang. mom.", "max eccen.", "final a");
+    for label in ["rk4", "leapfrog"] {
+        let mut m = monitor();
+        ctx.solver(label, "fixed-step");
+        let observe = |t: f64, s: &[f64; 4]| m.observe(t, s);
+        let end = ctx.time(label, || match label {
+            "rk4" => integrate_with(&rate, rk4_step, ic, [0.0, tf], dt, Limits::default(), observe),
+            _ => integrate_with(&rate, leapfrog_step, ic, [0.0, tf], dt, Limits::default(), observe),
+        })?;
+        let drift = m.max_drift();
+        println!("{:>10} {:>16.4e} {:>16.4e} {:>16.4e} {:>12.6}", label, drift[0], drift[1], drift[2], body.semi_major_axis(&end));
+        curves.push((label, m.history().iter().map(|(t, d)| (*t, d[0])).collect::<Vec<_>>()));
+    }
+
+    let (mut ymin, mut ymax) = (f64::INFINITY, f64::NEG_INFINITY);
+    for (_, c) in &curves {
+        for (_, d) in c.iter().skip(1) {
+            ymin = ymin.min(d.max(1e-16).log10());
+            ymax = ymax.max(d.max(1e-16).log10());
+        }
+    }
+
+    let path = ctx.path(name);
+    let theme = ctx.style();
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?;
+    let root = theme.stamp(&root)?;
+    let mut chart = ChartBuilder::on(&root)
+        .caption(format!("Two-body energy drift, e = {e}, {per_period} steps per period [semilogy-scale]"), theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 70)
+        .set_label_area_size(LabelAreaPosition::Bottom, 50)
+        .build_cartesian_2d(0.0..tf, ymin.floor()..ymax.ceil())?;
+
+    chart.configure_mesh()
+        .label_style(theme.label())
+        .axis_desc_style(theme.label())
+        .x_desc("t")
+        .y_desc("|E - E0| / |E0|")
+        .y_label_formatter(&|v| format!("1e{:.0}", v))
+        .draw()?;
+
+    for (i, (label, c)) in curves.iter().enumerate() {
+        let color = theme.color(i);
+        chart.draw_series(LineSeries::new(c.iter().skip(1).map(|(t, d)| (*t, d.max(1e-16).log10())), &color))?
+            .label(*label)
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+    }
+
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?;
+
+    root.present()?;
+    Ok(())
+}
+
+fn main() {
+    let mut ctx = RunContext::new("output", "orbits")
+        .expect("failed to create output directory");
+    let _ = orbit_study(&mut ctx, 0.6, 2000, 100, "energy_drift.png");
+    let _ = ctx.write_report();
+    let _ = ctx.write_manifest();
+}
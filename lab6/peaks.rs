@@ -0,0 +1,111 @@
+//!
+//! peaks.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Local extrema of a sampled signal, refined past the grid by the vertex
+//! of the parabola through each extremal sample and its neighbours, and an
+//! oscillation summary built from them: period, amplitude, and the
+//! exponential growth or decay rate of the amplitude envelope
+//!
+
+#![allow(dead_code)]
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    Peak,
+    Trough,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Extremum {
+    pub t: f64,
+    pub value: f64,
+    pub kind: Kind,
+}
+
+///
+/// Vertex of the parabola through three (possibly unequally spaced) points,
+/// None when they are collinear
+///
+fn vertex(t: [f64; 3], y: [f64; 3]) -> Option<(f64, f64)> {
+    // Newton form p(s) = y0 + d1 (s - t0) + d2 (s - t0)(s - t1)
+    let d1 = (y[1] - y[0]) / (t[1] - t[0]);
+    let d2 = ((y[2] - y[1]) / (t[2] - t[1]) - d1) / (t[2] - t[0]);
+    if d2 == 0.0 {
+        return None;
+    }
+    let s = 0.5 * (t[0] + t[1]) - d1 / (2.0 * d2);
+    Some((s, y[0] + d1 * (s - t[0]) + d2 * (s - t[0]) * (s - t[1])))
+}
+
+///
+/// Every interior local extremum of y(t), in time order. Plateaus count
+/// once, at their first sample
+///
+pub fn extrema(t: &[f64], y: &[f64]) -> Vec<Extremum> {
+    let n = t.len().min(y.len());
+    let mut out = Vec::new();
+    for i in 1..n.saturating_sub(1) {
+        let kind = if y[i] > y[i - 1] && y[i] >= y[i + 1] {
+            Kind::Peak
+        } else if y[i] < y[i - 1] && y[i] <= y[i + 1] {
+            Kind::Trough
+        } else {
+            continue;
+        };
+
+        let (ts, value) = vertex([t[i - 1], t[i], t[i + 1]], [y[i - 1], y[i], y[i + 1]])
+            .filter(|(s, _)| *s >= t[i - 1] && *s <= t[i + 1])
+            .unwrap_or((t[i], y[i]));
+        out.push(Extremum { t: ts, value, kind });
+    }
+    out
+}
+
+///
+/// Period, amplitude and envelope rate over the detected cycles. Period
+/// and amplitude average the last `window` cycles so a settled orbit is
+/// described rather than the transient; the rate is the least squares
+/// slope of ln(amplitude) over every cycle, negative for decay
+///
+#[derive(Clone, Debug)]
+pub struct Oscillation {
+    pub extrema: Vec<Extremum>,
+    pub period: Option<f64>,
+    pub amplitude: Option<f64>,
+    pub rate: Option<f64>,
+}
+
+pub fn oscillation(t: &[f64], y: &[f64], window: usize) -> Oscillation {
+    let extrema = extrema(t, y);
+    let peaks: Vec<&Extremum> = extrema.iter().filter(|e| e.kind == Kind::Peak).collect();
+
+    let period = (peaks.len() >= 2).then(|| {
+        let k = window.max(1).min(peaks.len() - 1);
+        let tail = &peaks[peaks.len() - 1 - k..];
+        (tail[k].t - tail[0].t) / k as f64
+    });
+
+    // half the swing between each peak and the trough that follows it
+    let cycles: Vec<(f64, f64)> = extrema
+        .windows(2)
+        .filter(|w| w[0].kind == Kind::Peak && w[1].kind == Kind::Trough)
+        .map(|w| (w[0].t, 0.5 * (w[0].value - w[1].value)))
+        .collect();
+
+    let amplitude = (!cycles.is_empty()).then(|| {
+        let k = window.max(1).min(cycles.len());
+        cycles[cycles.len() - k..].iter().map(|c| c.1).sum::<f64>() / k as f64
+    });
+
+    let logs: Vec<(f64, f64)> = cycles.iter().filter(|c| c.1 > 0.0).map(|c| (c.0, c.1.ln())).collect();
+    let rate = (logs.len() >= 2).then(|| {
+        let m = logs.len() as f64;
+        let (st, sl) = logs.iter().fold((0.0, 0.0), |(a, b), (ti, li)| (a + ti, b + li));
+        let (tbar, lbar) = (st / m, sl / m);
+        let num: f64 = logs.iter().map(|(ti, li)| (ti - tbar) * (li - lbar)).sum();
+        let den: f64 = logs.iter().map(|(ti, _)| (ti - tbar).powi(2)).sum();
+        num / den
+    });
+
+    Oscillation { extrema, period, amplitude, rate }
+}
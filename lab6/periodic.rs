@@ -0,0 +1,392 @@
+//!
+//! periodic.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Periodic orbits of autonomous systems by Newton shooting. The unknowns
+//! are a point x0 on the orbit and the period T; the equations are
+//! phi_T(x0) = x0 plus a phase condition <f(x_ref), x0 - x_ref> = 0 that
+//! pins x0 along the orbit. The period map is integrated with fixed-step
+//! RK4 and its Jacobian for Newton is formed by finite differences. The
+//! monodromy matrix of the converged orbit is integrated from the
+//! variational equations with the Jacobian of the rate instead, whose
+//! eigenvalues are the Floquet multipliers; it resolves strongly
+//! contracting multipliers the finite difference one loses in noise
+//!
+
+#![allow(dead_code)]
+
+use solvers::{rk4_step, Guard, Limits, Solution};
+
+use crate::linalg::{self, Matrix};
+
+#[derive(Clone, Copy, Debug)]
+pub struct ShootingOptions {
+    /// rk4 steps per period
+    pub steps: usize,
+    pub tol: f64,
+    pub max_iter: usize,
+    /// relative finite difference step for the monodromy columns
+    pub fd_eps: f64,
+    /// every rk4 step of every period map counts against these
+    pub limits: Limits,
+}
+
+impl Default for ShootingOptions {
+    fn default() -> ShootingOptions {
+        ShootingOptions { steps: 2000, tol: 1e-10, max_iter: 30, fd_eps: 1e-7, limits: Limits::default() }
+    }
+}
+
+///
+/// Converged orbit: the point and period found, one period of the cycle
+/// sampled at the rk4 steps, the variational monodromy matrix and its
+/// eigenvalues as (re, im), and the Newton iterations taken
+///
+pub struct PeriodicOrbit<const N: usize> {
+    pub x0: [f64; N],
+    pub period: f64,
+    pub cycle: Solution<N>,
+    pub monodromy: Matrix,
+    pub multipliers: Vec<(f64, f64)>,
+    /// eigenvalues of the last finite difference Newton Jacobian, a
+    /// diagnostic only: good to about fd_eps, noise below it
+    pub fd_multipliers: Vec<(f64, f64)>,
+    pub iterations: usize,
+}
+
+impl<const N: usize> PeriodicOrbit<N> {
+    ///
+    /// The multipliers with the trivial one (closest to 1, along the flow)
+    /// removed; all inside the unit circle means the orbit is stable
+    ///
+    pub fn nontrivial(&self) -> Vec<(f64, f64)> {
+        drop_trivial(&self.multipliers)
+    }
+}
+
+///
+/// The multipliers with the one closest to 1 removed
+///
+pub fn drop_trivial(multipliers: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let trivial = multipliers
+        .iter()
+        .enumerate()
+        .min_by(|a, b| {
+            let da = (a.1 .0 - 1.0).hypot(a.1 .1);
+            let db = (b.1 .0 - 1.0).hypot(b.1 .1);
+            da.total_cmp(&db)
+        })
+        .map(|(i, _)| i);
+    multipliers
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| Some(*i) != trivial)
+        .map(|(_, m)| *m)
+        .collect()
+}
+
+///
+/// The exponents with the trivial one (closest to 0, along the flow)
+/// removed, in the order given
+///
+fn drop_trivial_exponent(exponents: &[f64]) -> Vec<f64> {
+    let trivial = exponents
+        .iter()
+        .enumerate()
+        .min_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))
+        .map(|(i, _)| i);
+    exponents
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| Some(*i) != trivial)
+        .map(|(_, e)| *e)
+        .collect()
+}
+
+///
+/// phi_T(x0) by `steps` rk4 steps, each counted by guard
+///
+pub fn flow<F, const N: usize>(rate: &F, x0: [f64; N], period: f64, steps: usize, guard: &mut Guard)
+    -> Result<[f64; N], Box<dyn std::error::Error>>
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    let h = period / steps as f64;
+    let mut x = x0;
+    for i in 0..steps {
+        guard.step("periodic flow", i as f64 * h)?;
+        x = rk4_step(rate, i as f64 * h, &x, h);
+    }
+    Ok(x)
+}
+
+fn sample<F, const N: usize>(rate: &F, x0: [f64; N], period: f64, steps: usize, limits: Limits)
+    -> Result<Solution<N>, Box<dyn std::error::Error>>
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    limits.check::<N>("periodic cycle", steps)?;
+    let h = period / steps as f64;
+    let mut t = Vec::with_capacity(steps + 1);
+    let mut y = Vec::with_capacity(steps + 1);
+    t.push(0.0);
+    y.push(x0);
+    for i in 0..steps {
+        y.push(rk4_step(rate, i as f64 * h, &y[i], h));
+        t.push((i + 1) as f64 * h);
+    }
+    Ok((t, y))
+}
+
+///
+/// Finite difference Jacobian of phi_T with respect to x0
+///
+fn period_jacobian<F, const N: usize>(
+    rate: &F, x0: &[f64; N], image: &[f64; N], period: f64, opts: &ShootingOptions, guard: &mut Guard)
+    -> Result<Matrix, Box<dyn std::error::Error>>
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    let mut m = Matrix::zeros(N, N);
+    for j in 0..N {
+        let dx = opts.fd_eps * (1.0 + x0[j].abs());
+        let mut xp = *x0;
+        xp[j] += dx;
+        let yp = flow(rate, xp, period, opts.steps, guard)?;
+        let col: Vec<f64> = (0..N).map(|i| (yp[i] - image[i]) / dx).collect();
+        m.set_column(j, &col);
+    }
+    Ok(m)
+}
+
+///
+/// Newton shooting from an initial point and period guess, for instance a
+/// state near a peak of a short transient run and the spacing of its peaks.
+/// Newton runs on a finite difference Jacobian; the monodromy and
+/// multipliers of the converged orbit come from the variational equations
+/// with jac. One guard over opts.limits counts the rk4 steps of every
+/// period map
+///
+pub fn shoot<F, J, const N: usize>(rate: &F, jac: &J, guess: [f64; N], period: f64, opts: ShootingOptions)
+    -> Result<PeriodicOrbit<N>, Box<dyn std::error::Error>>
+where
+    F: Fn(f64, &[f64; N], &mut [f64; N]),
+    J: Fn(f64, &[f64; N]) -> [[f64; N]; N]
+{
+    let mut x0 = guess;
+    let mut period = period;
+    let mut guard = opts.limits.memory(usize::MAX).start::<N>();
+
+    for iter in 1..=opts.max_iter {
+        let image = flow(rate, x0, period, opts.steps, &mut guard)?;
+        let fd = period_jacobian(rate, &x0, &image, period, &opts, &mut guard)?;
+        let residual: Vec<f64> = (0..N).map(|i| image[i] - x0[i]).collect();
+        let size = residual.iter().fold(0.0_f64, |m, r| m.max(r.abs()));
+
+        if size < opts.tol {
+            let m = monodromy(rate, jac, x0, period, opts.steps, &mut guard)?;
+            return Ok(PeriodicOrbit {
+                x0,
+                period,
+                cycle: sample(rate, x0, period, opts.steps, opts.limits)?,
+                multipliers: m.eigenvalues()?,
+                monodromy: m,
+                fd_multipliers: fd.eigenvalues()?,
+                iterations: iter - 1,
+            });
+        }
+
+        // bordered system [M - I, f(phi); f(x0)^T, 0] [dx; dT] = [-r; 0]
+        let mut f_image = [0.0; N];
+        let mut f_x0 = [0.0; N];
+        rate(period, &image, &mut f_image);
+        rate(0.0, &x0, &mut f_x0);
+
+        let mut a = Matrix::zeros(N + 1, N + 1);
+        a.block_mut(0, 0, N, N).copy_from(fd.view());
+        for i in 0..N {
+            a[(i, i)] -= 1.0;
+            a[(i, N)] = f_image[i];
+        }
+        a.row_mut(N)[..N].copy_from_slice(&f_x0);
+        let mut rhs: Vec<f64> = residual.iter().map(|r| -r).collect();
+        rhs.push(0.0);
+        let (delta, report) = a.solve_report(&rhs)?;
+        linalg::check_condition("periodic orbit newton", report.condition);
+
+        // keep the period positive and its update moderate
+        let dt = delta[N].clamp(-0.5 * period, 0.5 * period);
+        for i in 0..N {
+            x0[i] += delta[i];
+        }
+        period += dt;
+        if !period.is_finite() || x0.iter().any(|x| !x.is_finite()) {
+            return Err(format!("shooting diverged at iteration {iter}").into());
+        }
+    }
+
+    Err(format!("shooting did not converge in {} iterations", opts.max_iter).into())
+}
+
+///
+/// Monodromy matrix and its Floquet multipliers (re, im), with the Floquet
+/// exponents ln|mu| / T in decreasing order
+///
+pub struct Floquet {
+    pub monodromy: Matrix,
+    pub multipliers: Vec<(f64, f64)>,
+    pub exponents: Vec<f64>,
+}
+
+impl Floquet {
+    pub fn nontrivial(&self) -> Vec<(f64, f64)> {
+        drop_trivial(&self.multipliers)
+    }
+
+    ///
+    /// The exponents with the trivial one (closest to 0) removed, still in
+    /// decreasing order; the first decides stability
+    ///
+    pub fn nontrivial_exponents(&self) -> Vec<f64> {
+        drop_trivial_exponent(&self.exponents)
+    }
+}
+
+///
+/// One rk4 step of x' = f(x) together with Phi' = J(x) Phi, on a state
+/// holding x followed by Phi row major
+///
+fn variational_step<F, J, const N: usize>(rate: &F, jac: &J, t: f64, v: &mut [f64], h: f64)
+where
+    F: Fn(f64, &[f64; N], &mut [f64; N]),
+    J: Fn(f64, &[f64; N]) -> [[f64; N]; N]
+{
+    let deriv = |t: f64, v: &[f64]| -> Vec<f64> {
+        let x: [f64; N] = std::array::from_fn(|i| v[i]);
+        let mut dv = vec![0.0; N + N * N];
+        let mut dx = [0.0; N];
+        rate(t, &x, &mut dx);
+        dv[..N].copy_from_slice(&dx);
+        let a = jac(t, &x);
+        for i in 0..N {
+            for j in 0..N {
+                dv[N + i * N + j] = (0..N).map(|k| a[i][k] * v[N + k * N + j]).sum();
+            }
+        }
+        dv
+    };
+    let axpy = |h: f64, k: &[f64]| -> Vec<f64> { v.iter().zip(k).map(|(a, b)| a + h * b).collect() };
+
+    let k1 = deriv(t, v);
+    let k2 = deriv(t + 0.5 * h, &axpy(0.5 * h, &k1));
+    let k3 = deriv(t + 0.5 * h, &axpy(0.5 * h, &k2));
+    let k4 = deriv(t + h, &axpy(h, &k3));
+    for (idx, x) in v.iter_mut().enumerate() {
+        *x += (h / 6.0) * (k1[idx] + 2.0 * k2[idx] + 2.0 * k3[idx] + k4[idx]);
+    }
+}
+
+fn variational_start<const N: usize>(x0: &[f64; N]) -> Vec<f64> {
+    let mut v = vec![0.0; N + N * N];
+    v[..N].copy_from_slice(x0);
+    for i in 0..N {
+        v[N + i * N + i] = 1.0;
+    }
+    v
+}
+
+///
+/// Monodromy matrix from the variational equations. The orbit and the
+/// fundamental matrix Phi' = J(x) Phi, Phi(0) = I are advanced together
+/// by rk4 over one period, so Phi(T) carries the integrator's own accuracy
+/// rather than a finite difference truncation
+///
+pub fn monodromy<F, J, const N: usize>(rate: &F, jac: &J, x0: [f64; N], period: f64, steps: usize, guard: &mut Guard)
+    -> Result<Matrix, Box<dyn std::error::Error>>
+where
+    F: Fn(f64, &[f64; N], &mut [f64; N]),
+    J: Fn(f64, &[f64; N]) -> [[f64; N]; N]
+{
+    let mut v = variational_start(&x0);
+    let h = period / steps as f64;
+    for s in 0..steps {
+        guard.step("monodromy", s as f64 * h)?;
+        variational_step(rate, jac, s as f64 * h, &mut v, h);
+    }
+
+    let mut m = Matrix::zeros(N, N);
+    for i in 0..N {
+        for j in 0..N {
+            m[(i, j)] = v[N + i * N + j];
+        }
+    }
+    Ok(m)
+}
+
+///
+/// Floquet exponents by reorthonormalizing Phi after every step (modified
+/// Gram-Schmidt) and summing ln of the diagonal of R over a period. Each
+/// period restarts from the previous period's frame, which is orthogonal
+/// iteration on the monodromy matrix, so the frame settles onto its Schur
+/// vectors and the sums onto ln|mu|; a complex pair only converges as a
+/// pair. A strongly stable orbit has multipliers far below the rounding of
+/// the largest entry of Phi(T), which its eigenvalues cannot resolve but
+/// these sums can
+///
+pub fn exponents<F, J, const N: usize>(rate: &F, jac: &J, x0: [f64; N], period: f64, steps: usize, guard: &mut Guard)
+    -> Result<Vec<f64>, Box<dyn std::error::Error>>
+where
+    F: Fn(f64, &[f64; N], &mut [f64; N]),
+    J: Fn(f64, &[f64; N]) -> [[f64; N]; N]
+{
+    const MAX_PERIODS: usize = 50;
+    let h = period / steps as f64;
+    let mut v = variational_start(&x0);
+    let mut last = vec![f64::NAN; N];
+
+    for _ in 0..MAX_PERIODS {
+        v[..N].copy_from_slice(&x0);
+        let mut sums = vec![0.0; N];
+        for s in 0..steps {
+            guard.step("floquet exponents", s as f64 * h)?;
+            variational_step(rate, jac, s as f64 * h, &mut v, h);
+
+            let phi = &mut v[N..];
+            for j in 0..N {
+                for k in 0..j {
+                    let dot: f64 = (0..N).map(|i| phi[i * N + j] * phi[i * N + k]).sum();
+                    for i in 0..N {
+                        phi[i * N + j] -= dot * phi[i * N + k];
+                    }
+                }
+                let norm = (0..N).map(|i| phi[i * N + j].powi(2)).sum::<f64>().sqrt();
+                sums[j] += norm.ln();
+                for i in 0..N {
+                    phi[i * N + j] /= norm;
+                }
+            }
+        }
+
+        let next: Vec<f64> = sums.iter().map(|s| s / period).collect();
+        let settled = next.iter().zip(&last).all(|(a, b)| (a - b).abs() <= 1e-12 * (1.0 + a.abs()));
+        last = next;
+        if settled {
+            break;
+        }
+    }
+    last.sort_by(|a, b| b.total_cmp(a));
+    Ok(last)
+}
+
+///
+/// Floquet multipliers of a converged orbit from the variational monodromy
+/// matrix, and the exponents from the reorthonormalized integration, every
+/// variational step of both counted against limits
+///
+pub fn floquet<F, J, const N: usize>(rate: &F, jac: &J, orbit: &PeriodicOrbit<N>, steps: usize, limits: Limits)
+    -> Result<Floquet, Box<dyn std::error::Error>>
+where
+    F: Fn(f64, &[f64; N], &mut [f64; N]),
+    J: Fn(f64, &[f64; N]) -> [[f64; N]; N]
+{
+    let mut guard = limits.memory(usize::MAX).start::<N>();
+    let m = monodromy(rate, jac, orbit.x0, orbit.period, steps, &mut guard)?;
+    Ok(Floquet {
+        multipliers: m.eigenvalues()?,
+        monodromy: m,
+        exponents: exponents(rate, jac, orbit.x0, orbit.period, steps, &mut guard)?,
+    })
+}
@@ -0,0 +1,195 @@
+//!
+//! phase.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Instantaneous phase and synchronization of oscillating components. The
+//! phase of a uniformly sampled series is the argument of its analytic
+//! signal (fourier::analytic), unwrapped to a continuous angle. For two
+//! oscillators locked n:m the generalized difference n phi_x - m phi_y
+//! stays bounded; the locking value |<exp(i (n phi_x - m phi_y))>| is 1
+//! for a constant difference and near 0 for drifting, independent phases
+//!
+
+#![allow(dead_code)]
+
+use std::f64::consts::PI;
+
+use crate::fourier;
+
+///
+/// Adds multiples of 2 pi so successive angles differ by less than pi
+///
+pub fn unwrap(phase: &mut [f64]) {
+    let mut offset = 0.0;
+    for k in 1..phase.len() {
+        let raw = phase[k] + offset;
+        let jump = raw - phase[k - 1];
+        offset -= 2.0 * PI * (jump / (2.0 * PI)).round();
+        phase[k] += offset;
+    }
+}
+
+///
+/// Unwrapped instantaneous phase of y, in radians
+///
+pub fn instantaneous(y: &[f64]) -> Vec<f64> {
+    let mut phase: Vec<f64> = fourier::analytic(y).iter().map(|(re, im)| im.atan2(*re)).collect();
+    unwrap(&mut phase);
+    phase
+}
+
+///
+/// Locking of x to y at the ratio n:m over the trimmed samples
+///
+#[derive(Clone, Copy, Debug)]
+pub struct Locking {
+    pub ratio: (u32, u32),
+    /// |<exp(i dphi)>|, in [0, 1]
+    pub plv: f64,
+    /// arg <exp(i dphi)>, in (-pi, pi]
+    pub mean_difference: f64,
+    /// mean frequencies of x and y, cycles per unit time
+    pub frequency: [f64; 2],
+    /// whole 2 pi slips of dphi from the first trimmed sample to the last
+    pub slips: usize,
+}
+
+///
+/// Least squares slope of y against t
+///
+fn slope(t: &[f64], y: &[f64]) -> f64 {
+    let n = t.len() as f64;
+    let (tbar, ybar) = (t.iter().sum::<f64>() / n, y.iter().sum::<f64>() / n);
+    let sty: f64 = t.iter().zip(y).map(|(a, b)| (a - tbar) * (b - ybar)).sum();
+    let stt: f64 = t.iter().map(|a| (a - tbar).powi(2)).sum();
+    sty / stt
+}
+
+///
+/// Mean frequency of an unwrapped phase, cycles per unit time
+///
+pub fn frequency(t: &[f64], phase: &[f64]) -> f64 {
+    slope(t, phase) / (2.0 * PI)
+}
+
+///
+/// Kuramoto order parameter R(t) = |mean_i exp(i phi_i(t))| of a
+/// population of phases on the same samples: 1 when all move as one, of
+/// order 1 / sqrt(units) when they are independent
+///
+pub fn order_parameter(phases: &[Vec<f64>]) -> Vec<f64> {
+    let Some(first) = phases.first() else {
+        return Vec::new();
+    };
+    let units = phases.len() as f64;
+    (0..first.len()).map(|k| {
+        let (c, s) = phases.iter().fold((0.0, 0.0), |(c, s), p| (c + p[k].cos(), s + p[k].sin()));
+        (c * c + s * s).sqrt() / units
+    }).collect()
+}
+
+///
+/// Phase locking of x and y, sampled together at uniform times t, at the
+/// ratio n:m. trim is the fraction of samples dropped at each end, where
+/// the analytic signal carries its edge error
+///
+pub fn locking(t: &[f64], x: &[f64], y: &[f64], ratio: (u32, u32), trim: f64)
+    -> Result<Locking, Box<dyn std::error::Error>> {
+    if t.len() != x.len() || t.len() != y.len() {
+        return Err(format!("phase: {} times for series of {} and {} samples", t.len(), x.len(), y.len()).into());
+    }
+    if !(0.0..0.5).contains(&trim) {
+        return Err(format!("phase: trim {trim} must be in [0, 0.5)").into());
+    }
+    let h = (t[t.len() - 1] - t[0]) / (t.len() - 1) as f64;
+    if let Some(k) = t.windows(2).position(|w| ((w[1] - w[0]) - h).abs() > 1e-6 * h) {
+        return Err(format!("phase: samples must be uniform, step {k} is {} against {h}", t[k + 1] - t[k]).into());
+    }
+    let cut = (trim * t.len() as f64) as usize;
+    let range = cut..t.len() - cut;
+    if range.len() < 3 {
+        return Err(format!("phase: {} samples left after trimming", range.len()).into());
+    }
+
+    let (px, py) = (instantaneous(x), instantaneous(y));
+    let (n, m) = (f64::from(ratio.0), f64::from(ratio.1));
+    let dphi: Vec<f64> = px[range.clone()].iter().zip(&py[range.clone()]).map(|(a, b)| n * a - m * b).collect();
+    let (c, s) = dphi.iter().fold((0.0, 0.0), |(c, s), d| (c + d.cos(), s + d.sin()));
+    let count = dphi.len() as f64;
+    let ts = &t[range.clone()];
+    Ok(Locking {
+        ratio,
+        plv: (c * c + s * s).sqrt() / count,
+        mean_difference: s.atan2(c),
+        frequency: [frequency(ts, &px[range.clone()]), frequency(ts, &py[range])],
+        slips: ((dphi[dphi.len() - 1] - dphi[0]).abs() / (2.0 * PI)) as usize,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sampled(f: impl Fn(f64) -> f64, n: usize, dt: f64) -> (Vec<f64>, Vec<f64>) {
+        let t: Vec<f64> = (0..n).map(|i| i as f64 * dt).collect();
+        let y = t.iter().map(|&s| f(s)).collect();
+        (t, y)
+    }
+
+    #[test]
+    fn unwrap_removes_the_two_pi_jumps() {
+        let truth: Vec<f64> = (0..50).map(|k| 0.4 * k as f64).collect();
+        let mut wrapped: Vec<f64> = truth.iter().map(|p| p.sin().atan2(p.cos())).collect();
+        unwrap(&mut wrapped);
+        for (a, b) in wrapped.iter().zip(&truth) {
+            assert!((a - b).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn hilbert_phase_of_a_cosine_is_linear() {
+        // the zero padding bends the phase near the ends only
+        let w = 2.0 * PI * 0.5;
+        let (t, y) = sampled(|s| (w * s).cos(), 2000, 0.02);
+        let phase = instantaneous(&y);
+        let offset = phase[1000] - w * t[1000];
+        assert!(offset.abs() < 1e-3, "offset {offset}");
+        for (p, s) in phase.iter().zip(&t).skip(200).take(1600) {
+            assert!((p - w * s).abs() < 1e-2, "phase {p} at t = {s}");
+        }
+        assert!((frequency(&t[200..1800], &phase[200..1800]) - 0.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn a_locked_pair_and_a_drifting_pair() {
+        let w = 2.0 * PI * 0.5;
+        let (t, x) = sampled(|s| (w * s).sin(), 2000, 0.02);
+        let (_, y) = sampled(|s| (2.0 * w * s + 0.3).sin(), 2000, 0.02);
+        let locked = locking(&t, &y, &x, (1, 2), 0.1).unwrap();
+        assert!(locked.plv > 0.999, "{locked:?}");
+        assert!((locked.mean_difference - (0.3 + 0.5 * PI)).abs() < 1e-3, "{locked:?}");
+        assert_eq!(locked.slips, 0);
+        assert!((locked.frequency[0] - 1.0).abs() < 1e-4 && (locked.frequency[1] - 0.5).abs() < 1e-4, "{locked:?}");
+
+        let (_, z) = sampled(|s| (w * std::f64::consts::SQRT_2 * s).sin(), 2000, 0.02);
+        let drifting = locking(&t, &x, &z, (1, 1), 0.1).unwrap();
+        assert!(drifting.plv < 0.2, "{drifting:?}");
+        assert!(drifting.slips >= 3, "{drifting:?}");
+    }
+
+    #[test]
+    fn order_parameter_is_one_for_equal_phases() {
+        let phase: Vec<f64> = (0..10).map(|k| 0.3 * k as f64).collect();
+        let r = order_parameter(&[phase.clone(), phase.clone(), phase]);
+        assert!(r.iter().all(|v| (v - 1.0).abs() < 1e-12));
+        let opposed = order_parameter(&[vec![0.0], vec![PI]]);
+        assert!(opposed[0] < 1e-12);
+    }
+
+    #[test]
+    fn bad_samples_are_refused() {
+        let t = [0.0, 1.0, 2.5, 3.0];
+        assert!(locking(&t, &[0.0; 4], &[0.0; 4], (1, 1), 0.0).is_err());
+        assert!(locking(&[0.0, 1.0, 2.0, 3.0], &[0.0; 4], &[0.0; 3], (1, 1), 0.0).is_err());
+        assert!(locking(&[0.0, 1.0, 2.0, 3.0], &[0.0; 4], &[0.0; 4], (1, 1), 0.5).is_err());
+    }
+}
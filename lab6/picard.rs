@@ -0,0 +1,88 @@
+//!
+//! picard.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Successive approximation (Picard) IVP solver. Each iterate is a whole
+//! trajectory on a fixed grid, y_{k+1}(t) = y0 + int_t0^t f(s, y_k(s)) ds,
+//! with the integral by cumulative trapezoid quadrature. Every iterate is
+//! kept so the convergence (and its cost) can be plotted directly
+//!
+
+#![allow(dead_code)]
+
+use solvers::{self, Limits};
+
+///
+/// Grid, every Picard iterate on it (iterate 0 is the constant initial
+/// condition), and the max-norm update of each sweep
+///
+pub struct PicardResult<const N: usize> {
+    pub t: Vec<f64>,
+    pub iterates: Vec<Vec<[f64; N]>>,
+    pub updates: Vec<f64>,
+}
+
+impl<const N: usize> PicardResult<N> {
+    pub fn last(&self) -> &Vec<[f64; N]> {
+        self.iterates.last().unwrap()
+    }
+}
+
+///
+/// Runs Picard sweeps until the max-norm update drops below tol or max_iter
+/// sweeps are taken. A dt that does not divide the span ends the grid on
+/// a shortened interval at ts[1]. Quadrature error is O(dt^2) so iterates
+/// converge to the trapezoidal solution, not the exact one. Every iterate
+/// max_iter could store is checked against limits up front, and each sweep
+/// counts as a step of the guard
+///
+pub fn picard<F, const N: usize>(
+    rate: &F,
+    ic: [f64; N],
+    ts: [f64; 2],
+    dt: f64,
+    tol: f64,
+    max_iter: usize,
+    limits: Limits) -> Result<PicardResult<N>, Box<dyn std::error::Error>>
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    let n = solvers::planned_steps("picard", ts, dt, limits)?;
+    limits.check::<N>("picard", (n + 1).saturating_mul(max_iter + 1))?;
+    let mut guard = limits.start::<0>();
+    let t: Vec<f64> = std::iter::once(ts[0]).chain(solvers::grid(ts, dt).map(|(_, _, next)| next)).collect();
+
+    let mut iterates: Vec<Vec<[f64; N]>> = vec![vec![ic; n + 1]];
+    let mut updates: Vec<f64> = Vec::new();
+    let mut f: Vec<[f64; N]> = vec![[0.0; N]; n + 1];
+
+    for _ in 0..max_iter {
+        guard.iteration("picard")?;
+        let prev = iterates.last().unwrap();
+        for i in 0..=n {
+            rate(t[i], &prev[i], &mut f[i]);
+        }
+
+        // cumulative trapezoid from t0
+        let mut next: Vec<[f64; N]> = Vec::with_capacity(n + 1);
+        next.push(ic);
+        for i in 1..=n {
+            let mut w = next[i - 1];
+            for j in 0..N {
+                w[j] += 0.5 * (t[i] - t[i - 1]) * (f[i - 1][j] + f[i][j]);
+            }
+            next.push(w);
+        }
+
+        let update = next
+            .iter()
+            .zip(prev.iter())
+            .flat_map(|(a, b)| a.iter().zip(b.iter()).map(|(x, y)| (x - y).abs()))
+            .fold(0.0, f64::max);
+        updates.push(update);
+        iterates.push(next);
+
+        if !update.is_finite() || update < tol {
+            break;
+        }
+    }
+
+    Ok(PicardResult { t, iterates, updates })
+}
@@ -0,0 +1,49 @@
+//!
+//! piecewise.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Functions of one variable defined piece by piece. Breakpoints b_1 < ..
+//! < b_k split the line into k + 1 pieces; piece j applies on
+//! [b_j, b_{j+1}), so the value at a breakpoint comes from the piece to
+//! its right. Typical uses are step changes in an environmental input or a
+//! policy switching on at a given time
+//!
+
+#![allow(dead_code)]
+
+use std::sync::Arc;
+
+pub type Piece = Arc<dyn Fn(f64) -> f64 + Send + Sync>;
+
+#[derive(Clone)]
+pub struct Piecewise {
+    pub breaks: Vec<f64>,
+    pieces: Vec<Piece>,
+}
+
+impl Piecewise {
+    pub fn new(breaks: &[f64], pieces: Vec<Piece>) -> Result<Piecewise, Box<dyn std::error::Error>> {
+        if pieces.len() != breaks.len() + 1 {
+            return Err(format!("piecewise: {} breakpoints need {} pieces, got {}", breaks.len(), breaks.len() + 1, pieces.len()).into());
+        }
+        if breaks.iter().any(|b| !b.is_finite()) || breaks.windows(2).any(|w| w[1] <= w[0]) {
+            return Err(format!("piecewise: breakpoints {breaks:?} must be finite and strictly increasing").into());
+        }
+        Ok(Piecewise { breaks: breaks.to_vec(), pieces })
+    }
+
+    ///
+    /// Constant value on each piece
+    ///
+    pub fn steps(breaks: &[f64], values: &[f64]) -> Result<Piecewise, Box<dyn std::error::Error>> {
+        let pieces = values.iter().map(|&v| Arc::new(move |_: f64| v) as Piece).collect();
+        Piecewise::new(breaks, pieces)
+    }
+
+    pub fn piece(&self, x: f64) -> usize {
+        self.breaks.partition_point(|b| *b <= x)
+    }
+
+    pub fn eval(&self, x: f64) -> f64 {
+        (self.pieces[self.piece(x)])(x)
+    }
+}
@@ -0,0 +1,210 @@
+//!
+//! problems.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Standard stiff initial value benchmarks with published reference
+//! solutions, for validating implicit solvers. Each problem carries its
+//! rate, closed form Jacobian, initial state, interval and the reference
+//! state at the end of the interval. Reference values are those of the
+//! Bari test set for IVP solvers (Mazzia and Magherini), computed there
+//! with RADAU5 at tight tolerances
+//!
+//!   robertson  3 species autocatalytic kinetics, rates across 9 decades
+//!   hires      8 species plant physiology model (Schafer 1975)
+//!
+//! Alongside them, boundary value problems from heat transfer and statics
+//! whose exact solutions are closed form
+//!
+//!   Fin   straight pin fin with an adiabatic tip, theta'' = m^2 theta
+//!   Beam  simply supported beam under a uniform load, EI w'''' = q
+//!
+
+#![allow(dead_code)]
+
+use crate::linalg::Matrix;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Problem<const N: usize> {
+    pub name: &'static str,
+    pub rate: fn(f64, &[f64; N], &mut [f64; N]),
+    pub jacobian: fn(f64, &[f64; N]) -> Matrix,
+    pub ic: [f64; N],
+    pub ts: [f64; 2],
+    /// y(ts[1])
+    pub reference: [f64; N],
+}
+
+impl<const N: usize> Problem<N> {
+    ///
+    /// Largest componentwise error against the reference, each relative
+    /// to max(|reference|, floor) so near zero components don't dominate
+    ///
+    pub fn error(&self, end: &[f64; N], floor: f64) -> f64 {
+        end.iter()
+            .zip(&self.reference)
+            .fold(0.0_f64, |m, (y, r)| m.max((y - r).abs() / r.abs().max(floor)))
+    }
+}
+
+fn robertson_rate(_t: f64, y: &[f64; 3], dy: &mut [f64; 3]) {
+    dy[0] = -0.04 * y[0] + 1e4 * y[1] * y[2];
+    dy[2] = 3e7 * y[1] * y[1];
+    dy[1] = -dy[0] - dy[2];
+}
+
+fn robertson_jacobian(_t: f64, y: &[f64; 3]) -> Matrix {
+    Matrix::from_rows(&[
+        [-0.04, 1e4 * y[2], 1e4 * y[1]],
+        [0.04, -1e4 * y[2] - 6e7 * y[1], -1e4 * y[1]],
+        [0.0, 6e7 * y[1], 0.0],
+    ])
+}
+
+pub fn robertson() -> Problem<3> {
+    Problem {
+        name: "robertson",
+        rate: robertson_rate,
+        jacobian: robertson_jacobian,
+        ic: [1.0, 0.0, 0.0],
+        ts: [0.0, 40.0],
+        reference: [0.715_827_068_719_383_4, 9.185_534_764_557_338e-6, 0.284_163_745_745_852_1],
+    }
+}
+
+fn hires_rate(_t: f64, y: &[f64; 8], dy: &mut [f64; 8]) {
+    let bind = 280.0 * y[5] * y[7];
+    dy[0] = -1.71 * y[0] + 0.43 * y[1] + 8.32 * y[2] + 0.0007;
+    dy[1] = 1.71 * y[0] - 8.75 * y[1];
+    dy[2] = -10.03 * y[2] + 0.43 * y[3] + 0.035 * y[4];
+    dy[3] = 8.32 * y[1] + 1.71 * y[2] - 1.12 * y[3];
+    dy[4] = -1.745 * y[4] + 0.43 * y[5] + 0.43 * y[6];
+    dy[5] = -bind + 0.69 * y[3] + 1.71 * y[4] - 0.43 * y[5] + 0.69 * y[6];
+    dy[6] = bind - 1.81 * y[6];
+    dy[7] = -dy[6];
+}
+
+fn hires_jacobian(_t: f64, y: &[f64; 8]) -> Matrix {
+    let mut jac = Matrix::from_rows(&[
+        [-1.71, 0.43, 8.32, 0.0, 0.0, 0.0, 0.0, 0.0],
+        [1.71, -8.75, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+        [0.0, 0.0, -10.03, 0.43, 0.035, 0.0, 0.0, 0.0],
+        [0.0, 8.32, 1.71, -1.12, 0.0, 0.0, 0.0, 0.0],
+        [0.0, 0.0, 0.0, 0.0, -1.745, 0.43, 0.43, 0.0],
+        [0.0, 0.0, 0.0, 0.69, 1.71, -0.43, 0.69, 0.0],
+        [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.81, 0.0],
+        [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.81, 0.0],
+    ]);
+    // the binding term 280 y6 y8
+    for (row, sign) in [(5, -1.0), (6, 1.0), (7, -1.0)] {
+        jac[(row, 5)] += sign * 280.0 * y[7];
+        jac[(row, 7)] += sign * 280.0 * y[5];
+    }
+    jac
+}
+
+pub fn hires() -> Problem<8> {
+    Problem {
+        name: "hires",
+        rate: hires_rate,
+        jacobian: hires_jacobian,
+        ic: [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0057],
+        ts: [0.0, 321.8122],
+        reference: [
+            0.737_131_257_332_566_8e-3,
+            0.144_248_572_631_618_5e-3,
+            0.588_872_974_096_757_5e-4,
+            0.117_565_134_328_314_9e-2,
+            0.238_635_619_883_133_1e-2,
+            0.623_896_825_274_279_6e-2,
+            0.284_999_839_518_576_9e-2,
+            0.285_000_160_481_423_1e-2,
+        ],
+    }
+}
+
+///
+/// Pin fin of constant cross section, temperature excess theta = T - T_inf
+/// held at theta_base at the wall (x = 0) and no heat lost through the tip
+///
+#[derive(Clone, Copy, Debug)]
+pub struct Fin {
+    /// convection coefficient, W / m^2 K
+    pub h: f64,
+    /// conductivity, W / m K
+    pub k: f64,
+    pub perimeter: f64,
+    pub area: f64,
+    pub length: f64,
+    pub theta_base: f64,
+}
+
+impl Fin {
+    ///
+    /// m = sqrt(h P / k A), per metre
+    ///
+    pub fn m(&self) -> f64 {
+        (self.h * self.perimeter / (self.k * self.area)).sqrt()
+    }
+
+    pub fn exact(&self, x: f64) -> f64 {
+        let m = self.m();
+        self.theta_base * (m * (self.length - x)).cosh() / (m * self.length).cosh()
+    }
+
+    ///
+    /// Heat through the base, -k A theta'(0) = sqrt(h P k A) theta_b tanh(m L)
+    ///
+    pub fn heat_rate(&self) -> f64 {
+        (self.h * self.perimeter * self.k * self.area).sqrt() * self.theta_base * (self.m() * self.length).tanh()
+    }
+
+    ///
+    /// Actual over ideal heat rate, tanh(m L) / (m L)
+    ///
+    pub fn efficiency(&self) -> f64 {
+        let ml = self.m() * self.length;
+        ml.tanh() / ml
+    }
+}
+
+///
+/// Simply supported (w = w'' = 0 at both ends) under a uniform load q
+/// per unit length, downward deflection positive
+///
+#[derive(Clone, Copy, Debug)]
+pub struct Beam {
+    /// Young's modulus, Pa
+    pub e: f64,
+    /// second moment of area, m^4
+    pub i: f64,
+    /// load, N / m
+    pub q: f64,
+    pub length: f64,
+}
+
+impl Beam {
+    pub fn rigidity(&self) -> f64 {
+        self.e * self.i
+    }
+
+    ///
+    /// w = q x (L^3 - 2 L x^2 + x^3) / (24 EI)
+    ///
+    pub fn exact(&self, x: f64) -> f64 {
+        let l = self.length;
+        self.q * x * (l.powi(3) - 2.0 * l * x * x + x.powi(3)) / (24.0 * self.rigidity())
+    }
+
+    ///
+    /// EI w'' = -q x (L - x) / 2 for downward w
+    ///
+    pub fn moment(&self, x: f64) -> f64 {
+        -0.5 * self.q * x * (self.length - x)
+    }
+
+    ///
+    /// 5 q L^4 / (384 EI) at midspan
+    ///
+    pub fn max_deflection(&self) -> f64 {
+        5.0 * self.q * self.length.powi(4) / (384.0 * self.rigidity())
+    }
+}
@@ -0,0 +1,150 @@
+//!
+//! projection.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Post-step projection onto a constraint manifold g(y) = 0. After any base
+//! step the state is moved to the nearest point (in the Euclidean sense,
+//! along the constraint gradients) that satisfies the constraints, solving
+//!
+//!   g(y + G^T lambda) = 0,  G = dg/dy at the unprojected y
+//!
+//! for the multipliers lambda by simplified Newton. Linear invariants are
+//! kept by every Runge-Kutta method already; quadratic ones such as an
+//! energy surface are not, and that drift is what the projection removes
+//!
+
+#![allow(dead_code)]
+
+use solvers::{self, Limits, Method, Solution};
+
+use crate::linalg::Matrix;
+use crate::system::OdeSystem;
+
+#[derive(Clone, Copy, Debug)]
+pub struct ProjectionOptions {
+    pub tol: f64,
+    pub max_iter: usize,
+}
+
+impl Default for ProjectionOptions {
+    fn default() -> ProjectionOptions {
+        ProjectionOptions { tol: 1e-12, max_iter: 20 }
+    }
+}
+
+///
+/// Largest constraint violation seen before projecting and the largest
+/// correction applied, over a whole run
+///
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ProjectionStats {
+    pub projections: usize,
+    pub max_violation: f64,
+    pub max_correction: f64,
+}
+
+fn max_norm(v: &[f64]) -> f64 {
+    v.iter().fold(0.0_f64, |m, x| m.max(x.abs()))
+}
+
+///
+/// Finite difference constraint Jacobian, one row per constraint
+///
+fn gradient<G, const N: usize>(g: &G, y: &[f64; N], g0: &[f64]) -> Matrix
+where G: Fn(&[f64; N]) -> Vec<f64> {
+    let mut jac = Matrix::zeros(g0.len(), N);
+    for j in 0..N {
+        let dy = 1e-7 * (1.0 + y[j].abs());
+        let mut yp = *y;
+        yp[j] += dy;
+        let col: Vec<f64> = g(&yp).iter().zip(g0).map(|(a, b)| (a - b) / dy).collect();
+        jac.set_column(j, &col);
+    }
+    jac
+}
+
+///
+/// Nearest point of g = 0 to y
+///
+pub fn project<G, const N: usize>(g: &G, y: &[f64; N], opts: ProjectionOptions) -> Result<[f64; N], Box<dyn std::error::Error>>
+where G: Fn(&[f64; N]) -> Vec<f64> {
+    let g0 = g(y);
+    if max_norm(&g0) < opts.tol {
+        return Ok(*y);
+    }
+    let jac = gradient(g, y, &g0);
+    let m = g0.len();
+
+    // G G^T, the Newton matrix for lambda, fixed at the unprojected point
+    let mut ggt = Matrix::zeros(m, m);
+    for a in 0..m {
+        for b in 0..m {
+            ggt[(a, b)] = (0..N).map(|k| jac[(a, k)] * jac[(b, k)]).sum();
+        }
+    }
+    let lu = ggt.lu()?;
+
+    let mut lambda = vec![0.0; m];
+    let mut residual = g0;
+    for _ in 0..opts.max_iter {
+        let dl = lu.solve(&residual.iter().map(|r| -r).collect::<Vec<f64>>());
+        for (l, d) in lambda.iter_mut().zip(&dl) {
+            *l += d;
+        }
+        let out: [f64; N] = std::array::from_fn(|k| y[k] + (0..m).map(|a| jac[(a, k)] * lambda[a]).sum::<f64>());
+        residual = g(&out);
+        if max_norm(&residual) < opts.tol {
+            return Ok(out);
+        }
+    }
+    Err(format!("projection left a violation of {:.3e} after {} iterations", max_norm(&residual), opts.max_iter).into())
+}
+
+///
+/// Fixed dt integration with any one-step method, projecting after every
+/// step. Same step signature as solvers::integrate, and the same shortened
+/// last step when dt does not divide the span. The run is checked against
+/// limits before anything is stored
+///
+#[allow(clippy::too_many_arguments)]
+pub fn integrate_projected<F, S, G, const N: usize>(
+    rate: &F, step: S, g: &G, ic: [f64; N], ts: [f64; 2], dt: f64, opts: ProjectionOptions, limits: Limits)
+    -> Result<(Solution<N>, ProjectionStats), Box<dyn std::error::Error>>
+where
+    F: Fn(f64, &[f64; N], &mut [f64; N]),
+    S: Fn(&F, f64, &[f64; N], f64) -> [f64; N],
+    G: Fn(&[f64; N]) -> Vec<f64>
+{
+    let n = solvers::planned_steps("projection", ts, dt, limits)?;
+    limits.check::<N>("projection", n)?;
+    let mut guard = limits.start::<N>();
+    let mut stats = ProjectionStats::default();
+    let mut t = Vec::with_capacity(n + 1);
+    let mut y = Vec::with_capacity(n + 1);
+    t.push(ts[0]);
+    y.push(project(g, &ic, opts)?);
+
+    for (i, (tk, h, next)) in solvers::grid(ts, dt).enumerate() {
+        guard.step("projection", tk)?;
+        let raw = step(rate, tk, &y[i], h);
+        let fixed = project(g, &raw, opts)?;
+        stats.projections += 1;
+        stats.max_violation = stats.max_violation.max(max_norm(&g(&raw)));
+        stats.max_correction = stats.max_correction.max(max_norm(&std::array::from_fn::<f64, N, _>(|k| fixed[k] - raw[k])));
+        t.push(next);
+        y.push(fixed);
+    }
+    Ok(((t, y), stats))
+}
+
+///
+/// integrate_projected on a system with one of the fixed step methods,
+/// its size checked against the state
+///
+#[allow(clippy::too_many_arguments)]
+pub fn integrate_projected_system<G, const N: usize>(
+    system: &impl OdeSystem, method: Method, g: &G, ic: [f64; N], ts: [f64; 2], dt: f64, opts: ProjectionOptions, limits: Limits)
+    -> Result<(Solution<N>, ProjectionStats), Box<dyn std::error::Error>>
+where G: Fn(&[f64; N]) -> Vec<f64> {
+    let rate = system.rhs::<N>()?;
+    integrate_projected(&rate, |r: &_, t, y: &[f64; N], h| method.step(r, t, y, h), g, ic, ts, dt, opts, limits)
+}
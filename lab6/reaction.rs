@@ -0,0 +1,186 @@
+//!
+//! reaction.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Mass action reaction networks. A network is parsed from one reaction
+//! per line,
+//!
+//!   2 B -> B + C @ 3e7
+//!
+//! with integer stoichiometric coefficients, '+' between species, the rate
+//! constant after '@', and '#' starting a comment. Species are numbered in
+//! order of first appearance. The network then gives the ODE right-hand
+//! side y_s' = sum_r (nu_out - nu_in)_s k_r prod_j y_j^nu_in_j and its
+//! Jacobian in closed form
+//!
+
+#![allow(dead_code)]
+
+use crate::linalg::Matrix;
+use crate::system::OdeSystem;
+
+#[derive(Clone, Debug)]
+pub struct Reaction {
+    /// (species, coefficient) pairs
+    pub reactants: Vec<(usize, u32)>,
+    pub products: Vec<(usize, u32)>,
+    pub k: f64,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Network {
+    pub species: Vec<String>,
+    pub reactions: Vec<Reaction>,
+}
+
+impl Network {
+    pub fn parse(text: &str) -> Result<Network, Box<dyn std::error::Error>> {
+        let mut net = Network::default();
+        for (n, raw) in text.lines().enumerate() {
+            let line = raw.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (body, k) = line.split_once('@')
+                .ok_or_else(|| format!("line {}: '{line}' has no '@ k' rate constant", n + 1))?;
+            let k: f64 = k.trim().parse()
+                .map_err(|_| format!("line {}: rate constant '{}' is not a number", n + 1, k.trim()))?;
+            if !k.is_finite() || k < 0.0 {
+                return Err(format!("line {}: rate constant {k} must be finite and non-negative", n + 1).into());
+            }
+            let (lhs, rhs) = body.split_once("->")
+                .ok_or_else(|| format!("line {}: '{line}' has no '->'", n + 1))?;
+            let reactants = net.side(lhs, n + 1)?;
+            let products = net.side(rhs, n + 1)?;
+            if reactants.is_empty() && products.is_empty() {
+                return Err(format!("line {}: reaction has no species", n + 1).into());
+            }
+            net.reactions.push(Reaction { reactants, products, k });
+        }
+        if net.reactions.is_empty() {
+            return Err("reaction network has no reactions".into());
+        }
+        Ok(net)
+    }
+
+    ///
+    /// One side of a reaction, "0" or empty for none
+    ///
+    fn side(&mut self, text: &str, line: usize) -> Result<Vec<(usize, u32)>, Box<dyn std::error::Error>> {
+        let mut terms: Vec<(usize, u32)> = Vec::new();
+        let text = text.trim();
+        if text.is_empty() || text == "0" {
+            return Ok(terms);
+        }
+        for term in text.split('+') {
+            let term = term.trim();
+            let split = term.find(|c: char| !c.is_ascii_digit()).unwrap_or(term.len());
+            let (count, name) = term.split_at(split);
+            let count: u32 = if count.is_empty() { 1 } else { count.parse()? };
+            let name = name.trim();
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                return Err(format!("line {line}: '{term}' is not a species").into());
+            }
+            let s = self.index(name).unwrap_or_else(|| {
+                self.species.push(name.to_string());
+                self.species.len() - 1
+            });
+            match terms.iter_mut().find(|(i, _)| *i == s) {
+                Some(entry) => entry.1 += count,
+                None => terms.push((s, count)),
+            }
+        }
+        Ok(terms)
+    }
+
+    pub fn index(&self, name: &str) -> Option<usize> {
+        self.species.iter().position(|s| s == name)
+    }
+
+    pub fn species_count(&self) -> usize {
+        self.species.len()
+    }
+
+    ///
+    /// Mass action rate of reaction r
+    ///
+    fn flux(&self, r: &Reaction, y: &[f64]) -> f64 {
+        r.reactants.iter().fold(r.k, |p, (s, nu)| p * y[*s].powi(*nu as i32))
+    }
+
+    ///
+    /// Net change of each species per unit flux of reaction r
+    ///
+    fn stoichiometry(&self, r: &Reaction) -> Vec<(usize, f64)> {
+        let mut net: Vec<(usize, f64)> = Vec::new();
+        for (s, nu, sign) in r.reactants.iter().map(|(s, nu)| (*s, *nu, -1.0)).chain(r.products.iter().map(|(s, nu)| (*s, *nu, 1.0))) {
+            match net.iter_mut().find(|(i, _)| *i == s) {
+                Some(entry) => entry.1 += sign * nu as f64,
+                None => net.push((s, sign * nu as f64)),
+            }
+        }
+        net
+    }
+
+    pub fn rate(&self, y: &[f64], dy: &mut [f64]) {
+        dy.iter_mut().for_each(|d| *d = 0.0);
+        for r in &self.reactions {
+            let flux = self.flux(r, y);
+            for (s, nu) in self.stoichiometry(r) {
+                dy[s] += nu * flux;
+            }
+        }
+    }
+
+    ///
+    /// d(rate) / dy from the power rule on each reactant
+    ///
+    pub fn jacobian(&self, y: &[f64]) -> Matrix {
+        let n = self.species_count();
+        let mut jac = Matrix::zeros(n, n);
+        for r in &self.reactions {
+            let net = self.stoichiometry(r);
+            for (j, nu_j) in &r.reactants {
+                let d_flux = r.reactants.iter().fold(r.k * *nu_j as f64, |p, (s, nu)| {
+                    let power = if s == j { *nu as i32 - 1 } else { *nu as i32 };
+                    p * y[*s].powi(power)
+                });
+                for (s, nu) in &net {
+                    jac[(*s, *j)] += nu * d_flux;
+                }
+            }
+        }
+        jac
+    }
+
+    ///
+    /// The generated ODEs as text, one per species
+    ///
+    pub fn equations(&self) -> Vec<String> {
+        (0..self.species_count()).map(|s| {
+            let terms: Vec<String> = self.reactions.iter().filter_map(|r| {
+                let nu = self.stoichiometry(r).iter().find(|(i, _)| *i == s).map_or(0.0, |e| e.1);
+                if nu == 0.0 {
+                    return None;
+                }
+                let factors: Vec<String> = r.reactants.iter()
+                    .map(|(j, p)| if *p == 1 { self.species[*j].clone() } else { format!("{}^{p}", self.species[*j]) })
+                    .collect();
+                Some(format!("{:+e} {}", nu * r.k, factors.join(" ")).trim_end().to_string())
+            }).collect();
+            format!("d{}/dt = {}", self.species[s], if terms.is_empty() { "0".to_string() } else { terms.join(" ") })
+        }).collect()
+    }
+}
+
+impl OdeSystem for Network {
+    fn dimension(&self) -> usize {
+        self.species_count()
+    }
+
+    ///
+    /// Mass action is autonomous, t is unused
+    ///
+    fn rate(&self, _t: f64, y: &[f64], dy: &mut [f64]) {
+        Network::rate(self, y, dy);
+    }
+}
@@ -0,0 +1,70 @@
+//!
+//! reference.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Where a convergence study's "exact" solution comes from. A closed form
+//! is exact; a fine run of an accurate solver or a trajectory stored from
+//! one is only as good as that run. Each reference answers at the study's
+//! time points, off its own grid through the cubic Hermite dense output,
+//! and describes itself so the outputs say what the errors were taken
+//! against
+//!
+
+#![allow(dead_code)]
+
+use solvers::Method;
+
+use crate::export;
+use crate::multistep::dense;
+use crate::trajectory::Trajectory;
+
+pub enum Reference<const N: usize> {
+    /// closed form y(t), named for the record
+    Analytic { name: String, solution: Box<dyn Fn(f64) -> [f64; N]> },
+    /// a run of method at dt over the study's span, taken as exact
+    Solver { method: Method, dt: f64 },
+    /// an (n, N + 1) .npy trajectory as export::write_npy writes it
+    File(String),
+}
+
+impl<const N: usize> Reference<N> {
+    pub fn analytic(name: &str, solution: impl Fn(f64) -> [f64; N] + 'static) -> Reference<N> {
+        Reference::Analytic { name: name.to_string(), solution: Box::new(solution) }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            Reference::Analytic { name, .. } => format!("analytic, {name}"),
+            Reference::Solver { method, dt } => format!("{} at dt = {dt:e}", method.name()),
+            Reference::File(path) => format!("file {path}"),
+        }
+    }
+
+    ///
+    /// Reference states at each time in t, for the problem rate with
+    /// initial condition ic over ts. A stored trajectory must cover every
+    /// time asked for; times within rounding of its ends are taken as the
+    /// ends, since a grid built as t0 + i dt can overshoot tf by an ulp
+    ///
+    pub fn values<F>(&self, rate: &F, ic: [f64; N], ts: [f64; 2], t: &[f64])
+        -> Result<Vec<[f64; N]>, Box<dyn std::error::Error>>
+    where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+        let traj = match self {
+            Reference::Analytic { solution, .. } => return Ok(t.iter().map(|ti| solution(*ti)).collect()),
+            Reference::Solver { method, dt } => {
+                let ((tr, yr), _) = method.solve(rate, ic, ts, *dt)?;
+                Trajectory::from_rows(&tr, &yr)
+            }
+            Reference::File(path) => export::read_npy(path)?,
+        };
+        let times = traj.time();
+        if times.is_empty() {
+            return Err(format!("reference {} has no points", self.describe()).into());
+        }
+        let (lo, hi) = (times[0], times[times.len() - 1]);
+        let slack = 1e-9 * (hi - lo).abs().max(1.0);
+        t.iter().map(|ti| {
+            let s = if (ti - hi).abs() <= slack { hi } else if (ti - lo).abs() <= slack { lo } else { *ti };
+            dense(rate, &traj, s).map_err(|e| format!("reference {}: {e}", self.describe()).into())
+        }).collect()
+    }
+}
@@ -0,0 +1,130 @@
+//!
+//! regularize.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Tikhonov regularized least squares for ill-posed linear inverse
+//! problems A x = b: minimize |A x - b|^2 + lambda^2 |L x|^2 through the
+//! normal equations (A^T A + lambda^2 L^T L) x = A^T b. L is the identity
+//! or a difference operator penalizing roughness. lambda is picked from the
+//! L-curve, log |A x - b| against log |L x| over a sweep of lambda, at its
+//! corner of largest curvature, where further smoothing starts costing fit
+//!
+
+#![allow(dead_code)]
+
+use crate::linalg::{self, Matrix, Report};
+
+///
+/// Scaled finite difference of the given order (0 is the identity) on n
+/// unknowns, (n - order) x n
+///
+pub fn difference_operator(n: usize, order: usize) -> Result<Matrix, Box<dyn std::error::Error>> {
+    if order >= n {
+        return Err(format!("regularize: difference of order {order} needs more than {n} unknowns").into());
+    }
+    // binomial stencil with alternating sign, (1), (-1, 1), (1, -2, 1), ..
+    let mut stencil = vec![1.0];
+    for _ in 0..order {
+        let mut next = vec![0.0; stencil.len() + 1];
+        for (k, c) in stencil.iter().enumerate() {
+            next[k] -= c;
+            next[k + 1] += c;
+        }
+        stencil = next;
+    }
+    let mut l = Matrix::zeros(n - order, n);
+    for i in 0..n - order {
+        for (k, c) in stencil.iter().enumerate() {
+            l[(i, i + k)] = *c;
+        }
+    }
+    Ok(l)
+}
+
+///
+/// Normal equation pieces A^T A, L^T L and A^T b, shared across a sweep
+/// of lambda
+///
+#[derive(Clone, Debug)]
+pub struct Tikhonov {
+    pub a: Matrix,
+    pub b: Vec<f64>,
+    pub l: Matrix,
+    ata: Matrix,
+    ltl: Matrix,
+    atb: Vec<f64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct LCurvePoint {
+    pub lambda: f64,
+    /// |A x - b|
+    pub residual: f64,
+    /// |L x|
+    pub seminorm: f64,
+    /// of the normal equations, about the square of A's at lambda = 0
+    pub condition: f64,
+    pub x: Vec<f64>,
+}
+
+impl Tikhonov {
+    pub fn new(a: Matrix, b: &[f64], l: Matrix) -> Result<Tikhonov, Box<dyn std::error::Error>> {
+        if a.rows != b.len() || l.cols != a.cols {
+            return Err(format!("regularize: A is {}x{}, b has {} entries, L is {}x{}", a.rows, a.cols, b.len(), l.rows, l.cols).into());
+        }
+        let at = a.transpose();
+        let ata = at.mul(&a);
+        let ltl = l.transpose().mul(&l);
+        let atb = at.mul_vec(b);
+        Ok(Tikhonov { a, b: b.to_vec(), l, ata, ltl, atb })
+    }
+
+    pub fn solve(&self, lambda: f64) -> Result<(Vec<f64>, Report), Box<dyn std::error::Error>> {
+        let mut m = self.ata.clone();
+        for i in 0..m.rows {
+            for j in 0..m.cols {
+                m[(i, j)] += lambda * lambda * self.ltl[(i, j)];
+            }
+        }
+        let (x, report) = m.solve_report(&self.atb)?;
+        linalg::check_condition("tikhonov normal equations", report.condition);
+        Ok((x, report))
+    }
+
+    pub fn point(&self, lambda: f64) -> Result<LCurvePoint, Box<dyn std::error::Error>> {
+        let (x, report) = self.solve(lambda)?;
+        let norm = |v: Vec<f64>| v.iter().map(|e| e * e).sum::<f64>().sqrt();
+        let residual = norm(self.a.mul_vec(&x).iter().zip(&self.b).map(|(ax, b)| ax - b).collect());
+        let seminorm = norm(self.l.mul_vec(&x));
+        Ok(LCurvePoint { lambda, residual, seminorm, condition: report.condition, x })
+    }
+
+    ///
+    /// L-curve at n lambdas spaced logarithmically over [lo, hi]
+    ///
+    pub fn l_curve(&self, lo: f64, hi: f64, n: usize) -> Result<Vec<LCurvePoint>, Box<dyn std::error::Error>> {
+        if !(lo > 0.0 && hi > lo) || n < 3 {
+            return Err(format!("regularize: need 0 < lo < hi and at least 3 lambdas, got [{lo}, {hi}], {n}").into());
+        }
+        (0..n).map(|k| self.point(lo * (hi / lo).powf(k as f64 / (n - 1) as f64))).collect()
+    }
+}
+
+///
+/// Index of the L-curve corner, the interior point of largest curvature of
+/// (log residual, log seminorm) as a curve in log lambda, by central
+/// differences, so the points must come from a logarithmic sweep. Only
+/// turns toward the origin count
+///
+pub fn corner(points: &[LCurvePoint]) -> Option<usize> {
+    let log: Vec<(f64, f64)> = points.iter().map(|p| (p.residual.max(1e-300).ln(), p.seminorm.max(1e-300).ln())).collect();
+    (1..log.len().saturating_sub(1)).filter_map(|i| {
+        let (a, b, c) = (log[i - 1], log[i], log[i + 1]);
+        let (dx, dy) = (0.5 * (c.0 - a.0), 0.5 * (c.1 - a.1));
+        let (ddx, ddy) = (c.0 - 2.0 * b.0 + a.0, c.1 - 2.0 * b.1 + a.1);
+        let speed = (dx * dx + dy * dy).powf(1.5);
+        // lambda increasing runs the curve from top left to bottom right,
+        // the corner turns counter clockwise
+        let kappa = (dx * ddy - dy * ddx) / speed;
+        (speed > 0.0 && kappa > 0.0).then_some((i, kappa))
+    }).max_by(|x, y| x.1.total_cmp(&y.1)).map(|(i, _)| i)
+}
@@ -0,0 +1,147 @@
+//!
+//! report.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! The mechanical part of a lab writeup: a run's figures with captions and
+//! its convergence and method comparison tables, rendered as a Markdown or
+//! LaTeX fragment to paste into the report. Studies hand their tables to
+//! the RunContext as they print them; figures come from the run's outputs
+//!
+
+#![allow(dead_code)]
+
+use std::fmt::Display;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Markdown,
+    Latex,
+}
+
+impl Format {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Format::Markdown => "md",
+            Format::Latex => "tex",
+        }
+    }
+}
+
+///
+/// Captioned table of preformatted cells, one header cell per column
+///
+#[derive(Clone, Debug)]
+pub struct Table {
+    pub caption: String,
+    pub header: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new<S: AsRef<str>>(caption: impl Into<String>, header: &[S]) -> Table {
+        Table { caption: caption.into(), header: header.iter().map(|h| h.as_ref().to_string()).collect(), rows: Vec::new() }
+    }
+
+    ///
+    /// Appends a row, cells past the header's width dropped and missing
+    /// ones left blank
+    ///
+    pub fn row<I, T>(&mut self, cells: I)
+    where
+        I: IntoIterator<Item = T>,
+        T: Display,
+    {
+        let mut row: Vec<String> = cells.into_iter().take(self.header.len()).map(|c| c.to_string()).collect();
+        row.resize(self.header.len(), String::new());
+        self.rows.push(row);
+    }
+
+    ///
+    /// The table as a LaTeX table float, cells right aligned and escaped,
+    /// ready to \input into a report
+    ///
+    pub fn to_latex_table(&self) -> String {
+        let line = |cells: &[String]| cells.iter().map(|c| latex(c)).collect::<Vec<_>>().join(" & ");
+        let mut out = String::from("\\begin{table}[htbp]\n\\centering\n");
+        out.push_str(&format!("\\caption{{{}}}\n", latex(&self.caption)));
+        out.push_str(&format!("\\begin{{tabular}}{{{}}}\n\\hline\n", "r".repeat(self.header.len())));
+        out.push_str(&format!("{} \\\\\n\\hline\n", line(&self.header)));
+        for row in &self.rows {
+            out.push_str(&format!("{} \\\\\n", line(row)));
+        }
+        out.push_str("\\hline\n\\end{tabular}\n\\end{table}\n");
+        out
+    }
+}
+
+/// LaTeX's special characters escaped for text mode
+fn latex(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str(r"\textbackslash{}"),
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '~' => out.push_str(r"\textasciitilde{}"),
+            '^' => out.push_str(r"\textasciicircum{}"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Pipes escaped so a cell cannot split a Markdown table row
+fn markdown(s: &str) -> String {
+    s.replace('|', r"\|")
+}
+
+///
+/// Figures (file relative to the run directory, caption) and tables of one
+/// run, in the order they were produced
+///
+#[derive(Clone, Debug, Default)]
+pub struct Report {
+    pub title: String,
+    pub figures: Vec<(String, String)>,
+    pub tables: Vec<Table>,
+}
+
+impl Report {
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Markdown => self.markdown(),
+            Format::Latex => self.latex(),
+        }
+    }
+
+    fn markdown(&self) -> String {
+        let mut out = format!("## {}\n", markdown(&self.title));
+        for table in &self.tables {
+            out.push_str(&format!("\n**{}**\n\n", markdown(&table.caption)));
+            out.push_str(&format!("| {} |\n", table.header.iter().map(|h| markdown(h)).collect::<Vec<_>>().join(" | ")));
+            out.push_str(&format!("|{}\n", " ---: |".repeat(table.header.len())));
+            for row in &table.rows {
+                out.push_str(&format!("| {} |\n", row.iter().map(|c| markdown(c)).collect::<Vec<_>>().join(" | ")));
+            }
+        }
+        for (file, caption) in &self.figures {
+            out.push_str(&format!("\n![{}]({file})\n\n*{}*\n", markdown(caption), markdown(caption)));
+        }
+        out
+    }
+
+    fn latex(&self) -> String {
+        let mut out = format!("\\subsection*{{{}}}\n", latex(&self.title));
+        for table in &self.tables {
+            out.push('\n');
+            out.push_str(&table.to_latex_table());
+        }
+        for (file, caption) in &self.figures {
+            out.push_str("\n\\begin{figure}[htbp]\n\\centering\n");
+            out.push_str(&format!("\\includegraphics[width=\\linewidth]{{{file}}}\n"));
+            out.push_str(&format!("\\caption{{{}}}\n\\end{{figure}}\n", latex(caption)));
+        }
+        out
+    }
+}
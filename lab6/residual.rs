@@ -0,0 +1,81 @@
+//!
+//! residual.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Defect of a numerical solution, r(t) = s'(t) - f(t, s(t)), where s is
+//! the natural cubic spline through each component of the trajectory. An
+//! exact solution has r = 0, so the size of r says how far the computed
+//! points are from solving the equation without a second, finer run to
+//! compare against. r is measured at step midpoints, where the spline is
+//! furthest from the knots. The spline adds an error of its own, O(h^4)
+//! at the midpoints of a smooth run, so up to fourth order the defect
+//! falls as h^p with the method's order p. The natural end conditions
+//! raise r over the first and last steps, decaying some twenty steps in
+//!
+
+#![allow(dead_code)]
+
+use crate::spline::{Interpolation, Spline};
+use crate::trajectory::Trajectory;
+
+///
+/// Defect at the midpoints t, and each component's scale: the largest
+/// |f_j| along the trajectory, so r / scale is a relative defect
+///
+pub struct Defect<const N: usize> {
+    pub t: Vec<f64>,
+    pub r: Vec<[f64; N]>,
+    pub scale: [f64; N],
+}
+
+impl<const N: usize> Defect<N> {
+    ///
+    /// |r_j| / scale_j at every midpoint for component j
+    ///
+    pub fn relative(&self, j: usize) -> Vec<f64> {
+        let scale = if self.scale[j] > 0.0 { self.scale[j] } else { 1.0 };
+        self.r.iter().map(|r| r[j].abs() / scale).collect()
+    }
+
+    ///
+    /// Largest relative defect of component j over midpoints inside
+    /// within, which can leave out the spline's end effects
+    ///
+    pub fn max_relative(&self, j: usize, within: [f64; 2]) -> f64 {
+        self.t.iter().zip(self.relative(j))
+            .filter(|(t, _)| (within[0]..=within[1]).contains(*t))
+            .fold(0.0, |m, (_, r)| m.max(r))
+    }
+}
+
+///
+/// Defect of traj as a solution of y' = rate(t, y). Needs at least three
+/// points at strictly increasing times, so a trajectory with an impulse
+/// (a repeated time) has to be split at it first
+///
+pub fn defect<F, const N: usize>(rate: &F, traj: &Trajectory<N>) -> Result<Defect<N>, Box<dyn std::error::Error>>
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    if traj.len() < 3 {
+        return Err(format!("defect needs at least three points, the trajectory has {}", traj.len()).into());
+    }
+    let t = traj.time();
+    let splines = (0..N).map(|j| Spline::new(t, traj.component(j), Interpolation::Cubic)).collect::<Result<Vec<_>, _>>()?;
+
+    let mut scale = [0.0_f64; N];
+    let mut f = [0.0; N];
+    for (i, ti) in t.iter().enumerate() {
+        rate(*ti, &traj.row(i), &mut f);
+        for j in 0..N {
+            scale[j] = scale[j].max(f[j].abs());
+        }
+    }
+
+    let mut out = Defect { t: Vec::with_capacity(t.len() - 1), r: Vec::with_capacity(t.len() - 1), scale };
+    for w in t.windows(2) {
+        let s = 0.5 * (w[0] + w[1]);
+        let y: [f64; N] = std::array::from_fn(|j| splines[j].eval(s));
+        rate(s, &y, &mut f);
+        out.t.push(s);
+        out.r.push(std::array::from_fn(|j| splines[j].derivative(s) - f[j]));
+    }
+    Ok(out)
+}
@@ -0,0 +1,71 @@
+//!
+//! richardson.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Global Richardson extrapolation of whole fixed-step trajectories. Runs
+//! of an order p method at dt and dt / 2 are combined point by point on
+//! the coarse grid, one order up, with an estimate of the fine run's own
+//! error. Kept out of the solvers crate, which builds no_std: this needs
+//! std's errors and formatting
+//!
+
+#![allow(dead_code)]
+
+use solvers::{Method, Solution};
+
+///
+/// Whole-trajectory Richardson extrapolation on the coarse run's grid: the
+/// combined solution one order up, and the estimated error of the fine
+/// run at every point, max over components
+///
+pub struct Richardson<const N: usize> {
+    pub t: Vec<f64>,
+    pub extrapolated: Vec<[f64; N]>,
+    pub estimate: Vec<f64>,
+}
+
+///
+/// Combines runs of an order p method at dt (coarse) and dt / 2 (fine)
+/// point by point as y_f + (y_f - y_c) / (2^p - 1), which cancels the
+/// h^p term of the global error, and |y_f - y_c| / (2^p - 1) estimates
+/// the fine run's own error. Every coarse time must also be a fine one
+///
+pub fn richardson<const N: usize>(order: u32, coarse: &Solution<N>, fine: &Solution<N>)
+    -> Result<Richardson<N>, Box<dyn std::error::Error>> {
+    let (tc, yc) = coarse;
+    let (tf, yf) = fine;
+    if tf.is_empty() {
+        return Err("the fine run has no points".into());
+    }
+    let span = tc.last().zip(tc.first()).map_or(1.0, |(b, a)| (b - a).abs().max(1.0));
+    let factor = 1.0 / (2.0_f64.powi(order as i32) - 1.0);
+    let mut out = Richardson {
+        t: Vec::with_capacity(tc.len()),
+        extrapolated: Vec::with_capacity(tc.len()),
+        estimate: Vec::with_capacity(tc.len()),
+    };
+    for (t, c) in tc.iter().zip(yc) {
+        // nearest fine point, which must be this time up to rounding
+        let i = tf.partition_point(|s| *s < *t);
+        let j = [i.saturating_sub(1), i.min(tf.len().saturating_sub(1))]
+            .into_iter()
+            .min_by(|a, b| (tf[*a] - t).abs().total_cmp(&(tf[*b] - t).abs()))
+            .filter(|j| (tf[*j] - t).abs() <= 1e-9 * span)
+            .ok_or_else(|| format!("t = {t} of the coarse run is not on the fine run's grid"))?;
+        let f = yf[j];
+        out.t.push(*t);
+        out.extrapolated.push(std::array::from_fn(|k| f[k] + (f[k] - c[k]) * factor));
+        out.estimate.push(f.iter().zip(c).map(|(a, b)| (a - b).abs() * factor).fold(0.0, f64::max));
+    }
+    Ok(out)
+}
+
+///
+/// Runs method at dt and dt / 2 over ts and extrapolates the pair
+///
+pub fn global_richardson<F, const N: usize>(method: Method, rate: &F, ic: [f64; N], ts: [f64; 2], dt: f64)
+    -> Result<Richardson<N>, Box<dyn std::error::Error>>
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    let (coarse, _) = method.solve(rate, ic, ts, dt)?;
+    let (fine, _) = method.solve(rate, ic, ts, 0.5 * dt)?;
+    richardson(method.order(), &coarse, &fine)
+}
@@ -0,0 +1,303 @@
+//!
+//! run_context.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Per-run output directory management. Every run gets its own timestamped
+//! directory that all plots/data are written into, plus a manifest holding
+//! the configuration, git revision, solvers used, and wall-clock timings.
+//! The run's plot style is kept here too, so every figure of a run matches,
+//! and with PLOT_FOOTER set each figure is stamped with the run, git
+//! revision, current solver, dt and tolerances it came from. Tables and
+//! figure captions handed to it make up the run's report fragment
+//!
+
+#![allow(dead_code)]
+
+use std::fmt::Display;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use crate::report::{Format, Report, Table};
+use crate::theme::PlotStyle;
+
+///
+/// Output directory and manifest bookkeeping for a single run
+///
+pub struct RunContext {
+    name:    String,
+    dir:     PathBuf,
+    stamp:   String,
+    started: Instant,
+    config:  Vec<(String, String)>,
+    solvers: Vec<(String, String)>,
+    outputs: Vec<String>,
+    timings: Vec<(String, f64)>,
+    style:   PlotStyle,
+    footer:  bool,
+    current: Option<String>,
+    git:     String,
+    tables:  Vec<Table>,
+    captions: Vec<(String, String)>,
+}
+
+///
+/// UTC calendar timestamp (YYYYmmdd-HHMMSS) from seconds since the epoch
+/// using the days-from-civil inverse, avoids pulling in a date crate
+///
+fn utc_stamp(secs: u64) -> String {
+    let days = (secs / 86_400) as i64;
+    let rem  = secs % 86_400;
+    let (hh, mm, ss) = (rem / 3600, (rem % 3600) / 60, rem % 60);
+
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp  = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year  = yoe + era * 400 + i64::from(month <= 2);
+
+    format!("{year:04}{month:02}{day:02}-{hh:02}{mm:02}{ss:02}")
+}
+
+///
+/// Short hash of the current git revision, or "unknown" outside a repo
+///
+fn git_hash() -> String {
+    Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|out| out.status.success())
+        .map_or_else(
+            || "unknown".to_string(),
+            |out| String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+impl RunContext {
+    ///
+    /// Creates `root/name-YYYYmmdd-HHMMSS`, suffixing a counter rather than
+    /// overwriting if a run already exists for that second
+    ///
+    pub fn new(root: &str, name: &str) -> Result<RunContext, Box<dyn std::error::Error>> {
+        let secs  = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let stamp = utc_stamp(secs);
+        fs::create_dir_all(root)?;
+
+        let mut dir = PathBuf::from(root).join(format!("{name}-{stamp}"));
+        let mut k = 1;
+        while dir.exists() {
+            dir = PathBuf::from(root).join(format!("{name}-{stamp}-{k}"));
+            k += 1;
+        }
+        fs::create_dir(&dir)?;
+
+        let style = PlotStyle::from_env();
+        let footer = std::env::var("PLOT_FOOTER").is_ok_and(|v| !v.is_empty() && v != "0");
+        Ok(RunContext {
+            name: name.to_string(),
+            dir,
+            stamp,
+            started: Instant::now(),
+            config:  vec![
+                ("plot_style".to_string(), style.name.to_string()),
+                ("plot_footer".to_string(), footer.to_string()),
+            ],
+            solvers: Vec::new(),
+            outputs: Vec::new(),
+            timings: Vec::new(),
+            style,
+            footer,
+            current: None,
+            git: git_hash(),
+            tables: Vec::new(),
+            captions: Vec::new(),
+        })
+    }
+
+    ///
+    /// Style for every figure of the run, PLOT_STYLE's preset unless set,
+    /// carrying the footer as of now when footers are on
+    ///
+    pub fn style(&self) -> PlotStyle {
+        PlotStyle { footer: self.footer.then(|| self.footer_text()), ..self.style.clone() }
+    }
+
+    pub fn set_style(&mut self, style: PlotStyle) {
+        self.config("plot_style", style.name);
+        self.style = style;
+    }
+
+    ///
+    /// Turns the provenance footer under every later figure on or off
+    ///
+    pub fn set_footer(&mut self, footer: bool) {
+        self.config("plot_footer", footer);
+        self.footer = footer;
+    }
+
+    ///
+    /// Run directory, git revision, the solver most recently recorded and
+    /// the dt and tolerances in the configuration, one line
+    ///
+    pub fn footer_text(&self) -> String {
+        let mut parts = vec![
+            self.dir.file_name().map_or(self.name.clone(), |d| d.to_string_lossy().into_owned()),
+            format!("git {}", self.git),
+        ];
+        if let Some(solver) = &self.current {
+            parts.push(solver.clone());
+        }
+        for key in ["dt", "rtol", "atol"] {
+            if let Some((_, v)) = self.config.iter().find(|(k, _)| k == key) {
+                parts.push(format!("{key} = {v}"));
+            }
+        }
+        parts.join("  |  ")
+    }
+
+    pub fn dir(&self) -> &PathBuf {
+        &self.dir
+    }
+
+    ///
+    /// Path for an output file inside the run directory, recorded in the
+    /// manifest. Returned as a String since the plotting backends take &str
+    ///
+    pub fn path(&mut self, file: &str) -> String {
+        if !self.outputs.iter().any(|f| f == file) {
+            self.outputs.push(file.to_string());
+        }
+        self.dir.join(file).to_string_lossy().into_owned()
+    }
+
+    ///
+    /// Records a configuration value, later values for a key replace earlier
+    ///
+    pub fn config(&mut self, key: &str, value: impl Display) {
+        let value = value.to_string();
+        match self.config.iter_mut().find(|(k, _)| k == key) {
+            Some(entry) => entry.1 = value,
+            None => self.config.push((key.to_string(), value)),
+        }
+    }
+
+    ///
+    /// Records a solver used by the run along with its revision, and makes
+    /// it the one named in figure footers
+    ///
+    pub fn solver(&mut self, name: &str, version: &str) {
+        if !self.solvers.iter().any(|(n, _)| n == name) {
+            self.solvers.push((name.to_string(), version.to_string()));
+        }
+        self.current = Some(format!("{name} ({version})"));
+    }
+
+    ///
+    /// Times a closure and adds the elapsed wall-clock seconds to label
+    ///
+    pub fn time<T>(&mut self, label: &str, f: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let out = f();
+        let elapsed = start.elapsed().as_secs_f64();
+        match self.timings.iter_mut().find(|(k, _)| k == label) {
+            Some(entry) => entry.1 += elapsed,
+            None => self.timings.push((label.to_string(), elapsed)),
+        }
+        out
+    }
+
+    ///
+    /// Caption for an output figure in the report, the file's stem otherwise
+    ///
+    pub fn caption(&mut self, file: &str, caption: impl Display) {
+        let caption = caption.to_string();
+        match self.captions.iter_mut().find(|(f, _)| f == file) {
+            Some(entry) => entry.1 = caption,
+            None => self.captions.push((file.to_string(), caption)),
+        }
+    }
+
+    ///
+    /// Adds a convergence or comparison table to the report
+    ///
+    pub fn table(&mut self, table: Table) {
+        self.tables.push(table);
+    }
+
+    ///
+    /// Writes table into the run directory as file via to_latex_table and
+    /// adds it to the report
+    ///
+    pub fn write_table(&mut self, file: &str, table: Table) -> Result<(), Box<dyn std::error::Error>> {
+        fs::write(self.path(file), table.to_latex_table())?;
+        self.table(table);
+        Ok(())
+    }
+
+    ///
+    /// The run's tables and its png outputs with their captions
+    ///
+    pub fn report(&self) -> Report {
+        let figures = self.outputs.iter()
+            .filter(|f| f.ends_with(".png"))
+            .map(|f| {
+                let caption = self.captions.iter().find(|(c, _)| c == f)
+                    .map_or_else(|| f.trim_end_matches(".png").replace('_', " "), |(_, c)| c.clone());
+                (f.clone(), caption)
+            })
+            .collect();
+        Report { title: format!("{} ({}, git {})", self.name, self.stamp, self.git), figures, tables: self.tables.clone() }
+    }
+
+    ///
+    /// Writes report.md and report.tex into the run directory, figure
+    /// paths relative to it
+    ///
+    pub fn write_report(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let report = self.report();
+        for format in [Format::Markdown, Format::Latex] {
+            fs::write(self.dir.join(format!("report.{}", format.extension())), report.render(format))?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Writes manifest.toml into the run directory
+    ///
+    pub fn write_manifest(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let quote = |s: &str| format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""));
+        let mut out = String::new();
+
+        out.push_str(&format!("name = {}\n", quote(&self.name)));
+        out.push_str(&format!("started_utc = {}\n", quote(&self.stamp)));
+        out.push_str(&format!("git = {}\n", quote(&self.git)));
+        out.push_str(&format!(
+            "version = {}\n", quote(option_env!("CARGO_PKG_VERSION").unwrap_or("unknown"))));
+        out.push_str(&format!("wall_seconds = {:.6}\n", self.started.elapsed().as_secs_f64()));
+        out.push_str(&format!(
+            "outputs = [{}]\n",
+            self.outputs.iter().map(|f| quote(f)).collect::<Vec<_>>().join(", ")));
+
+        out.push_str("\n[config]\n");
+        for (k, v) in &self.config {
+            out.push_str(&format!("{} = {}\n", quote(k), quote(v)));
+        }
+        out.push_str("\n[solvers]\n");
+        for (k, v) in &self.solvers {
+            out.push_str(&format!("{} = {}\n", quote(k), quote(v)));
+        }
+        out.push_str("\n[timings]\n");
+        for (k, v) in &self.timings {
+            out.push_str(&format!("{} = {v:.6}\n", quote(k)));
+        }
+
+        let mut file = fs::File::create(self.dir.join("manifest.toml"))?;
+        file.write_all(out.as_bytes())?;
+        Ok(())
+    }
+}
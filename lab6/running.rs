@@ -0,0 +1,98 @@
+//!
+//! running.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Summary statistics accumulated while integrating, so peak values, their
+//! times, means and oscillation amplitudes are available from a run whose
+//! trajectory was never stored. Feed it from any observer callback
+//!
+
+#![allow(dead_code)]
+
+///
+/// Per-component running min/max (with the times they occur) and the time
+/// averaged mean over [t_skip, t_last]. Points before t_skip are ignored so
+/// the summary can describe the settled behaviour instead of the transient
+///
+#[derive(Clone, Debug)]
+pub struct RunningStats<const N: usize> {
+    pub t_skip: f64,
+    pub count: usize,
+    pub min: [f64; N],
+    pub max: [f64; N],
+    pub t_min: [f64; N],
+    pub t_max: [f64; N],
+    integral: [f64; N],
+    last: Option<(f64, [f64; N])>,
+    t_first: f64,
+}
+
+impl<const N: usize> RunningStats<N> {
+    pub fn new(t_skip: f64) -> RunningStats<N> {
+        RunningStats {
+            t_skip,
+            count: 0,
+            min: [f64::INFINITY; N],
+            max: [f64::NEG_INFINITY; N],
+            t_min: [f64::NAN; N],
+            t_max: [f64::NAN; N],
+            integral: [0.0; N],
+            last: None,
+            t_first: f64::NAN,
+        }
+    }
+
+    ///
+    /// Accumulates one accepted point, in increasing t
+    ///
+    pub fn observe(&mut self, t: f64, y: &[f64; N]) {
+        if t < self.t_skip {
+            return;
+        }
+        for (i, &x) in y.iter().enumerate() {
+            if x < self.min[i] {
+                self.min[i] = x;
+                self.t_min[i] = t;
+            }
+            if x > self.max[i] {
+                self.max[i] = x;
+                self.t_max[i] = t;
+            }
+        }
+
+        // trapezoid in time so unequal steps are weighted correctly
+        match self.last {
+            Some((tp, yp)) => {
+                for ((acc, x), xp) in self.integral.iter_mut().zip(y.iter()).zip(yp.iter()) {
+                    *acc += 0.5 * (t - tp) * (x + xp);
+                }
+            }
+            None => self.t_first = t,
+        }
+        self.last = Some((t, *y));
+        self.count += 1;
+    }
+
+    ///
+    /// Time average over the observed window, the single value when only
+    /// one point was seen
+    ///
+    pub fn mean(&self) -> [f64; N] {
+        match self.last {
+            Some((t, _)) if t > self.t_first => std::array::from_fn(|i| self.integral[i] / (t - self.t_first)),
+            Some((_, y)) => y,
+            None => [f64::NAN; N],
+        }
+    }
+
+    ///
+    /// Half the peak-to-peak range, the oscillation amplitude once the
+    /// window covers only the settled cycle
+    ///
+    pub fn amplitude(&self) -> [f64; N] {
+        std::array::from_fn(|i| 0.5 * (self.max[i] - self.min[i]))
+    }
+
+    pub fn last(&self) -> Option<[f64; N]> {
+        self.last.map(|(_, y)| y)
+    }
+}
@@ -0,0 +1,74 @@
+//!
+//! scaling.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Nondimensionalization helpers. Given characteristic scales Y_i for each
+//! state and T for time, a system y' = f(t, y) is integrated as
+//! u' = (T / Y_i) f(t0 + T s, Y u) in u = y / Y, s = (t - t0) / T, so the
+//! solver sees O(1) states and coefficients; results are mapped back
+//!
+
+#![allow(dead_code)]
+
+use solvers::Solution;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Scales<const N: usize> {
+    pub state: [f64; N],
+    pub time: f64,
+    pub t0: f64,
+}
+
+impl<const N: usize> Scales<N> {
+    pub fn new(state: [f64; N], time: f64, t0: f64) -> Scales<N> {
+        Scales { state, time, t0 }
+    }
+
+    pub fn scale(&self, y: &[f64; N]) -> [f64; N] {
+        std::array::from_fn(|i| y[i] / self.state[i])
+    }
+
+    pub fn unscale(&self, u: &[f64; N]) -> [f64; N] {
+        std::array::from_fn(|i| u[i] * self.state[i])
+    }
+
+    pub fn scale_time(&self, t: f64) -> f64 {
+        (t - self.t0) / self.time
+    }
+
+    pub fn unscale_time(&self, s: f64) -> f64 {
+        self.t0 + s * self.time
+    }
+
+    ///
+    /// Rate of the scaled system in (s, u) built from the dimensional rate
+    ///
+    pub fn rate<'a, F>(&'a self, rate: &'a F) -> impl Fn(f64, &[f64; N], &mut [f64; N]) + 'a
+    where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+        move |s: f64, u: &[f64; N], du: &mut [f64; N]| {
+            let mut dy = [0.0; N];
+            rate(self.unscale_time(s), &self.unscale(u), &mut dy);
+            for i in 0..N {
+                du[i] = self.time * dy[i] / self.state[i];
+            }
+        }
+    }
+
+    ///
+    /// Runs any fixed-step solver on the scaled system and maps the
+    /// trajectory back. `solve` receives (rate, u0, scaled span, scaled dt)
+    ///
+    pub fn solve<F, S>(&self, rate: &F, ic: [f64; N], ts: [f64; 2], dt: f64, solve: S)
+        -> Result<Solution<N>, Box<dyn std::error::Error>>
+    where
+        F: Fn(f64, &[f64; N], &mut [f64; N]),
+        S: Fn(&dyn Fn(f64, &[f64; N], &mut [f64; N]), [f64; N], [f64; 2], f64) -> Result<Solution<N>, Box<dyn std::error::Error>>
+    {
+        let scaled = self.rate(rate);
+        let span = [self.scale_time(ts[0]), self.scale_time(ts[1])];
+        let (s, u) = solve(&scaled, self.scale(&ic), span, dt / self.time)?;
+        Ok((
+            s.iter().map(|si| self.unscale_time(*si)).collect(),
+            u.iter().map(|ui| self.unscale(ui)).collect(),
+        ))
+    }
+}
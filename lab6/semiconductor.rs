@@ -14,12 +14,84 @@
 
 use plotters::prelude::*; 
 use plotters_bitmap::BitMapBackend;
-use plotters::style::Palette99;
+use solvers::{CorrectorFailure, Limits, SolveStats};
+
+mod adams;
+mod arclength;
+mod averaging;
+mod axes;
+mod batch;
+mod circuit;
+mod correlation;
+mod downsample;
+mod experiment;
+mod export;
+mod fourier;
+mod graph;
+mod harmonic;
+mod inset;
+mod interval;
+mod legend;
+mod linalg;
+mod modified;
+mod multirate;
+mod multistep;
+mod network;
+mod noise;
+mod peaks;
+mod periodic;
+mod phase;
+mod projection;
+mod report;
+mod run_context;
+mod running;
+mod spectral;
+mod spline;
+mod state;
+mod sweep_store;
+mod system;
+mod taylor;
+mod theme;
+mod tolerance;
+mod trajectory;
+mod vanderpol;
+
+use axes::Axis;
+use circuit::Circuit;
+use experiment::Experiment;
+use inset::Inset;
+use interval::{Interval, IntervalSystem};
+use legend::{Legend, Position};
+use multistep::{Mode, Startup};
+use network::{Coupling, Network};
+use report::Table;
+use run_context::RunContext;
+use running::RunningStats;
+use state::StateVar;
+use system::OdeSystem;
+use theme::PlotStyle;
+use taylor::{Series, SeriesSystem};
+use tolerance::Tolerance;
+use trajectory::{SharedTrajectory, Trajectory};
 
 // ODE: y'' = ay' - (y')^3 - y 
 // System of first order 
 // z' = [z2; az2 - (z2)^3 - z1]
 
+///
+/// The voltage and its rate of change, both dimensionless as the 
+/// equation is written 
+///
+pub const STATES: [StateVar; 2] = [
+    StateVar::new("voltage", "dimensionless"), 
+    StateVar::new("dV/dt", "dimensionless"), 
+]; 
+
+///
+/// The lab's initial condition, V = 0 with a small kick dV/dt = 0.1 
+///
+pub const IC: [f64; 2] = [0.0, 0.1]; 
+
 ///
 /// Rate function for nonlinear system of 1st Order ODE's 
 ///
@@ -29,242 +101,2397 @@ pub fn rate(alpha: f64, z: &[f64; 2], dz: &mut [f64; 2]) {
 }
 
 ///
-/// RK4 rk4r for 2 state linear system 
+/// The semiconductor at a fixed alpha as an OdeSystem, for the solvers 
+/// that take one 
 ///
-fn rk4(alpha: f64, dt: f64, t0: f64, tf: f64) -> (Vec<f64>, Vec<[f64; 2]>) {
-    let el = ((tf - t0) / dt).floor() as usize;  
-    let mut t: Vec<f64> = Vec::with_capacity(el + 1); 
-    let mut y: Vec<[f64; 2]> = Vec::with_capacity(el + 1);
+#[derive(Clone, Copy, Debug)]
+pub struct Oscillator {
+    pub alpha: f64, 
+}
 
-    t.push(t0);
-    y.push([0.0, 0.1]);
+impl OdeSystem for Oscillator {
+    fn dimension(&self) -> usize {
+        2
+    }
 
-    // initialize local per step states
-    let mut k1: [f64; 2] = [0.0, 0.0];
-    let mut k2: [f64; 2] = [0.0, 0.0];
-    let mut k3: [f64; 2] = [0.0, 0.0];
-    let mut k4: [f64; 2] = [0.0, 0.0];
+    fn rate(&self, _t: f64, y: &[f64], dy: &mut [f64]) {
+        dy[0] = y[1]; 
+        dy[1] = self.alpha * y[1] - y[1].powi(3) - y[0]; 
+    }
+}
 
-    let mut w1: [f64; 2];
-    let mut w2: [f64; 2] = [0.0, 0.0];
-    let mut w3: [f64; 2] = [0.0, 0.0];
-    let mut w4: [f64; 2] = [0.0, 0.0];
+///
+/// rate for a batch of alphas at once, lane k of z at alphas[k] 
+///
+pub fn rate_batch(alphas: &[f64], z: &[Vec<f64>; 2], dz: &mut [Vec<f64>; 2]) {
+    let [v, dv] = z; 
+    let [dz0, dz1] = dz; 
+    dz0.copy_from_slice(dv); 
+    for (((d, a), v), dv) in dz1.iter_mut().zip(alphas).zip(v).zip(dv) {
+        *d = a * dv - dv.powi(3) - v; 
+    }
+}
 
-    let update = |w: &[f64; 2], k: &[f64; 2], u: &mut [f64; 2], h: f64| {
-         u[0] = w[0] + h * k[0]; 
-         u[1] = w[1] + h * k[1]; 
-    }; 
+///
+/// Jacobian of rate with respect to (V, dV/dt) 
+///
+pub fn jacobian(alpha: f64, z: &[f64; 2]) -> [[f64; 2]; 2] {
+    [[0.0, 1.0], [-1.0, alpha - 3.0 * z[1].powi(2)]]
+}
 
-    let next = |w1: &[f64; 2], ks: &[[f64; 2]; 4]| -> [f64; 2] {
-        let mut wnext: [f64; 2] = [0.0, 0.0]; 
-        let pool0 = (ks[0][0] + 2.0 * ks[1][0] + 2.0 * ks[2][0] + ks[3][0]);
-        let pool1 = (ks[0][1] + 2.0 * ks[1][1] + 2.0 * ks[2][1] + ks[3][1]);
+///
+/// Two oscillators at alphas[0] and alphas[1] coupled through their 
+/// voltages, V_i'' = a_i V_i' - V_i'^3 - V_i + k (V_j - V_i), in the 
+/// state (V_1, V_1', V_2, V_2') 
+///
+pub fn coupled_rate(alphas: [f64; 2], k: f64, z: &[f64; 4], dz: &mut [f64; 4]) {
+    dz[0] = z[1]; 
+    dz[1] = alphas[0] * z[1] - z[1].powi(3) - z[0] + k * (z[2] - z[0]); 
+    dz[2] = z[3]; 
+    dz[3] = alphas[1] * z[3] - z[3].powi(3) - z[2] + k * (z[0] - z[2]); 
+}
 
-        wnext[0] = w1[0] + (dt / 6.0) * pool0; 
-        wnext[1] = w1[1] + (dt / 6.0) * pool1; 
-        wnext 
-    };
+///
+/// Rate function on truncated power series for the Taylor integrator 
+///
+pub fn rate_series(alpha: f64, z: &[Series; 2]) -> [Series; 2] {
+    [z[1].clone(), alpha * &z[1] - z[1].powi(3) - z[0].clone()]
+}
 
-    // main computation 
-    for i in 1..=el {
-        w1 = *y.last().unwrap(); 
-        rate(alpha, &w1, &mut k1);
-        update(&w1, &k1, &mut w2, 0.5_f64 * dt); 
-        rate(alpha, &w2, &mut k2); 
-        update(&w2, &k2, &mut w3, 0.5_f64 * dt); 
-        rate(alpha, &w3, &mut k3); 
-        update(&w3, &k3, &mut w4, dt);
-        rate(alpha, &w4, &mut k4); 
-        y.push(next(&w1, &[k1, k2, k3, k4]));
+///
+/// RK4 for the semiconductor at alpha on the dt grid from t0, through the 
+/// shared solvers::rk4_step. Like abam4, a shortened last step lands on tf 
+///
+fn rk4(alpha: f64, ic: [f64; 2], dt: f64, t0: f64, tf: f64) 
+    -> Result<solvers::Solution<2>, Box<dyn std::error::Error>> {
+    solvers::integrate(&Oscillator { alpha }.rhs()?, solvers::rk4_step, ic, [t0, tf], dt, Limits::default())
+}
 
-        let ti = t0 + (i as f64) * dt; 
-        t.push(ti);
-    }
+///
+/// AB/AM4 PECE with an rk4 startup, the lab's predictor-corrector, through 
+/// the shared multistep::solve_with_mode 
+///
+fn abam4_pred_corr(alpha: f64, ic: [f64; 2], dt: f64, t0: f64, tf: f64) 
+    -> Result<solvers::Solution<2>, Box<dyn std::error::Error>> {
+    Ok(abam4_with_mode(alpha, ic, dt, t0, tf, Startup::Rk4, Mode::Pece(1))?.0)
+}
 
-    (t, y)
+///
+/// AB/AM4 in any startup and evaluation mode, a step whose corrector fails 
+/// retried as two halves 
+///
+fn abam4_with_mode(alpha: f64, ic: [f64; 2], dt: f64, t0: f64, tf: f64, startup: Startup, mode: Mode) 
+    -> Result<(solvers::Solution<2>, SolveStats), Box<dyn std::error::Error>> {
+    multistep::solve_with_mode(
+        &Oscillator { alpha }.rhs()?, ic, [t0, tf], dt, startup, mode, CorrectorFailure::Halve, Limits::default())
 }
 
-fn abam4_pred_corr(alpha: f64, dt: f64, t0: f64, tf: f64) 
-    -> (Vec<f64>, Vec<[f64; 2]>) {
-    // get first 3 values 
-    let el = ((tf - t0) / dt).floor() as usize;  
-    let (_, y0) = rk4(alpha, dt, t0, t0 + 3.0 * dt);
-    
-    let mut t: Vec<f64> = Vec::with_capacity(el + 1); 
-    let mut y: Vec<[f64; 2]> = Vec::with_capacity(el + 1);
-    let mut f: [[f64; 2]; 4] = [[0.0, 0.0]; 4]; 
+///
+/// Plot each element of solution from rk4 against time vector, every 
+/// series LTTB downsampled to downsample::PLOT_POINTS. Series the legend 
+/// hides are skipped; the legend is placed and laid out as it says. An 
+/// inset redraws the shown series at full resolution over its window 
+///
+fn plot(theme: &PlotStyle, solutions: &[SharedTrajectory<2>], alphas: &[f64; 5], legend: &Legend, inset: Option<&Inset>, 
+    path: &str, title: &str) -> Result<(), Box<dyn std::error::Error>> {
+
+    let (mut ymin, mut ymax) = (f64::INFINITY, f64::NEG_INFINITY);
+    let (mut tmin, mut tmax) = (f64::INFINITY, f64::NEG_INFINITY);
+    for sol in solutions {
+        let (lo, hi) = sol.bounds(); 
+        ymin = ymin.min(lo);
+        ymax = ymax.max(hi); 
+        tmin = tmin.min(sol.time()[0]); 
+        tmax = tmax.max(sol.time()[sol.len() - 1]); 
+    }
+
+    let pad = (ymax - ymin) * 0.05; 
+    ymax += pad; 
+    ymin -= pad; 
+    if let Some(inset) = inset {
+        ymax = inset.headroom(ymin, ymax); 
+    }
+    let axis = Axis::default(); 
+    let (x_range, y_range) = (axis.range(tmin, tmax), axis.range(ymin, ymax)); 
 
-    // initialize array 
-    for (i, y0i) in y0.iter().enumerate() {
-        t.push(t0 + (i as f64) * dt);
-        y.push(*y0i);
-        rate(alpha, y0i, &mut f[i]); // get first rate functions  
+    // (label, style, alpha index, component) of every series drawn 
+    let mut entries = Vec::new(); 
+    for (i, alpha) in alphas.iter().enumerate().take(solutions.len()) {
+        for (j, state) in STATES.iter().enumerate() {
+            let color = if j == 0 { theme.color(i) } else { theme.color(i).mix(0.55) }; 
+            let label = format!("{}: {}", state.name, alpha); 
+            if legend.shows(&label) {
+                entries.push((label, color.stroke_width(theme.line), i, j)); 
+            }
+        }
+    }
+    let keys: Vec<(String, ShapeStyle)> = entries.iter().map(|e| (e.0.clone(), e.1)).collect(); 
+
+    let root = BitMapBackend::new(path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let (area, strip) = legend.split(&root, theme, keys.len()); 
+    let mut chart = ChartBuilder::on(&area)
+        .caption(title, theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 55)
+        .set_label_area_size(LabelAreaPosition::Bottom, 50)
+        .build_cartesian_2d(x_range.clone(), y_range.clone())?; 
+
+    chart.configure_mesh()
+        .label_style(theme.label())
+        .axis_desc_style(theme.label())
+        .x_desc("t")
+        .y_desc(state::axis_label(&STATES))
+        .x_label_formatter(&axis.formatter(&x_range))
+        .y_label_formatter(&axis.formatter(&y_range))
+        .x_max_light_lines(axis.minor)
+        .y_max_light_lines(axis.minor)
+        .draw()?; 
+
+    for (_, style, i, j) in &entries {
+        let sol = &solutions[*i]; 
+        chart.draw_series(LineSeries::new(
+            downsample::points(sol.time(), sol.component(*j), downsample::PLOT_POINTS), 
+            *style, 
+        ))?; 
+    }
+
+    if let Some(inset) = inset {
+        let zoomed: Vec<(&[f64], &[f64], ShapeStyle)> = entries.iter()
+            .map(|(_, style, i, j)| (solutions[*i].time(), solutions[*i].component(*j), *style))
+            .collect(); 
+        let shown = inset.draw(&chart.plotting_area().strip_coord_spec(), theme, &zoomed)?; 
+        chart.draw_series([inset.frame(shown, theme)])?; 
     }
 
-    let predict = |w: &[f64; 2], f: &[[f64; 2]; 4], wpred: &mut [f64; 2]| {
-        let pool0 = 55.0 * f[3][0] - 59.0 * f[2][0] + 37.0 * f[1][0] - 9.0 * f[0][0];
-        let pool1 = 55.0 * f[3][1] - 59.0 * f[2][1] + 37.0 * f[1][1] - 9.0 * f[0][1];
-        wpred[0] = w[0] + (dt / 24.0) * pool0;  
-        wpred[1] = w[1] + (dt / 24.0) * pool1; 
-    };
+    match &strip {
+        Some(strip) => legend.draw(strip, theme, &keys)?, 
+        None => legend.draw(&chart.plotting_area().strip_coord_spec(), theme, &keys)?, 
+    }
+
+    root.present()?; 
+    Ok(())
+} 
+
+fn solve<F>(ctx: &mut RunContext, func: &F, dt: f64, name: &str, title: &str)
+    -> Result<(), Box<dyn std::error::Error>>
+where F: Fn(f64, [f64; 2], f64, f64, f64) -> Result<solvers::Solution<2>, Box<dyn std::error::Error>> {
+    let (t0, tf) = (0.0, 100.0);
+    let alphas = [0.5, 1.5, 2.5, 3.5, 4.5];
+    let mut solutions: Vec<SharedTrajectory<2>> = Vec::with_capacity(alphas.len());
+
+    let stem = name.trim_end_matches(".png");
+    for a in alphas {
+        let (t, y) = ctx.time(stem, || func(a, IC, dt, t0, tf))?; 
+        solutions.push(Trajectory::from_rows(&t, &y).share());
+    }
+    
+    // export on its own thread from shared handles while the plot renders 
+    let path = ctx.path(name);
+    let npz = ctx.path(&format!("{stem}.npz")); 
+    let mat = ctx.path(&format!("{stem}.mat")); 
+    let labels = STATES.map(|s| s.column()); 
+    let mut columns: Vec<String> = Vec::with_capacity(alphas.len()); 
+    for i in 0..alphas.len() {
+        let column_stem = format!("{stem}_alpha_{i}"); 
+        for label in std::iter::once("t").chain(labels.iter().map(String::as_str)) {
+            ctx.path(&format!("{column_stem}_{label}.npy")); 
+        }
+        columns.push(ctx.dir().join(&column_stem).to_string_lossy().into_owned()); 
+    }
+    let shared = solutions.clone(); 
+    let exporter = std::thread::spawn(move || {
+        // one (n, 2) array per alpha, names are valid MATLAB identifiers 
+        let names: Vec<String> = (0..shared.len()).map(|i| format!("alpha_{i}")).collect();
+        let rows: Vec<Vec<[f64; 2]>> = shared.iter().map(|s| s.to_rows()).collect(); 
+        let named: Vec<(&str, &[[f64; 2]])> = names
+            .iter()
+            .zip(rows.iter())
+            .map(|(name, y)| (name.as_str(), y.as_slice()))
+            .collect();
+        let t = shared[0].time(); 
+        let _ = export::write_npz(&npz, t, &named);
+        let _ = export::write_mat(&mat, t, &named);
 
-    // Ensure that we shift our rate functions before applying 
-    let correct = |w: &mut [f64; 2], f: &[[f64; 2]; 4], fpred: &[f64; 2]| {
-        let pool0 = 9.0 * fpred[0] + 19.0 * f[3][0] - 5.0 * f[2][0] + f[1][0]; 
-        let pool1 = 9.0 * fpred[1] + 19.0 * f[3][1] - 5.0 * f[2][1] + f[1][1]; 
-        w[0] += (dt / 24.0) * pool0; 
-        w[1] += (dt / 24.0) * pool1; 
-    };
+        // flat per-component columns for memory-mapped reads 
+        for (sol, stem) in shared.iter().zip(columns.iter()) {
+            let _ = sol.write_columns(stem, &labels); 
+        }
+    }); 
+    // ten curves fill the plot, so the legend goes underneath, a column per alpha 
+    let legend = Legend::default().position(Position::Below).columns(alphas.len()); 
+    // and the waveform is lost in 100 time units, so the last period of the 
+    // slowest oscillation is enlarged 
+    let last = &solutions[alphas.len() - 1]; 
+    let inset = peaks::oscillation(last.time(), last.component(0), 3).period
+        .filter(|p| p.is_finite() && *p < tf - t0)
+        .map(|p| Inset::last_period(tf, p)); 
+    let _ = plot(&ctx.style(), &solutions, &alphas, &legend, inset.as_ref(), &path, title); 
+    let _ = exporter.join(); 
+    Ok(())
+}
 
-    for i in 4..=el {
-        // get current approximated value of y 
-        let mut w: [f64; 2] = *y.last().unwrap(); 
-        let mut wpred: [f64; 2] = [0.0, 0.0];
+///
+/// Interval extension of the rate function and of its derivative along the
+/// flow, z'' = J(z) z', for the validated integrator 
+///
+fn interval_rate(alpha: f64, z: &[Interval; 2]) -> [Interval; 2] {
+    [z[1], alpha * z[1] - z[1].powi(3) - z[0]]
+}
 
-        // we have the four rate functions we need  
-        predict(&w, &f, &mut wpred);
-        let mut fpred = [0.0, 0.0]; 
-        rate(alpha, &wpred, &mut fpred);
-        
-        // correct predicted value 
-        correct(&mut w, &f, &fpred);
-        let mut fcorr = [0.0, 0.0];
-        rate(alpha, &w, &mut fcorr);
+fn interval_rate_deriv(alpha: f64, z: &[Interval; 2]) -> [Interval; 2] {
+    let dz = interval_rate(alpha, z); 
+    let a = Interval::point(alpha); 
+    [dz[1], (a - 3.0 * z[1].sqr()) * dz[1] - dz[0]]
+}
 
-        // update rate functions 
-        f.rotate_left(1);
-        f[3] = fcorr; 
+impl SeriesSystem for Oscillator {
+    fn rate_series(&self, y: &[Series], dy: &mut [Series]) {
+        dy.clone_from_slice(&rate_series(self.alpha, &[y[0].clone(), y[1].clone()])); 
+    }
+}
 
-        // update arrays 
-        let ti = t0 + (i as f64) * dt; 
-        y.push(w); 
-        t.push(ti);
+impl IntervalSystem for Oscillator {
+    fn rate_interval(&self, y: &[Interval], dy: &mut [Interval]) {
+        dy.copy_from_slice(&interval_rate(self.alpha, &[y[0], y[1]])); 
     }
 
-    (t, y)
+    fn rate_interval_deriv(&self, y: &[Interval], d2y: &mut [Interval]) {
+        d2y.copy_from_slice(&interval_rate_deriv(self.alpha, &[y[0], y[1]])); 
+    }
 }
 
 ///
-/// Plot each element of solution from rk4 against time vector 
+/// Rigorous enclosure of the solution over a short horizon plotted against 
+/// the RK4 trajectory. Reports the final enclosure width, a guaranteed bound 
+/// on the error of any method landing inside it 
 ///
-fn plot(t: &[f64], solutions: &Vec<Vec<[f64; 2]>>, alphas: &[f64; 5], path: &str, title: &str)
+fn validated(ctx: &mut RunContext, alpha: f64, tf: f64, dt: f64) 
     -> Result<(), Box<dyn std::error::Error>> {
+    let y0 = IC.map(Interval::point); 
+    let ((t, enclosure), _) = ctx.time("validated taylor2", || {
+        interval::validated_taylor2_system(&Oscillator { alpha }, y0, 0.0, tf, dt, Limits::default())
+    })?;
+    let (_, y) = rk4(alpha, IC, dt, 0.0, tf)?; 
 
-    let n = t.len(); 
+    let last = enclosure.last().unwrap(); 
+    println!(
+        "alpha = {alpha}: enclosure width at t = {tf} is [{:.3e}, {:.3e}], rk4 inside: {}",
+        last[0].width(), last[1].width(),
+        last[0].contains(y.last().unwrap()[0]) && last[1].contains(y.last().unwrap()[1])
+    );
+
+    let n = t.len().min(y.len()); 
     let (mut ymin, mut ymax) = (f64::INFINITY, f64::NEG_INFINITY);
+    for zi in &enclosure {
+        ymin = ymin.min(zi[0].lo); 
+        ymax = ymax.max(zi[0].hi); 
+    }
+    let pad = (ymax - ymin) * 0.05; 
 
-    for sol in solutions {
-        for yi in sol {
-            ymin = ymin.min(yi[0]).min(yi[1]);
-            ymax = ymax.max(yi[0]).max(yi[1]); 
+    let path = ctx.path(&format!("validated_{alpha}.png")); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let mut chart = ChartBuilder::on(&root)
+        .caption(format!("Validated Enclosure vs RK4, a = {alpha}, dt = {dt}"), theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 55)
+        .set_label_area_size(LabelAreaPosition::Bottom, 50)
+        .build_cartesian_2d(t[0]..t[n - 1], (ymin - pad)..(ymax + pad))?; 
+
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("t").y_desc(STATES[0].label()).draw()?; 
+
+    let (lower, upper, rk4) = (theme.color(3), theme.color(1), theme.color(0)); 
+    chart.draw_series(LineSeries::new((0..n).map(|i| (t[i], enclosure[i][0].lo)), lower.stroke_width(theme.thin)))?
+        .label("lower bound")
+        .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], lower));
+    chart.draw_series(LineSeries::new((0..n).map(|i| (t[i], enclosure[i][0].hi)), upper.stroke_width(theme.thin)))?
+        .label("upper bound")
+        .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], upper));
+    chart.draw_series(LineSeries::new((0..n).map(|i| (t[i], y[i][0])), rk4.stroke_width(theme.thin)))?
+        .label("rk4")
+        .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], rk4));
+
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?; 
+
+    root.present()?; 
+    Ok(())
+}
+
+///
+/// Final state error of both methods against an order 20 Taylor reference
+///
+fn taylor_reference(ctx: &mut RunContext, tf: f64) 
+    -> Result<(), Box<dyn std::error::Error>> {
+    println!("{:>6} {:>8} {:>14} {:>14}", "alpha", "dt", "rk4 err", "abam4 err"); 
+    ctx.solver("taylor", "order 20, fixed-step");
+    for alpha in [0.5, 2.5, 4.5] {
+        let ((_, reference), _) = ctx.time("taylor reference", || {
+            taylor::taylor_system(&Oscillator { alpha }, 20, IC, 0.0, tf, 1e-2, Limits::default())
+        })?; 
+        let exact = reference.last().unwrap(); 
+        let err = |y: &[f64; 2]| (y[0] - exact[0]).hypot(y[1] - exact[1]); 
+
+        for dt in [1e-2, 1e-3] {
+            let (_, y_rk) = rk4(alpha, IC, dt, 0.0, tf)?; 
+            let (_, y_ab) = abam4_pred_corr(alpha, IC, dt, 0.0, tf)?; 
+            println!(
+                "{:>6} {:>8.0e} {:>14.6e} {:>14.6e}", alpha, dt, 
+                err(y_rk.last().unwrap()), err(y_ab.last().unwrap())
+            ); 
         }
     }
+    Ok(())
+}
 
-    let pad = (ymax - ymin) * 0.05; 
-    ymax += pad; 
-    ymin -= pad; 
+///
+/// Global pseudospectral solution against local RK4 stepping, both measured 
+/// against the Taylor reference and by rate evaluations spent 
+///
+fn spectral_comparison(ctx: &mut RunContext, alpha: f64, tf: f64) 
+    -> Result<(), Box<dyn std::error::Error>> {
+    ctx.solver("chebyshev_picard", "lobatto nodes, picard sweeps");
+    let ((_, reference), _) = taylor::taylor(
+        &|z: &[Series; 2]| rate_series(alpha, z), 20, IC, 0.0, tf, 1e-2, Limits::default())?; 
+    let exact = *reference.last().unwrap(); 
+    let err = |y: &[f64; 2]| (y[0] - exact[0]).hypot(y[1] - exact[1]); 
+    let segments = (4.0 * tf).ceil() as usize; 
+
+    println!("{:>10} {:>8} {:>12} {:>14}", "method", "n / dt", "rate evals", "error"); 
+    for n in [4, 8, 16, 32] {
+        let result = ctx.time("chebyshev picard", || {
+            spectral::chebyshev_picard_system(&Oscillator { alpha }, IC, [0.0, tf], segments, n, 1e-13, 200, Limits::default())
+        });
+        match result {
+            Ok(((_, y), stats)) => println!(
+                "{:>10} {:>8} {:>12} {:>14.6e}", "spectral", n, stats.rate_evals, err(y.last().unwrap())), 
+            Err(e) => println!("{:>10} {:>8} {e}", "spectral", n), 
+        }
+    }
+    for dt in [1e-1, 1e-2, 1e-3] {
+        let (_, y) = rk4(alpha, IC, dt, 0.0, tf)?; 
+        let evals = 4 * (y.len() - 1); 
+        println!("{:>10} {:>8.0e} {:>12} {:>14.6e}", "rk4", dt, evals, err(y.last().unwrap())); 
+    }
+    Ok(())
+}
+
+///
+/// Effect of the AB/AM4 startup strategy on early-time error, measured 
+/// against a Taylor reference on the same grid over [0, tf] and plotted 
+///
+fn startup_study(ctx: &mut RunContext, alpha: f64, dt: f64, tf: f64, name: &str) 
+    -> Result<(), Box<dyn std::error::Error>> {
+    let strategies = [Startup::Rk4, Startup::Rk4Refined(10), Startup::Bootstrap]; 
+    let ((_, reference), _) = taylor::taylor(
+        &|z: &[Series; 2]| rate_series(alpha, z), 20, IC, 0.0, tf, dt, Limits::default())?; 
+
+    let mut curves: Vec<(String, Vec<f64>, Vec<f64>)> = Vec::new(); 
+    println!("{:>12} {:>14} {:>14}", "startup", "err at t3", "max err"); 
+    for strategy in strategies {
+        let ((t, y), _) = abam4_with_mode(alpha, IC, dt, 0.0, tf, strategy, Mode::Pece(1))?; 
+        let n = t.len().min(reference.len()); 
+        let err: Vec<f64> = (0..n)
+            .map(|i| (y[i][0] - reference[i][0]).hypot(y[i][1] - reference[i][1]))
+            .collect(); 
+        println!(
+            "{:>12} {:>14.6e} {:>14.6e}", strategy.name(), err[3], 
+            err.iter().copied().fold(0.0, f64::max)
+        ); 
+        curves.push((strategy.name(), t[..n].to_vec(), err)); 
+    }
 
-    let (tmin, tmax) = (t[0], t[n - 1]); 
+    let (mut ymin, mut ymax) = (f64::INFINITY, f64::NEG_INFINITY); 
+    for (_, _, err) in &curves {
+        for e in &err[1..] {
+            ymin = ymin.min(e.max(1e-16).log10()); 
+            ymax = ymax.max(e.max(1e-16).log10()); 
+        }
+    }
 
-    let root = BitMapBackend::new(path, (1200,700)).into_drawing_area();
+    let path = ctx.path(name); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
     root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let mut chart = ChartBuilder::on(&root)
+        .caption(format!("AB/AM4 Startup Error, a = {alpha}, dt = {dt} [semilogy-scale]"), theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 70)
+        .set_label_area_size(LabelAreaPosition::Bottom, 50)
+        .build_cartesian_2d(0.0..tf, ymin.floor()..ymax.ceil())?; 
+
+    chart.configure_mesh()
+        .label_style(theme.label())
+        .axis_desc_style(theme.label())
+        .x_desc("t")
+        .y_desc("error")
+        .y_label_formatter(&|v| format!("1e{:.0}", v))
+        .draw()?; 
+
+    for (i, (label, t, err)) in curves.iter().enumerate() {
+        let color = theme.color(i); 
+        chart.draw_series(LineSeries::new(
+            t.iter().zip(err.iter()).skip(1).map(|(ti, e)| (*ti, e.max(1e-16).log10())),
+            &color,
+        ))?
+        .label(label.as_str())
+        .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+    }
+
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?; 
+
+    root.present()?; 
+    Ok(())
+}
+
+///
+/// Cost against accuracy of the AB/AM4 evaluation modes: final and max 
+/// error over [0, tf] against the Taylor reference on the same grid, with 
+/// the rate evaluations each mode spent and the steps it had to halve 
+///
+fn mode_study(ctx: &mut RunContext, alpha: f64, tf: f64) 
+    -> Result<(), Box<dyn std::error::Error>> {
+    let modes = [Mode::Pec(1), Mode::Pece(1), Mode::Pec(2), Mode::Pece(2), Mode::Pece(3)]; 
+    ctx.solver("abam4_with_mode", "PEC, PECE, P(EC)^m, P(EC)^mE"); 
+
+    println!("{:>10} {:>8} {:>10} {:>8} {:>14} {:>14}", "mode", "dt", "rate evals", "halved", "final err", "max err"); 
+    for dt in [1e-2, 1e-3] {
+        let ((_, reference), _) = taylor::taylor(
+            &|z: &[Series; 2]| rate_series(alpha, z), 20, IC, 0.0, tf, dt, Limits::default())?; 
+        // a finely resolved startup, otherwise its error hides the modes 
+        for mode in modes {
+            let ((_, y), stats) = ctx.time(&format!("abam4 {}", mode.name()), || {
+                abam4_with_mode(alpha, IC, dt, 0.0, tf, Startup::Rk4Refined(1000), mode)
+            })?; 
+            let n = y.len().min(reference.len()); 
+            let err: Vec<f64> = (0..n)
+                .map(|i| (y[i][0] - reference[i][0]).hypot(y[i][1] - reference[i][1]))
+                .collect(); 
+            println!(
+                "{:>10} {:>8.0e} {:>10} {:>8} {:>14.6e} {:>14.6e}", mode.name(), dt, stats.rate_evals, 
+                stats.corrector_failures, err[n - 1], err.iter().copied().fold(0.0, f64::max)); 
+        }
+    }
+    Ok(())
+}
+
+///
+/// Work-precision of the VSVO Adams solver, free and with its order capped 
+/// at 4, over a range of tolerances next to fixed-step AB/AM4, error 
+/// against the Taylor reference at tf 
+///
+fn vsvo_study(ctx: &mut RunContext, alpha: f64, tf: f64) 
+    -> Result<(), Box<dyn std::error::Error>> {
+    ctx.solver("vsvo_adams", "PECE, orders 1-12");
+    let ((_, reference), _) = taylor::taylor(
+        &|z: &[Series; 2]| rate_series(alpha, z), 20, IC, 0.0, tf, 1e-3, Limits::default())?; 
+    let exact = *reference.last().unwrap(); 
+    let err = |y: &[f64; 2]| (y[0] - exact[0]).hypot(y[1] - exact[1]); 
+
+    println!("{:>9} {:>8} {:>8} {:>8} {:>10} {:>14}", "method", "tol/dt", "steps", "rejects", "rate evals", "error"); 
+    for tol in [1e-4, 1e-6, 1e-8, 1e-10] {
+        let opts = adams::AdamsOptions { tol: Tolerance::uniform(tol, 1e-3 * tol), ..Default::default() }; 
+        let result = ctx.time("vsvo adams", || {
+            adams::vsvo_adams(
+                &|_t: f64, z: &[f64; 2], dz: &mut [f64; 2]| rate(alpha, z, dz), 
+                IC, [0.0, tf], opts)
+        }); 
+        match result {
+            Ok(sol) => println!(
+                "{:>9} {:>8.0e} {:>8} {:>8} {:>10} {:>14.6e}", "vsvo", tol, sol.stats.accepted, 
+                sol.stats.rejected, sol.stats.rate_evals, err(sol.y.last().unwrap())), 
+            Err(e) => println!("{:>9} {:>8.0e} {e}", "vsvo", tol), 
+        }
+    }
+    ctx.solver("vsvo_adams", "PECE, order capped at 4"); 
+    for tol in [1e-4, 1e-6, 1e-8, 1e-10] {
+        let opts = adams::AdamsOptions { tol: Tolerance::uniform(tol, 1e-3 * tol), max_order: 4, ..Default::default() }; 
+        let result = ctx.time("vsvo adams order 4", || {
+            adams::vsvo_adams(
+                &|_t: f64, z: &[f64; 2], dz: &mut [f64; 2]| rate(alpha, z, dz), 
+                IC, [0.0, tf], opts)
+        }); 
+        match result {
+            Ok(sol) => println!(
+                "{:>9} {:>8.0e} {:>8} {:>8} {:>10} {:>14.6e}", "vsvo <= 4", tol, sol.stats.accepted, 
+                sol.stats.rejected, sol.stats.rate_evals, err(sol.y.last().unwrap())), 
+            Err(e) => println!("{:>9} {:>8.0e} {e}", "vsvo <= 4", tol), 
+        }
+    }
+    for dt in [1e-2, 1e-3] {
+        let (_, y) = abam4_pred_corr(alpha, IC, dt, 0.0, tf)?; 
+        let steps = y.len() - 1; 
+        println!(
+            "{:>9} {:>8.0e} {:>8} {:>8} {:>10} {:>14.6e}", "abam4", dt, steps, 0, 
+            2 * steps + 12, err(y.last().unwrap())); 
+    }
+    Ok(())
+}
+
+///
+/// RK4, AB/AM4 and the adaptive VSVO Adams on one figure for one alpha.
+/// The three voltages are compared on a shared grid through their dense
+/// outputs; the window of width tf / 20 around their widest spread is
+/// boxed on the main plot and redrawn enlarged in an inset
+///
+fn method_overlay(ctx: &mut RunContext, alpha: f64, tf: f64, dt: f64, tol: f64, name: &str)
+    -> Result<(), Box<dyn std::error::Error>> {
+    let f = |_t: f64, z: &[f64; 2], dz: &mut [f64; 2]| rate(alpha, z, dz);
+    ctx.solver("vsvo_adams", "PECE, orders 1-12");
+    let (t, y) = ctx.time("rk4 overlay", || rk4(alpha, IC, dt, 0.0, tf))?;
+    let fixed = Trajectory::from_rows(&t, &y);
+    let (t, y) = ctx.time("abam4 overlay", || abam4_pred_corr(alpha, IC, dt, 0.0, tf))?;
+    let multi = Trajectory::from_rows(&t, &y);
+    let opts = adams::AdamsOptions { tol: Tolerance::uniform(tol, 1e-3 * tol), ..Default::default() };
+    let sol = ctx.time("vsvo overlay", || adams::vsvo_adams(&f, IC, [0.0, tf], opts))?;
+    let adaptive = Trajectory::from_rows(&sol.t, &sol.y);
+    let runs = [
+        (format!("rk4, dt = {dt}"), &fixed),
+        (format!("ab/am4, dt = {dt}"), &multi),
+        (format!("vsvo adams, tol = {tol:e}"), &adaptive),
+    ];
+
+    // spread of V across the methods on a grid all three cover
+    let end = runs.iter().filter_map(|(_, r)| r.time().last().copied()).fold(tf, f64::min);
+    let (inset, at, spread) = Inset::widest_spread(end, 4000, tf / 40.0, |s| {
+        runs.iter().map(|(_, r)| Ok(multistep::dense(&f, r, s)?[0])).collect()
+    })?;
+    let [z0, z1] = inset.window;
+    println!("widest spread in V is {spread:.4e} at t = {at:.3}, inset over [{z0:.3}, {z1:.3}]");
+
+    let mut table = Table::new(format!("Methods overlaid, alpha = {alpha}, t to {tf}"),
+        &["method", "points", "V(tf)", "dV/dt(tf)"]);
+    for (label, r) in &runs {
+        let last = r.last().unwrap_or([f64::NAN; 2]);
+        table.row([label.clone(), r.len().to_string(), format!("{:.6e}", last[0]), format!("{:.6e}", last[1])]);
+    }
+    ctx.table(table);
+
+    let (mut lo, mut hi) = (f64::INFINITY, f64::NEG_INFINITY);
+    for (_, r) in &runs {
+        for v in r.component(0) {
+            lo = lo.min(*v);
+            hi = hi.max(*v);
+        }
+    }
+
+    let path = ctx.path(name);
+    ctx.caption(name, format!("V(t) at alpha = {alpha} by rk4, ab/am4 and vsvo adams, with the window of widest disagreement enlarged"));
+    let theme = ctx.style();
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?;
+    let root = theme.stamp(&root)?;
     let mut chart = ChartBuilder::on(&root)
-        .caption(title, ("sans-serif", 22))
-        .margin(10)
+        .caption(format!("Semiconductor V(t) by Three Methods, a = {alpha}"), theme.caption())
+        .margin(theme.margin)
         .set_label_area_size(LabelAreaPosition::Left, 55)
         .set_label_area_size(LabelAreaPosition::Bottom, 50)
-        .build_cartesian_2d(tmin..tmax, ymin..ymax)?; 
+        .build_cartesian_2d(0.0..tf, lo..inset.headroom(lo, hi))?;
 
-    chart.configure_mesh().x_desc("t").y_desc("voltage").draw()?; 
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("t").y_desc(STATES[0].label()).draw()?;
 
-    for (i, (alpha, sol)) in alphas.iter().zip(solutions.iter()).enumerate() {
-        let color0 = Palette99::pick(i); 
-        let color1 = color0.mix(0.55);
+    for (k, (label, r)) in runs.iter().enumerate() {
+        let color = theme.color(k);
+        chart.draw_series(LineSeries::new(r.points(0), color.stroke_width(theme.thin)))?
+            .label(label.as_str())
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+    }
+    let zoomed: Vec<(&[f64], &[f64], ShapeStyle)> = runs.iter().enumerate()
+        .map(|(k, (_, r))| (r.time(), r.component(0), theme.color(k).stroke_width(theme.line)))
+        .collect();
+    let shown = inset.draw(&chart.plotting_area().strip_coord_spec(), &theme, &zoomed)?;
+    chart.draw_series([inset.frame(shown, &theme)])?;
 
-        chart.draw_series(LineSeries::new(
-            (0..n).map(|j| (t[j], sol[j][0])),
-                ShapeStyle {
-                    color: color0.to_rgba(), 
-                    filled: false, 
-                    stroke_width: 2 
-                },
-            ))?
-            .label(format!("N1: {}", alpha))
-            .legend(move |(x, y)| {
-                PathElement::new(vec![(x, y), (x + 20, y)], 
-                    ShapeStyle{ 
-                        color: color0.to_rgba(),
-                        filled: false, 
-                        stroke_width: 2 
-                    })});
+    chart.configure_series_labels()
+        .position(SeriesLabelPosition::UpperLeft)
+        .label_font(theme.label())
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?;
 
-        chart.draw_series(LineSeries::new(
-            (0..n).map(|j| (t[j], sol[j][1])),
-                ShapeStyle {
-                    color: color1.to_rgba(), 
-                    filled: false, 
-                    stroke_width: 2 
-                },
-            ))?
-            .label(format!("N2: {}", alpha))
-            .legend(move |(x, y)| {
-                PathElement::new(vec![(x, y), (x + 20, y)], 
-                    ShapeStyle{ 
-                        color: color1.to_rgba(),
-                        filled: false, 
-                        stroke_width: 2 
-                    })});
+    root.present()?;
+    Ok(())
+}
+
+///
+/// Where the semiconductor forces the adaptive solver into small steps: 
+/// V(t) from VSVO Adams on the left axis, the accepted step size dt(t) on 
+/// a log right axis, and every rejected attempt marked on both, at its 
+/// start on the V curve and at the step it tried on the dt axis 
+///
+fn step_history(ctx: &mut RunContext, alpha: f64, tf: f64, tol: f64, name: &str) 
+    -> Result<(), Box<dyn std::error::Error>> {
+    let f = |_t: f64, z: &[f64; 2], dz: &mut [f64; 2]| rate(alpha, z, dz); 
+    ctx.solver("vsvo_adams", "PECE, orders 1-12"); 
+    let opts = adams::AdamsOptions { tol: Tolerance::uniform(tol, 1e-3 * tol), ..Default::default() }; 
+    let sol = ctx.time("vsvo steps", || adams::vsvo_adams(&f, IC, [0.0, tf], opts))?; 
+
+    // step i runs from t[i] to t[i + 1]. The opening ramp up from the 
+    // cautious initial step says nothing about the dynamics, so the 
+    // smallest step and the dt axis only count what follows it 
+    let steps: Vec<(f64, f64)> = sol.t.windows(2).map(|w| (w[0], w[1] - w[0])).collect(); 
+    let ramp = steps.windows(2).position(|w| w[1].1 < w[0].1).unwrap_or(0); 
+    let (t_min, h_min) = steps[ramp..].iter().copied().fold((0.0, f64::INFINITY), |a, s| if s.1 < a.1 { s } else { a }); 
+    let h_max = steps.iter().map(|s| s.1).fold(0.0, f64::max); 
+    let mut table = Table::new(format!("VSVO Adams steps, alpha = {alpha}, tol = {tol:e}, t to {tf}"), 
+        &["accepted", "rejected", "smallest dt after ramp", "at t", "largest dt"]); 
+    table.row([sol.stats.accepted.to_string(), sol.stats.rejected.to_string(), format!("{h_min:.3e}"), 
+        format!("{t_min:.3}"), format!("{h_max:.3e}")]); 
+    println!("{}", table.caption); 
+    println!("{:>9} {:>9} {:>22} {:>8} {:>12}", table.header[0], table.header[1], table.header[2], table.header[3], table.header[4]); 
+    for row in &table.rows {
+        println!("{:>9} {:>9} {:>22} {:>8} {:>12}", row[0], row[1], row[2], row[3], row[4]); 
     }
+    ctx.table(table); 
+
+    let (lo, hi) = sol.y.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), z| (lo.min(z[0]), hi.max(z[0]))); 
+    let pad = 0.05 * (hi - lo); 
+    let log_h: Vec<f64> = steps[ramp..].iter().map(|s| s.1.log10())
+        .chain(sol.rejected.iter().filter(|r| r.0 >= steps[ramp].0).map(|r| r.1.log10()))
+        .collect(); 
+    let h_lo = log_h.iter().copied().fold(f64::INFINITY, f64::min).floor(); 
+    let h_hi = log_h.iter().copied().fold(f64::NEG_INFINITY, f64::max).ceil(); 
+
+    let path = ctx.path(name); 
+    ctx.caption(name, format!("V(t) and the accepted step size of VSVO Adams, rejected steps crossed, alpha = {alpha}, tol = {tol:e}")); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let mut chart = ChartBuilder::on(&root)
+        .caption(format!("Adaptive Steps on the Semiconductor, a = {alpha}"), theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 55)
+        .set_label_area_size(LabelAreaPosition::Right, 70)
+        .set_label_area_size(LabelAreaPosition::Bottom, 50)
+        .build_cartesian_2d(0.0..tf, (lo - pad)..(hi + pad))?
+        .set_secondary_coord(0.0..tf, h_lo..h_hi); 
+
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("t").y_desc(STATES[0].label()).draw()?; 
+    chart.configure_secondary_axes()
+        .label_style(theme.label())
+        .axis_desc_style(theme.label())
+        .y_desc("dt")
+        .y_label_formatter(&|v| format!("1e{:.1}", v))
+        .draw()?; 
+
+    let v_color = theme.color(0); 
+    chart.draw_series(LineSeries::new(sol.t.iter().zip(&sol.y).map(|(t, z)| (*t, z[0])), v_color.stroke_width(theme.line)))?
+        .label(format!("{} (left)", STATES[0].name))
+        .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], v_color));
+    let h_color = theme.color(4); 
+    chart.draw_secondary_series(LineSeries::new(steps.iter().map(|(t, h)| (*t, h.log10())), h_color.stroke_width(theme.line)))?
+        .label("accepted dt (right)")
+        .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], h_color));
+
+    // a rejection at t starts from the accepted point there 
+    let reject_color = theme.color(3); 
+    let at = |t: f64| sol.y[sol.t.partition_point(|s| *s < t).min(sol.t.len() - 1)][0]; 
+    chart.draw_series(sol.rejected.iter().map(|(t, _)| Cross::new((*t, at(*t)), 4, reject_color.stroke_width(2))))?
+        .label("rejected step")
+        .legend(move |(x, y)| Cross::new((x + 10, y), 4, reject_color.stroke_width(2)));
+    chart.draw_secondary_series(sol.rejected.iter().map(|(t, h)| Cross::new((*t, h.log10()), 4, reject_color.stroke_width(2))))?; 
 
     chart.configure_series_labels()
+        .position(SeriesLabelPosition::LowerLeft)
+        .label_font(theme.label())
         .border_style(BLACK)
         .background_style(WHITE.mix(0.85))
         .draw()?; 
 
     root.present()?; 
     Ok(())
-} 
+}
 
-fn solve<F>(func: &F, dt: f64, path: &str, title: &str)
-where F: Fn(f64, f64, f64, f64) -> (Vec<f64>, Vec<[f64; 2]>) {
-    let (t0, tf) = (0.0, 100.0);
-    let n = ((tf - t0) / dt).floor() as usize; 
-    let alphas = [0.5, 1.5, 2.5, 3.5, 4.5];
-    let mut t = Vec::with_capacity(n + 1);
-    let mut solutions = Vec::with_capacity(alphas.len());
+///
+/// Settled oscillation of every alpha from running statistics over
+/// [t_skip, tf], the trajectory itself is never stored. The alphas run 
+/// together as one rk4 batch, each lane streamed into its own statistics 
+///
+fn settled_summary(ctx: &mut RunContext, tf: f64, t_skip: f64) -> Result<(), Box<dyn std::error::Error>> {
+    let dt = 1e-3; 
+    let alphas = [0.5, 1.5, 2.5, 3.5, 4.5]; 
+    ctx.solver("batch::solve_with", "classical rk4 over the alphas, streamed into running statistics"); 
+    let mut stats: Vec<RunningStats<2>> = alphas.iter().map(|_| RunningStats::new(t_skip)).collect(); 
+    ctx.time("running statistics", || {
+        batch::solve_with(
+            &|_t: f64, z: &[Vec<f64>; 2], dz: &mut [Vec<f64>; 2]| rate_batch(&alphas, z, dz), 
+            &[IC; 5], [0.0, tf], dt, Limits::default(), |t, z| {
+                for (k, s) in stats.iter_mut().enumerate() {
+                    s.observe(t, &z.lane(k)); 
+                }
+            })
+    })?; 
+    println!(
+        "{:>6} {:>14} {:>14} {:>14} {:>14}", "alpha", 
+        format!("amp {}", STATES[0].name), format!("amp {}", STATES[1].name), 
+        format!("mean {}", STATES[0].name), format!("peak t {}", STATES[0].name)); 
+    for (alpha, stats) in alphas.iter().zip(&stats) {
+        let amp = stats.amplitude(); 
+        println!(
+            "{:>6} {:>14.6e} {:>14.6e} {:>14.6e} {:>14.3}", alpha, amp[0], amp[1], 
+            stats.mean()[0], stats.t_max[0]); 
+    }
+    Ok(())
+}
+
+///
+/// Steady oscillation across a dense alpha sweep: count alphas over [lo, hi] 
+/// run as one rk4 batch, the voltage of each kept past t_skip and reduced 
+/// to its settled amplitude and angular frequency 2 pi / T. An alpha whose 
+/// envelope still moves more than 1% over the kept stretch is flagged as 
+/// unsettled. Near the Hopf point the cycle is V ~ 2 sqrt(alpha / 3) at 
+/// frequency 1, drawn for comparison 
+///
+fn alpha_sweep(ctx: &mut RunContext, range: [f64; 2], count: usize, tf: f64, t_skip: f64, name: &str) 
+    -> Result<(), Box<dyn std::error::Error>> {
+    let dt = 1e-2; 
+    let alphas: Vec<f64> = (0..count).map(|k| range[0] + (range[1] - range[0]) * k as f64 / (count - 1).max(1) as f64).collect(); 
+    ctx.solver("batch::solve_with", "classical rk4 over the alpha sweep"); 
+    ctx.config("alpha_sweep", format!("{count} alphas in {range:?}, tf = {tf}, settled past t = {t_skip}, dt = {dt}")); 
 
-    for i in 0..=n {
-        t.push(t0 + (i as f64) * dt); 
-    } 
+    let mut t: Vec<f64> = Vec::new(); 
+    let mut voltage: Vec<Vec<f64>> = vec![Vec::new(); count]; 
+    ctx.time("alpha sweep", || {
+        batch::solve_with(
+            &|_t: f64, z: &[Vec<f64>; 2], dz: &mut [Vec<f64>; 2]| rate_batch(&alphas, z, dz), 
+            &vec![IC; count], [0.0, tf], dt, Limits::default(), |ti, z| {
+                if ti >= t_skip {
+                    t.push(ti); 
+                    for (v, x) in voltage.iter_mut().zip(&z.y[0]) {
+                        v.push(*x); 
+                    }
+                }
+            })
+    })?; 
 
-    for a in alphas {
-        let (_, y) = func(a, dt, t0, tf); 
-        solutions.push(y);
+    // (alpha, amplitude, frequency, settled) 
+    let mut summary: Vec<(f64, f64, f64, bool)> = Vec::with_capacity(count); 
+    for (alpha, v) in alphas.iter().zip(&voltage) {
+        let osc = peaks::oscillation(&t, v, 3); 
+        let (Some(period), Some(amplitude)) = (osc.period, osc.amplitude) else {
+            continue; 
+        }; 
+        let settled = osc.rate.is_some_and(|r| r.abs() * (tf - t_skip) < 1e-2); 
+        summary.push((*alpha, amplitude, 2.0 * std::f64::consts::PI / period, settled)); 
     }
-    
-    let _ = plot(&t, &solutions, &alphas, path, title); 
+    let unsettled: Vec<f64> = summary.iter().filter(|s| !s.3).map(|s| s.0).collect(); 
+    println!("{} of {count} alphas oscillating, {} settled by t = {tf}", summary.len(), summary.len() - unsettled.len()); 
+    if let (Some(a), Some(b)) = (unsettled.first(), unsettled.last()) {
+        println!("unsettled alphas span [{a:.3}, {b:.3}]"); 
+    }
+
+    let mut table = Table::new(format!("Settled oscillation against alpha, every tenth of {count}"), 
+        &["alpha", "amplitude", "frequency", "settled"]); 
+    for s in summary.iter().step_by((count / 10).max(1)) {
+        table.row([format!("{:.3}", s.0), format!("{:.6}", s.1), format!("{:.6}", s.2), s.3.to_string()]); 
+    }
+    ctx.table(table); 
+
+    let path = ctx.path(name); 
+    ctx.caption(name, format!("Settled amplitude and angular frequency of the voltage for {count} alphas in [{}, {}]", range[0], range[1])); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let panels = root.split_evenly((1, 2)); 
+    let hopf = |a: f64, k: usize| if k == 0 { 2.0 * (a / 3.0).sqrt() } else { 1.0 }; 
+
+    for (k, panel) in panels.iter().enumerate() {
+        let pick = |s: &(f64, f64, f64, bool)| if k == 0 { s.1 } else { s.2 }; 
+        let (title, desc) = if k == 0 { ("Settled Amplitude", "voltage amplitude") } else { ("Angular Frequency", "2 pi / period") }; 
+        let top = summary.iter().map(pick).fold(0.0_f64, f64::max) * 1.15; 
+
+        let mut chart = ChartBuilder::on(panel)
+            .caption(title, theme.caption())
+            .margin(theme.margin)
+            .set_label_area_size(LabelAreaPosition::Left, 55)
+            .set_label_area_size(LabelAreaPosition::Bottom, 50)
+            .build_cartesian_2d(range[0]..range[1], 0.0..top)?; 
+
+        chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("alpha").y_desc(desc).draw()?; 
+
+        let color = theme.color(0); 
+        chart.draw_series(LineSeries::new(summary.iter().map(|s| (s.0, pick(s))), color.stroke_width(theme.line)))?
+            .label("rk4 sweep")
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(2)));
+        chart.draw_series(summary.iter().filter(|s| !s.3).map(|s| Circle::new((s.0, pick(s)), 4, color)))?
+            .label("unsettled")
+            .legend(move |(x, y)| Circle::new((x + 10, y), 4, color));
+
+        let color = theme.color(1); 
+        let near: Vec<(f64, f64)> = (0..=100).map(|i| range[0].max(0.0) + 0.015 * i as f64).map(|a| (a, hopf(a, k))).collect(); 
+        chart.draw_series(DashedLineSeries::new(near, 8, 4, color.stroke_width(theme.thin)))?
+            .label("hopf, small alpha")
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+
+        chart.configure_series_labels()
+            .label_font(theme.label())
+            .position(if k == 0 { SeriesLabelPosition::LowerRight } else { SeriesLabelPosition::UpperRight })
+            .border_style(BLACK)
+            .background_style(WHITE.mix(0.85))
+            .draw()?; 
+    }
+
+    root.present()?; 
+    Ok(())
 }
 
+///
+/// Alpha sweeps of growing width, one rk4 run per alpha against a single 
+/// batched run over all of them. The final states must agree bitwise 
+///
+fn batch_study(ctx: &mut RunContext, tf: f64, widths: &[usize]) 
+    -> Result<(), Box<dyn std::error::Error>> {
+    let dt = 1e-3; 
+    ctx.config("batch_widths", format!("{widths:?}, alpha in [0.5, 4.5], tf = {tf}, dt = {dt}")); 
+    println!("{:>8} {:>12} {:>12} {:>9} {:>10}", "alphas", "per alpha", "batched", "speedup", "max diff"); 
+    for &m in widths {
+        let alphas: Vec<f64> = (0..m).map(|k| 0.5 + 4.0 * k as f64 / (m - 1).max(1) as f64).collect(); 
+        let ics = vec![IC; m]; 
 
-fn main() {
+        let start = std::time::Instant::now(); 
+        let single: Vec<[f64; 2]> = ctx.time("batch study single", || alphas.iter().map(|&alpha| {
+            solvers::Method::Rk4.solve_with(&|_t: f64, z: &[f64; 2], dz: &mut [f64; 2]| rate(alpha, z, dz), IC, [0.0, tf], dt, |_, _| {})
+        }).collect::<Result<_, _>>())?; 
+        let t_single = start.elapsed().as_secs_f64(); 
 
-    solve(
-        &abam4_pred_corr, 
-        1e-3, 
-        "abam4_semiconductor.png", 
-        "AB/AM 4th Order Semiconductor System for Range of (a), dt = 1e-3"
-    );
-    solve(
-        &rk4, 
-        1e-3,
-        "rk4_semiconductor.png", 
-        "Runge-Kutta 4th Order, Semiconductor System for Range of (a), dt = 1e-3"
-    );
+        let start = std::time::Instant::now(); 
+        let batched = ctx.time("batch study batched", || {
+            batch::solve_with(&|_t: f64, z: &[Vec<f64>; 2], dz: &mut [Vec<f64>; 2]| rate_batch(&alphas, z, dz), &ics, [0.0, tf], dt, Limits::default(), |_, _| {})
+        })?; 
+        let t_batched = start.elapsed().as_secs_f64(); 
 
-    solve(
-        &abam4_pred_corr, 
-        1e-1, 
-        "bad_timestep.png", 
-        "AB/AM 4th Order, Semiconductor System for Range of (a), dt = 1e-1"
-    );
+        let diff = single.iter().enumerate()
+            .fold(0.0_f64, |d, (k, y)| (0..2).fold(d, |d, i| d.max((y[i] - batched.y[i][k]).abs()))); 
+        println!("{:>8} {:>12.4} {:>12.4} {:>9.2} {:>10.1e}", m, t_single, t_batched, t_single / t_batched, diff); 
+    }
+    Ok(())
+}
+
+///
+/// Period, amplitude and envelope rate of the voltage for every alpha from 
+/// the refined extrema of the AB/AM4 trajectory, last three cycles averaged 
+///
+fn oscillation_study(ctx: &mut RunContext, dt: f64, tf: f64) 
+    -> Result<(), Box<dyn std::error::Error>> {
+    println!("{:>6} {:>8} {:>12} {:>12} {:>14}", "alpha", "cycles", "period", "amplitude", "envelope rate"); 
+    for alpha in [0.5, 1.5, 2.5, 3.5, 4.5] {
+        let (t, y) = ctx.time("abam4_semiconductor", || abam4_pred_corr(alpha, IC, dt, 0.0, tf))?; 
+        let traj: Trajectory<2> = Trajectory::from_rows(&t, &y); 
+        let osc = peaks::oscillation(traj.time(), traj.component(0), 3); 
+        let cycles = osc.extrema.iter().filter(|e| e.kind == peaks::Kind::Peak).count(); 
+        let show = |v: Option<f64>| v.map_or("-".to_string(), |x| format!("{x:.6}")); 
+        println!(
+            "{:>6} {:>8} {:>12} {:>12} {:>14}", alpha, cycles, show(osc.period), 
+            show(osc.amplitude), show(osc.rate)); 
+    }
+    Ok(())
+}
+
+///
+/// Period of every alpha read off the autocorrelation of the settled 
+/// voltage, rho's first peak past its first zero, beside the period 
+/// from refined peak spacing. The autocorrelations of three alphas are 
+/// plotted with their 95% bands 
+///
+fn autocorrelation_study(ctx: &mut RunContext, dt: f64, tf: f64, t_skip: f64, name: &str) 
+    -> Result<(), Box<dyn std::error::Error>> {
+    let (every, max_lag) = (5, 30.0); 
+    ctx.solver("rk4", "fixed-step"); 
+    ctx.solver("correlation::autocorrelation", "fft, biased estimate"); 
+    ctx.config("autocorrelation", format!("voltage sampled every {} after t = {t_skip}, lags to {max_lag}", dt * every as f64)); 
+    let h = dt * every as f64; 
+
+    let caption = format!("Period from autocorrelation, rk4 at dt = {dt} to t = {tf}"); 
+    let mut table = Table::new(caption.clone(), &["alpha", "acf period", "peak period", "difference", "rho at period"]); 
+    println!("{caption}"); 
+    println!("{:>6} {:>12} {:>12} {:>12} {:>14}", "alpha", "acf period", "peak period", "difference", "rho at period"); 
+    let mut shown = Vec::new(); 
+    for alpha in [0.5, 1.5, 2.5, 3.5, 4.5] {
+        let (t, y) = ctx.time("rk4_semiconductor", || rk4(alpha, IC, dt, 0.0, tf))?; 
+        let skip = t.partition_point(|s| *s < t_skip); 
+        let v: Vec<f64> = y[skip..].iter().step_by(every).map(|z| z[0]).collect(); 
+        let acf = correlation::autocorrelation(&v, h, (max_lag / h).round() as usize)?; 
+        let traj: Trajectory<2> = Trajectory::from_rows(&t[skip..], &y[skip..]); 
+        let peak = peaks::oscillation(traj.time(), traj.component(0), 3).period; 
+        let period = acf.period(); 
+        let at = period.map(|p| acf.rho[(p / h).round() as usize]); 
+        let show = |v: Option<f64>, precision: usize| v.map_or("-".to_string(), |x| format!("{x:.precision$}")); 
+        let diff = period.zip(peak).map(|(a, b)| format!("{:.2e}", (a - b).abs())).unwrap_or("-".to_string()); 
+        let row = [format!("{alpha}"), show(period, 6), show(peak, 6), diff, show(at, 4)]; 
+        println!("{:>6} {:>12} {:>12} {:>12} {:>14}", row[0], row[1], row[2], row[3], row[4]); 
+        table.row(row); 
+        if [0.5, 2.5, 4.5].contains(&alpha) {
+            shown.push((alpha, acf)); 
+        }
+    }
+    ctx.table(table); 
+
+    let panels: Vec<correlation::Panel> = shown.iter().map(|(alpha, acf)| correlation::Panel {
+        title: format!("Voltage autocorrelation, alpha = {alpha}"), 
+        correlation: acf, 
+        reference: None, 
+    }).collect(); 
+    correlation::plot(&ctx.style(), &panels, &ctx.path(name))?; 
+    ctx.caption(name, format!("Autocorrelation of the settled voltage for alpha = 0.5, 2.5, 4.5, rk4 at dt = {dt}, 95% bands")); 
+    Ok(())
+}
+
+///
+/// Synchronization of two voltage-coupled oscillators of different alpha, 
+/// and so different natural periods, as the coupling k grows. Phases come 
+/// from the Hilbert transform of each settled voltage; the 1:1 locking 
+/// value, the two mean frequencies and the phase slips are tabulated, and 
+/// the locking value and the phase difference over time are plotted 
+///
+fn synchronization_study(ctx: &mut RunContext, alphas: [f64; 2], couplings: &[f64], tf: f64, t_skip: f64, name: &str) 
+    -> Result<(), Box<dyn std::error::Error>> {
+    let (dt, every, trim) = (1e-2, 10, 0.05); 
+    ctx.solver("rk4", "fixed-step, coupled pair"); 
+    ctx.solver("phase::locking", "hilbert phase by fft, 1:1"); 
+    ctx.config("synchronization", format!("alphas {alphas:?}, voltage coupling, after t = {t_skip}, trim {trim}")); 
+    let ic = [IC[0], IC[1], IC[0], -IC[1]]; 
+
+    let caption = format!("Locking of alpha = {} and {}, rk4 at dt = {dt} to t = {tf}", alphas[0], alphas[1]); 
+    let headers = ["coupling", "plv", "mean dphi", "freq 1", "freq 2", "slips"]; 
+    let mut table = Table::new(caption.clone(), &headers); 
+    println!("{caption}"); 
+    println!("{:>10} {:>10} {:>10} {:>10} {:>10} {:>8}", headers[0], headers[1], headers[2], headers[3], headers[4], headers[5]); 
+    let mut results = Vec::new(); 
+    for &k in couplings {
+        let f = |_t: f64, z: &[f64; 4], dz: &mut [f64; 4]| coupled_rate(alphas, k, z, dz); 
+        let ((t, y), _) = ctx.time("rk4 coupled", || solvers::Method::Rk4.solve(&f, ic, [0.0, tf], dt))?; 
+        let skip = t.partition_point(|s| *s < t_skip); 
+        let ts: Vec<f64> = t[skip..].iter().step_by(every).copied().collect(); 
+        let v1: Vec<f64> = y[skip..].iter().step_by(every).map(|z| z[0]).collect(); 
+        let v2: Vec<f64> = y[skip..].iter().step_by(every).map(|z| z[2]).collect(); 
+        let lock = phase::locking(&ts, &v1, &v2, (1, 1), trim)?; 
+        let row = [
+            format!("{k}"), format!("{:.4}", lock.plv), format!("{:.4}", lock.mean_difference), 
+            format!("{:.6}", lock.frequency[0]), format!("{:.6}", lock.frequency[1]), lock.slips.to_string(), 
+        ]; 
+        println!("{:>10} {:>10} {:>10} {:>10} {:>10} {:>8}", row[0], row[1], row[2], row[3], row[4], row[5]); 
+        table.row(row); 
+
+        let cut = (trim * ts.len() as f64) as usize; 
+        let (p1, p2) = (phase::instantaneous(&v1), phase::instantaneous(&v2)); 
+        let dphi: Vec<(f64, f64)> = (cut..ts.len() - cut).map(|i| (ts[i], p1[i] - p2[i])).collect(); 
+        results.push((k, lock, dphi)); 
+    }
+    ctx.table(table); 
+
+    let path = ctx.path(name); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let panels = root.split_evenly((2, 1)); 
+
+    let k_top = couplings.iter().fold(0.0_f64, |m, k| m.max(*k)); 
+    let mut chart = ChartBuilder::on(&panels[0])
+        .caption(format!("1:1 Locking Value, alpha = {} and {}", alphas[0], alphas[1]), theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 70)
+        .set_label_area_size(LabelAreaPosition::Bottom, 40)
+        .build_cartesian_2d(0.0..k_top, 0.0..1.05)?; 
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("coupling k").y_desc("plv").draw()?; 
+    let color = theme.color(0); 
+    chart.draw_series(LineSeries::new(results.iter().map(|(k, l, _)| (*k, l.plv)), color.stroke_width(theme.line)))?; 
+    chart.draw_series(results.iter().map(|(k, l, _)| Circle::new((*k, l.plv), 4, color.filled())))?; 
+
+    let (lo, hi) = results.iter().flat_map(|(_, _, d)| d.iter().map(|p| p.1))
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), x| (lo.min(x), hi.max(x))); 
+    let pad = 0.05 * (hi - lo).max(1.0); 
+    let span = results[0].2[0].0..results[0].2[results[0].2.len() - 1].0; 
+    let mut chart = ChartBuilder::on(&panels[1])
+        .caption("Phase Difference phi_1 - phi_2", theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 70)
+        .set_label_area_size(LabelAreaPosition::Bottom, 40)
+        .build_cartesian_2d(span, (lo - pad)..(hi + pad))?; 
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("t").y_desc("dphi [rad]").draw()?; 
+    for (j, (k, _, dphi)) in results.iter().enumerate() {
+        let color = theme.color(j); 
+        let (t, d): (Vec<f64>, Vec<f64>) = dphi.iter().copied().unzip(); 
+        chart.draw_series(LineSeries::new(downsample::points(&t, &d, downsample::PLOT_POINTS), color.stroke_width(theme.line)))?
+            .label(format!("k = {k}"))
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(2)));
+    }
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .position(SeriesLabelPosition::UpperRight)
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?; 
+
+    root.present()?; 
+    ctx.caption(name, format!("Phase locking of voltage-coupled oscillators at alpha = {} and {} against coupling, and their Hilbert phase difference", alphas[0], alphas[1])); 
+    Ok(())
+}
+
+///
+/// Synchronization of a network of oscillators with alphas spread over 
+/// a range as the coupling grows: all-to-all (mean field) through the 
+/// voltage and through dV/dt, and a nearest-neighbor ring through dV/dt. 
+/// Each unit's Hilbert phase gives the time-averaged Kuramoto order 
+/// parameter and its mean frequency; units whose frequencies agree to 
+/// 1e-4 are counted as one locked cluster 
+///
+fn network_study(ctx: &mut RunContext, range: [f64; 2], couplings: &[f64], tf: f64, t_skip: f64, name: &str) 
+    -> Result<(), Box<dyn std::error::Error>> {
+    const UNITS: usize = 40; 
+    const STATE: usize = 2 * UNITS; 
+    let (dt, every, trim) = (1e-2, 10, 0.05); 
+    ctx.solver("rk4", "fixed-step, network::Network"); 
+    ctx.solver("phase::order_parameter", "hilbert phases by fft"); 
+    ctx.config("network", format!("{UNITS} units, alpha in {range:?}, after t = {t_skip}, trim {trim}")); 
+
+    let caption = format!("{UNITS} coupled oscillators, alpha in [{}, {}], rk4 at dt = {dt} to t = {tf}", range[0], range[1]); 
+    let headers = ["topology", "through", "coupling", "edges", "lambda2", "mean R", "clusters", "largest"]; 
+    let mut table = Table::new(caption.clone(), &headers); 
+    println!("{caption}"); 
+    println!("{:>12} {:>8} {:>10} {:>8} {:>10} {:>10} {:>10} {:>10}", headers[0], headers[1], headers[2], headers[3], headers[4], headers[5], headers[6], headers[7]); 
+    let configs = [("all-to-all", Coupling::Voltage), ("all-to-all", Coupling::Rate), ("ring", Coupling::Rate)]; 
+    let mut order = vec![Vec::new(); configs.len()]; 
+    let mut spectra = Vec::new(); 
+    for (c, (topology, through)) in configs.iter().enumerate() {
+        for &k in couplings {
+            let builder = Network::builder(UNITS).alphas_between(range[0], range[1]).through(*through); 
+            let net = if *topology == "ring" { builder.ring(k) } else { builder.all_to_all(k) }.build()?; 
+            let lambda2 = net.graph().algebraic_connectivity()?; 
+            let ic = net.scattered::<STATE>(2.0, 3); 
+            let ((t, y), _) = ctx.time("rk4 network", || system::solve(&net, solvers::Method::Rk4, ic, [0.0, tf], dt))?; 
+            let skip = t.partition_point(|s| *s < t_skip); 
+            let ts: Vec<f64> = t[skip..].iter().step_by(every).copied().collect(); 
+            let cut = (trim * ts.len() as f64) as usize; 
+            let window = cut..ts.len() - cut; 
+            let phases: Vec<Vec<f64>> = (0..UNITS).map(|i| {
+                let v: Vec<f64> = net.voltage(&y[skip..], i).into_iter().step_by(every).collect(); 
+                phase::instantaneous(&v)[window.clone()].to_vec()
+            }).collect(); 
+            let r = phase::order_parameter(&phases); 
+            let mean_r = r.iter().sum::<f64>() / r.len() as f64; 
+            let freqs: Vec<f64> = phases.iter().map(|p| phase::frequency(&ts[window.clone()], p)).collect(); 
+
+            let mut sorted = freqs.clone(); 
+            sorted.sort_by(f64::total_cmp); 
+            let mut clusters = vec![1]; 
+            for w in sorted.windows(2) {
+                if w[1] - w[0] < 1e-4 {
+                    *clusters.last_mut().unwrap() += 1; 
+                } else {
+                    clusters.push(1); 
+                }
+            }
+            let row = [
+                topology.to_string(), through.name().to_string(), format!("{k}"), net.edges.len().to_string(), format!("{lambda2:.4}"), 
+                format!("{mean_r:.4}"), clusters.len().to_string(), clusters.iter().max().unwrap().to_string(), 
+            ]; 
+            println!("{:>12} {:>8} {:>10} {:>8} {:>10} {:>10} {:>10} {:>10}", row[0], row[1], row[2], row[3], row[4], row[5], row[6], row[7]); 
+            table.row(row); 
+            order[c].push((k, mean_r)); 
+            if c == 1 {
+                spectra.push((k, net.alphas.iter().copied().zip(freqs).collect::<Vec<(f64, f64)>>())); 
+            }
+        }
+    }
+    ctx.table(table); 
+
+    let path = ctx.path(name); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let panels = root.split_evenly((2, 1)); 
+
+    let k_top = couplings.iter().fold(0.0_f64, |m, k| m.max(*k)); 
+    let mut chart = ChartBuilder::on(&panels[0])
+        .caption(format!("Order Parameter of {UNITS} Coupled Oscillators"), theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 70)
+        .set_label_area_size(LabelAreaPosition::Bottom, 40)
+        .build_cartesian_2d(0.0..k_top, 0.0..1.05)?; 
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("coupling k").y_desc("mean R").draw()?; 
+    for (j, ((topology, through), points)) in configs.iter().zip(&order).enumerate() {
+        let color = theme.color(j); 
+        chart.draw_series(LineSeries::new(points.iter().copied(), color.stroke_width(theme.line)))?
+            .label(format!("{topology} through {}", through.name()))
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(2)));
+        chart.draw_series(points.iter().map(|p| Circle::new(*p, 4, color.filled())))?; 
+    }
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .position(SeriesLabelPosition::LowerRight)
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?; 
+
+    let (lo, hi) = spectra.iter().flat_map(|(_, s)| s.iter().map(|p| p.1))
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), x| (lo.min(x), hi.max(x))); 
+    let pad = 0.05 * (hi - lo).max(1e-3); 
+    let mut chart = ChartBuilder::on(&panels[1])
+        .caption("Mean Frequency of Each Unit, all-to-all through dV/dt", theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 70)
+        .set_label_area_size(LabelAreaPosition::Bottom, 40)
+        .build_cartesian_2d(range[0]..range[1], (lo - pad)..(hi + pad))?; 
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("alpha").y_desc("frequency").draw()?; 
+    for (j, (k, points)) in spectra.iter().enumerate() {
+        let color = theme.color(j); 
+        chart.draw_series(LineSeries::new(points.iter().copied(), color.stroke_width(theme.thin)))?
+            .label(format!("k = {k}"))
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(2)));
+        chart.draw_series(points.iter().map(|p| Circle::new(*p, 3, color.filled())))?; 
+    }
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .position(SeriesLabelPosition::UpperRight)
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?; 
+
+    root.present()?; 
+    ctx.caption(name, format!("Order parameter against coupling for {UNITS} oscillators, and each unit's mean frequency against its alpha")); 
+    Ok(())
+}
+
+///
+/// Limit cycle of every alpha found directly by Newton shooting, seeded by 
+/// a short transient (t in [0, 40]) whose last peak and peak spacing give 
+/// the point and period guesses, with the largest nontrivial Floquet 
+/// multiplier of each from the variational equations. Cycles are plotted 
+/// in the phase plane 
+///
+fn limit_cycles(ctx: &mut RunContext, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let alphas = [0.5, 1.5, 2.5, 3.5, 4.5]; 
+    ctx.solver("periodic::shoot", "newton shooting, rk4 period map"); 
+    ctx.solver("periodic::floquet", "variational monodromy along the shooting orbit, rk4"); 
+    let opts = periodic::ShootingOptions::default(); 
+
+    let mut cycles: Vec<(f64, Vec<[f64; 2]>)> = Vec::new(); 
+    println!("{:>6} {:>12} {:>6} {:>12} {:>24}", "alpha", "period", "iters", "max voltage", "multiplier"); 
+    for alpha in alphas {
+        let f = |_t: f64, z: &[f64; 2], dz: &mut [f64; 2]| rate(alpha, z, dz); 
+        let j = |_t: f64, z: &[f64; 2]| jacobian(alpha, z); 
+        let ((t, y), _) = solvers::Method::Rk4.solve(&f, IC, [0.0, 40.0], 1e-2)?; 
+        let traj: Trajectory<2> = Trajectory::from_rows(&t, &y); 
+        let osc = peaks::oscillation(traj.time(), traj.component(0), 2); 
+        let (Some(period), Some(last)) = (osc.period, osc.extrema.iter().rfind(|e| e.kind == peaks::Kind::Peak)) else {
+            println!("{:>6} no oscillation in the transient", alpha); 
+            continue; 
+        }; 
+        let x0 = traj.at(last.t)?; 
+
+        let result = ctx.time("limit cycle shooting", || {
+            periodic::shoot(&f, &j, x0, period, opts)
+        }); 
+        match result {
+            Ok(orbit) => {
+                let vmax = orbit.cycle.1.iter().fold(f64::NEG_INFINITY, |m, z| m.max(z[0])); 
+                // eigenvalues of the monodromy lose a multiplier far below the 
+                // trivial one, so it comes from the exponent, the trivial 0 dropped 
+                let floquet = periodic::floquet(&f, &j, &orbit, opts.steps, opts.limits)?;
+                let mu = floquet.nontrivial_exponents().first().map_or(f64::NAN, |e| (e * orbit.period).exp());
+                println!(
+                    "{:>6} {:>12.8} {:>6} {:>12.8} {:>24.6e}", alpha, orbit.period, orbit.iterations, vmax, mu); 
+                cycles.push((alpha, orbit.cycle.1)); 
+            }
+            Err(e) => println!("{:>6} {e}", alpha), 
+        }
+    }
+
+    let (mut lo, mut hi) = ([f64::INFINITY; 2], [f64::NEG_INFINITY; 2]); 
+    for (_, cycle) in &cycles {
+        for z in cycle {
+            for k in 0..2 {
+                lo[k] = lo[k].min(z[k]); 
+                hi[k] = hi[k].max(z[k]); 
+            }
+        }
+    }
+
+    let path = ctx.path(name); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Semiconductor Limit Cycles by Newton Shooting", theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 55)
+        .set_label_area_size(LabelAreaPosition::Bottom, 50)
+        .build_cartesian_2d((lo[0] * 1.05)..(hi[0] * 1.05), (lo[1] * 1.05)..(hi[1] * 1.05))?; 
+
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc(STATES[0].label()).y_desc(STATES[1].label()).draw()?; 
+
+    for (k, (alpha, cycle)) in cycles.iter().enumerate() {
+        let color = theme.color(k); 
+        chart.draw_series(LineSeries::new(cycle.iter().map(|z| (z[0], z[1])), &color))?
+            .label(format!("a = {alpha}"))
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+    }
+
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?; 
+
+    root.present()?; 
+    Ok(())
+}
+
+///
+/// Largest real part among the eigenvalues of the Jacobian at the origin 
+///
+fn origin_growth(alpha: f64) -> Result<f64, Box<dyn std::error::Error>> {
+    let eig = linalg::Matrix::from_rows(&jacobian(alpha, &[0.0, 0.0])).eigenvalues()?; 
+    Ok(eig.iter().fold(f64::NEG_INFINITY, |m, (re, _)| m.max(*re)))
+}
+
+///
+/// Growth rate of a small kick off the origin measured from a simulation: 
+/// the slope of the log envelope of V, or of log E where the motion does 
+/// not oscillate. Only the stretch before the state leaves 4 kicks of the 
+/// origin counts, so the cubic term stays negligible 
+///
+fn simulated_growth(alpha: f64, kick: f64, tf: f64, dt: f64) -> Result<f64, Box<dyn std::error::Error>> {
+    let f = |_t: f64, z: &[f64; 2], dz: &mut [f64; 2]| rate(alpha, z, dz); 
+    let ic = [0.0, kick]; 
+    let (mut t, mut voltage, mut last, mut linear) = (Vec::new(), Vec::new(), ic, true); 
+    solvers::Method::Rk4.solve_with(&f, ic, [0.0, tf], dt, |ti, z| {
+        linear = linear && z[0].hypot(z[1]) <= 4.0 * kick; 
+        if linear {
+            t.push(ti); 
+            voltage.push(z[0]); 
+            last = *z; 
+        }
+    })?; 
+    let span = t[t.len() - 1]; 
+    Ok(peaks::oscillation(&t, &voltage, 1).rate
+        .unwrap_or_else(|| 0.5 * (energy(&last) / energy(&ic)).ln() / span))
+}
+
+///
+/// Bisection on [lo, hi] for where growth changes sign, stable below. 
+/// Returns the bracket once narrower than tol and the halvings taken 
+///
+fn threshold<G>(lo: f64, hi: f64, tol: f64, mut growth: G) -> Result<([f64; 2], usize), Box<dyn std::error::Error>>
+where G: FnMut(f64) -> Result<f64, Box<dyn std::error::Error>> {
+    let (mut a, mut b) = (lo, hi); 
+    if growth(a)? >= 0.0 || growth(b)? <= 0.0 {
+        return Err(format!("no stable to unstable change on [{lo}, {hi}]").into()); 
+    }
+    let mut halvings = 0; 
+    while b - a > tol {
+        let m = 0.5 * (a + b); 
+        if growth(m)? > 0.0 { b = m; } else { a = m; }
+        halvings += 1; 
+    }
+    Ok(([a, b], halvings))
+}
+
+///
+/// Answers for what alpha the semiconductor oscillates. The origin has 
+/// eigenvalues (alpha +- sqrt(alpha^2 - 4)) / 2, so it loses stability 
+/// where their real part alpha / 2 crosses zero, a Hopf point that births 
+/// the limit cycle. Bisection finds the threshold twice, from the 
+/// eigenvalues and from simulated growth of a small kick, to tol 
+///
+fn hopf_study(ctx: &mut RunContext, range: [f64; 2], tol: f64) -> Result<(), Box<dyn std::error::Error>> {
+    let (kick, tf, dt) = (1e-3, 400.0, 5e-2); 
+    ctx.solver("hopf threshold", "bisection on jacobian eigenvalues and simulated growth"); 
+    ctx.config("hopf", format!("alpha in {range:?}, tol = {tol}, kick = {kick}, tf = {tf}, dt = {dt}")); 
+
+    let mut table = Table::new(format!("Critical alpha where the origin loses stability, tolerance {tol}"), 
+        &["test", "critical alpha", "bracket", "halvings"]); 
+    let mut record = |name: &str, ([a, b], halvings): ([f64; 2], usize)| {
+        println!("{name}: oscillation for alpha > {:.6} +- {:.1e}, {halvings} halvings", 0.5 * (a + b), 0.5 * (b - a)); 
+        table.row([name.to_string(), format!("{:.6}", 0.5 * (a + b)), format!("[{a:.6}, {b:.6}]"), halvings.to_string()]); 
+    }; 
+    record("jacobian eigenvalues", ctx.time("hopf eigenvalues", || threshold(range[0], range[1], tol, origin_growth))?); 
+    record("simulated growth", ctx.time("hopf simulated", || {
+        threshold(range[0], range[1], tol, |alpha| simulated_growth(alpha, kick, tf, dt))
+    })?); 
+    ctx.table(table); 
+    Ok(())
+}
+
+///
+/// Phase plane overlay of rk4 runs from several initial conditions at one 
+/// alpha, inside and outside the cycle. Every run should settle onto the 
+/// same limit cycle, so their late periods and amplitudes agree whatever 
+/// the start 
+///
+fn ic_overlay(ctx: &mut RunContext, alpha: f64, ics: &[[f64; 2]], tf: f64, dt: f64, name: &str) 
+    -> Result<(), Box<dyn std::error::Error>> {
+    ctx.solver("rk4", "fixed-step"); 
+    ctx.config("overlay_ics", format!("{ics:?}")); 
+
+    let mut runs: Vec<Trajectory<2>> = Vec::with_capacity(ics.len()); 
+    let mut table = Table::new(format!("Late oscillation from each initial condition, alpha = {alpha}, t to {tf}"), 
+        &["V(0)", "dV/dt(0)", "period", "amplitude"]); 
+    for ic in ics {
+        let (t, y) = ctx.time("rk4 overlay", || rk4(alpha, *ic, dt, 0.0, tf))?; 
+        let traj = Trajectory::from_rows(&t, &y); 
+        let osc = peaks::oscillation(traj.time(), traj.component(0), 3); 
+        let show = |v: Option<f64>| v.map_or("-".to_string(), |v| format!("{v:.6}")); 
+        table.row([format!("{}", ic[0]), format!("{}", ic[1]), show(osc.period), show(osc.amplitude)]); 
+        runs.push(traj); 
+    }
+    println!("{:>8} {:>9} {:>10} {:>10}", "V(0)", "dV/dt(0)", "period", "amplitude"); 
+    for row in &table.rows {
+        println!("{:>8} {:>9} {:>10} {:>10}", row[0], row[1], row[2], row[3]); 
+    }
+    ctx.table(table); 
+
+    let (mut lo, mut hi) = ([f64::INFINITY; 2], [f64::NEG_INFINITY; 2]); 
+    for traj in &runs {
+        for k in 0..2 {
+            for x in traj.component(k) {
+                lo[k] = lo[k].min(*x); 
+                hi[k] = hi[k].max(*x); 
+            }
+        }
+    }
+    let pad = [0.05 * (hi[0] - lo[0]), 0.05 * (hi[1] - lo[1])]; 
+
+    let path = ctx.path(name); 
+    ctx.caption(name, format!("Phase plane, alpha = {alpha}: runs from {} initial conditions settling onto one limit cycle", ics.len())); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let mut chart = ChartBuilder::on(&root)
+        .caption(format!("Semiconductor from {} Initial Conditions, a = {alpha}", ics.len()), theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 55)
+        .set_label_area_size(LabelAreaPosition::Bottom, 50)
+        .build_cartesian_2d((lo[0] - pad[0])..(hi[0] + pad[0]), (lo[1] - pad[1])..(hi[1] + pad[1]))?; 
+
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc(STATES[0].label()).y_desc(STATES[1].label()).draw()?; 
+
+    for (k, (ic, traj)) in ics.iter().zip(&runs).enumerate() {
+        let color = theme.color(k); 
+        let phase = traj.component(0).iter().copied().zip(traj.component(1).iter().copied()); 
+        chart.draw_series(LineSeries::new(phase, color.stroke_width(theme.thin)))?
+            .label(format!("z(0) = ({}, {})", ic[0], ic[1]))
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+        chart.draw_series([Circle::new((ic[0], ic[1]), 4, color.filled())])?; 
+    }
+
+    chart.configure_series_labels()
+        .position(SeriesLabelPosition::LowerLeft)
+        .label_font(theme.label())
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?; 
+
+    root.present()?; 
+    Ok(())
+}
+
+///
+/// Euler and Heun at step h against the true solution and against the 
+/// solution of each one's modified equation, truncated after its leading 
+/// term. The method's points should sit on the modified solution, far 
+/// closer than on the true one. Euler's numerical leading term is checked 
+/// against its closed form -(J f) / 2 along the way 
+///
+fn modified_equation_study(ctx: &mut RunContext, alpha: f64, tf: f64, h: f64, name: &str) 
+    -> Result<(), Box<dyn std::error::Error>> {
+    let f = |_t: f64, z: &[f64; 2], dz: &mut [f64; 2]| rate(alpha, z, dz); 
+    let methods = [solvers::Method::Euler, solvers::Method::Heun]; 
+    ctx.solver("modified::modified_rate", "leading term from one step against the rk4 flow, Richardson halved"); 
+    // fine enough that every step h lands on every 10th point 
+    let fine = h / 10.0; 
+    let ((t_exact, exact), _) = solvers::Method::Rk4.solve(&f, IC, [0.0, tf], fine)?; 
+
+    let mut runs = Vec::with_capacity(methods.len()); 
+    let mut table = Table::new(format!("Distance of each method's points from the true and the modified solution, alpha = {alpha}, h = {h}, t to {tf}"), 
+        &["method", "max |V - V_true|", "max |V - V_modified|", "ratio"]); 
+    for method in methods {
+        let ((t, y), _) = ctx.time("method run", || method.solve(&f, IC, [0.0, tf], h))?; 
+        let g = modified::modified_rate(method, &f, h); 
+        let ((_, modified), _) = ctx.time("modified equation", || solvers::Method::Rk4.solve(&g, IC, [0.0, tf], fine))?; 
+        let gap = |reference: &[[f64; 2]]| y.iter().zip(reference.iter().step_by(10))
+            .map(|(a, b)| (a[0] - b[0]).abs()).fold(0.0, f64::max); 
+        let (to_true, to_modified) = (gap(&exact), gap(&modified)); 
+        table.row([method.name().to_string(), format!("{to_true:.4e}"), format!("{to_modified:.4e}"), 
+            format!("{:.1}", to_true / to_modified)]); 
+        runs.push((method, t, y, modified)); 
+    }
+
+    // Euler's f_2 in closed form, the solution's curvature halved 
+    let (_, _, y_euler, _) = &runs[0]; 
+    let mut worst = 0.0_f64; 
+    for z in y_euler {
+        let mut dz = [0.0; 2]; 
+        rate(alpha, z, &mut dz); 
+        let jac = jacobian(alpha, z); 
+        let closed: [f64; 2] = std::array::from_fn(|i| -0.5 * (jac[i][0] * dz[0] + jac[i][1] * dz[1])); 
+        let numeric = modified::leading_term(solvers::Method::Euler, &f, 0.0, z, h); 
+        worst = worst.max((numeric[0] - closed[0]).hypot(numeric[1] - closed[1]) / closed[0].hypot(closed[1]).max(1e-12)); 
+    }
+    println!("euler leading term against -(J f) / 2: max relative difference {worst:.3e}"); 
+    println!("{}", table.caption); 
+    println!("{:>8} {:>18} {:>22} {:>8}", table.header[0], table.header[1], table.header[2], table.header[3]); 
+    for row in &table.rows {
+        println!("{:>8} {:>18} {:>22} {:>8}", row[0], row[1], row[2], row[3]); 
+    }
+    ctx.table(table); 
+
+    let path = ctx.path(name); 
+    ctx.caption(name, format!("Euler and Heun at h = {h} on the true and the modified-equation solutions, alpha = {alpha}")); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let panels = root.split_evenly((2, 1)); 
+    for (panel, (method, t, y, modified)) in panels.iter().zip(&runs) {
+        let (lo, hi) = exact.iter().chain(y).chain(modified).fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), z| (lo.min(z[0]), hi.max(z[0]))); 
+        let pad = 0.05 * (hi - lo); 
+        let mut chart = ChartBuilder::on(panel)
+            .caption(format!("{} at h = {h} and its Modified Equation, a = {alpha}", method.name()), theme.caption())
+            .margin(theme.margin)
+            .set_label_area_size(LabelAreaPosition::Left, 55)
+            .set_label_area_size(LabelAreaPosition::Bottom, 40)
+            .build_cartesian_2d(0.0..tf, (lo - pad)..(hi + pad))?; 
+        chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("t").y_desc(STATES[0].label()).draw()?; 
+
+        let true_color = theme.color(0); 
+        chart.draw_series(LineSeries::new(t_exact.iter().zip(&exact).map(|(t, z)| (*t, z[0])), true_color.stroke_width(theme.thin)))?
+            .label("true solution")
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], true_color));
+        let modified_color = theme.color(1); 
+        chart.draw_series(LineSeries::new(t_exact.iter().zip(modified).map(|(t, z)| (*t, z[0])), modified_color.stroke_width(theme.line)))?
+            .label("modified equation")
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], modified_color));
+        let point_color = theme.color(3); 
+        chart.draw_series(t.iter().zip(y).map(|(t, z)| Circle::new((*t, z[0]), 2, point_color.filled())))?
+            .label(format!("{} points", method.name()))
+            .legend(move |(x, y)| Circle::new((x + 10, y), 3, point_color.filled()));
+        chart.configure_series_labels()
+            .label_font(theme.label())
+            .position(SeriesLabelPosition::LowerLeft)
+            .border_style(BLACK)
+            .background_style(WHITE.mix(0.85))
+            .draw()?; 
+    }
+    root.present()?; 
+    Ok(())
+}
+
+///
+/// E = (V^2 + V'^2) / 2 along a trajectory. Along solutions 
+/// dE/dt = a V'^2 - V'^4: the first term drives, the second damps 
+///
+pub fn energy(z: &[f64; 2]) -> f64 {
+    0.5 * (z[0] * z[0] + z[1] * z[1])
+}
+
+pub fn energy_terms(alpha: f64, z: &[f64; 2]) -> [f64; 2] {
+    let v2 = z[1] * z[1]; 
+    [alpha * v2, -v2 * v2]
+}
+
+///
+/// Energy balance behind the limit cycle. Small swings have V' small, so 
+/// the driving a V'^2 beats the damping V'^4 and E grows; large swings 
+/// lose more than they gain. Runs from inside and outside the cycle 
+/// converge in E, and over one settled period the two terms cancel 
+///
+fn energy_study(ctx: &mut RunContext, alpha: f64, tf: f64, dt: f64, name: &str) 
+    -> Result<(), Box<dyn std::error::Error>> {
+    let f = |_t: f64, z: &[f64; 2], dz: &mut [f64; 2]| rate(alpha, z, dz); 
+    ctx.solver("solvers::Method::Rk4", "fixed-step"); 
+    let starts = [IC, [0.0, 4.0]]; 
+    let runs: Vec<Trajectory<2>> = starts.iter()
+        .map(|ic| {
+            let ((t, y), _) = ctx.time("rk4 energy", || solvers::Method::Rk4.solve(&f, *ic, [0.0, tf], dt))?; 
+            Ok(Trajectory::from_rows(&t, &y))
+        })
+        .collect::<Result<_, Box<dyn std::error::Error>>>()?; 
+
+    // work of each term over the last full period of every run 
+    let mut table = Table::new(format!("Work of the driving and damping terms over the last period, alpha = {alpha}"), 
+        &["start", "period", "driving", "damping", "net"]); 
+    println!("{:>14} {:>10} {:>14} {:>14} {:>12}", "start", "period", "driving", "damping", "net"); 
+    for (ic, traj) in starts.iter().zip(&runs) {
+        let osc = peaks::oscillation(traj.time(), traj.component(0), 1); 
+        let peaks: Vec<f64> = osc.extrema.iter().filter(|e| e.kind == peaks::Kind::Peak).map(|e| e.t).collect(); 
+        let [.., a, b] = peaks[..] else {
+            println!("{:>14} fewer than two peaks by t = {tf}", format!("{ic:?}")); 
+            continue; 
+        }; 
+        let t = traj.time(); 
+        let mut work = [0.0; 2]; 
+        for i in 1..t.len() {
+            if t[i - 1] < a || t[i] > b {
+                continue; 
+            }
+            let (p, q) = (energy_terms(alpha, &traj.row(i - 1)), energy_terms(alpha, &traj.row(i))); 
+            for k in 0..2 {
+                work[k] += 0.5 * (t[i] - t[i - 1]) * (p[k] + q[k]); 
+            }
+        }
+        println!("{:>14} {:>10.6} {:>14.6e} {:>14.6e} {:>12.3e}", format!("{ic:?}"), b - a, work[0], work[1], work[0] + work[1]); 
+        table.row([format!("{ic:?}"), format!("{:.6}", b - a), format!("{:.6e}", work[0]), format!("{:.6e}", work[1]), format!("{:.3e}", work[0] + work[1])]); 
+    }
+    ctx.table(table); 
+
+    let path = ctx.path(name); 
+    ctx.caption(name, format!("Energy E = (V^2 + V'^2) / 2 from inside and outside the cycle, and the driving and damping terms of dE/dt, alpha = {alpha}")); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let panels = root.split_evenly((2, 1)); 
+
+    let e_top = runs.iter().flat_map(|r| (0..r.len()).map(move |i| energy(&r.row(i)))).fold(0.0_f64, f64::max) * 1.1; 
+    let mut chart = ChartBuilder::on(&panels[0])
+        .caption(format!("Energy of the Semiconductor Oscillator, a = {alpha}"), theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 55)
+        .set_label_area_size(LabelAreaPosition::Bottom, 40)
+        .build_cartesian_2d(0.0..tf, 0.0..e_top)?; 
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("t").y_desc("E").draw()?; 
+    for (k, (ic, traj)) in starts.iter().zip(&runs).enumerate() {
+        let color = theme.color(k); 
+        let e: Vec<f64> = (0..traj.len()).map(|i| energy(&traj.row(i))).collect(); 
+        chart.draw_series(LineSeries::new(downsample::points(traj.time(), &e, downsample::PLOT_POINTS), color.stroke_width(theme.line)))?
+            .label(format!("z(0) = ({}, {})", ic[0], ic[1]))
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(2)));
+    }
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .position(SeriesLabelPosition::UpperRight)
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?; 
+
+    // decomposition along the run from the lab's initial condition 
+    let traj = &runs[0]; 
+    let terms: Vec<[f64; 2]> = (0..traj.len()).map(|i| energy_terms(alpha, &traj.row(i))).collect(); 
+    let series = [
+        ("driving a V'^2", terms.iter().map(|p| p[0]).collect::<Vec<f64>>()), 
+        ("damping -V'^4", terms.iter().map(|p| p[1]).collect()), 
+        ("dE/dt", terms.iter().map(|p| p[0] + p[1]).collect()), 
+    ]; 
+    let (lo, hi) = series.iter().flat_map(|(_, v)| v.iter()).fold((0.0_f64, 0.0_f64), |(lo, hi), x| (lo.min(*x), hi.max(*x))); 
+    let mut chart = ChartBuilder::on(&panels[1])
+        .caption("Terms of dE/dt", theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 55)
+        .set_label_area_size(LabelAreaPosition::Bottom, 40)
+        .build_cartesian_2d(0.0..tf, (1.1 * lo)..(1.1 * hi))?; 
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("t").y_desc("dE/dt").draw()?; 
+    for (k, (label, v)) in series.iter().enumerate() {
+        let color = theme.color(k + 2); 
+        let width = if k == 2 { theme.line } else { theme.thin }; 
+        chart.draw_series(LineSeries::new(downsample::points(traj.time(), v, downsample::PLOT_POINTS), color.stroke_width(width)))?
+            .label(*label)
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(width)));
+    }
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .position(SeriesLabelPosition::LowerLeft)
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?; 
+
+    root.present()?; 
+    Ok(())
+}
+
+///
+/// The semiconductor against reference Van der Pol limit cycles. Each 
+/// benchmark mu is run as alpha = mu in the lab's form, mapped into Van der 
+/// Pol coordinates and measured there; the same start is also run in the 
+/// Van der Pol form directly and mapped back, which must retrace the lab's 
+/// trajectory 
+///
+fn van_der_pol_study(ctx: &mut RunContext, tf: f64, dt: f64) -> Result<(), Box<dyn std::error::Error>> {
+    ctx.solver("vanderpol", "rayleigh to van der pol map, rk4 in both forms"); 
+    let mut table = Table::new("Semiconductor limit cycles in Van der Pol form against reference values", 
+        &["mu", "period", "reference", "max u", "reference", "retrace"]); 
+    println!("{:>4} {:>12} {:>12} {:>12} {:>12} {:>10}", "mu", "period", "reference", "max u", "reference", "retrace"); 
+    for (mu, period_ref, amp_ref) in vanderpol::BENCHMARKS {
+        let f = |_t: f64, z: &[f64; 2], dz: &mut [f64; 2]| rate(mu, z, dz); 
+        let g = |_t: f64, u: &[f64; 2], du: &mut [f64; 2]| vanderpol::rate(mu, u, du); 
+        let ((t, y), _) = ctx.time("rk4 rayleigh", || solvers::Method::Rk4.solve(&f, IC, [0.0, tf], dt))?; 
+        let lab = Trajectory::from_rows(&t, &y); 
+        let vdp = vanderpol::trajectory_to_van_der_pol(mu, &lab)?; 
+
+        // settled oscillation from the second half of the run 
+        let half = vdp.len() / 2; 
+        let osc = peaks::oscillation(&vdp.time()[half..], &vdp.component(0)[half..], 4); 
+        let top = peaks::extrema(&vdp.time()[half..], &vdp.component(0)[half..]).iter()
+            .filter(|e| e.kind == peaks::Kind::Peak).map(|e| e.value).fold(f64::NEG_INFINITY, f64::max); 
+        let period = osc.period.ok_or(format!("no settled oscillation at mu = {mu}"))?; 
+
+        let ((tu, u), _) = ctx.time("rk4 van der pol", || solvers::Method::Rk4.solve(&g, vdp.row(0), [0.0, tf], dt))?; 
+        let back = vanderpol::trajectory_from_van_der_pol(mu, &Trajectory::from_rows(&tu, &u))?; 
+        let retrace = (0..back.len()).map(|i| {
+            let (a, b) = (back.row(i), lab.row(i)); 
+            (a[0] - b[0]).abs().max((a[1] - b[1]).abs())
+        }).fold(0.0, f64::max); 
+
+        println!("{:>4} {:>12.8} {:>12.8} {:>12.8} {:>12.8} {:>10.2e}", mu, period, period_ref, top, amp_ref, retrace); 
+        table.row([format!("{mu}"), format!("{period:.8}"), format!("{period_ref:.8}"), format!("{top:.8}"), format!("{amp_ref:.8}"), format!("{retrace:.2e}")]); 
+    }
+    ctx.table(table); 
+    Ok(())
+}
+
+///
+/// Floquet stability of the limit cycle against alpha. Each orbit is found
+/// by shooting, continued from the previous alpha, and its exponents come
+/// from the reorthonormalized variational equations. In the plane the
+/// product of the multipliers is exp of the integrated trace (Liouville),
+/// and with the trivial one at 1 that is the nontrivial multiplier itself,
+/// which gives an independent check. The eigenvalue of the variational 
+/// monodromy and, as a diagnostic, of the finite difference one are shown 
+/// beside it to show where each drowns in noise 
+///
+fn floquet_study(ctx: &mut RunContext, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let alphas: Vec<f64> = (1..=20).map(|k| 0.25 * k as f64).collect(); 
+    let opts = periodic::ShootingOptions::default(); 
+    ctx.solver("periodic::floquet", "reorthonormalized variational equations along shooting orbits, rk4"); 
+
+    // seed the continuation from a transient at the first alpha 
+    let first = alphas[0]; 
+    let f = |_t: f64, z: &[f64; 2], dz: &mut [f64; 2]| rate(first, z, dz); 
+    let ((t, y), _) = solvers::Method::Rk4.solve(&f, IC, [0.0, 80.0], 1e-2)?; 
+    let osc = peaks::oscillation(&t, &y.iter().map(|z| z[0]).collect::<Vec<f64>>(), 2); 
+    let (Some(mut period), Some(last)) = (osc.period, osc.extrema.iter().rfind(|e| e.kind == peaks::Kind::Peak)) else {
+        return Err(format!("no oscillation at alpha = {first} to seed the continuation").into()); 
+    }; 
+    let mut x0 = y[t.iter().position(|ti| *ti >= last.t).unwrap_or(t.len() - 1)]; 
+
+    let mut rows: Vec<[f64; 4]> = Vec::new(); 
+    println!("{:>6} {:>12} {:>14} {:>14} {:>14} {:>14} {:>12}", 
+        "alpha", "period", "mu exponent", "mu liouville", "mu monodromy", "mu fd", "exponent"); 
+    for alpha in alphas {
+        let f = |_t: f64, z: &[f64; 2], dz: &mut [f64; 2]| rate(alpha, z, dz); 
+        let j = |_t: f64, z: &[f64; 2]| jacobian(alpha, z); 
+
+        let orbit = match periodic::shoot(&f, &j, x0, period, opts) {
+            Ok(orbit) => orbit, 
+            Err(e) => {
+                println!("{:>6} {e}", alpha); 
+                continue; 
+            }
+        }; 
+        let floquet = ctx.time("floquet exponents", || periodic::floquet(&f, &j, &orbit, opts.steps, opts.limits))?; 
+        (x0, period) = (orbit.x0, orbit.period); 
+
+        // trapezoid integral of the trace over the sampled cycle 
+        let (tc, yc) = &orbit.cycle; 
+        let trace: f64 = tc.windows(2).zip(yc.windows(2))
+            .map(|(ts, zs)| 0.5 * (ts[1] - ts[0]) * (jacobian(alpha, &zs[0])[1][1] + jacobian(alpha, &zs[1])[1][1]))
+            .sum(); 
+
+        // the largest exponent once the trivial 0 is dropped 
+        let exponent = floquet.nontrivial_exponents().first().copied().unwrap_or(f64::NAN);
+        let eig = orbit.nontrivial().first().map_or(f64::NAN, |m| m.0); 
+        let fd = periodic::drop_trivial(&orbit.fd_multipliers).first().map_or(f64::NAN, |m| m.0); 
+        println!("{:>6} {:>12.8} {:>14.6e} {:>14.6e} {:>14.6e} {:>14.6e} {:>12.6}", 
+            alpha, orbit.period, (exponent * orbit.period).exp(), trace.exp(), eig, fd, exponent); 
+        rows.push([alpha, exponent, trace / orbit.period, fd.abs().ln() / orbit.period]); 
+    }
+    if rows.is_empty() {
+        return Err("no limit cycle converged".into()); 
+    }
+
+    let lo = rows.iter().flat_map(|r| r[1..].iter()).filter(|x| x.is_finite()).fold(0.0_f64, |m, x| m.min(*x)); 
+    let (a0, a1) = (rows[0][0], rows[rows.len() - 1][0]); 
+
+    let path = ctx.path(name); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Floquet Exponent of the Limit Cycle", theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 55)
+        .set_label_area_size(LabelAreaPosition::Bottom, 50)
+        .build_cartesian_2d(a0..a1, (lo * 1.1)..0.0)?; 
+
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("alpha").y_desc("ln|mu| / T").draw()?; 
+
+    for (k, label) in ["variational", "liouville", "finite difference (diagnostic)"].into_iter().enumerate() {
+        let color = theme.color(k); 
+        chart.draw_series(LineSeries::new(rows.iter().map(|r| (r[0], r[k + 1])).filter(|p| p.1.is_finite()), &color))?
+            .label(label)
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+    }
+
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?; 
+
+    root.present()?; 
+    Ok(())
+}
+
+///
+/// Method of averaging against the numeric solution. With h = alpha V' -
+/// V'^3 the slow flow is r' = alpha r / 2 - 3 r^3 / 8, so the predicted
+/// cycle amplitude is sqrt(4 alpha / 3) at the unperturbed period 2 pi.
+/// The envelope error compares the averaged r(t) with the exact polar
+/// radius sqrt(V^2 + V'^2) of the rk4 solution through the transient;
+/// both errors shrink with alpha as the oscillator becomes weakly
+/// nonlinear 
+///
+fn averaging_study(ctx: &mut RunContext, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let alphas = [0.05, 0.1, 0.2, 0.5, 1.0, 2.0]; 
+    let ic = IC; 
+    let (dt, stride) = (1e-2, 10); 
+    ctx.solver("averaging::amplitude", "first order averaged slow flow, rk4"); 
+
+    println!("{:>6} {:>10} {:>10} {:>10} {:>10} {:>12} {:>10}", 
+        "alpha", "r exact", "r avg", "r rk4", "rel err", "envelope err", "period"); 
+    let mut shown: Option<(solvers::Solution<2>, solvers::Solution<2>)> = None; 
+    for alpha in alphas {
+        let h = |_x: f64, v: f64| alpha * v - v.powi(3); 
+        let f = |_t: f64, z: &[f64; 2], dz: &mut [f64; 2]| rate(alpha, z, dz); 
+        let tf = (20.0 / alpha).ceil().max(100.0); 
+
+        let ((t, y), _) = solvers::Method::Rk4.solve(&f, ic, [0.0, tf], dt)?; 
+        let (r0, phi0) = averaging::polar(ic[0], ic[1]); 
+        let (ts, slow) = ctx.time("averaged slow flow", || {
+            averaging::amplitude(&h, r0, phi0, [0.0, tf], dt * stride as f64)
+        })?; 
+
+        let envelope = t.iter().zip(&y).step_by(stride).zip(&slow)
+            .map(|((_, z), s)| (z[0].hypot(z[1]) - s[0]).abs())
+            .fold(0.0_f64, f64::max); 
+        let voltage: Vec<f64> = y.iter().map(|z| z[0]).collect(); 
+        let osc = peaks::oscillation(&t, &voltage, 4); 
+        let exact = (4.0 * alpha / 3.0).sqrt(); 
+        let avg = averaging::limit_amplitude(&h, 1e-3, 10.0).unwrap_or(f64::NAN); 
+        let numeric = osc.amplitude.unwrap_or(f64::NAN); 
+
+        println!("{:>6} {:>10.6} {:>10.6} {:>10.6} {:>10.2e} {:>12.2e} {:>10.6}", 
+            alpha, exact, avg, numeric, ((avg - numeric) / numeric).abs(), envelope, osc.period.unwrap_or(f64::NAN)); 
+
+        if alpha == 0.1 {
+            shown = Some(((t, y), (ts, slow))); 
+        }
+    }
+
+    let Some(((t, y), (ts, slow))) = shown else {
+        return Err("no run to plot".into()); 
+    }; 
+    let voltage: Vec<f64> = y.iter().map(|z| z[0]).collect(); 
+    let r: Vec<f64> = slow.iter().map(|s| s[0]).collect(); 
+    let top = r.iter().chain(voltage.iter()).fold(0.0_f64, |m, x| m.max(x.abs())) * 1.1; 
+
+    let path = ctx.path(name); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Averaged Envelope against RK4, alpha = 0.1", theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 55)
+        .set_label_area_size(LabelAreaPosition::Bottom, 50)
+        .build_cartesian_2d(t[0]..t[t.len() - 1], -top..top)?; 
+
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("t").y_desc(STATES[0].label()).draw()?; 
+
+    let color = theme.color(0); 
+    chart.draw_series(LineSeries::new(t.iter().copied().zip(voltage.iter().copied()), &color))?
+        .label("rk4")
+        .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+
+    let color = theme.color(1); 
+    for sign in [1.0, -1.0] {
+        let series = chart.draw_series(LineSeries::new(ts.iter().zip(&r).map(|(ti, ri)| (*ti, sign * ri)), color.stroke_width(theme.line)))?; 
+        if sign > 0.0 {
+            series.label("averaged envelope")
+                .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(theme.line)));
+        }
+    }
+
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?; 
+
+    root.present()?; 
+    Ok(())
+}
+
+///
+/// Harmonic balance continued in alpha from the averaged single harmonic
+/// at small alpha, overlaid with the settled amplitude and period of rk4
+/// runs. The tail column is the size of the last retained harmonic, which
+/// grows as the cycle sharpens toward a relaxation oscillation and bounds
+/// how far the truncated series can be trusted 
+///
+fn harmonic_balance_study(ctx: &mut RunContext, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let opts = harmonic::BalanceOptions::default(); 
+    let alphas: Vec<f64> = (1..=50).map(|k| 0.1 * k as f64).collect(); 
+    ctx.solver("harmonic::balance", "15 harmonic balance, damped newton, continuation in alpha"); 
+
+    let mut guess = harmonic::Harmonic::guess((4.0 * alphas[0] / 3.0).sqrt(), 1.0, opts.harmonics); 
+    let mut balanced: Vec<(f64, harmonic::Harmonic)> = Vec::new(); 
+    for &alpha in &alphas {
+        let g = |x: f64, v: f64| alpha * v - v.powi(3) - x; 
+        match ctx.time("harmonic balance", || harmonic::balance(&g, &guess, opts)) {
+            Ok(h) => {
+                guess = h.clone(); 
+                balanced.push((alpha, h)); 
+            }
+            Err(e) => println!("{:>6} {e}", alpha), 
+        }
+    }
+    if balanced.is_empty() {
+        return Err("harmonic balance failed at every alpha".into()); 
+    }
+
+    // time domain reference every fifth alpha 
+    println!("{:>6} {:>12} {:>12} {:>12} {:>12} {:>10} {:>10}", 
+        "alpha", "period hb", "period rk4", "amp hb", "amp rk4", "tail", "iters"); 
+    let mut reference: Vec<(f64, f64, f64)> = Vec::new(); 
+    for (alpha, h) in balanced.iter().skip(4).step_by(5) {
+        let f = |_t: f64, z: &[f64; 2], dz: &mut [f64; 2]| rate(*alpha, z, dz); 
+        let ((t, y), _) = solvers::Method::Rk4.solve(&f, IC, [0.0, 150.0], 1e-2)?; 
+        let voltage: Vec<f64> = y.iter().map(|z| z[0]).collect(); 
+        let osc = peaks::oscillation(&t, &voltage, 4); 
+        let (Some(period), Some(amplitude)) = (osc.period, osc.amplitude) else {
+            continue; 
+        }; 
+        println!("{:>6.1} {:>12.8} {:>12.8} {:>12.8} {:>12.8} {:>10.2e} {:>10}", 
+            alpha, h.period(), period, h.amplitude(), amplitude, h.tail(), h.iterations); 
+        reference.push((*alpha, period, amplitude)); 
+    }
+
+    let path = ctx.path(name); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let panels = root.split_evenly((1, 2)); 
+    let (a0, a1) = (balanced[0].0, balanced[balanced.len() - 1].0); 
+
+    // amplitude panel, then period panel, each with hb as a line and rk4 as points 
+    let amp_top = balanced.iter().map(|(_, h)| h.amplitude()).fold(0.0_f64, f64::max) * 1.1; 
+    let per_top = balanced.iter().map(|(_, h)| h.period()).fold(0.0_f64, f64::max) * 1.1; 
+    for (k, panel) in panels.iter().enumerate() {
+        let (title, desc, lo, hi) = if k == 0 {
+            ("Cycle Amplitude", "voltage amplitude", 0.0, amp_top)
+        } else {
+            ("Cycle Period", "period", 6.0, per_top)
+        }; 
+        let hb = |h: &harmonic::Harmonic| if k == 0 { h.amplitude() } else { h.period() }; 
+        let rk = |r: &(f64, f64, f64)| if k == 0 { r.2 } else { r.1 }; 
+
+        let mut chart = ChartBuilder::on(panel)
+            .caption(format!("{title} by Harmonic Balance"), theme.caption())
+            .margin(theme.margin)
+            .set_label_area_size(LabelAreaPosition::Left, 55)
+            .set_label_area_size(LabelAreaPosition::Bottom, 50)
+            .build_cartesian_2d(a0..a1, lo..hi)?; 
+
+        chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("alpha").y_desc(desc).draw()?; 
+
+        let color = theme.color(0); 
+        chart.draw_series(LineSeries::new(balanced.iter().map(|(a, h)| (*a, hb(h))), &color))?
+            .label("harmonic balance")
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+
+        let color = theme.color(1); 
+        chart.draw_series(reference.iter().map(|r| Circle::new((r.0, rk(r)), 4, color.filled())))?
+            .label("rk4 settled")
+            .legend(move |(x, y)| Circle::new((x + 10, y), 4, color.filled()));
+
+        chart.configure_series_labels()
+            .label_font(theme.label())
+            .position(SeriesLabelPosition::UpperLeft)
+            .border_style(BLACK)
+            .background_style(WHITE.mix(0.85))
+            .draw()?; 
+    }
+
+    root.present()?; 
+    Ok(())
+}
+
+///
+/// Arc length stepping against fixed dt rk4 at the same step count for a
+/// strongly nonlinear alpha, both measured at tf against a fine rk4
+/// reference. The plot marks the accepted points of the coarsest run on
+/// dV/dt, which crowd onto the fast fronts of each cycle 
+///
+fn arclength_study(ctx: &mut RunContext, alpha: f64, tf: f64, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let f = |_t: f64, z: &[f64; 2], dz: &mut [f64; 2]| rate(alpha, z, dz); 
+    let ic = IC; 
+    ctx.solver("arclength::solve", "rk4 in arc length of (t, V, dV/dt)"); 
+
+    let ((_, reference), _) = ctx.time("rk4 reference", || solvers::Method::Rk4.solve(&f, ic, [0.0, tf], 1e-5))?; 
+    let exact = reference[reference.len() - 1]; 
+    let error = |z: &[f64; 2]| (z[0] - exact[0]).abs().max((z[1] - exact[1]).abs()); 
+
+    println!("{:>8} {:>8} {:>12} {:>12} {:>10} {:>10}", "ds", "steps", "arc error", "fixed error", "min dt", "max dt"); 
+    let mut shown: Option<solvers::Solution<2>> = None; 
+    for ds in [1e-1, 5e-2, 2e-2, 1e-2] {
+        let (t, y) = ctx.time("arc length rk4", || arclength::solve(&f, ic, [0.0, tf], arclength::ArcOptions::new(ds)))?; 
+        let steps = t.len() - 1; 
+        let ((_, fixed), _) = solvers::Method::Rk4.solve(&f, ic, [0.0, tf], tf / steps as f64)?; 
+        let (lo, hi) = t.windows(2)
+            .map(|w| w[1] - w[0])
+            .fold((f64::INFINITY, 0.0_f64), |(lo, hi), h| (lo.min(h), hi.max(h))); 
+        println!("{:>8.0e} {:>8} {:>12.2e} {:>12.2e} {:>10.1e} {:>10.1e}", 
+            ds, steps, error(&y[steps]), error(&fixed[fixed.len() - 1]), lo, hi); 
+        if shown.is_none() {
+            shown = Some((t, y)); 
+        }
+    }
+
+    let Some((t, y)) = shown else {
+        return Err("no arc length run to plot".into()); 
+    }; 
+    // the last few cycles, settled onto the limit cycle 
+    let window = (tf - 25.0).max(0.0); 
+    let points: Vec<(f64, f64)> = t.iter().zip(&y).filter(|(ti, _)| **ti >= window).map(|(ti, z)| (*ti, z[1])).collect(); 
+    let top = points.iter().fold(0.0_f64, |m, p| m.max(p.1.abs())) * 1.1; 
+
+    let path = ctx.path(name); 
+    let theme = ctx.style(); 
+    let root = BitMapBackend::new(&path, theme.size).into_drawing_area();
+    root.fill(&WHITE)?; 
+    let root = theme.stamp(&root)?; 
+    let mut chart = ChartBuilder::on(&root)
+        .caption(format!("Arc Length Steps on the Limit Cycle, alpha = {alpha}"), theme.caption())
+        .margin(theme.margin)
+        .set_label_area_size(LabelAreaPosition::Left, 55)
+        .set_label_area_size(LabelAreaPosition::Bottom, 50)
+        .build_cartesian_2d(window..tf, -top..top)?; 
+
+    chart.configure_mesh().label_style(theme.label()).axis_desc_style(theme.label()).x_desc("t").y_desc(STATES[1].label()).draw()?; 
+
+    let color = theme.color(0); 
+    chart.draw_series(LineSeries::new(points.iter().copied(), &color))?
+        .label("arc length rk4")
+        .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+
+    let color = theme.color(1); 
+    chart.draw_series(points.iter().map(|p| Circle::new(*p, 2, color.filled())))?
+        .label("accepted points")
+        .legend(move |(x, y)| Circle::new((x + 10, y), 3, color.filled()));
+
+    chart.configure_series_labels()
+        .label_font(theme.label())
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.85))
+        .draw()?; 
+
+    root.present()?; 
+    Ok(())
+}
+
+///
+/// Multirate rk4 with dV/dt as the fast component against single rate rk4 
+/// at the micro step, over a sweep of both the macro step H and the micro 
+/// steps m per macro step. Work is counted in component evaluations, what 
+/// a rate split by component would pay. Sub-stepping the fast derivative 
+/// is what keeps the macro step stable at large alpha; past that the error 
+/// levels off at the macro step's error in V, a floor that only a smaller 
+/// H lowers 
+///
+fn multirate_study(ctx: &mut RunContext, alpha: f64, tf: f64, hs: &[f64]) -> Result<(), Box<dyn std::error::Error>> {
+    let f = |_t: f64, z: &[f64; 2], dz: &mut [f64; 2]| rate(alpha, z, dz); 
+    let ic = IC; 
+    let part: multirate::Partition<2> = multirate::Partition::new(&[1])?; 
+    ctx.solver("multirate::solve", "slowest-first multirate rk4, hermite slow interpolation"); 
+    ctx.config("multirate", format!("alpha = {alpha}, H in {hs:?}, fast = {}", STATES[1].name)); 
+
+    let ((_, reference), _) = ctx.time("rk4 reference", || solvers::Method::Rk4.solve(&f, ic, [0.0, tf], 1e-5))?; 
+    let exact = reference[reference.len() - 1]; 
+    let error = |y: &[[f64; 2]]| {
+        let z = y[y.len() - 1]; 
+        (z[0] - exact[0]).abs().max((z[1] - exact[1]).abs()) 
+    }; 
+
+    println!("{:>6} {:>4} {:>14} {:>12} {:>14} {:>12}", "H", "m", "multirate err", "evals", "rk4 H/m err", "evals"); 
+    // error at the largest m for each H, the floor the macro step sets 
+    let mut floors = Vec::with_capacity(hs.len()); 
+    for &big_h in hs {
+        let mut floor = f64::NAN; 
+        for m in [1, 2, 4, 8, 16] {
+            let ((_, y), stats) = ctx.time("multirate rk4", || multirate::solve(&f, &part, ic, [0.0, tf], big_h, m, Limits::default()))?; 
+            let ((_, single), _) = solvers::Method::Rk4.solve(&f, ic, [0.0, tf], big_h / m as f64)?; 
+            let single_evals = 4 * STATES.len() * (single.len() - 1); 
+            floor = error(&y); 
+            println!("{:>6} {:>4} {:>14.4e} {:>12} {:>14.4e} {:>12}", 
+                big_h, m, floor, stats.component_evals(), error(&single), single_evals); 
+        }
+        floors.push((big_h, floor)); 
+    }
+    for w in floors.windows(2) {
+        let order = (w[0].1 / w[1].1).ln() / (w[0].0 / w[1].0).ln(); 
+        println!("floor H = {} -> {}: {:.3e} -> {:.3e}, observed order {order:.2}", w[0].0, w[1].0, w[0].1, w[1].1); 
+    }
+    Ok(())
+}
+
+///
+/// Energy balance as an invariant. With W the work done by the nonlinear 
+/// resistance, W' = V'(a V' - V'^3), the quantity (V^2 + V'^2) / 2 - W is 
+/// constant along exact solutions but drifts under every explicit method. 
+/// Each method runs plain and with a projection onto the invariant surface 
+///
+fn projection_study(ctx: &mut RunContext, alpha: f64, tf: f64, dt: f64) -> Result<(), Box<dyn std::error::Error>> {
+    let f = |_t: f64, z: &[f64; 3], dz: &mut [f64; 3]| {
+        let mut d = [0.0; 2]; 
+        rate(alpha, &[z[0], z[1]], &mut d); 
+        dz[0] = d[0]; 
+        dz[1] = d[1]; 
+        dz[2] = z[1] * (alpha * z[1] - z[1].powi(3)); 
+    }; 
+    let ic = [IC[0], IC[1], 0.0]; 
+    let energy = |z: &[f64; 3]| 0.5 * (z[0] * z[0] + z[1] * z[1]) - z[2]; 
+    let e0 = energy(&ic); 
+    let g = |z: &[f64; 3]| vec![energy(z) - e0]; 
+    ctx.solver("projection::integrate_projected", "post-step projection onto g(y) = 0"); 
+    ctx.config("projection", format!("alpha = {alpha}, dt = {dt}, invariant = energy balance")); 
+
+    let ((_, reference), _) = ctx.time("rk4 reference", || solvers::Method::Rk4.solve(&f, ic, [0.0, tf], 1e-4))?; 
+    let exact = reference[reference.len() - 1]; 
+    let error = |z: &[f64; 3]| (z[0] - exact[0]).abs().max((z[1] - exact[1]).abs()); 
+    let drift = |y: &[[f64; 3]]| y.iter().fold(0.0_f64, |m, z| m.max((energy(z) - e0).abs())); 
+
+    println!("{:>10} {:>12} {:>12} {:>12} {:>12} {:>12}", 
+        "method", "drift", "error", "proj drift", "proj error", "max fix"); 
+    for method in solvers::Method::ALL {
+        let step = |r: &_, t: f64, y: &[f64; 3], h: f64| method.step(r, t, y, h); 
+        let ((_, plain), _) = method.solve(&f, ic, [0.0, tf], dt)?; 
+        let ((_, fixed), stats) = ctx.time("projected", || {
+            projection::integrate_projected(&f, step, &g, ic, [0.0, tf], dt, projection::ProjectionOptions::default(), Limits::default()) 
+        })?; 
+        let last = |y: &[[f64; 3]]| y[y.len() - 1]; 
+        let finite = plain.iter().all(|z| z.iter().all(|x| x.is_finite())); 
+        if finite {
+            println!("{:>10} {:>12.4e} {:>12.4e} {:>12.4e} {:>12.4e} {:>12.4e}", 
+                method.name(), drift(&plain), error(&last(&plain)), drift(&fixed), error(&last(&fixed)), stats.max_correction); 
+        } else {
+            println!("{:>10} {:>12} {:>12} {:>12.4e} {:>12.4e} {:>12.4e}", 
+                method.name(), "unstable", "-", drift(&fixed), error(&last(&fixed)), stats.max_correction); 
+        }
+    }
+    Ok(())
+}
+
+///
+/// Parallel LC tank across a tunnel diode, the circuit behind the model. 
+/// The diode conducts i = -g1 v + g3 v^3, negative resistance near zero 
+/// bias, and R is the tank's loss. In the inductor current, with time in 
+/// units of sqrt(LC), 
+///
+///   i'' = alpha i' - beta i'^3 - i,  alpha = (g1 - 1/R) sqrt(L/C), 
+///                                    beta = g3 (L/C)^(3/2) 
+///
+/// so y = sqrt(beta) i obeys the lab ODE with that alpha 
+///
+#[derive(Clone, Copy, Debug)]
+struct Tank {
+    r:  f64, 
+    l:  f64, 
+    c:  f64, 
+    g1: f64, 
+    g3: f64, 
+}
+
+impl Tank {
+    fn alpha(&self) -> f64 {
+        (self.g1 - 1.0 / self.r) * (self.l / self.c).sqrt()
+    }
+
+    fn beta(&self) -> f64 {
+        self.g3 * (self.l / self.c).powf(1.5)
+    }
+
+    fn time_scale(&self) -> f64 {
+        (self.l * self.c).sqrt()
+    }
+
+    ///
+    /// Node 1 holds R, L, C and the diode to ground; state [v, i_L] 
+    ///
+    fn circuit(&self) -> Result<Circuit, Box<dyn std::error::Error>> {
+        let (g1, g3) = (self.g1, self.g3); 
+        Circuit::builder(1)
+            .capacitor(1, 0, self.c)
+            .inductor(1, 0, self.l)
+            .resistor(1, 0, self.r)
+            .conductance(1, 0, move |v| -g1 * v + g3 * v.powi(3))
+            .build()
+    }
+
+    ///
+    /// Circuit state [v, i_L] to the lab state [y, y'] 
+    ///
+    fn lab_state(&self, x: &[f64; 2]) -> [f64; 2] {
+        let s = self.beta().sqrt(); 
+        [s * x[1], s * (self.c / self.l).sqrt() * x[0]]
+    }
+
+    fn circuit_state(&self, z: &[f64; 2]) -> [f64; 2] {
+        let s = self.beta().sqrt(); 
+        [z[1] / (s * (self.c / self.l).sqrt()), z[0] / s]
+    }
+}
+
+///
+/// alpha derived from circuit parameters instead of set by hand. Each tank 
+/// is simulated in SI units through the circuit builder and compared, after 
+/// scaling, with the lab rate at the derived alpha on the same grid 
+///
+fn circuit_study(ctx: &mut RunContext, tf: f64, dt: f64) -> Result<(), Box<dyn std::error::Error>> {
+    let (l, c, r, g3): (f64, f64, f64, f64) = (1e-6, 1e-9, 1e3, 1e-3); 
+    ctx.solver("circuit::Circuit", "nodal analysis, rk4"); 
+    ctx.config("tank", format!("L = {l} H, C = {c} F, R = {r} ohm, g3 = {g3} S/V^2")); 
+
+    println!("{:>12} {:>8} {:>12} {:>14} {:>12}", "g1 (S)", "alpha", "beta", "sqrt(LC) (s)", "max diff"); 
+    for target in [0.5, 2.5, 4.5] {
+        // the g1 that gives the target alpha 
+        let g1 = 1.0 / r + target * (c / l).sqrt(); 
+        let tank = Tank { r, l, c, g1, g3 }; 
+        let circuit = tank.circuit()?; 
+        let scale = tank.time_scale(); 
+
+        let ic = IC; 
+        let ((_, lab), _) = system::solve(&Oscillator { alpha: tank.alpha() }, solvers::Method::Rk4, ic, [0.0, tf], dt)?; 
+        let ((_, si), _) = ctx.time("circuit rk4", || system::solve(&circuit, solvers::Method::Rk4, tank.circuit_state(&ic), [0.0, tf * scale], dt * scale))?; 
+        let diff = lab.iter().zip(&si).fold(0.0_f64, |m, (z, x)| {
+            let w = tank.lab_state(x); 
+            m.max((z[0] - w[0]).abs()).max((z[1] - w[1]).abs())
+        }); 
+        println!("{:>12.6e} {:>8.3} {:>12.4e} {:>14.4e} {:>12.4e}", g1, tank.alpha(), tank.beta(), scale, diff); 
+    }
+    Ok(())
+}
+
+///
+/// Runs both methods over every alpha for a range of step sizes and stores 
+/// the whole experiment matrix in a single parquet file 
+///
+#[cfg(feature = "parquet")]
+fn sweep(path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    type Method = fn(f64, [f64; 2], f64, f64, f64) -> Result<solvers::Solution<2>, Box<dyn std::error::Error>>;
+    let methods: [(&str, Method); 2] = [("rk4", rk4), ("abam4", abam4_pred_corr)];
+    let alphas = [0.5, 1.5, 2.5, 3.5, 4.5];
+    let (t0, tf) = (0.0, 100.0);
+
+    let mut table: sweep_store::SweepTable<2> = sweep_store::SweepTable::labeled(&STATES); 
+    for (name, method) in methods {
+        for dt in [1e-1, 1e-2, 1e-3] {
+            for a in alphas {
+                let (t, y) = method(a, IC, dt, t0, tf)?; 
+                table.push(name, a, dt, &t, &y); 
+            }
+        }
+    }
+    table.write_parquet(path, 1 << 20)
+}
+
+///
+/// Every semiconductor experiment, in the order a bare run goes through them 
+///
+const EXPERIMENTS: &[Experiment] = &[
+    Experiment {
+        name: "abam4", system: "semiconductor, 5 alphas", solver: "ab/am4 pred-corr, dt = 1e-3", 
+        outputs: &["abam4_semiconductor.png", "abam4_semiconductor.npz", "abam4_semiconductor.mat"], 
+        run: |ctx| {
+            solve(ctx, &abam4_pred_corr, 1e-3, "abam4_semiconductor.png", 
+                "AB/AM 4th Order Semiconductor System for Range of (a), dt = 1e-3")
+        }, 
+    }, 
+    Experiment {
+        name: "rk4", system: "semiconductor, 5 alphas", solver: "rk4, dt = 1e-3", 
+        outputs: &["rk4_semiconductor.png", "rk4_semiconductor.npz", "rk4_semiconductor.mat"], 
+        run: |ctx| {
+            solve(ctx, &rk4, 1e-3, "rk4_semiconductor.png", 
+                "Runge-Kutta 4th Order, Semiconductor System for Range of (a), dt = 1e-3")
+        }, 
+    }, 
+    Experiment {
+        name: "bad_timestep", system: "semiconductor, 5 alphas", solver: "ab/am4 pred-corr, dt = 1e-1", 
+        outputs: &["bad_timestep.png", "bad_timestep.npz", "bad_timestep.mat"], 
+        run: |ctx| {
+            solve(ctx, &abam4_pred_corr, 1e-1, "bad_timestep.png", 
+                "AB/AM 4th Order, Semiconductor System for Range of (a), dt = 1e-1")
+        }, 
+    }, 
+    Experiment {
+        name: "validated", system: "semiconductor, alpha 0.5 and 2.5", solver: "interval taylor enclosure", 
+        outputs: &["validated_0.5.png", "validated_2.5.png"], 
+        run: |ctx| {
+            for alpha in [0.5, 2.5] {
+                if let Err(e) = validated(ctx, alpha, 2.0, 1e-3) {
+                    eprintln!("validated integration failed for alpha = {alpha}: {e}"); 
+                }
+            }
+            Ok(())
+        }, 
+    }, 
+    Experiment {
+        name: "taylor", system: "semiconductor", solver: "taylor order 20 reference", outputs: &[], 
+        run: |ctx| taylor_reference(ctx, 10.0), 
+    }, 
+    Experiment {
+        name: "spectral", system: "semiconductor, alpha 2.5", solver: "chebyshev picard against rk4", outputs: &[], 
+        run: |ctx| spectral_comparison(ctx, 2.5, 10.0), 
+    }, 
+    Experiment {
+        name: "startup", system: "semiconductor, alpha 2.5", solver: "ab/am4 startup strategies", outputs: &["abam4_startup.png"], 
+        run: |ctx| startup_study(ctx, 2.5, 1e-2, 2.0, "abam4_startup.png"), 
+    }, 
+    Experiment {
+        name: "vsvo", system: "semiconductor, alpha 4.5", solver: "vsvo adams, orders 1-12 and 1-4", outputs: &[], 
+        run: |ctx| vsvo_study(ctx, 4.5, 10.0), 
+    }, 
+    Experiment {
+        name: "modes", system: "semiconductor, alpha 4.5", solver: "ab/am4 PEC, PECE, P(EC)^m", outputs: &[], 
+        run: |ctx| mode_study(ctx, 4.5, 10.0), 
+    }, 
+    Experiment {
+        name: "overlay", system: "semiconductor, alpha 4.5", solver: "rk4, ab/am4, dt = 1e-2; vsvo adams, tol = 1e-6", 
+        outputs: &["method_overlay.png"], 
+        run: |ctx| method_overlay(ctx, 4.5, 20.0, 1e-2, 1e-6, "method_overlay.png"), 
+    }, 
+    Experiment {
+        name: "steps", system: "semiconductor, alpha 10", solver: "vsvo adams, tol = 1e-6", outputs: &["step_history.png"], 
+        run: |ctx| step_history(ctx, 10.0, 30.0, 1e-6, "step_history.png"), 
+    }, 
+    Experiment {
+        name: "settled", system: "semiconductor, 5 alphas", solver: "batched rk4, running statistics", outputs: &[], 
+        run: |ctx| settled_summary(ctx, 100.0, 50.0), 
+    }, 
+    Experiment {
+        name: "alpha_sweep", system: "semiconductor, 100 alphas in [0, 5]", solver: "batched rk4, settled extrema", 
+        outputs: &["alpha_sweep.png"], 
+        run: |ctx| alpha_sweep(ctx, [0.0, 5.0], 100, 200.0, 100.0, "alpha_sweep.png"), 
+    }, 
+    Experiment {
+        name: "batch", system: "semiconductor, alpha sweeps", solver: "rk4 per alpha against batched rk4", outputs: &[], 
+        run: |ctx| batch_study(ctx, 20.0, &[5, 64, 1024]), 
+    }, 
+    Experiment {
+        name: "oscillation", system: "semiconductor, 5 alphas", solver: "ab/am4, refined extrema", outputs: &[], 
+        run: |ctx| oscillation_study(ctx, 1e-3, 100.0), 
+    }, 
+    Experiment {
+        name: "autocorrelation", system: "semiconductor, 5 alphas", solver: "rk4, fft autocorrelation", 
+        outputs: &["autocorrelation.png"], run: |ctx| autocorrelation_study(ctx, 1e-2, 400.0, 100.0, "autocorrelation.png"), 
+    }, 
+    Experiment {
+        name: "limit_cycles", system: "semiconductor, 5 alphas", solver: "newton shooting", outputs: &["limit_cycles.png"], 
+        run: |ctx| limit_cycles(ctx, "limit_cycles.png"), 
+    }, 
+    Experiment {
+        name: "synchronization", system: "two coupled semiconductors, alpha 2.5 and 3.5", solver: "rk4, hilbert phase", 
+        outputs: &["synchronization.png"], 
+        run: |ctx| synchronization_study(ctx, [2.5, 3.5], &[0.0, 0.1, 0.2, 0.4, 0.6, 0.8, 1.0], 600.0, 100.0, "synchronization.png"), 
+    }, 
+    Experiment {
+        name: "network", system: "40 coupled semiconductors, alpha in [2, 3]", solver: "rk4, hilbert phases", 
+        outputs: &["network.png"], 
+        run: |ctx| network_study(ctx, [2.0, 3.0], &[0.0, 0.05, 0.1, 0.2, 0.4, 0.8, 1.6], 400.0, 100.0, "network.png"), 
+    }, 
+    Experiment {
+        name: "hopf", system: "semiconductor, alpha in [-1, 5]", solver: "bisection, eigenvalues and simulated growth", outputs: &[], 
+        run: |ctx| hopf_study(ctx, [-1.0, 5.0], 1e-4), 
+    }, 
+    Experiment {
+        name: "ic_overlay", system: "semiconductor, alpha 2.5, 6 initial conditions", solver: "rk4, dt = 1e-2", 
+        outputs: &["ic_overlay.png"], 
+        run: |ctx| ic_overlay(ctx, 2.5, &[IC, [0.0, 4.0], [3.0, 0.0], [-2.0, -2.0], [0.5, -0.5], [-4.0, 1.0]], 60.0, 1e-2, "ic_overlay.png"), 
+    }, 
+    Experiment {
+        name: "modified", system: "semiconductor, alpha 0.5", solver: "euler, heun at h = 0.1 and their modified equations", 
+        outputs: &["modified_equation.png"], 
+        run: |ctx| modified_equation_study(ctx, 0.5, 40.0, 0.1, "modified_equation.png"), 
+    }, 
+    Experiment {
+        name: "energy", system: "semiconductor, alpha 2.5", solver: "rk4, dt = 1e-2", outputs: &["energy.png"], 
+        run: |ctx| energy_study(ctx, 2.5, 40.0, 1e-2, "energy.png"), 
+    }, 
+    Experiment {
+        name: "van_der_pol", system: "semiconductor as van der pol, mu = 1, 2, 5", solver: "rk4 in both forms", outputs: &[], 
+        run: |ctx| van_der_pol_study(ctx, 100.0, 1e-3), 
+    }, 
+    Experiment {
+        name: "floquet", system: "semiconductor", solver: "variational equations along the cycle", outputs: &["floquet.png"], 
+        run: |ctx| floquet_study(ctx, "floquet.png"), 
+    }, 
+    Experiment {
+        name: "averaging", system: "semiconductor, range of alpha", solver: "method of averaging against rk4", outputs: &["averaging.png"], 
+        run: |ctx| averaging_study(ctx, "averaging.png"), 
+    }, 
+    Experiment {
+        name: "harmonic_balance", system: "semiconductor", solver: "harmonic balance, continuation", outputs: &["harmonic_balance.png"], 
+        run: |ctx| harmonic_balance_study(ctx, "harmonic_balance.png"), 
+    }, 
+    Experiment {
+        name: "arclength", system: "semiconductor, alpha 10", solver: "rk4 in arc length", outputs: &["arclength.png"], 
+        run: |ctx| arclength_study(ctx, 10.0, 50.0, "arclength.png"), 
+    }, 
+    Experiment {
+        name: "multirate", system: "semiconductor, alpha 10", solver: "multirate rk4", outputs: &[], 
+        run: |ctx| multirate_study(ctx, 10.0, 50.0, &[0.1, 0.05, 0.025]), 
+    }, 
+    Experiment {
+        name: "projection", system: "semiconductor, alpha 1", solver: "post-step projection", outputs: &[], 
+        run: |ctx| projection_study(ctx, 1.0, 100.0, 0.05), 
+    }, 
+    Experiment {
+        name: "circuit", system: "semiconductor from circuit parameters", solver: "nodal analysis, rk4", outputs: &[], 
+        run: |ctx| circuit_study(ctx, 50.0, 1e-3), 
+    }, 
+    #[cfg(feature = "parquet")]
+    Experiment {
+        name: "parquet_sweep", system: "semiconductor, methods x dt x alpha", solver: "rk4 and ab/am4", 
+        outputs: &["semiconductor_sweep.parquet"], 
+        run: |ctx| {
+            let path = ctx.path("semiconductor_sweep.parquet"); 
+            ctx.time("parquet sweep", || sweep(&path))
+        }, 
+    }, 
+]; 
+
+///
+/// Configuration shared by the alpha sweeps 
+///
+fn setup(ctx: &mut RunContext) {
+    ctx.config("t_span", "[0, 100]");
+    ctx.config("ic", format!("{IC:?}"));
+    ctx.config("alphas", "[0.5, 1.5, 2.5, 3.5, 4.5]");
+    ctx.solver("rk4", "fixed-step");
+    ctx.solver("abam4_pred_corr", "fixed-step, rk4 startup");
+}
+
+fn main() {
+    experiment::main("semiconductor", EXPERIMENTS, setup); 
 }
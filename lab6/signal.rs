@@ -0,0 +1,97 @@
+//!
+//! signal.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Smoothing of uniformly sampled noisy data. The moving average replaces
+//! each sample by the mean of a centered window; Savitzky-Golay fits a
+//! polynomial of low degree to the window by least squares instead, so
+//! peaks keep their height, and differentiating the fit gives derivative
+//! estimates far less noisy than finite differences of the raw data. Near
+//! the ends the windows are shifted inward rather than shrunk, the fit then
+//! evaluated off center
+//!
+
+#![allow(dead_code)]
+
+use crate::linalg::Matrix;
+
+fn check_window(len: usize, window: usize) -> Result<(), Box<dyn std::error::Error>> {
+    if window.is_multiple_of(2) || window < 3 {
+        return Err(format!("signal: window {window} must be odd and at least 3").into());
+    }
+    if window > len {
+        return Err(format!("signal: window {window} is longer than the data ({len} samples)").into());
+    }
+    Ok(())
+}
+
+///
+/// Centered mean over window samples, the window shrinking symmetrically
+/// near the ends
+///
+pub fn moving_average(y: &[f64], window: usize) -> Result<Vec<f64>, Box<dyn std::error::Error>> {
+    check_window(y.len(), window)?;
+    let n = y.len();
+    let mut prefix = vec![0.0; n + 1];
+    for i in 0..n {
+        prefix[i + 1] = prefix[i] + y[i];
+    }
+    Ok((0..n).map(|i| {
+        let half = (window / 2).min(i).min(n - 1 - i);
+        (prefix[i + half + 1] - prefix[i - half]) / (2 * half + 1) as f64
+    }).collect())
+}
+
+///
+/// Weights w_k such that sum_k w_k y_k is the deriv-th derivative at
+/// offset z (in samples, from the window's first point) of the degree
+/// order least squares polynomial through the window
+///
+fn weights(window: usize, order: usize, deriv: usize, z: f64) -> Result<Vec<f64>, Box<dyn std::error::Error>> {
+    let cols = order + 1;
+    let mut a = Matrix::zeros(window, cols);
+    for k in 0..window {
+        for p in 0..cols {
+            a[(k, p)] = (k as f64 - z).powi(p as i32);
+        }
+    }
+    let mut normal = Matrix::zeros(cols, cols);
+    for i in 0..cols {
+        for j in 0..cols {
+            normal[(i, j)] = (0..window).map(|k| a[(k, i)] * a[(k, j)]).sum();
+        }
+    }
+    let lu = normal.lu()?;
+    // row deriv of (A^T A)^-1 A^T, by solving against each column of A^T
+    let factorial: f64 = (1..=deriv).map(|v| v as f64).product();
+    Ok((0..window).map(|k| {
+        let column: Vec<f64> = (0..cols).map(|p| a[(k, p)]).collect();
+        factorial * lu.solve(&column)[deriv]
+    }).collect())
+}
+
+///
+/// Savitzky-Golay filter: the deriv-th derivative (0 to smooth) of a degree
+/// order polynomial fit over an odd window, for samples dx apart
+///
+pub fn savitzky_golay(y: &[f64], window: usize, order: usize, deriv: usize, dx: f64)
+    -> Result<Vec<f64>, Box<dyn std::error::Error>> {
+    check_window(y.len(), window)?;
+    if order >= window || deriv > order {
+        return Err(format!("signal: need deriv <= order < window, got deriv {deriv}, order {order}, window {window}").into());
+    }
+    if !(dx > 0.0 && dx.is_finite()) {
+        return Err(format!("signal: sample spacing {dx} must be positive").into());
+    }
+    let n = y.len();
+    let half = window / 2;
+    let scale = dx.powi(deriv as i32);
+    let center = weights(window, order, deriv, half as f64)?;
+    let mut out = vec![0.0; n];
+    for (i, o) in out.iter_mut().enumerate() {
+        // first sample of the window, shifted inward at the ends
+        let start = i.saturating_sub(half).min(n - window);
+        let w = if start + half == i { center.clone() } else { weights(window, order, deriv, (i - start) as f64)? };
+        *o = w.iter().zip(&y[start..start + window]).map(|(wk, yk)| wk * yk).sum::<f64>() / scale;
+    }
+    Ok(out)
+}
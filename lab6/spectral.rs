@@ -0,0 +1,221 @@
+//!
+//! spectral.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Global pseudospectral IVP solver. The solution on each time segment is
+//! represented by its values at Chebyshev-Gauss-Lobatto nodes and found by
+//! Picard iteration, y <- y0 + int f(y), with the integral taken exactly on
+//! the Chebyshev interpolant. Converges spectrally in the node count where
+//! local steppers converge algebraically in dt
+//!
+
+#![allow(dead_code)]
+
+use std::f64::consts::PI;
+use solvers::{Limits, Solution, SolveStats};
+
+use crate::system::OdeSystem;
+
+///
+/// Cumulative integral from the left end of the segment of the Chebyshev
+/// interpolant through g, sampled at the Lobatto nodes x_j = cos(pi j / n).
+/// `half` is half the segment length
+///
+fn cumulative_integral(g: &[f64], half: f64) -> Vec<f64> {
+    let n = g.len() - 1;
+    let nf = n as f64;
+
+    // discrete cosine transform to coefficients a_k of sum a_k T_k
+    let mut a = vec![0.0; n + 1];
+    for (k, ak) in a.iter_mut().enumerate() {
+        let mut sum = 0.0;
+        for (j, gj) in g.iter().enumerate() {
+            let w = if j == 0 || j == n { 0.5 } else { 1.0 };
+            sum += w * gj * (PI * (j * k) as f64 / nf).cos();
+        }
+        *ak = 2.0 * sum / nf;
+    }
+    a[0] *= 0.5;
+    a[n] *= 0.5;
+
+    // antiderivative coefficients, int T_k = T_{k+1}/2(k+1) - T_{k-1}/2(k-1)
+    let mut b = vec![0.0; n + 2];
+    b[1] += a[0];
+    if n >= 1 {
+        b[2] += 0.25 * a[1];
+    }
+    for k in 2..=n {
+        b[k + 1] += a[k] / (2.0 * (k + 1) as f64);
+        b[k - 1] -= a[k] / (2.0 * (k - 1) as f64);
+    }
+
+    // fix the constant so the integral vanishes at x = -1
+    let left: f64 = b.iter().enumerate().map(|(k, bk)| if k % 2 == 0 { *bk } else { -bk }).sum();
+    (0..=n)
+        .map(|j| {
+            let theta = PI * j as f64 / nf;
+            let val: f64 = b.iter().enumerate().map(|(k, bk)| bk * (k as f64 * theta).cos()).sum();
+            half * (val - left)
+        })
+        .collect()
+}
+
+///
+/// Pseudospectral Picard solver over `segments` equal segments of ts with n + 1
+/// Lobatto nodes each. Picard only contracts when segment length times the
+/// Lipschitz constant is small, so stiff or strongly nonlinear problems need
+/// more segments. Iterates until the nodal update falls below tol. The
+/// stored nodes are checked against limits up front, each sweep counts as
+/// a step of the guard. Returns the solution at the nodes of every segment
+/// in ascending time; a sweep costs n + 1 rate evaluations and each
+/// segment counts as one accepted step. n and segments must be positive
+///
+#[allow(clippy::too_many_arguments)]
+pub fn chebyshev_picard<F, const N: usize>(
+    rate: &F,
+    ic: [f64; N],
+    ts: [f64; 2],
+    segments: usize,
+    n: usize,
+    tol: f64,
+    max_iter: usize,
+    limits: Limits) -> Result<(Solution<N>, SolveStats), Box<dyn std::error::Error>>
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    let [t0, tf] = ts;
+    if n == 0 || segments == 0 {
+        return Err(format!("chebyshev picard needs a node past the first and a segment, got n = {n} and {segments} segments").into());
+    }
+    if !(tf - t0).is_finite() || tf <= t0 {
+        return Err(format!("chebyshev picard over [{t0}, {tf}]").into());
+    }
+    limits.check::<N>("chebyshev picard", segments.saturating_mul(n))?;
+    let mut guard = limits.start::<0>();
+    let len = (tf - t0) / segments as f64;
+    let half = 0.5 * len;
+
+    let mut t: Vec<f64> = Vec::with_capacity(segments * n + 1);
+    let mut y: Vec<[f64; N]> = Vec::with_capacity(segments * n + 1);
+    let mut sweeps = 0;
+    let mut y0 = ic;
+
+    for s in 0..segments {
+        let (a, b) = (t0 + s as f64 * len, t0 + (s + 1) as f64 * len);
+        let mid = 0.5 * (a + b);
+
+        // node j = 0 is the right end of the segment
+        let nodes: Vec<f64> = (0..=n).map(|j| mid + half * (PI * j as f64 / n as f64).cos()).collect();
+        let mut ys: Vec<[f64; N]> = vec![y0; n + 1];
+        let mut fs: Vec<[f64; N]> = vec![[0.0; N]; n + 1];
+
+        let mut converged = false;
+        for _ in 0..max_iter {
+            guard.step("chebyshev picard", a)?;
+            sweeps += 1;
+            for j in 0..=n {
+                rate(nodes[j], &ys[j], &mut fs[j]);
+            }
+
+            let mut change: f64 = 0.0;
+            for i in 0..N {
+                let g: Vec<f64> = fs.iter().map(|f| f[i]).collect();
+                let integral = cumulative_integral(&g, half);
+                for j in 0..=n {
+                    let next = y0[i] + integral[j];
+                    change = change.max((next - ys[j][i]).abs());
+                    ys[j][i] = next;
+                }
+            }
+
+            if !change.is_finite() {
+                break;
+            }
+            if change < tol {
+                converged = true;
+                break;
+            }
+        }
+
+        if !converged {
+            return Err(format!("picard sweep did not converge on segment [{a}, {b}]").into());
+        }
+
+        // ascending order, skipping the shared left node after the first segment
+        let skip = usize::from(s > 0);
+        for j in (0..=(n - skip)).rev() {
+            t.push(nodes[j]);
+            y.push(ys[j]);
+        }
+        y0 = ys[0];
+    }
+
+    let stats = SolveStats { accepted: segments, rate_evals: sweeps * (n + 1), ..SolveStats::default() };
+    Ok(((t, y), stats))
+}
+
+///
+/// chebyshev_picard on a system, its size checked against the state
+///
+#[allow(clippy::too_many_arguments)]
+pub fn chebyshev_picard_system<const N: usize>(
+    system: &impl OdeSystem,
+    ic: [f64; N],
+    ts: [f64; 2],
+    segments: usize,
+    n: usize,
+    tol: f64,
+    max_iter: usize,
+    limits: Limits) -> Result<(Solution<N>, SolveStats), Box<dyn std::error::Error>> {
+    chebyshev_picard(&system.rhs::<N>()?, ic, ts, segments, n, tol, max_iter, limits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn growth(_t: f64, y: &[f64; 1], dy: &mut [f64; 1]) {
+        dy[0] = y[0];
+    }
+
+    fn error_at_one(segments: usize, n: usize) -> f64 {
+        let ((t, y), _) = chebyshev_picard(&growth, [1.0], [0.0, 1.0], segments, n, 1e-14, 200, Limits::default()).unwrap();
+        assert!((t[t.len() - 1] - 1.0).abs() < 1e-15);
+        (y[y.len() - 1][0] - 1.0_f64.exp()).abs()
+    }
+
+    #[test]
+    fn integral_of_a_polynomial_is_exact() {
+        // g = 3 x^2 on [-1, 1] integrates to x^3 + 1
+        let n = 6;
+        let x: Vec<f64> = (0..=n).map(|j| (PI * j as f64 / n as f64).cos()).collect();
+        let g: Vec<f64> = x.iter().map(|v| 3.0 * v * v).collect();
+        for (xi, got) in x.iter().zip(cumulative_integral(&g, 1.0)) {
+            assert!((got - (xi * xi * xi + 1.0)).abs() < 1e-13, "at {xi}: {got}");
+        }
+    }
+
+    #[test]
+    fn picard_converges_spectrally_on_growth() {
+        // each pair of extra nodes gains more than a factor of 10
+        let errors: Vec<f64> = [2, 4, 6, 8].iter().map(|&n| error_at_one(1, n)).collect();
+        for w in errors.windows(2) {
+            assert!(w[1] < 1e-1 * w[0], "{errors:?}");
+        }
+        assert!(error_at_one(1, 14) < 1e-13);
+        assert!(error_at_one(4, 6) < error_at_one(1, 6));
+    }
+
+    #[test]
+    fn picard_counts_its_work() {
+        let ((t, _), stats) = chebyshev_picard(&growth, [1.0], [0.0, 2.0], 3, 5, 1e-12, 100, Limits::default()).unwrap();
+        assert_eq!(t.len(), 3 * 5 + 1);
+        assert_eq!(stats.accepted, 3);
+        assert_eq!(stats.rate_evals % 6, 0);
+    }
+
+    #[test]
+    fn no_nodes_or_segments_is_an_error() {
+        for (segments, n) in [(1, 0), (0, 4)] {
+            assert!(chebyshev_picard(&growth, [1.0], [0.0, 1.0], segments, n, 1e-12, 50, Limits::default()).is_err());
+        }
+        assert!(chebyshev_picard(&growth, [1.0], [1.0, 1.0], 1, 4, 1e-12, 50, Limits::default()).is_err());
+    }
+}
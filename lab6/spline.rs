@@ -0,0 +1,96 @@
+//!
+//! spline.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Interpolation of tabulated data (x_i, y_i) with strictly increasing
+//! x_i, piecewise linear or natural cubic. The cubic spline stores the
+//! second derivative at every knot from the usual tridiagonal system with
+//! zero curvature at the ends. Outside the table the end values are held,
+//! since a measured curve says nothing about the extrapolated region
+//!
+
+#![allow(dead_code)]
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interpolation {
+    Linear,
+    /// natural cubic, C2 through every point
+    Cubic,
+}
+
+#[derive(Clone, Debug)]
+pub struct Spline {
+    pub x: Vec<f64>,
+    pub y: Vec<f64>,
+    pub kind: Interpolation,
+    /// second derivative at each knot, zero for Linear
+    m: Vec<f64>,
+}
+
+impl Spline {
+    pub fn new(x: &[f64], y: &[f64], kind: Interpolation) -> Result<Spline, Box<dyn std::error::Error>> {
+        if x.len() != y.len() || x.len() < 2 {
+            return Err(format!("spline: need at least two points and equal lengths, got {} x and {} y", x.len(), y.len()).into());
+        }
+        if let Some(i) = x.iter().chain(y).position(|v| !v.is_finite()) {
+            return Err(format!("spline: entry {i} of the table is not finite").into());
+        }
+        if let Some(i) = x.windows(2).position(|w| w[1] <= w[0]) {
+            return Err(format!("spline: x must be strictly increasing, x[{}] = {} >= x[{}] = {}", i, x[i], i + 1, x[i + 1]).into());
+        }
+        let n = x.len();
+        let mut m = vec![0.0; n];
+        if kind == Interpolation::Cubic && n > 2 {
+            // interior rows h_{i-1} m_{i-1} + 2 (h_{i-1} + h_i) m_i + h_i m_{i+1} = 6 (d_i - d_{i-1})
+            let h: Vec<f64> = x.windows(2).map(|w| w[1] - w[0]).collect();
+            let d: Vec<f64> = (0..n - 1).map(|i| (y[i + 1] - y[i]) / h[i]).collect();
+            let mut c = vec![0.0; n];
+            let mut r = vec![0.0; n];
+            for i in 1..n - 1 {
+                let pivot = 2.0 * (h[i - 1] + h[i]) - h[i - 1] * c[i - 1];
+                c[i] = h[i] / pivot;
+                r[i] = (6.0 * (d[i] - d[i - 1]) - h[i - 1] * r[i - 1]) / pivot;
+            }
+            for i in (1..n - 1).rev() {
+                m[i] = r[i] - c[i] * m[i + 1];
+            }
+        }
+        Ok(Spline { x: x.to_vec(), y: y.to_vec(), kind, m })
+    }
+
+    ///
+    /// Index i with x_i <= x < x_{i+1}, inside the table
+    ///
+    fn interval(&self, x: f64) -> usize {
+        self.x.partition_point(|xi| *xi <= x).clamp(1, self.x.len() - 1) - 1
+    }
+
+    pub fn eval(&self, x: f64) -> f64 {
+        let n = self.x.len();
+        if x <= self.x[0] {
+            return self.y[0];
+        }
+        if x >= self.x[n - 1] {
+            return self.y[n - 1];
+        }
+        let i = self.interval(x);
+        let h = self.x[i + 1] - self.x[i];
+        let (a, b) = ((self.x[i + 1] - x) / h, (x - self.x[i]) / h);
+        a * self.y[i] + b * self.y[i + 1]
+            + ((a.powi(3) - a) * self.m[i] + (b.powi(3) - b) * self.m[i + 1]) * h * h / 6.0
+    }
+
+    ///
+    /// dy/dx, zero outside the table where the ends are held
+    ///
+    pub fn derivative(&self, x: f64) -> f64 {
+        let n = self.x.len();
+        if x < self.x[0] || x > self.x[n - 1] {
+            return 0.0;
+        }
+        let i = self.interval(x);
+        let h = self.x[i + 1] - self.x[i];
+        let (a, b) = ((self.x[i + 1] - x) / h, (x - self.x[i]) / h);
+        (self.y[i + 1] - self.y[i]) / h
+            + ((1.0 - 3.0 * a * a) * self.m[i] + (3.0 * b * b - 1.0) * self.m[i + 1]) * h / 6.0
+    }
+}
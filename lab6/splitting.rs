@@ -0,0 +1,290 @@
+//!
+//! splitting.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Operator splitting for reaction-diffusion systems u_t = D u_xx + R(u) of
+//! S species on a 1D cell centered grid with zero flux (closed) or
+//! absorbing (open) ends. The diffusion
+//! sub-step is a theta scheme (implicit, one tridiagonal solve per species)
+//! and the reaction sub-step is rk4 on every cell independently, so the
+//! ODE rate functions of the labs plug in unchanged. Lie alternates the two
+//! once per step (first order), Strang brackets the reaction by two half
+//! diffusion steps (second order when the diffusion step is Crank-Nicolson)
+//!
+
+#![allow(dead_code)]
+
+use solvers::{self, rk4_step, Limits};
+
+use crate::system::OdeSystem;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Splitting {
+    Lie,
+    Strang,
+}
+
+impl Splitting {
+    pub fn name(self) -> &'static str {
+        match self {
+            Splitting::Lie => "lie",
+            Splitting::Strang => "strang",
+        }
+    }
+}
+
+///
+/// What lies past the end cells
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Ends {
+    /// closed: the ghost cell mirrors the end cell, nothing crosses
+    #[default]
+    ZeroFlux,
+    /// open: u = 0 on the end face, the ghost cell the end cell negated
+    Absorbing,
+}
+
+impl Ends {
+    pub fn name(self) -> &'static str {
+        match self {
+            Ends::ZeroFlux => "zero flux",
+            Ends::Absorbing => "absorbing",
+        }
+    }
+
+    ///
+    /// Ghost cell value beyond an end cell holding u
+    ///
+    fn ghost(self, u: f64) -> f64 {
+        match self {
+            Ends::ZeroFlux => u,
+            Ends::Absorbing => -u,
+        }
+    }
+}
+
+///
+/// Diffusion coefficient per species, the cell width, theta (1 is
+/// backward Euler, 0.5 is Crank-Nicolson) and the boundary
+///
+#[derive(Clone, Copy, Debug)]
+pub struct Diffusion<const S: usize> {
+    pub coeff: [f64; S],
+    pub dx: f64,
+    pub theta: f64,
+    pub ends: Ends,
+}
+
+impl<const S: usize> Diffusion<S> {
+    pub fn crank_nicolson(coeff: [f64; S], dx: f64) -> Diffusion<S> {
+        Diffusion { coeff, dx, theta: 0.5, ends: Ends::ZeroFlux }
+    }
+
+    pub fn backward_euler(coeff: [f64; S], dx: f64) -> Diffusion<S> {
+        Diffusion { coeff, dx, theta: 1.0, ends: Ends::ZeroFlux }
+    }
+
+    pub fn with_ends(self, ends: Ends) -> Diffusion<S> {
+        Diffusion { ends, ..self }
+    }
+
+    ///
+    /// (L u)_i for one species, the end cells' neighbors from the ghosts
+    ///
+    fn laplacian(&self, u: &[[f64; S]], s: usize, i: usize) -> f64 {
+        let n = u.len();
+        let left = if i == 0 { self.ends.ghost(u[0][s]) } else { u[i - 1][s] };
+        let right = if i + 1 == n { self.ends.ghost(u[n - 1][s]) } else { u[i + 1][s] };
+        (left - 2.0 * u[i][s] + right) / (self.dx * self.dx)
+    }
+
+    ///
+    /// Rate at which each species' mass, sum u dx, leaves through the two
+    /// end faces; zero for closed ends
+    ///
+    pub fn outflow(&self, u: &[[f64; S]]) -> [f64; S] {
+        let n = u.len();
+        std::array::from_fn(|s| {
+            let face = |end: f64| self.coeff[s] * (end - self.ends.ghost(end)) / self.dx;
+            face(u[0][s]) + face(u[n - 1][s])
+        })
+    }
+
+    ///
+    /// D u_xx on every cell, the diffusion half of the method of lines rate
+    ///
+    pub fn apply(&self, u: &[[f64; S]]) -> Vec<[f64; S]> {
+        (0..u.len()).map(|i| std::array::from_fn(|s| self.coeff[s] * self.laplacian(u, s, i))).collect()
+    }
+
+    ///
+    /// One theta step of u_t = D u_xx over dt, in place. The implicit part
+    /// is linear and solved directly, so there is no corrector iteration to
+    /// fail and no CorrectorFailure policy to apply
+    ///
+    pub fn step(&self, u: &mut [[f64; S]], dt: f64) {
+        let n = u.len();
+        if n < 2 {
+            return;
+        }
+        for s in 0..S {
+            let r = self.coeff[s] * dt / (self.dx * self.dx);
+            let rhs: Vec<f64> = (0..n)
+                .map(|i| u[i][s] + (1.0 - self.theta) * self.coeff[s] * dt * self.laplacian(u, s, i))
+                .collect();
+            let (lo, hi) = (-self.theta * r, -self.theta * r);
+            // an end row keeps 2 - ghost / u of its diagonal
+            let end = 2.0 - self.ends.ghost(1.0);
+            let diag: Vec<f64> = (0..n)
+                .map(|i| 1.0 + self.theta * r * if i == 0 || i + 1 == n { end } else { 2.0 })
+                .collect();
+            for (cell, x) in u.iter_mut().zip(thomas(lo, &diag, hi, &rhs)) {
+                cell[s] = x;
+            }
+        }
+    }
+}
+
+///
+/// Tridiagonal solve with constant off diagonals, no pivoting; the
+/// diffusion matrices are diagonally dominant so none is needed
+///
+fn thomas(lo: f64, diag: &[f64], hi: f64, rhs: &[f64]) -> Vec<f64> {
+    let n = diag.len();
+    let mut c = vec![0.0; n];
+    let mut d = vec![0.0; n];
+    c[0] = hi / diag[0];
+    d[0] = rhs[0] / diag[0];
+    for i in 1..n {
+        let m = diag[i] - lo * c[i - 1];
+        c[i] = hi / m;
+        d[i] = (rhs[i] - lo * d[i - 1]) / m;
+    }
+    let mut x = vec![0.0; n];
+    x[n - 1] = d[n - 1];
+    for i in (0..n - 1).rev() {
+        x[i] = d[i] - c[i] * x[i + 1];
+    }
+    x
+}
+
+///
+/// One rk4 step of the reaction on every cell
+///
+pub fn react<F, const S: usize>(rate: &F, t: f64, u: &mut [[f64; S]], dt: f64)
+where F: Fn(f64, &[f64; S], &mut [f64; S]) {
+    for cell in u.iter_mut() {
+        *cell = rk4_step(rate, t, cell, dt);
+    }
+}
+
+///
+/// One split step from t to t + dt
+///
+pub fn step<F, const S: usize>(scheme: Splitting, rate: &F, diff: &Diffusion<S>, t: f64, u: &mut [[f64; S]], dt: f64)
+where F: Fn(f64, &[f64; S], &mut [f64; S]) {
+    match scheme {
+        Splitting::Lie => {
+            diff.step(u, dt);
+            react(rate, t, u, dt);
+        }
+        Splitting::Strang => {
+            diff.step(u, 0.5 * dt);
+            react(rate, t, u, dt);
+            diff.step(u, 0.5 * dt);
+        }
+    }
+}
+
+///
+/// Fixed dt split integration over ts, every state (the initial one
+/// included) handed to `observe`. The step count is checked against
+/// limits first and the wall clock as it runs. Returns the final state
+///
+#[allow(clippy::too_many_arguments)]
+pub fn integrate_with<F, O, const S: usize>(
+    scheme: Splitting, rate: &F, diff: &Diffusion<S>, u0: &[[f64; S]], ts: [f64; 2], dt: f64, limits: Limits, mut observe: O)
+    -> Result<Vec<[f64; S]>, Box<dyn std::error::Error>>
+where
+    F: Fn(f64, &[f64; S], &mut [f64; S]),
+    O: FnMut(f64, &[[f64; S]])
+{
+    solvers::planned_steps(scheme.name(), ts, dt, limits)?;
+    let mut guard = limits.memory(usize::MAX).start::<S>();
+    let mut u = u0.to_vec();
+    observe(ts[0], &u);
+    for (t, h, next) in solvers::grid(ts, dt) {
+        guard.step(scheme.name(), t)?;
+        step(scheme, rate, diff, t, &mut u, h);
+        observe(next, &u);
+    }
+    Ok(u)
+}
+
+///
+/// integrate_with with the reaction as a system, its size checked against
+/// the species per cell
+///
+#[allow(clippy::too_many_arguments)]
+pub fn integrate_system<O, const S: usize>(
+    scheme: Splitting, reaction: &impl OdeSystem, diff: &Diffusion<S>, u0: &[[f64; S]], ts: [f64; 2], dt: f64, limits: Limits, observe: O)
+    -> Result<Vec<[f64; S]>, Box<dyn std::error::Error>>
+where O: FnMut(f64, &[[f64; S]]) {
+    integrate_with(scheme, &reaction.rhs::<S>()?, diff, u0, ts, dt, limits, observe)
+}
+
+///
+/// Splitting error hook: the per cell difference between diffusion then
+/// reaction and reaction then diffusion over one dt from u. The leading
+/// term is dt^2 [D, R] u / 2, so it estimates the local Lie error and
+/// shows where in space the two operators fail to commute
+///
+pub fn commutator_defect<F, const S: usize>(rate: &F, diff: &Diffusion<S>, t: f64, u: &[[f64; S]], dt: f64) -> Vec<[f64; S]>
+where F: Fn(f64, &[f64; S], &mut [f64; S]) {
+    let mut dr = u.to_vec();
+    diff.step(&mut dr, dt);
+    react(rate, t, &mut dr, dt);
+
+    let mut rd = u.to_vec();
+    react(rate, t, &mut rd, dt);
+    diff.step(&mut rd, dt);
+
+    dr.iter().zip(&rd).map(|(a, b)| std::array::from_fn(|s| a[s] - b[s])).collect()
+}
+
+///
+/// Method of lines rate for the unsplit system, for references computed
+/// with any ODE solver on the flattened state
+///
+pub fn mol_rate<F, const S: usize>(rate: &F, diff: &Diffusion<S>, t: f64, u: &[[f64; S]]) -> Vec<[f64; S]>
+where F: Fn(f64, &[f64; S], &mut [f64; S]) {
+    u.iter()
+        .zip(diff.apply(u))
+        .map(|(cell, d)| {
+            let mut r = [0.0; S];
+            rate(t, cell, &mut r);
+            std::array::from_fn(|s| r[s] + d[s])
+        })
+        .collect()
+}
+
+///
+/// One classical rk4 step of the unsplit method of lines system, the
+/// reference the split solutions are measured against. Explicit, so dt
+/// must respect the diffusion limit of roughly dx^2 / (2 max D)
+///
+pub fn mol_step<F, const S: usize>(rate: &F, diff: &Diffusion<S>, t: f64, u: &mut [[f64; S]], dt: f64)
+where F: Fn(f64, &[f64; S], &mut [f64; S]) {
+    let shift = |k: &[[f64; S]], h: f64| -> Vec<[f64; S]> {
+        u.iter().zip(k).map(|(x, d)| std::array::from_fn(|s| x[s] + h * d[s])).collect()
+    };
+    let k1 = mol_rate(rate, diff, t, u);
+    let k2 = mol_rate(rate, diff, t + 0.5 * dt, &shift(&k1, 0.5 * dt));
+    let k3 = mol_rate(rate, diff, t + 0.5 * dt, &shift(&k2, 0.5 * dt));
+    let k4 = mol_rate(rate, diff, t + dt, &shift(&k3, dt));
+    for (i, cell) in u.iter_mut().enumerate() {
+        for s in 0..S {
+            cell[s] += (dt / 6.0) * (k1[i][s] + 2.0 * k2[i][s] + 2.0 * k3[i][s] + k4[i][s]);
+        }
+    }
+}
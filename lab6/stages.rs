@@ -0,0 +1,131 @@
+//!
+//! stages.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Stage-structured competition: each species is split into juveniles
+//! and adults, state [J1, A1, J2, A2]. The linear part, births into the
+//! juvenile stage, maturation and stage deaths, is a 4 x 4 transition
+//! matrix; competition acts on adults only, as in the unstructured model,
+//!
+//!   y' = T y - (0, A1 (b1 A1 + c1 A2), 0, A2 (b2 A2 + c2 A1))
+//!
+//! so any fixed step solver on [f64; 4] runs it unchanged
+//!
+
+#![allow(dead_code)]
+
+use crate::state::StateVar;
+
+pub const STATES: [StateVar; 4] = [
+    StateVar::new("J1", "individuals"),
+    StateVar::new("A1", "individuals"),
+    StateVar::new("J2", "individuals"),
+    StateVar::new("A2", "individuals"),
+];
+
+///
+/// Per-capita rates of one species, all per unit time
+///
+#[derive(Clone, Copy, Debug)]
+pub struct VitalRates {
+    /// juveniles born per adult
+    pub fecundity: f64,
+    /// juveniles becoming adults
+    pub maturation: f64,
+    pub juvenile_death: f64,
+    pub adult_death: f64,
+}
+
+impl VitalRates {
+    ///
+    /// 2 x 2 block of the transition matrix, rows [J, A]
+    ///
+    pub fn block(&self) -> [[f64; 2]; 2] {
+        [
+            [-(self.maturation + self.juvenile_death), self.fecundity],
+            [self.maturation, -self.adult_death],
+        ]
+    }
+
+    ///
+    /// Low density growth rate, the dominant eigenvalue of the block. It
+    /// plays the part of a in the unstructured model
+    ///
+    pub fn growth_rate(&self) -> f64 {
+        let m = self.block();
+        let (tr, det) = (m[0][0] + m[1][1], m[0][0] * m[1][1] - m[0][1] * m[1][0]);
+        0.5 * (tr + (tr * tr - 4.0 * det).max(0.0).sqrt())
+    }
+
+    ///
+    /// Adult per-capita growth once juveniles track adults,
+    /// m f / (m + d_J) - d_A
+    ///
+    pub fn adult_recruitment(&self) -> f64 {
+        self.maturation * self.fecundity / (self.maturation + self.juvenile_death) - self.adult_death
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct StagedEcosystem {
+    pub transition: [[f64; 4]; 4],
+    pub b: [f64; 2],
+    pub c: [f64; 2],
+}
+
+impl StagedEcosystem {
+    ///
+    /// From any transition matrix. Off-diagonal entries are flows between
+    /// stages and must be non-negative, as must b and c
+    ///
+    pub fn new(transition: [[f64; 4]; 4], b: [f64; 2], c: [f64; 2]) -> Result<StagedEcosystem, Box<dyn std::error::Error>> {
+        for (i, row) in transition.iter().enumerate() {
+            for (j, v) in row.iter().enumerate() {
+                if !v.is_finite() || (i != j && *v < 0.0) {
+                    return Err(format!("staged ecosystem: transition[{i}][{j}] = {v} must be finite, and non-negative off the diagonal").into());
+                }
+            }
+        }
+        for (name, v) in [("self_limitation", b), ("competition", c)] {
+            if let Some(i) = v.iter().position(|x| !x.is_finite() || *x < 0.0) {
+                return Err(format!("staged ecosystem: {name}[{i}] = {} must be finite and non-negative", v[i]).into());
+            }
+        }
+        Ok(StagedEcosystem { transition, b, c })
+    }
+
+    ///
+    /// Block diagonal transition matrix, the species only interacting
+    /// through adult competition
+    ///
+    pub fn from_vital_rates(rates: [VitalRates; 2], b: [f64; 2], c: [f64; 2]) -> Result<StagedEcosystem, Box<dyn std::error::Error>> {
+        for (s, r) in rates.iter().enumerate() {
+            let all = [r.fecundity, r.maturation, r.juvenile_death, r.adult_death];
+            if all.iter().any(|v| !v.is_finite() || *v < 0.0) {
+                return Err(format!("staged ecosystem: vital rates of species {} must be finite and non-negative, got {r:?}", s + 1).into());
+            }
+        }
+        let mut transition = [[0.0; 4]; 4];
+        for (s, r) in rates.iter().enumerate() {
+            let block = r.block();
+            for i in 0..2 {
+                for j in 0..2 {
+                    transition[2 * s + i][2 * s + j] = block[i][j];
+                }
+            }
+        }
+        StagedEcosystem::new(transition, b, c)
+    }
+
+    pub fn adults(y: &[f64; 4]) -> [f64; 2] {
+        [y[1], y[3]]
+    }
+
+    pub fn rate(&self, _t: f64, y: &[f64; 4], dy: &mut [f64; 4]) {
+        for (i, d) in dy.iter_mut().enumerate() {
+            *d = (0..4).map(|j| self.transition[i][j] * y[j]).sum();
+        }
+        let a = StagedEcosystem::adults(y);
+        dy[1] -= a[0] * (self.b[0] * a[0] + self.c[0] * a[1]);
+        dy[3] -= a[1] * (self.b[1] * a[1] + self.c[1] * a[0]);
+    }
+}
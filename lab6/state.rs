@@ -0,0 +1,46 @@
+//!
+//! state.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Names and units for the components of a system's state, so plots,
+//! table headers and exported columns are labeled from the model itself
+//!
+
+#![allow(dead_code)]
+
+#[derive(Clone, Copy, Debug)]
+pub struct StateVar {
+    pub name: &'static str,
+    pub unit: &'static str,
+}
+
+impl StateVar {
+    pub const fn new(name: &'static str, unit: &'static str) -> StateVar {
+        StateVar { name, unit }
+    }
+
+    ///
+    /// Axis or legend text, "name [unit]" when a unit is given
+    ///
+    pub fn label(&self) -> String {
+        if self.unit.is_empty() {
+            self.name.to_string()
+        } else {
+            format!("{} [{}]", self.name, self.unit)
+        }
+    }
+
+    ///
+    /// Identifier safe for column headers and array names, anything other
+    /// than an ascii alphanumeric becomes '_'
+    ///
+    pub fn column(&self) -> String {
+        self.name.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect()
+    }
+}
+
+///
+/// Comma separated labels, for a y axis shared by every component
+///
+pub fn axis_label(states: &[StateVar]) -> String {
+    states.iter().map(StateVar::label).collect::<Vec<_>>().join(", ")
+}
@@ -0,0 +1,159 @@
+//!
+//! sweep_store.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Collects a full parameter sweep (method, alpha, dt, trajectory) into a
+//! single long-format table. With the `parquet` feature the table is
+//! written as one queryable Parquet file instead of a pile of PNG/CSV
+//!
+
+#![allow(dead_code)]
+
+use crate::state::StateVar;
+
+///
+/// Long-format experiment matrix, one row per stored sample. Columns are
+/// kept separately so they can be handed to a columnar writer directly
+///
+pub struct SweepTable<const N: usize> {
+    method: Vec<String>,
+    alpha:  Vec<f64>,
+    dt:     Vec<f64>,
+    t:      Vec<f64>,
+    y:      [Vec<f64>; N],
+    names:  [String; N],
+}
+
+impl<const N: usize> SweepTable<N> {
+    pub fn new() -> SweepTable<N> {
+        SweepTable {
+            method: Vec::new(),
+            alpha:  Vec::new(),
+            dt:     Vec::new(),
+            t:      Vec::new(),
+            y:      std::array::from_fn(|_| Vec::new()),
+            names:  std::array::from_fn(|j| format!("y{j}")),
+        }
+    }
+
+    ///
+    /// Empty table whose state columns are named after the system's states
+    ///
+    pub fn labeled(states: &[StateVar; N]) -> SweepTable<N> {
+        SweepTable { names: std::array::from_fn(|j| states[j].column()), ..SweepTable::new() }
+    }
+
+    ///
+    /// Appends one trajectory, repeating its method/alpha/dt tags per sample
+    ///
+    pub fn push(&mut self, method: &str, alpha: f64, dt: f64, t: &[f64], y: &[[f64; N]]) {
+        let n = t.len().min(y.len());
+        self.method.extend(std::iter::repeat_n(method.to_string(), n));
+        self.alpha.extend(std::iter::repeat_n(alpha, n));
+        self.dt.extend(std::iter::repeat_n(dt, n));
+        self.t.extend_from_slice(&t[..n]);
+        for (j, col) in self.y.iter_mut().enumerate() {
+            col.extend(y[..n].iter().map(|row| row[j]));
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.t.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.t.is_empty()
+    }
+
+    ///
+    /// Writes the table as a Parquet file with columns
+    /// method (utf8), alpha, dt, t, then one double per state (y0 .. y{N-1}
+    /// unless the table was labeled). Rows are split
+    /// into row groups of `group_rows` so readers can skip by predicate
+    ///
+    #[cfg(feature = "parquet")]
+    pub fn write_parquet(&self, path: &str, group_rows: usize)
+        -> Result<(), Box<dyn std::error::Error>> {
+        use std::sync::Arc;
+        use parquet::data_type::{ByteArray, ByteArrayType, DoubleType};
+        use parquet::file::properties::WriterProperties;
+        use parquet::file::writer::SerializedFileWriter;
+        use parquet::schema::parser::parse_message_type;
+
+        let mut message = String::from(
+            "message sweep { REQUIRED BYTE_ARRAY method (UTF8); \
+             REQUIRED DOUBLE alpha; REQUIRED DOUBLE dt; REQUIRED DOUBLE t;"
+        );
+        for name in &self.names {
+            message.push_str(&format!(" REQUIRED DOUBLE {name};"));
+        }
+        message.push_str(" }");
+
+        let schema = Arc::new(parse_message_type(&message)?);
+        let props  = Arc::new(WriterProperties::builder().build());
+        let file   = std::fs::File::create(path)?;
+        let mut writer = SerializedFileWriter::new(file, schema, props)?;
+
+        let group_rows = group_rows.max(1);
+        let mut start = 0;
+        while start < self.len() {
+            let end = (start + group_rows).min(self.len());
+            let mut group = writer.next_row_group()?;
+            let mut column = 0;
+
+            while let Some(mut col) = group.next_column()? {
+                if column == 0 {
+                    let values: Vec<ByteArray> = self.method[start..end]
+                        .iter()
+                        .map(|m| ByteArray::from(m.as_str()))
+                        .collect();
+                    col.typed::<ByteArrayType>().write_batch(&values, None, None)?;
+                } else {
+                    let values = match column {
+                        1 => &self.alpha[start..end],
+                        2 => &self.dt[start..end],
+                        3 => &self.t[start..end],
+                        j => &self.y[j - 4][start..end],
+                    };
+                    col.typed::<DoubleType>().write_batch(values, None, None)?;
+                }
+                col.close()?;
+                column += 1;
+            }
+            group.close()?;
+            start = end;
+        }
+
+        writer.close()?;
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "parquet"))]
+mod tests {
+    use super::*;
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+    use parquet::record::RowAccessor;
+
+    #[test]
+    fn parquet_round_trip_keeps_every_row() {
+        let mut table: SweepTable<2> = SweepTable::new();
+        table.push("rk4", 0.5, 0.1, &[0.0, 0.1, 0.2], &[[1.0, 0.0], [0.99, -0.1], [0.96, -0.2]]);
+        table.push("abam4", 1.5, 0.05, &[0.0, 0.05], &[[1.0, 0.0], [0.998, -0.05]]);
+        let path = std::env::temp_dir().join(format!("sweep_store_{}.parquet", std::process::id()));
+        table.write_parquet(path.to_str().unwrap(), 2).unwrap();
+
+        let reader = SerializedFileReader::new(std::fs::File::open(&path).unwrap()).unwrap();
+        assert_eq!(reader.metadata().num_row_groups(), 3);
+        let rows: Vec<_> = reader.get_row_iter(None).unwrap().map(|r| r.unwrap()).collect();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(rows.len(), table.len());
+        for (i, row) in rows.iter().enumerate() {
+            assert_eq!(row.get_string(0).unwrap(), &table.method[i]);
+            assert_eq!(row.get_double(1).unwrap(), table.alpha[i]);
+            assert_eq!(row.get_double(2).unwrap(), table.dt[i]);
+            assert_eq!(row.get_double(3).unwrap(), table.t[i]);
+            assert_eq!(row.get_double(4).unwrap(), table.y[0][i]);
+            assert_eq!(row.get_double(5).unwrap(), table.y[1][i]);
+        }
+    }
+}
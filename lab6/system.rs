@@ -0,0 +1,63 @@
+//!
+//! system.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! A system of first order ODEs as a type: its parameters live in the
+//! struct and rate works on slices, so one implementation serves every
+//! state size. The solvers themselves stay generic over a rate closure on
+//! a fixed size state; rhs() checks the size and returns that closure.
+//! solve and solve_multistep here hand it to rk4 and friends or to AB/AM4,
+//! and the adaptive, IMEX, multirate, spectral, splitting, projection,
+//! batch and continuation solvers each take a system through their own
+//! *_system entry point. The Taylor and validated integrators need the
+//! rate on series or intervals too, through SeriesSystem and IntervalSystem
+//!
+
+#![allow(dead_code)]
+
+use solvers::{CorrectorFailure, Limits, Method, Solution, SolveStats};
+
+use crate::multistep::{self, Mode, Startup};
+
+pub trait OdeSystem {
+    ///
+    /// Number of states
+    ///
+    fn dimension(&self) -> usize;
+
+    fn rate(&self, t: f64, y: &[f64], dy: &mut [f64]);
+
+    fn check_size<const N: usize>(&self) -> Result<(), Box<dyn std::error::Error>> {
+        if self.dimension() != N {
+            return Err(format!("system has {} states, the state has {N}", self.dimension()).into());
+        }
+        Ok(())
+    }
+
+    ///
+    /// Right-hand side on a fixed size state for the ODE solvers, once
+    /// check_size passes
+    ///
+    #[allow(clippy::type_complexity)]
+    fn rhs<const N: usize>(&self) -> Result<impl Fn(f64, &[f64; N], &mut [f64; N]) + '_, Box<dyn std::error::Error>> {
+        self.check_size::<N>()?;
+        Ok(move |t: f64, y: &[f64; N], dy: &mut [f64; N]| self.rate(t, y, dy))
+    }
+}
+
+///
+/// system over ts with a fixed step explicit method, with its SolveStats
+///
+pub fn solve<const N: usize>(system: &impl OdeSystem, method: Method, ic: [f64; N], ts: [f64; 2], dt: f64)
+    -> Result<(Solution<N>, SolveStats), Box<dyn std::error::Error>> {
+    method.solve(&system.rhs::<N>()?, ic, ts, dt)
+}
+
+///
+/// system over ts with AB/AM4, as multistep::solve_with_mode, with its
+/// SolveStats
+///
+pub fn solve_multistep<const N: usize>(
+    system: &impl OdeSystem, ic: [f64; N], ts: [f64; 2], dt: f64, startup: Startup, mode: Mode, on_failure: CorrectorFailure)
+    -> Result<(Solution<N>, SolveStats), Box<dyn std::error::Error>> {
+    multistep::solve_with_mode(&system.rhs::<N>()?, ic, ts, dt, startup, mode, on_failure, Limits::default())
+}
@@ -0,0 +1,262 @@
+//!
+//! taylor.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! High order Taylor series integrator. Rate functions are evaluated on
+//! truncated power series (Taylor mode automatic differentiation) so the
+//! coefficients y_k = y^(k)(t) / k! follow from the recurrence
+//! y_{k+1} = f(y)_k / (k + 1) without deriving any derivatives by hand.
+//! Suited to polynomial rate functions, e.g. the ecosystem and semiconductor
+//!
+
+#![allow(dead_code)]
+
+use std::ops::{Add, Mul, Neg, Sub};
+use solvers::{Limits, Solution, SolveStats};
+
+use crate::system::OdeSystem;
+
+///
+/// Truncated power series in the step variable, c[k] multiplies h^k
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Series {
+    pub c: Vec<f64>,
+}
+
+impl Series {
+    ///
+    /// Constant series of the given truncation length
+    ///
+    pub fn constant(x: f64, len: usize) -> Series {
+        let mut c = vec![0.0; len];
+        c[0] = x;
+        Series { c }
+    }
+
+    pub fn len(&self) -> usize {
+        self.c.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.c.is_empty()
+    }
+
+    pub fn scale(&self, s: f64) -> Series {
+        Series { c: self.c.iter().map(|x| s * x).collect() }
+    }
+
+    pub fn powi(&self, n: u32) -> Series {
+        let mut out = Series::constant(1.0, self.len());
+        for _ in 0..n {
+            out = &out * self;
+        }
+        out
+    }
+
+    ///
+    /// Horner evaluation of the polynomial at h
+    ///
+    pub fn eval(&self, h: f64) -> f64 {
+        self.c.iter().rev().fold(0.0, |acc, &ck| acc * h + ck)
+    }
+}
+
+impl Add for &Series {
+    type Output = Series;
+    fn add(self, rhs: &Series) -> Series {
+        Series { c: self.c.iter().zip(rhs.c.iter()).map(|(a, b)| a + b).collect() }
+    }
+}
+
+impl Sub for &Series {
+    type Output = Series;
+    fn sub(self, rhs: &Series) -> Series {
+        Series { c: self.c.iter().zip(rhs.c.iter()).map(|(a, b)| a - b).collect() }
+    }
+}
+
+///
+/// Cauchy product truncated to the common length
+///
+impl Mul for &Series {
+    type Output = Series;
+    fn mul(self, rhs: &Series) -> Series {
+        let n = self.len().min(rhs.len());
+        let mut c = vec![0.0; n];
+        for (k, ck) in c.iter_mut().enumerate() {
+            *ck = (0..=k).map(|j| self.c[j] * rhs.c[k - j]).sum();
+        }
+        Series { c }
+    }
+}
+
+impl Neg for &Series {
+    type Output = Series;
+    fn neg(self) -> Series {
+        self.scale(-1.0)
+    }
+}
+
+impl Mul<&Series> for f64 {
+    type Output = Series;
+    fn mul(self, rhs: &Series) -> Series {
+        rhs.scale(self)
+    }
+}
+
+// owned forms so rate functions can chain expressions without borrowing
+impl Add for Series {
+    type Output = Series;
+    fn add(self, rhs: Series) -> Series {
+        &self + &rhs
+    }
+}
+
+impl Sub for Series {
+    type Output = Series;
+    fn sub(self, rhs: Series) -> Series {
+        &self - &rhs
+    }
+}
+
+impl Mul for Series {
+    type Output = Series;
+    fn mul(self, rhs: Series) -> Series {
+        &self * &rhs
+    }
+}
+
+impl Mul<Series> for f64 {
+    type Output = Series;
+    fn mul(self, rhs: Series) -> Series {
+        rhs.scale(self)
+    }
+}
+
+///
+/// An autonomous OdeSystem whose rate also runs on truncated power series,
+/// for the Taylor integrator
+///
+pub trait SeriesSystem: OdeSystem {
+    fn rate_series(&self, y: &[Series], dy: &mut [Series]);
+}
+
+///
+/// Taylor coefficients of the solution through y at the expansion point,
+/// generated order by order from the rate function on series arguments
+///
+pub fn coefficients<F, const N: usize>(rate: &F, y: &[f64; N], order: usize) -> [Series; N]
+where F: Fn(&[Series; N]) -> [Series; N] {
+    let len = order + 1;
+    let mut ys: [Series; N] = std::array::from_fn(|i| Series::constant(y[i], len));
+
+    // f(y)_k only depends on y_0..y_k so each pass fixes one more coefficient
+    for k in 0..order {
+        let f = rate(&ys);
+        for (yi, fi) in ys.iter_mut().zip(f.iter()) {
+            yi.c[k + 1] = fi.c[k] / (k + 1) as f64;
+        }
+    }
+    ys
+}
+
+///
+/// Fixed-step Taylor method of the given order. When dt does not divide
+/// the span a shortened last step lands on tf. The run is checked against
+/// limits before anything is stored. rate_evals counts the series
+/// evaluations of rate, order of them a step
+///
+pub fn taylor<F, const N: usize>(rate: &F, order: usize, ic: [f64; N], t0: f64, tf: f64, dt: f64, limits: Limits)
+    -> Result<(Solution<N>, SolveStats), Box<dyn std::error::Error>>
+where F: Fn(&[Series; N]) -> [Series; N] {
+    let n = solvers::planned_steps("taylor", [t0, tf], dt, limits)?;
+    limits.check::<N>("taylor", n)?;
+    let mut guard = limits.start::<N>();
+    let mut t: Vec<f64> = Vec::with_capacity(n + 1);
+    let mut y: Vec<[f64; N]> = Vec::with_capacity(n + 1);
+    t.push(t0);
+    y.push(ic);
+    let calls = std::cell::Cell::new(0);
+    let counted = |ys: &[Series; N]| {
+        calls.set(calls.get() + 1);
+        rate(ys)
+    };
+
+    for (tk, h, next) in solvers::grid([t0, tf], dt) {
+        guard.step("taylor", tk)?;
+        let coeffs = coefficients(&counted, y.last().unwrap(), order);
+        y.push(std::array::from_fn(|j| coeffs[j].eval(h)));
+        t.push(next);
+    }
+
+    let stats = SolveStats { accepted: t.len() - 1, rate_evals: calls.get(), ..SolveStats::default() };
+    Ok(((t, y), stats))
+}
+
+///
+/// taylor on a system, its size checked against the state
+///
+pub fn taylor_system<const N: usize>(system: &impl SeriesSystem, order: usize, ic: [f64; N], t0: f64, tf: f64, dt: f64, limits: Limits)
+    -> Result<(Solution<N>, SolveStats), Box<dyn std::error::Error>> {
+    system.check_size::<N>()?;
+    let rate = |y: &[Series; N]| -> [Series; N] {
+        let mut dy = std::array::from_fn(|i| Series::constant(0.0, y[i].len()));
+        system.rate_series(y, &mut dy);
+        dy
+    };
+    taylor(&rate, order, ic, t0, tf, dt, limits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn growth(y: &[Series; 1]) -> [Series; 1] {
+        [y[0].clone()]
+    }
+
+    fn error_at_one(order: usize, dt: f64) -> f64 {
+        let ((t, y), _) = taylor(&growth, order, [1.0], 0.0, 1.0, dt, Limits::default()).unwrap();
+        assert!((t[t.len() - 1] - 1.0).abs() < 1e-15);
+        (y[y.len() - 1][0] - 1.0_f64.exp()).abs()
+    }
+
+    #[test]
+    fn coefficients_of_growth_are_inverse_factorials() {
+        let c = coefficients(&growth, &[2.0], 6);
+        let mut factorial = 1.0;
+        for (k, ck) in c[0].c.iter().enumerate() {
+            if k > 0 {
+                factorial *= k as f64;
+            }
+            assert!((ck - 2.0 / factorial).abs() < 1e-15, "c[{k}] = {ck}");
+        }
+    }
+
+    #[test]
+    fn series_products_truncate() {
+        let a = Series { c: vec![1.0, 2.0, 3.0] };
+        let b = Series { c: vec![4.0, 5.0, 6.0] };
+        assert_eq!((&a * &b).c, vec![4.0, 13.0, 28.0]);
+        assert_eq!(a.powi(2).c, vec![1.0, 4.0, 10.0]);
+        assert_eq!(a.eval(0.5), 1.0 + 1.0 + 0.75);
+    }
+
+    #[test]
+    fn order_of_convergence_on_growth() {
+        // halving dt divides the error by about 2^order
+        for order in [1, 2, 4] {
+            let ratio = error_at_one(order, 0.1) / error_at_one(order, 0.05);
+            let observed = ratio.log2();
+            assert!((observed - order as f64).abs() < 0.2, "order {order} observed {observed}");
+        }
+        assert!(error_at_one(12, 0.1) < 1e-14);
+    }
+
+    #[test]
+    fn stats_count_order_evaluations_a_step() {
+        let (_, stats) = taylor(&growth, 5, [1.0], 0.0, 1.0, 0.25, Limits::default()).unwrap();
+        assert_eq!(stats.accepted, 4);
+        assert_eq!(stats.rate_evals, 4 * 5);
+    }
+}
@@ -0,0 +1,158 @@
+//!
+//! theme.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! One look for every lab figure. A PlotStyle holds the font, text sizes,
+//! margin, line widths, figure sizes and palette that used to be written
+//! out in each chart; the run's style lives on its RunContext and every
+//! plotting function reads it from there. Presets: report (the original
+//! look), slides (larger text and heavier lines on a 16:9 canvas) and
+//! colorblind (report with the Okabe-Ito palette). PLOT_STYLE in the
+//! environment picks one by name for a whole run. A style may also carry
+//! a footer line of run metadata, stamped under every figure drawn with it
+//!
+
+#![allow(dead_code)]
+
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use plotters::style::{Palette, Palette99, RGBAColor, RGBColor};
+
+///
+/// Okabe and Ito's palette, distinguishable under the common color vision
+/// deficiencies. Black goes last, it is also the reference curve color
+///
+const OKABE_ITO: [RGBColor; 8] = [
+    RGBColor(230, 159, 0),
+    RGBColor(86, 180, 233),
+    RGBColor(0, 158, 115),
+    RGBColor(0, 114, 178),
+    RGBColor(213, 94, 0),
+    RGBColor(204, 121, 167),
+    RGBColor(240, 228, 66),
+    RGBColor(0, 0, 0),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Colors {
+    /// plotters' Palette99
+    Palette99,
+    OkabeIto,
+}
+
+#[derive(Clone, Debug)]
+pub struct PlotStyle {
+    pub name: &'static str,
+    pub font: &'static str,
+    /// caption and axis/legend text sizes
+    pub caption_size: u32,
+    pub label_size: u32,
+    pub margin: u32,
+    /// main curves and secondary (reference, faint, dashed) curves
+    pub line: u32,
+    pub thin: u32,
+    /// single chart and side by side panel figures
+    pub size: (u32, u32),
+    pub panels: (u32, u32),
+    pub colors: Colors,
+    /// provenance line under the figure, None for a clean figure
+    pub footer: Option<String>,
+}
+
+impl PlotStyle {
+    pub fn report() -> PlotStyle {
+        PlotStyle {
+            name: "report",
+            font: "sans-serif",
+            caption_size: 22,
+            label_size: 12,
+            margin: 10,
+            line: 2,
+            thin: 1,
+            size: (1200, 700),
+            panels: (1800, 650),
+            colors: Colors::Palette99,
+            footer: None,
+        }
+    }
+
+    pub fn slides() -> PlotStyle {
+        PlotStyle {
+            name: "slides",
+            caption_size: 34,
+            label_size: 20,
+            margin: 20,
+            line: 4,
+            thin: 2,
+            size: (1600, 900),
+            panels: (2400, 900),
+            ..PlotStyle::report()
+        }
+    }
+
+    pub fn colorblind() -> PlotStyle {
+        PlotStyle { name: "colorblind", colors: Colors::OkabeIto, ..PlotStyle::report() }
+    }
+
+    pub fn named(name: &str) -> Option<PlotStyle> {
+        match name {
+            "report" => Some(PlotStyle::report()),
+            "slides" => Some(PlotStyle::slides()),
+            "colorblind" => Some(PlotStyle::colorblind()),
+            _ => None,
+        }
+    }
+
+    ///
+    /// The preset PLOT_STYLE names, report when it is unset or unknown
+    ///
+    pub fn from_env() -> PlotStyle {
+        match std::env::var("PLOT_STYLE") {
+            Ok(name) => PlotStyle::named(&name).unwrap_or_else(|| {
+                eprintln!("warning: unknown PLOT_STYLE {name:?}, using report (choices: report, slides, colorblind)");
+                PlotStyle::report()
+            }),
+            Err(_) => PlotStyle::report(),
+        }
+    }
+
+    ///
+    /// Series color k, wrapping around the palette
+    ///
+    pub fn color(&self, k: usize) -> RGBAColor {
+        match self.colors {
+            Colors::Palette99 => Palette99::pick(k).to_rgba(),
+            Colors::OkabeIto => OKABE_ITO[k % OKABE_ITO.len()].to_rgba(),
+        }
+    }
+
+    pub fn caption(&self) -> (&'static str, u32) {
+        (self.font, self.caption_size)
+    }
+
+    ///
+    /// Tick labels, axis descriptions and legend entries
+    ///
+    pub fn label(&self) -> (&'static str, u32) {
+        (self.font, self.label_size)
+    }
+
+    ///
+    /// Writes the footer along the bottom of root and returns the area
+    /// above it for the chart, root itself when there is no footer
+    ///
+    pub fn stamp<DB>(&self, root: &DrawingArea<DB, Shift>) -> Result<DrawingArea<DB, Shift>, Box<dyn std::error::Error>>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        let Some(footer) = &self.footer else {
+            return Ok(root.clone());
+        };
+        let size = (3 * self.label_size / 4).max(8);
+        let (_, height) = root.dim_in_pixel();
+        let (chart, strip) = root.split_vertically(height.saturating_sub(size + self.margin));
+        let style = (self.font, size).into_font().color(&BLACK.mix(0.6));
+        strip.draw(&Text::new(footer.clone(), (self.margin as i32, (self.margin / 2) as i32), style))?;
+        Ok(chart)
+    }
+}
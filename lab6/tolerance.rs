@@ -0,0 +1,58 @@
+//!
+//! tolerance.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Per-component error tolerances for the adaptive solvers. Each state gets
+//! its own rtol and atol, so a population of 1e5 and a charge of 0.1 in
+//! the same system are each controlled on their own scale
+//!
+
+#![allow(dead_code)]
+
+#[derive(Clone, Copy, Debug)]
+pub struct Tolerance<const N: usize> {
+    pub rtol: [f64; N],
+    pub atol: [f64; N],
+}
+
+impl<const N: usize> Tolerance<N> {
+    pub fn new(rtol: [f64; N], atol: [f64; N]) -> Tolerance<N> {
+        Tolerance { rtol, atol }
+    }
+
+    ///
+    /// Same rtol and atol for every component
+    ///
+    pub fn uniform(rtol: f64, atol: f64) -> Tolerance<N> {
+        Tolerance { rtol: [rtol; N], atol: [atol; N] }
+    }
+
+    ///
+    /// Shared rtol with atol proportional to a characteristic magnitude of
+    /// each component, atol_i = rtol * scale_i
+    ///
+    pub fn scaled(rtol: f64, scale: &[f64; N]) -> Tolerance<N> {
+        Tolerance { rtol: [rtol; N], atol: std::array::from_fn(|i| rtol * scale[i].abs()) }
+    }
+
+    ///
+    /// Loosest relative tolerance, for heuristics that need a single value
+    ///
+    pub fn rtol_max(&self) -> f64 {
+        self.rtol.iter().copied().fold(0.0, f64::max)
+    }
+
+    ///
+    /// Error weight of component i across a step from y0 to y1
+    ///
+    pub fn scale(&self, i: usize, y0: &[f64; N], y1: &[f64; N]) -> f64 {
+        self.atol[i] + self.rtol[i] * y0[i].abs().max(y1[i].abs())
+    }
+
+    ///
+    /// Weighted RMS norm, a step is acceptable when this is at most 1
+    ///
+    pub fn norm(&self, err: &[f64; N], y0: &[f64; N], y1: &[f64; N]) -> f64 {
+        let sum: f64 = (0..N).map(|i| (err[i] / self.scale(i, y0, y1)).powi(2)).sum();
+        (sum / N as f64).sqrt()
+    }
+}
@@ -0,0 +1,219 @@
+//!
+//! trajectory.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Columnar (struct of arrays) trajectory storage. Time and every state
+//! component live in their own contiguous Vec, so a per-component pass
+//! (plotting, extrema, export) reads one dense slice instead of striding
+//! through [f64; N] rows. Trajectories are immutable once built and are
+//! shared between threads behind an Arc without copying
+//!
+//! Between the stored points a trajectory is probed through a natural
+//! cubic spline per component, built on first use and kept until the next
+//! push: the state and its rate at any t, and the local extrema over a t
+//! window at the zeros of the spline's derivative rather than at samples
+//!
+
+#![allow(dead_code)]
+
+use std::sync::{Arc, OnceLock};
+
+use crate::export;
+use crate::peaks::{Extremum, Kind};
+use crate::spline::{Interpolation, Spline};
+
+#[derive(Clone, Debug)]
+pub struct Trajectory<const N: usize> {
+    t: Vec<f64>,
+    y: [Vec<f64>; N],
+    /// one spline per component for the probes, empty until asked for
+    splines: OnceLock<Vec<Spline>>,
+}
+
+///
+/// Read-only handle safe to clone into other threads
+///
+pub type SharedTrajectory<const N: usize> = Arc<Trajectory<N>>;
+
+impl<const N: usize> Trajectory<N> {
+    pub fn with_capacity(n: usize) -> Trajectory<N> {
+        Trajectory { t: Vec::with_capacity(n), y: std::array::from_fn(|_| Vec::with_capacity(n)), splines: OnceLock::new() }
+    }
+
+    ///
+    /// Transposes the row layout every solver returns
+    ///
+    pub fn from_rows(t: &[f64], rows: &[[f64; N]]) -> Trajectory<N> {
+        let n = t.len().min(rows.len());
+        let mut out = Trajectory::with_capacity(n);
+        for i in 0..n {
+            out.push(t[i], &rows[i]);
+        }
+        out
+    }
+
+    pub fn push(&mut self, t: f64, y: &[f64; N]) {
+        self.splines.take();
+        self.t.push(t);
+        for (col, x) in self.y.iter_mut().zip(y.iter()) {
+            col.push(*x);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.t.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.t.is_empty()
+    }
+
+    pub fn time(&self) -> &[f64] {
+        &self.t
+    }
+
+    ///
+    /// Zero-copy view of component j
+    ///
+    pub fn component(&self, j: usize) -> &[f64] {
+        &self.y[j]
+    }
+
+    ///
+    /// (t, y_j) pairs for plotting
+    ///
+    pub fn points(&self, j: usize) -> impl Iterator<Item = (f64, f64)> + '_ {
+        self.t.iter().copied().zip(self.y[j].iter().copied())
+    }
+
+    pub fn row(&self, i: usize) -> [f64; N] {
+        std::array::from_fn(|j| self.y[j][i])
+    }
+
+    pub fn last(&self) -> Option<[f64; N]> {
+        (!self.is_empty()).then(|| self.row(self.len() - 1))
+    }
+
+    ///
+    /// Back to rows, for the exporters and solvers that take [f64; N] slices
+    ///
+    pub fn to_rows(&self) -> Vec<[f64; N]> {
+        (0..self.len()).map(|i| self.row(i)).collect()
+    }
+
+    ///
+    /// Min and max over every component, for shared plot axes
+    ///
+    pub fn bounds(&self) -> (f64, f64) {
+        self.y
+            .iter()
+            .flat_map(|col| col.iter())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), x| (lo.min(*x), hi.max(*x)))
+    }
+
+    ///
+    /// The probe splines, built on the first call. Needs strictly
+    /// increasing times, so a trajectory with an impulse (a repeated time)
+    /// has to be split at it first
+    ///
+    fn splines(&self) -> Result<&[Spline], Box<dyn std::error::Error>> {
+        if let Some(splines) = self.splines.get() {
+            return Ok(splines);
+        }
+        let built = (0..N).map(|j| Spline::new(&self.t, &self.y[j], Interpolation::Cubic)).collect::<Result<Vec<_>, _>>()?;
+        Ok(self.splines.get_or_init(|| built))
+    }
+
+    fn check_span(&self, t: f64) -> Result<(), Box<dyn std::error::Error>> {
+        match (self.t.first(), self.t.last()) {
+            (Some(t0), Some(tf)) if (*t0..=*tf).contains(&t) => Ok(()),
+            _ => Err(format!("t = {t} is outside the trajectory's span").into()),
+        }
+    }
+
+    ///
+    /// State at t, anywhere inside the span
+    ///
+    pub fn at(&self, t: f64) -> Result<[f64; N], Box<dyn std::error::Error>> {
+        self.check_span(t)?;
+        let splines = self.splines()?;
+        Ok(std::array::from_fn(|j| splines[j].eval(t)))
+    }
+
+    ///
+    /// dy/dt at t from the splines, not from the rate function, so it
+    /// carries the interpolation's error as well as the solver's
+    ///
+    pub fn derivative_at(&self, t: f64) -> Result<[f64; N], Box<dyn std::error::Error>> {
+        self.check_span(t)?;
+        let splines = self.splines()?;
+        Ok(std::array::from_fn(|j| splines[j].derivative(t)))
+    }
+
+    ///
+    /// Local extrema of component j with t in range, in time order: the
+    /// zeros of the spline's derivative, quadratic on each interval, where
+    /// it changes sign
+    ///
+    pub fn extrema_in(&self, j: usize, range: [f64; 2]) -> Result<Vec<Extremum>, Box<dyn std::error::Error>> {
+        let [lo, hi] = range;
+        if j >= N || lo.is_nan() || hi.is_nan() || hi < lo {
+            return Err(format!("no extrema of component {j} over [{lo}, {hi}]").into());
+        }
+        let spline = &self.splines()?[j];
+        let t = &self.t;
+        let mut out = Vec::new();
+        for i in t.partition_point(|s| *s <= lo).saturating_sub(1)..t.len() - 1 {
+            let (a, b) = (t[i], t[i + 1]);
+            if a > hi {
+                break;
+            }
+            // the derivative through its values at both ends and the middle
+            let (d0, dm, d1) = (spline.derivative(a), spline.derivative(0.5 * (a + b)), spline.derivative(b));
+            let (c2, c1) = (2.0 * (d0 - 2.0 * dm + d1), -3.0 * d0 + 4.0 * dm - d1);
+            let roots = if c2 == 0.0 {
+                vec![-d0 / c1]
+            } else {
+                let disc = c1 * c1 - 4.0 * c2 * d0;
+                if disc < 0.0 {
+                    continue;
+                }
+                let q = -0.5 * (c1 + c1.signum() * disc.sqrt());
+                let mut r = vec![q / c2, d0 / q];
+                r.sort_by(f64::total_cmp);
+                r
+            };
+            // u in [0, 1) so a zero on a knot is found once
+            for u in roots.into_iter().filter(|u| (0.0..1.0).contains(u)) {
+                let s = a + u * (b - a);
+                if s < lo || s > hi {
+                    continue;
+                }
+                let (before, after) = (spline.derivative(s - 1e-6 * (b - a)), spline.derivative(s + 1e-6 * (b - a)));
+                let kind = match (before > 0.0, after > 0.0) {
+                    (true, false) => Kind::Peak,
+                    (false, true) => Kind::Trough,
+                    _ => continue,
+                };
+                out.push(Extremum { t: s, value: spline.eval(s), kind });
+            }
+        }
+        Ok(out)
+    }
+
+    pub fn share(self) -> SharedTrajectory<N> {
+        Arc::new(self)
+    }
+
+    ///
+    /// Writes `{stem}_t.npy` and one `{stem}_{name}.npy` per component as
+    /// flat 1-D arrays. Each column is contiguous on disk, so
+    /// np.load(path, mmap_mode="r") maps it without reading the file
+    ///
+    pub fn write_columns(&self, stem: &str, names: &[String; N]) -> Result<(), Box<dyn std::error::Error>> {
+        export::write_npy_column(&format!("{stem}_t.npy"), &self.t)?;
+        for (name, col) in names.iter().zip(self.y.iter()) {
+            export::write_npy_column(&format!("{stem}_{name}.npy"), col)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+//!
+//! two_body.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Planar two-body problem in the relative coordinate, a particle in the
+//! central field -mu r / |r|^3, state [x, y, vx, vy]. Energy, angular
+//! momentum and the Laplace-Runge-Lenz vector are conserved exactly, so
+//! their drift measures the integrator alone. The state is laid out as
+//! [q, v] with q' = v for the leapfrog step
+//!
+
+#![allow(dead_code)]
+
+use std::f64::consts::PI;
+
+use crate::state::StateVar;
+
+pub const STATES: [StateVar; 4] = [
+    StateVar::new("x", "length"),
+    StateVar::new("y", "length"),
+    StateVar::new("vx", "length/time"),
+    StateVar::new("vy", "length/time"),
+];
+
+#[derive(Clone, Copy, Debug)]
+pub struct TwoBody {
+    /// gravitational parameter G (m1 + m2)
+    pub mu: f64,
+}
+
+impl TwoBody {
+    pub fn rate(&self, _t: f64, s: &[f64; 4], ds: &mut [f64; 4]) {
+        let r3 = s[0].hypot(s[1]).powi(3);
+        ds[0] = s[2];
+        ds[1] = s[3];
+        ds[2] = -self.mu * s[0] / r3;
+        ds[3] = -self.mu * s[1] / r3;
+    }
+
+    ///
+    /// Specific orbital energy v^2 / 2 - mu / r
+    ///
+    pub fn energy(&self, s: &[f64; 4]) -> f64 {
+        0.5 * (s[2] * s[2] + s[3] * s[3]) - self.mu / s[0].hypot(s[1])
+    }
+
+    ///
+    /// a = -mu / (2 E), from the energy alone
+    ///
+    pub fn semi_major_axis(&self, s: &[f64; 4]) -> f64 {
+        -self.mu / (2.0 * self.energy(s))
+    }
+
+    ///
+    /// Specific angular momentum x vy - y vx
+    ///
+    pub fn angular_momentum(s: &[f64; 4]) -> f64 {
+        s[0] * s[3] - s[1] * s[2]
+    }
+
+    ///
+    /// Eccentricity vector (v x h) / mu - r / |r|, pointing at periapsis
+    ///
+    pub fn eccentricity(&self, s: &[f64; 4]) -> [f64; 2] {
+        let h = TwoBody::angular_momentum(s);
+        let r = s[0].hypot(s[1]);
+        [s[3] * h / self.mu - s[0] / r, -s[2] * h / self.mu - s[1] / r]
+    }
+
+    ///
+    /// Start at periapsis on the x axis of an ellipse with semi-major axis
+    /// a and eccentricity e, moving counterclockwise
+    ///
+    pub fn periapsis(&self, a: f64, e: f64) -> Result<[f64; 4], Box<dyn std::error::Error>> {
+        if !a.is_finite() || a <= 0.0 || !(0.0..1.0).contains(&e) {
+            return Err(format!("two body: need a > 0 and 0 <= e < 1 for an ellipse, got a = {a}, e = {e}").into());
+        }
+        let rp = a * (1.0 - e);
+        Ok([rp, 0.0, 0.0, (self.mu * (1.0 + e) / rp).sqrt()])
+    }
+
+    ///
+    /// Kepler period 2 pi sqrt(a^3 / mu)
+    ///
+    pub fn period(&self, a: f64) -> f64 {
+        2.0 * PI * (a.powi(3) / self.mu).sqrt()
+    }
+}
@@ -0,0 +1,117 @@
+//!
+//! units.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Typed units for model parameters. Every rate carries its time unit as a
+//! type parameter, so a per-year interaction coefficient cannot be passed
+//! where the rest of the model is per-day without an explicit conversion;
+//! the mismatch is a compile error instead of a silently wrong plot
+//!
+
+#![allow(dead_code)]
+
+use std::marker::PhantomData;
+
+pub trait TimeUnit: Copy {
+    const NAME: &'static str;
+    const DAYS: f64;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Day;
+
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Year;
+
+impl TimeUnit for Day {
+    const NAME: &'static str = "day";
+    const DAYS: f64 = 1.0;
+}
+
+impl TimeUnit for Year {
+    const NAME: &'static str = "year";
+    const DAYS: f64 = 365.25;
+}
+
+///
+/// Number of individuals, independent of the time unit
+///
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Population(pub f64);
+
+///
+/// A point or span in time measured in U
+///
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Time<U: TimeUnit>(f64, PhantomData<U>);
+
+///
+/// Per-capita growth rate, 1 / U
+///
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Rate<U: TimeUnit>(f64, PhantomData<U>);
+
+///
+/// Per-capita effect of one individual, 1 / (individual U)
+///
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Interaction<U: TimeUnit>(f64, PhantomData<U>);
+
+impl<U: TimeUnit> Time<U> {
+    pub fn new(value: f64) -> Time<U> {
+        Time(value, PhantomData)
+    }
+
+    pub fn value(self) -> f64 {
+        self.0
+    }
+
+    pub fn to<V: TimeUnit>(self) -> Time<V> {
+        Time::new(self.0 * U::DAYS / V::DAYS)
+    }
+}
+
+impl<U: TimeUnit> Rate<U> {
+    pub fn new(value: f64) -> Rate<U> {
+        Rate(value, PhantomData)
+    }
+
+    pub fn value(self) -> f64 {
+        self.0
+    }
+
+    pub fn to<V: TimeUnit>(self) -> Rate<V> {
+        Rate::new(self.0 * V::DAYS / U::DAYS)
+    }
+}
+
+impl<U: TimeUnit> Interaction<U> {
+    pub fn new(value: f64) -> Interaction<U> {
+        Interaction(value, PhantomData)
+    }
+
+    pub fn value(self) -> f64 {
+        self.0
+    }
+
+    pub fn to<V: TimeUnit>(self) -> Interaction<V> {
+        Interaction::new(self.0 * V::DAYS / U::DAYS)
+    }
+}
+
+impl<U: TimeUnit> std::fmt::Display for Rate<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} / {}", self.0, U::NAME)
+    }
+}
+
+impl<U: TimeUnit> std::fmt::Display for Interaction<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} / (individual {})", self.0, U::NAME)
+    }
+}
+
+impl<U: TimeUnit> std::fmt::Display for Time<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} {}", self.0, U::NAME)
+    }
+}
@@ -0,0 +1,31 @@
+//!
+//! competition.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! The two species competition rate the ecosystem lab is built on,
+//! N_i' = N_i (a_i - b_i N_i - c_i N_j), as a plain function of the
+//! parameters so it runs without the lab's builder, forcing schedules or
+//! plotting. core only, so it builds no_std with the solvers crate
+//!
+
+#![allow(dead_code)]
+
+///
+/// Parameters of the unforced model, per unit time
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Competition {
+    pub a: [f64; 2],
+    pub b: [f64; 2],
+    pub c: [f64; 2],
+}
+
+impl Competition {
+    pub fn rate(&self, _t: f64, pop: &[f64; 2], d_pop: &mut [f64; 2]) {
+        rate(&self.a, &self.b, &self.c, pop, d_pop);
+    }
+}
+
+pub fn rate(a: &[f64; 2], b: &[f64; 2], c: &[f64; 2], pop: &[f64; 2], d_pop: &mut [f64; 2]) {
+    d_pop[0] = pop[0] * (a[0] - b[0] * pop[0] - c[0] * pop[1]);
+    d_pop[1] = pop[1] * (a[1] - b[1] * pop[1] - c[1] * pop[0]);
+}
@@ -0,0 +1,493 @@
+//!
+//! lib.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! The solvers crate: the integrators every lab shares, so each lab
+//! depends on one tested implementation instead of copying the stepping
+//! logic into its main. At the root are the fixed-step explicit one-step
+//! methods, generic over the state dimension. Rate functions take
+//! (t, y, dy) so any system (parameters captured in a closure, or a lab's
+//! OdeSystem rhs()) plugs into every method. multistep holds the AB/AM4
+//! predictor-corrector, nordsieck the scaled derivative array it steps,
+//! linalg the dense LU, QR and eigenvalue kernels, limits the step, memory
+//! and wall clock limits, state_vec the lazy state arithmetic, and
+//! competition the ecosystem lab's rate.
+//!
+//! With the default `std` feature off the crate builds as no_std + alloc,
+//! so the rk4 ecosystem model, and the linear algebra an implicit method
+//! needs, run on a microcontroller with a global allocator. Nothing here
+//! calls the float library (linalg brings its own sqrt); only
+//! limits::Guard, which reads the clock, needs std
+//!
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod competition;
+pub mod limits;
+pub mod linalg;
+pub mod multistep;
+pub mod nordsieck;
+pub mod state_vec;
+
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+pub use limits::Guard;
+pub use limits::{bytes, human, Limits};
+
+use crate::state_vec::{Expr, StateVec};
+
+///
+/// Time vector and solution, the return shape every solver shares
+///
+pub type Solution<const N: usize> = (Vec<f64>, Vec<[f64; N]>);
+
+/// halvings of one step the Halve policy tries before giving up
+pub const MAX_HALVINGS: usize = 10;
+
+///
+/// What an implicit or corrected method does when its corrector fails to
+/// converge on a step
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CorrectorFailure {
+    /// retry the step as two halves, recursively
+    Halve,
+    /// take the last iterate and warn
+    Accept,
+    /// return an error
+    #[default]
+    Abort,
+}
+
+impl CorrectorFailure {
+    pub const ALL: [CorrectorFailure; 3] = [CorrectorFailure::Halve, CorrectorFailure::Accept, CorrectorFailure::Abort];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            CorrectorFailure::Halve  => "halve",
+            CorrectorFailure::Accept => "accept",
+            CorrectorFailure::Abort  => "abort",
+        }
+    }
+}
+
+///
+/// Work done by a solve, counted where it happens rather than inferred
+/// from the step count, so cost comparisons between explicit, adaptive
+/// and implicit methods count the same things. Fields a method has no use
+/// for stay zero. Method::solve, AB/AM4, the Adams and IMEX solvers,
+/// multirate (inside its MultirateStats) and the Taylor, interval and
+/// spectral solvers all return one
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SolveStats {
+    pub accepted: usize,
+    pub rejected: usize,
+    pub rate_evals: usize,
+    pub jacobian_evals: usize,
+    pub factorizations: usize,
+    pub linear_solves: usize,
+    pub newton_iters: usize,
+    /// steps whose corrector did not converge, and what was done about it
+    pub corrector_failures: usize,
+    pub on_failure: CorrectorFailure,
+}
+
+impl core::ops::AddAssign for SolveStats {
+    fn add_assign(&mut self, other: SolveStats) {
+        self.accepted += other.accepted;
+        self.rejected += other.rejected;
+        self.rate_evals += other.rate_evals;
+        self.jacobian_evals += other.jacobian_evals;
+        self.factorizations += other.factorizations;
+        self.linear_solves += other.linear_solves;
+        self.newton_iters += other.newton_iters;
+        self.corrector_failures += other.corrector_failures;
+    }
+}
+
+///
+/// u = y + h k
+///
+pub fn axpy<const N: usize>(y: &[f64; N], h: f64, k: &[f64; N]) -> [f64; N] {
+    core::array::from_fn(|i| y[i] + h * k[i])
+}
+
+///
+/// Whole steps of dt in ts. A quotient within roundoff of an integer is
+/// that integer, so 0.3 / 0.1 is three steps and not two; otherwise the
+/// cast truncates, which is floor for a non-negative span. The nearest
+/// integer is taken by a cast of n + 1/2, f64::round being std only
+///
+pub fn step_count(ts: [f64; 2], dt: f64) -> usize {
+    let n = (ts[1] - ts[0]) / dt;
+    let nearest = (n + 0.5) as usize;
+    if (n - nearest as f64).abs() <= 1e-9 * n.abs().max(1.0) {
+        nearest
+    } else {
+        n as usize
+    }
+}
+
+///
+/// Length of the shortened last step landing on ts[1] when dt does not
+/// divide the span, None when the whole steps already reach it
+///
+pub fn final_step(ts: [f64; 2], dt: f64) -> Option<f64> {
+    let h = ts[1] - (ts[0] + (step_count(ts, dt) as f64) * dt);
+    (h > 1e-9 * dt).then_some(h)
+}
+
+///
+/// Every step over ts as (t, h, t + h): the whole steps of dt from ts[0],
+/// then the shortened one when dt does not divide the span. The last step
+/// ends on ts[1] itself, not on ts[0] + n dt, so a run finishes exactly on
+/// the end it was asked for
+///
+pub fn grid(ts: [f64; 2], dt: f64) -> impl Iterator<Item = (f64, f64, f64)> {
+    let (n, last) = (step_count(ts, dt), final_step(ts, dt));
+    let at = move |i: usize| if i == n && last.is_none() { ts[1] } else { ts[0] + (i as f64) * dt };
+    (0..n).map(move |i| (at(i), dt, at(i + 1))).chain(last.map(|h| (at(n), h, ts[1])))
+}
+
+///
+/// Steps a fixed-step solve named what takes over ts, the shortened last
+/// one included, once dt is a positive step and the count is within
+/// limits. Every fixed-step loop asks this before it allocates
+///
+pub fn planned_steps(what: &str, ts: [f64; 2], dt: f64, limits: Limits) -> Result<usize, Box<dyn core::error::Error>> {
+    if !(dt.is_finite() && dt > 0.0) {
+        return Err(format!("{what}: dt = {dt} is not a positive step").into());
+    }
+    if !(ts[0].is_finite() && ts[1].is_finite() && ts[1] >= ts[0]) {
+        return Err(format!("{what}: the span {ts:?} is not a finite forward interval").into());
+    }
+    let n = step_count(ts, dt).saturating_add(usize::from(final_step(ts, dt).is_some()));
+    limits.check_steps(what, n)?;
+    Ok(n)
+}
+
+///
+/// Forward Euler, order 1
+///
+pub fn euler_step<F, const N: usize>(rate: &F, t: f64, y: &[f64; N], h: f64) -> [f64; N]
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    let (y, mut k1) = (StateVec(*y), StateVec::zeros());
+    rate(t, &y, &mut k1);
+    (&y + h * &k1).eval().0
+}
+
+///
+/// Modified Euler (Heun), trapezoid with an Euler predictor, order 2
+///
+pub fn heun_step<F, const N: usize>(rate: &F, t: f64, y: &[f64; N], h: f64) -> [f64; N]
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    let (y, mut k1, mut k2) = (StateVec(*y), StateVec::zeros(), StateVec::zeros());
+    rate(t, &y, &mut k1);
+    rate(t + h, &(&y + h * &k1).eval(), &mut k2);
+    (&y + 0.5 * h * (&k1 + &k2)).eval().0
+}
+
+///
+/// Explicit midpoint, order 2
+///
+pub fn midpoint_step<F, const N: usize>(rate: &F, t: f64, y: &[f64; N], h: f64) -> [f64; N]
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    let (y, mut k1, mut k2) = (StateVec(*y), StateVec::zeros(), StateVec::zeros());
+    rate(t, &y, &mut k1);
+    rate(t + 0.5 * h, &(&y + 0.5 * h * &k1).eval(), &mut k2);
+    (&y + h * &k2).eval().0
+}
+
+///
+/// Classical Runge-Kutta, order 4. Every stage is taken from y itself
+///
+pub fn rk4_step<F, const N: usize>(rate: &F, t: f64, y: &[f64; N], h: f64) -> [f64; N]
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    let y = StateVec(*y);
+    let (mut k1, mut k2, mut k3, mut k4) = (StateVec::zeros(), StateVec::zeros(), StateVec::zeros(), StateVec::zeros());
+    rate(t, &y, &mut k1);
+    rate(t + 0.5 * h, &(&y + 0.5 * h * &k1).eval(), &mut k2);
+    rate(t + 0.5 * h, &(&y + 0.5 * h * &k2).eval(), &mut k3);
+    rate(t + h, &(&y + h * &k3).eval(), &mut k4);
+    (&y + h / 6.0 * (&k1 + 2.0 * &k2 + 2.0 * &k3 + &k4)).eval().0
+}
+
+///
+/// Stormer-Verlet (leapfrog, kick-drift-kick), order 2 and symplectic.
+/// Only for separable second order systems with state [q, v] split in
+/// halves, where the rate returns q' = v and a v' that depends on q alone
+///
+pub fn leapfrog_step<F, const N: usize>(rate: &F, t: f64, y: &[f64; N], h: f64) -> [f64; N]
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    let m = N / 2;
+    let mut k = [0.0; N];
+    rate(t, y, &mut k);
+    let mut out = *y;
+    for i in 0..m {
+        out[m + i] += 0.5 * h * k[m + i];
+    }
+    for i in 0..m {
+        out[i] += h * out[m + i];
+    }
+    rate(t + h, &out, &mut k);
+    for i in 0..m {
+        out[m + i] += 0.5 * h * k[m + i];
+    }
+    out
+}
+
+///
+/// Drives any one-step method over ts with a fixed dt, its steps and the
+/// size of the stored trajectory checked against limits first
+///
+pub fn integrate<F, S, const N: usize>(rate: &F, step: S, ic: [f64; N], ts: [f64; 2], dt: f64, limits: Limits)
+    -> Result<Solution<N>, Box<dyn core::error::Error>>
+where
+    F: Fn(f64, &[f64; N], &mut [f64; N]),
+    S: Fn(&F, f64, &[f64; N], f64) -> [f64; N]
+{
+    let n = planned_steps("integrate", ts, dt, limits)?;
+    limits.check::<N>("integrate", n)?;
+    let mut t: Vec<f64> = Vec::with_capacity(n + 1);
+    let mut y: Vec<[f64; N]> = Vec::with_capacity(n + 1);
+    integrate_with(rate, step, ic, ts, dt, limits, |ti, yi| {
+        t.push(ti);
+        y.push(*yi);
+    })?;
+    Ok((t, y))
+}
+
+///
+/// Same stepping as integrate, but every point (the initial condition
+/// included) is handed to `observe` instead of being stored, so the caller
+/// decides what to keep. When dt does not divide the span a last,
+/// shortened step lands on ts[1]. A dt that is not a positive step, or
+/// more steps than limits allow, is refused before the first step; with
+/// std the wall clock limit is checked as it runs. Returns the final state
+///
+pub fn integrate_with<F, S, O, const N: usize>(
+    rate: &F,
+    step: S,
+    ic: [f64; N],
+    ts: [f64; 2],
+    dt: f64,
+    limits: Limits,
+    mut observe: O) -> Result<[f64; N], Box<dyn core::error::Error>>
+where
+    F: Fn(f64, &[f64; N], &mut [f64; N]),
+    S: Fn(&F, f64, &[f64; N], f64) -> [f64; N],
+    O: FnMut(f64, &[f64; N])
+{
+    planned_steps("integrate", ts, dt, limits)?;
+    // nothing is stored here, so only the count and the clock are guarded
+    #[cfg(feature = "std")]
+    let mut guard = limits.memory(usize::MAX).start::<N>();
+    let mut y = ic;
+    observe(ts[0], &y);
+    for (t, h, next) in grid(ts, dt) {
+        #[cfg(feature = "std")]
+        guard.step("integrate", t)?;
+        y = step(rate, t, &y, h);
+        observe(next, &y);
+    }
+    Ok(y)
+}
+
+///
+/// The explicit one-step family, ordered by convergence order
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Method {
+    Euler,
+    Heun,
+    Midpoint,
+    Rk4,
+}
+
+impl Method {
+    pub const ALL: [Method; 4] = [Method::Euler, Method::Heun, Method::Midpoint, Method::Rk4];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Method::Euler    => "euler",
+            Method::Heun     => "heun",
+            Method::Midpoint => "midpoint",
+            Method::Rk4      => "rk4",
+        }
+    }
+
+    pub fn order(self) -> u32 {
+        match self {
+            Method::Euler => 1,
+            Method::Heun | Method::Midpoint => 2,
+            Method::Rk4 => 4,
+        }
+    }
+
+    ///
+    /// Rate evaluations per step
+    ///
+    pub fn stages(self) -> usize {
+        match self {
+            Method::Euler => 1,
+            Method::Heun | Method::Midpoint => 2,
+            Method::Rk4 => 4,
+        }
+    }
+
+    pub fn step<F, const N: usize>(self, rate: &F, t: f64, y: &[f64; N], h: f64) -> [f64; N]
+    where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+        match self {
+            Method::Euler    => euler_step(rate, t, y, h),
+            Method::Heun     => heun_step(rate, t, y, h),
+            Method::Midpoint => midpoint_step(rate, t, y, h),
+            Method::Rk4      => rk4_step(rate, t, y, h),
+        }
+    }
+
+    ///
+    /// Stored solve over ts under the default Limits
+    ///
+    pub fn solve<F, const N: usize>(self, rate: &F, ic: [f64; N], ts: [f64; 2], dt: f64)
+        -> Result<(Solution<N>, SolveStats), Box<dyn core::error::Error>>
+    where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+        self.solve_limited(rate, ic, ts, dt, Limits::default())
+    }
+
+    ///
+    /// Stored solve over ts, with every call of rate counted
+    ///
+    pub fn solve_limited<F, const N: usize>(self, rate: &F, ic: [f64; N], ts: [f64; 2], dt: f64, limits: Limits)
+        -> Result<(Solution<N>, SolveStats), Box<dyn core::error::Error>>
+    where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+        let calls = core::cell::Cell::new(0);
+        let counted = |t: f64, y: &[f64; N], dy: &mut [f64; N]| {
+            calls.set(calls.get() + 1);
+            rate(t, y, dy);
+        };
+        let sol = integrate(&counted, |r: &_, t, y: &[f64; N], h| self.step(r, t, y, h), ic, ts, dt, limits)?;
+        let stats = SolveStats { accepted: sol.0.len() - 1, rate_evals: calls.get(), ..SolveStats::default() };
+        Ok((sol, stats))
+    }
+
+    ///
+    /// Streams every point to `observe` rather than storing the trajectory,
+    /// under the default Limits
+    ///
+    pub fn solve_with<F, O, const N: usize>(self, rate: &F, ic: [f64; N], ts: [f64; 2], dt: f64, observe: O)
+        -> Result<[f64; N], Box<dyn core::error::Error>>
+    where
+        F: Fn(f64, &[f64; N], &mut [f64; N]),
+        O: FnMut(f64, &[f64; N])
+    {
+        integrate_with(rate, |r: &F, t, y: &[f64; N], h| self.step(r, t, y, h), ic, ts, dt, Limits::default(), observe)
+    }
+}
+
+///
+/// Raw Euler trajectory, the locally extrapolated trajectory, and the
+/// per-step Richardson error estimate |y_{h/2} - y_h|
+///
+pub struct Extrapolated<const N: usize> {
+    pub t: Vec<f64>,
+    pub raw: Vec<[f64; N]>,
+    pub extrapolated: Vec<[f64; N]>,
+    pub estimate: Vec<f64>,
+}
+
+///
+/// Euler with Richardson extrapolation every step. From the current
+/// extrapolated state one step of h and two of h/2 are taken and combined
+/// as 2 y_{h/2} - y_h, cancelling the O(h) error term for an order 2
+/// method. The plain Euler trajectory at h is carried alongside for contrast.
+/// A span dt does not divide ends with a shortened step onto ts[1]; a dt
+/// that is not a positive step, or a run past the default Limits, is refused
+///
+pub fn extrapolated_euler<F, const N: usize>(rate: &F, ic: [f64; N], ts: [f64; 2], dt: f64)
+    -> Result<Extrapolated<N>, Box<dyn core::error::Error>>
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    let n = planned_steps("extrapolated euler", ts, dt, Limits::default())?;
+    Limits::default().check::<N>("extrapolated euler", n)?;
+    let mut out = Extrapolated {
+        t: Vec::with_capacity(n + 1),
+        raw: Vec::with_capacity(n + 1),
+        extrapolated: Vec::with_capacity(n + 1),
+        estimate: Vec::with_capacity(n + 1),
+    };
+    out.t.push(ts[0]);
+    out.raw.push(ic);
+    out.extrapolated.push(ic);
+    out.estimate.push(0.0);
+
+    for (i, (ti, h, next)) in grid(ts, dt).enumerate() {
+        out.raw.push(euler_step(rate, ti, &out.raw[i], h));
+
+        let w = out.extrapolated[i];
+        let full = euler_step(rate, ti, &w, h);
+        let half = euler_step(rate, ti, &w, 0.5 * h);
+        let half = euler_step(rate, ti + 0.5 * h, &half, 0.5 * h);
+
+        out.estimate.push(
+            half.iter().zip(full.iter()).map(|(a, b)| (a - b).abs()).fold(0.0, f64::max));
+        out.extrapolated.push(core::array::from_fn(|j| 2.0 * half[j] - full[j]));
+        out.t.push(next);
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_count_takes_a_roundoff_quotient_as_whole() {
+        assert_eq!(step_count([0.0, 0.3], 0.1), 3);
+        assert_eq!(final_step([0.0, 0.3], 0.1), None);
+        assert_eq!(step_count([1.0, 1.7], 0.1), 7);
+    }
+
+    #[test]
+    fn dt_off_the_span_ends_on_a_shortened_step() {
+        assert_eq!(step_count([0.0, 1.0], 0.3), 3);
+        let h = final_step([0.0, 1.0], 0.3).unwrap();
+        assert!((h - 0.1).abs() < 1e-12);
+        assert_eq!(step_count([0.0, 0.05], 0.1), 0);
+    }
+
+    #[test]
+    fn integrate_refuses_a_run_past_its_limits() {
+        let decay = |_t: f64, y: &[f64; 1], dy: &mut [f64; 1]| dy[0] = -y[0];
+        let limits = Limits { max_steps: 10, ..Limits::default() };
+        assert!(integrate(&decay, rk4_step, [1.0], [0.0, 1.0], 0.1, limits).is_ok());
+        assert!(integrate(&decay, rk4_step, [1.0], [0.0, 1.0], 0.05, limits).is_err());
+        assert!(integrate(&decay, rk4_step, [1.0], [0.0, 1.0], 0.0, Limits::default()).is_err());
+        assert!(Method::Rk4.solve(&decay, [1.0], [0.0, 1.0], f64::NAN).is_err());
+        assert!(Method::Rk4.solve(&decay, [1.0], [0.0, 1.0], 1e-300).is_err());
+    }
+
+    #[test]
+    fn integrate_refuses_a_trajectory_over_the_memory_budget() {
+        let decay = |_t: f64, y: &[f64; 1], dy: &mut [f64; 1]| dy[0] = -y[0];
+        // 11 points of t and one state
+        let limits = Limits::default().memory(bytes::<1>(10));
+        assert!(integrate(&decay, rk4_step, [1.0], [0.0, 1.0], 0.1, limits).is_ok());
+        assert!(integrate(&decay, rk4_step, [1.0], [0.0, 1.0], 0.05, limits).is_err());
+        // streaming stores nothing, so only the step count applies
+        assert!(integrate_with(&decay, rk4_step, [1.0], [0.0, 1.0], 0.05, limits, |_, _| {}).is_ok());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn integrate_with_stops_at_the_wall_clock() {
+        let decay = |_t: f64, y: &[f64; 1], dy: &mut [f64; 1]| dy[0] = -y[0];
+        let limits = Limits::default().wall(core::time::Duration::ZERO);
+        let err = integrate_with(&decay, rk4_step, [1.0], [0.0, 1.0], 1e-3, limits, |_, _| {}).unwrap_err();
+        assert!(err.to_string().contains("wall clock"));
+    }
+}
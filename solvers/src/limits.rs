@@ -0,0 +1,195 @@
+//!
+//! limits.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Step count, memory and wall clock limits for the solve loops. A dt
+//! typed a few orders too small, or an adaptive solver stuck rejecting
+//! near a singularity, otherwise runs until the trajectory Vec has taken
+//! all the memory there is. A fixed-step solve knows its step count before
+//! it starts and is refused up front, on the count or on the estimated
+//! size of its trajectory, core only. A loop that only learns as it goes
+//! carries a Guard and checks it once per attempted step, the wall clock
+//! included; Guard reads the clock, so it needs the std feature
+//!
+
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Limits {
+    /// attempted steps, rejected ones included
+    pub max_steps: usize,
+    /// bytes of stored trajectory, see bytes
+    pub max_bytes: usize,
+    /// read by limits::Guard, which the loops that cannot count ahead carry
+    pub max_wall: Option<Duration>,
+}
+
+impl Default for Limits {
+    fn default() -> Limits {
+        Limits { max_steps: 10_000_000, max_bytes: 2 << 30, max_wall: Some(Duration::from_secs(600)) }
+    }
+}
+
+///
+/// Estimated memory of a stored trajectory of n steps in N states: the
+/// time and every component as f64 at each of its n + 1 points
+///
+pub fn bytes<const N: usize>(n: usize) -> usize {
+    n.saturating_add(1).saturating_mul((N + 1) * core::mem::size_of::<f64>())
+}
+
+///
+/// A byte count at a readable scale
+///
+pub fn human(bytes: usize) -> String {
+    let units = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < units.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", units[unit])
+}
+
+impl Limits {
+    pub fn unlimited() -> Limits {
+        Limits { max_steps: usize::MAX, max_bytes: usize::MAX, max_wall: None }
+    }
+
+    pub fn steps(mut self, max_steps: usize) -> Limits {
+        self.max_steps = max_steps;
+        self
+    }
+
+    pub fn wall(mut self, max_wall: Duration) -> Limits {
+        self.max_wall = Some(max_wall);
+        self
+    }
+
+    pub fn memory(mut self, max_bytes: usize) -> Limits {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    ///
+    /// A fixed-step solve of n steps against max_steps, before it starts
+    ///
+    pub fn check_steps(&self, what: &str, n: usize) -> Result<(), Box<dyn core::error::Error>> {
+        if n > self.max_steps {
+            return Err(format!("{what}: {n} steps exceed the limit of {}, is dt too small?", self.max_steps).into());
+        }
+        Ok(())
+    }
+
+    ///
+    /// Pre-flight check of a stored solve of n steps in N states, before
+    /// anything is allocated for it. A run too large to store can still
+    /// be streamed through Method::solve_with
+    ///
+    pub fn check<const N: usize>(&self, what: &str, n: usize) -> Result<(), Box<dyn core::error::Error>> {
+        self.check_steps(what, n)?;
+        let size = bytes::<N>(n);
+        if size > self.max_bytes {
+            return Err(format!("{what}: storing {n} steps needs about {}, over the budget of {}; \
+                is dt too small, or should the run be streamed?", human(size), human(self.max_bytes)).into());
+        }
+        Ok(())
+    }
+}
+
+/// wall clock is read once every this many steps
+#[cfg(feature = "std")]
+const CLOCK_EVERY: usize = 64;
+
+#[cfg(feature = "std")]
+impl Limits {
+    ///
+    /// Guard for a loop storing N states per accepted step. Rejected
+    /// attempts count against the memory budget too, so it errs high
+    ///
+    pub fn start<const N: usize>(&self) -> Guard {
+        Guard { limits: *self, start: Instant::now(), steps: 0, step_bytes: bytes::<N>(0) }
+    }
+}
+
+///
+/// Running count of one solve against its Limits
+///
+#[cfg(feature = "std")]
+pub struct Guard {
+    limits: Limits,
+    start: Instant,
+    steps: usize,
+    step_bytes: usize,
+}
+
+///
+/// Which limit a Guard passed
+///
+#[cfg(feature = "std")]
+enum Passed {
+    Steps,
+    Memory,
+    Wall(Duration),
+}
+
+#[cfg(feature = "std")]
+impl Guard {
+    ///
+    /// Counts one attempted step from t, an error once either limit is
+    /// passed
+    ///
+    pub fn step(&mut self, what: &str, t: f64) -> Result<(), Box<dyn core::error::Error>> {
+        match self.count() {
+            None => Ok(()),
+            Some(passed) => Err(self.message(what, passed, &format!(" at t = {t}")).into()),
+        }
+    }
+
+    ///
+    /// Counts one pass of a loop with no time axis, a Newton iteration or
+    /// a Picard sweep, against the same limits
+    ///
+    pub fn iteration(&mut self, what: &str) -> Result<(), Box<dyn core::error::Error>> {
+        match self.count() {
+            None => Ok(()),
+            Some(passed) => Err(self.message(what, passed, "").into()),
+        }
+    }
+
+    fn count(&mut self) -> Option<Passed> {
+        self.steps += 1;
+        if self.steps > self.limits.max_steps {
+            return Some(Passed::Steps);
+        }
+        if self.steps.saturating_mul(self.step_bytes) > self.limits.max_bytes {
+            return Some(Passed::Memory);
+        }
+        match self.limits.max_wall {
+            Some(wall) if self.steps.is_multiple_of(CLOCK_EVERY) && self.start.elapsed() > wall => Some(Passed::Wall(wall)),
+            _ => None,
+        }
+    }
+
+    fn message(&self, what: &str, passed: Passed, at: &str) -> String {
+        match passed {
+            Passed::Steps => format!("{what}: passed the limit of {} steps{at}", self.limits.max_steps),
+            Passed::Memory => format!("{what}: passed the memory budget of {}{at} after {} steps",
+                human(self.limits.max_bytes), self.steps),
+            Passed::Wall(wall) => format!("{what}: passed the {wall:?} wall clock limit{at} after {} steps", self.steps),
+        }
+    }
+
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
@@ -0,0 +1,1062 @@
+//!
+//! linalg.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Small dense linear algebra for the Newton and stability solvers: a row
+//! major matrix, LU factorization with partial pivoting, and eigenvalues of
+//! a general real matrix by Hessenberg reduction and shifted QR. Least
+//! squares goes through Householder QR with column pivoting, which orders
+//! |R_kk| decreasing so a numerical rank can be read off the diagonal and
+//! dependent columns left out of the fit. Blocks, rows and columns can be
+//! borrowed as views (MatrixView, MatrixViewMut) instead of copied, which
+//! is how the blocked products address their tiles. Solves can report a
+//! 1-norm condition estimate (Hager's, a few extra solves) and the forward
+//! error bound it implies. Only alloc is needed: the square roots of the
+//! Householder norms and the eigenvalue shifts come from sqrt below rather
+//! than the float library
+//!
+
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{Index, IndexMut};
+
+///
+/// Condition numbers above this leave fewer than 6 of the 16 digits
+///
+pub const ILL_CONDITIONED: f64 = 1e10;
+
+///
+/// Block size for mul_tiled, 64 x 64 doubles is 32 KiB, an L1 data cache
+///
+pub const TILE: usize = 64;
+
+///
+/// Condition estimate and the relative forward error bound
+/// |x - x*| / |x| <= condition * max(|b - A x| / (|A| |x|), eps), 1-norms
+///
+#[derive(Clone, Copy, Debug)]
+pub struct Report {
+    pub condition: f64,
+    pub error_bound: f64,
+}
+
+///
+/// Hager's estimate of |B|_1 for B known only through x -> B x and
+/// x -> B^T x, exact more often than not and never an overestimate
+///
+pub fn inverse_norm_estimate<S, T>(n: usize, solve: S, solve_transpose: T) -> f64
+where
+    S: Fn(&[f64]) -> Vec<f64>,
+    T: Fn(&[f64]) -> Vec<f64>
+{
+    let mut x = vec![1.0 / n as f64; n];
+    let mut estimate = 0.0;
+    for _ in 0..5 {
+        let y = solve(&x);
+        estimate = y.iter().map(|v| v.abs()).sum::<f64>();
+        let sign: Vec<f64> = y.iter().map(|v| if *v >= 0.0 { 1.0 } else { -1.0 }).collect();
+        let z = solve_transpose(&sign);
+        let j = (0..n).max_by(|&a, &b| z[a].abs().total_cmp(&z[b].abs())).unwrap_or(0);
+        let zx: f64 = z.iter().zip(&x).map(|(a, b)| a * b).sum();
+        if z[j].abs() <= zx {
+            break;
+        }
+        x = vec![0.0; n];
+        x[j] = 1.0;
+    }
+    estimate
+}
+
+///
+/// Square root by Newton's iteration from an exponent halving guess. After
+/// the first step the iterates fall monotonically onto sqrt(x), so the
+/// loop stops at the first one that does not decrease. Within an ulp of
+/// the correctly rounded root; NaN below zero
+///
+pub fn sqrt(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 || x == f64::INFINITY {
+        return x;
+    }
+    let mut y = f64::from_bits((x.to_bits() >> 1) + 0x1ff8_0000_0000_0000);
+    y = 0.5 * (y + x / y);
+    loop {
+        let next = 0.5 * (y + x / y);
+        if next >= y {
+            return y;
+        }
+        y = next;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Matrix {
+    pub rows: usize,
+    pub cols: usize,
+    data: Vec<f64>,
+}
+
+impl Matrix {
+    pub fn zeros(rows: usize, cols: usize) -> Matrix {
+        Matrix { rows, cols, data: vec![0.0; rows * cols] }
+    }
+
+    pub fn identity(n: usize) -> Matrix {
+        let mut m = Matrix::zeros(n, n);
+        for i in 0..n {
+            m[(i, i)] = 1.0;
+        }
+        m
+    }
+
+    pub fn from_rows<const C: usize>(rows: &[[f64; C]]) -> Matrix {
+        Matrix { rows: rows.len(), cols: C, data: rows.iter().flat_map(|r| r.iter().copied()).collect() }
+    }
+
+    pub fn column(&self, j: usize) -> Vec<f64> {
+        (0..self.rows).map(|i| self[(i, j)]).collect()
+    }
+
+    pub fn set_column(&mut self, j: usize, v: &[f64]) {
+        for (i, x) in v.iter().enumerate().take(self.rows) {
+            self[(i, j)] = *x;
+        }
+    }
+
+    pub fn transpose(&self) -> Matrix {
+        let mut out = Matrix::zeros(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                out[(j, i)] = self[(i, j)];
+            }
+        }
+        out
+    }
+
+    ///
+    /// Product through the tiled kernel, which below one tile is the plain
+    /// loop on slices without the per entry bounds checks. Every entry sums
+    /// its terms in the same order, so the results agree bit for bit with
+    /// mul_plain
+    ///
+    pub fn mul(&self, other: &Matrix) -> Matrix {
+        self.mul_tiled(other, TILE)
+    }
+
+    ///
+    /// i-k-j loop, streaming along rows of other and of the output
+    ///
+    pub fn mul_plain(&self, other: &Matrix) -> Matrix {
+        assert_eq!(self.cols, other.rows, "matrix dimensions do not agree");
+        let mut out = Matrix::zeros(self.rows, other.cols);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                let a = self[(i, k)];
+                for j in 0..other.cols {
+                    out[(i, j)] += a * other[(k, j)];
+                }
+            }
+        }
+        out
+    }
+
+    ///
+    /// Cache blocked product over tile x tile blocks: one block of the
+    /// output stays resident while the matching blocks of self and other
+    /// stream through it
+    ///
+    pub fn mul_tiled(&self, other: &Matrix, tile: usize) -> Matrix {
+        assert_eq!(self.cols, other.rows, "matrix dimensions do not agree");
+        let mut out = Matrix::zeros(self.rows, other.cols);
+        tiled(self.view(), other.view(), &mut out.view_mut(), tile.max(1));
+        out
+    }
+
+    ///
+    /// Cache oblivious product: halve the largest of the three dimensions
+    /// until the block is at most leaf on every side, so some level of the
+    /// recursion fits each level of the cache without knowing its size
+    ///
+    pub fn mul_recursive(&self, other: &Matrix, leaf: usize) -> Matrix {
+        assert_eq!(self.cols, other.rows, "matrix dimensions do not agree");
+        let mut out = Matrix::zeros(self.rows, other.cols);
+        recurse(self.view(), other.view(), &mut out.view_mut(), leaf.max(1));
+        out
+    }
+
+    ///
+    /// Strassen's seven half size products in place of eight, recursing
+    /// until a dimension is at most crossover and finishing with mul_tiled.
+    /// Odd sizes are padded with a zero row or column at each level. The
+    /// sums of blocks cost O(n^2) extra error, rounding differs from mul
+    ///
+    pub fn mul_strassen(&self, other: &Matrix, crossover: usize) -> Matrix {
+        assert_eq!(self.cols, other.rows, "matrix dimensions do not agree");
+        strassen(self.view(), other.view(), crossover.max(1))
+    }
+
+    ///
+    /// The whole matrix as a view
+    ///
+    pub fn view(&self) -> MatrixView<'_> {
+        MatrixView { data: &self.data, stride: self.cols, rows: self.rows, cols: self.cols }
+    }
+
+    pub fn view_mut(&mut self) -> MatrixViewMut<'_> {
+        MatrixViewMut { data: &mut self.data, stride: self.cols, rows: self.rows, cols: self.cols }
+    }
+
+    ///
+    /// rows x cols block from (r0, c0), without copying
+    ///
+    pub fn block(&self, r0: usize, c0: usize, rows: usize, cols: usize) -> MatrixView<'_> {
+        self.view().block(r0, c0, rows, cols)
+    }
+
+    pub fn block_mut(&mut self, r0: usize, c0: usize, rows: usize, cols: usize) -> MatrixViewMut<'_> {
+        self.view_mut().into_block(r0, c0, rows, cols)
+    }
+
+    pub fn row(&self, i: usize) -> &[f64] {
+        &self.data[i * self.cols..(i + 1) * self.cols]
+    }
+
+    pub fn row_mut(&mut self, i: usize) -> &mut [f64] {
+        &mut self.data[i * self.cols..(i + 1) * self.cols]
+    }
+
+    pub fn mul_vec(&self, v: &[f64]) -> Vec<f64> {
+        (0..self.rows).map(|i| (0..self.cols).map(|j| self[(i, j)] * v[j]).sum()).collect()
+    }
+
+    pub fn trace(&self) -> f64 {
+        (0..self.rows.min(self.cols)).map(|i| self[(i, i)]).sum()
+    }
+
+    ///
+    /// Solves self x = b by LU with partial pivoting
+    ///
+    pub fn solve(&self, b: &[f64]) -> Result<Vec<f64>, Box<dyn core::error::Error>> {
+        Ok(self.lu()?.solve(b))
+    }
+
+    ///
+    /// Solve with the condition estimate and forward error bound
+    ///
+    pub fn solve_report(&self, b: &[f64]) -> Result<(Vec<f64>, Report), Box<dyn core::error::Error>> {
+        let lu = self.lu()?;
+        let x = lu.solve(b);
+        let condition = lu.condition();
+        let residual: f64 = self.mul_vec(&x).iter().zip(b).map(|(ax, bi)| (ax - bi).abs()).sum();
+        let size = lu.norm * x.iter().map(|v| v.abs()).sum::<f64>();
+        let relative = if size > 0.0 { residual / size } else { 0.0 };
+        Ok((x, Report { condition, error_bound: condition * relative.max(f64::EPSILON) }))
+    }
+
+    ///
+    /// Largest absolute column sum
+    ///
+    pub fn norm_1(&self) -> f64 {
+        (0..self.cols).map(|j| (0..self.rows).map(|i| self[(i, j)].abs()).sum::<f64>()).fold(0.0, f64::max)
+    }
+
+    pub fn lu(&self) -> Result<Lu, Box<dyn core::error::Error>> {
+        if self.rows != self.cols {
+            return Err(format!("lu of a non-square {}x{} matrix", self.rows, self.cols).into());
+        }
+        let n = self.rows;
+        let mut a = self.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let scale = self.data.iter().fold(0.0_f64, |m, x| m.max(x.abs())).max(f64::MIN_POSITIVE);
+
+        for col in 0..n {
+            let piv = (col..n)
+                .max_by(|&x, &y| a[(x, col)].abs().total_cmp(&a[(y, col)].abs()))
+                .unwrap();
+            if a[(piv, col)].abs() <= 1e-14 * scale {
+                return Err(format!("matrix is singular to working precision at column {col}").into());
+            }
+            if piv != col {
+                for j in 0..n {
+                    a.data.swap(piv * n + j, col * n + j);
+                }
+                perm.swap(piv, col);
+            }
+            for r in (col + 1)..n {
+                let m = a[(r, col)] / a[(col, col)];
+                a[(r, col)] = m;
+                for j in (col + 1)..n {
+                    a[(r, j)] -= m * a[(col, j)];
+                }
+            }
+        }
+        Ok(Lu { a, perm, norm: self.norm_1() })
+    }
+
+    ///
+    /// Householder QR with column pivoting
+    ///
+    pub fn qr(&self) -> Qr {
+        let (m, n) = (self.rows, self.cols);
+        let mut r = self.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut reflectors = Vec::new();
+        for k in 0..m.min(n) {
+            let norm = |r: &Matrix, j: usize| (k..m).map(|i| r[(i, j)] * r[(i, j)]).sum::<f64>();
+            let p = (k..n).max_by(|&x, &y| norm(&r, x).total_cmp(&norm(&r, y))).unwrap_or(k);
+            if p != k {
+                for i in 0..m {
+                    r.data.swap(i * n + p, i * n + k);
+                }
+                perm.swap(p, k);
+            }
+            let size = sqrt(norm(&r, k));
+            let alpha = if r[(k, k)] > 0.0 { -size } else { size };
+            let mut v: Vec<f64> = (k..m).map(|i| r[(i, k)]).collect();
+            v[0] -= alpha;
+            let vv: f64 = v.iter().map(|x| x * x).sum();
+            let beta = if vv > 0.0 { 2.0 / vv } else { 0.0 };
+            for j in k..n {
+                let s = beta * (k..m).map(|i| v[i - k] * r[(i, j)]).sum::<f64>();
+                for i in k..m {
+                    r[(i, j)] -= s * v[i - k];
+                }
+            }
+            reflectors.push((beta, v));
+        }
+        Qr { r, reflectors, perm }
+    }
+
+    ///
+    /// All eigenvalues as (re, im) pairs, complex ones in conjugate pairs
+    ///
+    pub fn eigenvalues(&self) -> Result<Vec<(f64, f64)>, Box<dyn core::error::Error>> {
+        if self.rows != self.cols {
+            return Err("eigenvalues of a non-square matrix".into());
+        }
+        let mut h = self.clone();
+        h.hessenberg();
+        h.hqr()
+    }
+
+    ///
+    /// In-place reduction to upper Hessenberg form by Gaussian similarity
+    /// transforms with pivoting
+    ///
+    fn hessenberg(&mut self) {
+        let n = self.rows;
+        for m in 1..n.saturating_sub(1) {
+            let piv = (m..n).max_by(|&x, &y| self[(x, m - 1)].abs().total_cmp(&self[(y, m - 1)].abs())).unwrap();
+            let x = self[(piv, m - 1)];
+            if piv != m {
+                for j in (m - 1)..n {
+                    self.data.swap(piv * n + j, m * n + j);
+                }
+                for i in 0..n {
+                    self.data.swap(i * n + piv, i * n + m);
+                }
+            }
+            if x == 0.0 {
+                continue;
+            }
+            for i in (m + 1)..n {
+                let y = self[(i, m - 1)] / x;
+                if y == 0.0 {
+                    continue;
+                }
+                self[(i, m - 1)] = 0.0;
+                for j in m..n {
+                    let v = self[(m, j)];
+                    self[(i, j)] -= y * v;
+                }
+                for r in 0..n {
+                    let v = self[(r, i)];
+                    self[(r, m)] += y * v;
+                }
+            }
+        }
+    }
+
+    ///
+    /// Francis double shift QR on an upper Hessenberg matrix
+    ///
+    fn hqr(&mut self) -> Result<Vec<(f64, f64)>, Box<dyn core::error::Error>> {
+        let n = self.rows;
+        let mut out = vec![(0.0, 0.0); n];
+        let anorm: f64 = (0..n)
+            .flat_map(|i| (i.saturating_sub(1)..n).map(move |j| (i, j)))
+            .map(|(i, j)| self[(i, j)].abs())
+            .sum();
+
+        let mut nn = n as isize - 1;
+        let mut t = 0.0;
+        while nn >= 0 {
+            let mut its = 0;
+            loop {
+                // look for a small subdiagonal element
+                let mut l = nn;
+                while l >= 1 {
+                    let lu = l as usize;
+                    let s = self[(lu - 1, lu - 1)].abs() + self[(lu, lu)].abs();
+                    let s = if s == 0.0 { anorm } else { s };
+                    if self[(lu, lu - 1)].abs() + s == s {
+                        self[(lu, lu - 1)] = 0.0;
+                        break;
+                    }
+                    l -= 1;
+                }
+                let nu = nn as usize;
+                let x = self[(nu, nu)];
+                if l == nn {
+                    out[nu] = (x + t, 0.0);
+                    nn -= 1;
+                    break;
+                }
+                let y = self[(nu - 1, nu - 1)];
+                let w = self[(nu, nu - 1)] * self[(nu - 1, nu)];
+                if l == nn - 1 {
+                    let p = 0.5 * (y - x);
+                    let q = p * p + w;
+                    let z = sqrt(q.abs());
+                    let x = x + t;
+                    if q >= 0.0 {
+                        let z = p + z.copysign(p);
+                        let second = if z != 0.0 { x - w / z } else { x + z };
+                        out[nu - 1] = (x + z, 0.0);
+                        out[nu] = (second, 0.0);
+                    } else {
+                        out[nu - 1] = (x + p, z);
+                        out[nu] = (x + p, -z);
+                    }
+                    nn -= 2;
+                    break;
+                }
+                if its == 60 {
+                    return Err("QR iteration did not converge".into());
+                }
+
+                let (mut x, mut y, mut w) = (x, y, w);
+                if its == 10 || its == 20 {
+                    // exceptional shift
+                    t += x;
+                    for i in 0..=nu {
+                        self[(i, i)] -= x;
+                    }
+                    let s = self[(nu, nu - 1)].abs() + self[(nu - 1, nu - 2)].abs();
+                    x = 0.75 * s;
+                    y = x;
+                    w = -0.4375 * s * s;
+                }
+                its += 1;
+
+                // form the shift and look for two consecutive small subdiagonals
+                let lu = l as usize;
+                let mut m = nu - 2;
+                let (mut p, mut q, mut r);
+                loop {
+                    let z = self[(m, m)];
+                    let rr = x - z;
+                    let ss = y - z;
+                    p = (rr * ss - w) / self[(m + 1, m)] + self[(m, m + 1)];
+                    q = self[(m + 1, m + 1)] - z - rr - ss;
+                    r = self[(m + 2, m + 1)];
+                    let s = p.abs() + q.abs() + r.abs();
+                    p /= s;
+                    q /= s;
+                    r /= s;
+                    if m == lu {
+                        break;
+                    }
+                    let u = self[(m, m - 1)].abs() * (q.abs() + r.abs());
+                    let v = p.abs() * (self[(m - 1, m - 1)].abs() + z.abs() + self[(m + 1, m + 1)].abs());
+                    if u + v == v {
+                        break;
+                    }
+                    m -= 1;
+                }
+                for i in (m + 2)..=nu {
+                    self[(i, i - 2)] = 0.0;
+                    if i != m + 2 {
+                        self[(i, i - 3)] = 0.0;
+                    }
+                }
+
+                // double QR step on rows l..nn and columns m..nn
+                let mut k = m;
+                while k < nu {
+                    if k != m {
+                        p = self[(k, k - 1)];
+                        q = self[(k + 1, k - 1)];
+                        r = if k != nu - 1 { self[(k + 2, k - 1)] } else { 0.0 };
+                        x = p.abs() + q.abs() + r.abs();
+                        if x != 0.0 {
+                            p /= x;
+                            q /= x;
+                            r /= x;
+                        }
+                    }
+                    let s = sqrt(p * p + q * q + r * r).copysign(p);
+                    if s != 0.0 {
+                        if k == m {
+                            if l as usize != m {
+                                self[(k, k - 1)] = -self[(k, k - 1)];
+                            }
+                        } else {
+                            self[(k, k - 1)] = -s * x;
+                        }
+                        p += s;
+                        x = p / s;
+                        y = q / s;
+                        let z = r / s;
+                        q /= p;
+                        r /= p;
+                        for j in k..=nu {
+                            let mut pp = self[(k, j)] + q * self[(k + 1, j)];
+                            if k != nu - 1 {
+                                pp += r * self[(k + 2, j)];
+                                self[(k + 2, j)] -= pp * z;
+                            }
+                            self[(k + 1, j)] -= pp * y;
+                            self[(k, j)] -= pp * x;
+                        }
+                        let mmin = if nu < k + 3 { nu } else { k + 3 };
+                        for i in lu..=mmin {
+                            let mut pp = x * self[(i, k)] + y * self[(i, k + 1)];
+                            if k != nu - 1 {
+                                pp += z * self[(i, k + 2)];
+                                self[(i, k + 2)] -= pp * r;
+                            }
+                            self[(i, k + 1)] -= pp * q;
+                            self[(i, k)] -= pp;
+                        }
+                    }
+                    k += 1;
+                }
+            }
+        }
+        Ok(out)
+    }
+}
+
+///
+/// Householder QR with column pivoting, A P = Q R. Reflector k is
+/// I - beta_k v_k v_k^T acting on rows k.., R is kept in the upper triangle
+///
+#[derive(Clone, Debug)]
+pub struct Qr {
+    r: Matrix,
+    reflectors: Vec<(f64, Vec<f64>)>,
+    /// column j of A P is column perm[j] of A
+    pub perm: Vec<usize>,
+}
+
+impl Qr {
+    ///
+    /// |R_kk|, non-increasing
+    ///
+    pub fn diagonal(&self) -> Vec<f64> {
+        (0..self.reflectors.len()).map(|k| self.r[(k, k)].abs()).collect()
+    }
+
+    ///
+    /// Number of |R_kk| above tol |R_00|
+    ///
+    pub fn rank(&self, tol: f64) -> usize {
+        let d = self.diagonal();
+        d.iter().take_while(|v| d.first().is_some_and(|top| **v > tol * top)).count()
+    }
+
+    ///
+    /// Basic least squares solution on the first rank pivoted columns,
+    /// zero for the rest
+    ///
+    pub fn solve(&self, b: &[f64], rank: usize) -> Vec<f64> {
+        let mut c = b.to_vec();
+        for (k, (beta, v)) in self.reflectors.iter().enumerate() {
+            let s = beta * v.iter().zip(&c[k..]).map(|(a, b)| a * b).sum::<f64>();
+            for (ci, vi) in c[k..].iter_mut().zip(v) {
+                *ci -= s * vi;
+            }
+        }
+        let mut z = vec![0.0; rank];
+        for i in (0..rank).rev() {
+            z[i] = (c[i] - (i + 1..rank).map(|j| self.r[(i, j)] * z[j]).sum::<f64>()) / self.r[(i, i)];
+        }
+        let mut x = vec![0.0; self.r.cols];
+        for (j, zj) in z.iter().enumerate() {
+            x[self.perm[j]] = *zj;
+        }
+        x
+    }
+}
+
+///
+/// Least squares min |A x - b| with columns scaled to unit norm first, so
+/// rank is judged independent of units. Returns x and the numerical rank
+/// at relative tolerance tol; coefficients of dependent columns are zero
+///
+pub fn least_squares(a: &Matrix, b: &[f64], tol: f64) -> Result<(Vec<f64>, usize), Box<dyn core::error::Error>> {
+    if a.rows != b.len() || a.rows < a.cols {
+        return Err(format!("least squares: A is {}x{} with {} observations, need rows >= cols", a.rows, a.cols, b.len()).into());
+    }
+    let mut scaled = a.clone();
+    let scale: Vec<f64> = (0..a.cols).map(|j| {
+        let s = sqrt(a.column(j).iter().map(|v| v * v).sum::<f64>());
+        if s > 0.0 { s } else { 1.0 }
+    }).collect();
+    for i in 0..a.rows {
+        for j in 0..a.cols {
+            scaled[(i, j)] /= scale[j];
+        }
+    }
+    let qr = scaled.qr();
+    let rank = qr.rank(tol);
+    let x = qr.solve(b, rank).iter().zip(&scale).map(|(x, s)| x / s).collect();
+    Ok((x, rank))
+}
+
+///
+/// Borrowed rows x cols window into a Matrix, row i starting stride
+/// entries after row i - 1. Views of views narrow the window; nothing is
+/// copied until to_matrix
+///
+#[derive(Clone, Copy, Debug)]
+pub struct MatrixView<'a> {
+    data: &'a [f64],
+    stride: usize,
+    pub rows: usize,
+    pub cols: usize,
+}
+
+///
+/// Mutable window, unique like the &mut Matrix it came from. Splitting
+/// hands out disjoint halves that can be written side by side
+///
+#[derive(Debug)]
+pub struct MatrixViewMut<'a> {
+    data: &'a mut [f64],
+    stride: usize,
+    pub rows: usize,
+    pub cols: usize,
+}
+
+///
+/// Offset and length of the slice behind a block, checked against the
+/// parent window
+///
+fn window(rows: usize, cols: usize, stride: usize, r0: usize, c0: usize, r: usize, c: usize) -> (usize, usize) {
+    assert!(r0 + r <= rows && c0 + c <= cols, "block {r}x{c} at ({r0}, {c0}) outside a {rows}x{cols} view");
+    let len = if r == 0 || c == 0 { 0 } else { (r - 1) * stride + c };
+    (r0 * stride + c0, len)
+}
+
+impl<'a> MatrixView<'a> {
+    pub fn block(&self, r0: usize, c0: usize, rows: usize, cols: usize) -> MatrixView<'a> {
+        let (start, len) = window(self.rows, self.cols, self.stride, r0, c0, rows, cols);
+        MatrixView { data: &self.data[start..start + len], stride: self.stride, rows, cols }
+    }
+
+    pub fn row(&self, i: usize) -> &'a [f64] {
+        assert!(i < self.rows, "row {i} of a view with {} rows", self.rows);
+        &self.data[i * self.stride..i * self.stride + self.cols]
+    }
+
+    pub fn column(&self, j: usize) -> impl Iterator<Item = f64> + 'a {
+        assert!(j < self.cols, "column {j} of a view with {} columns", self.cols);
+        let (data, stride) = (self.data, self.stride);
+        (0..self.rows).map(move |i| data[i * stride + j])
+    }
+
+    pub fn to_matrix(self) -> Matrix {
+        Matrix { rows: self.rows, cols: self.cols, data: (0..self.rows).flat_map(|i| self.row(i).iter().copied()).collect() }
+    }
+
+    ///
+    /// Copy into a rows x cols matrix, zero past the view's edges
+    ///
+    pub fn padded(&self, rows: usize, cols: usize) -> Matrix {
+        let mut out = Matrix::zeros(rows, cols);
+        let (r, c) = (self.rows.min(rows), self.cols.min(cols));
+        out.block_mut(0, 0, r, c).copy_from(self.block(0, 0, r, c));
+        out
+    }
+}
+
+impl<'a> MatrixViewMut<'a> {
+    pub fn as_view(&self) -> MatrixView<'_> {
+        MatrixView { data: self.data, stride: self.stride, rows: self.rows, cols: self.cols }
+    }
+
+    ///
+    /// Reborrowed block, the parent usable again once it is dropped
+    ///
+    pub fn block(&mut self, r0: usize, c0: usize, rows: usize, cols: usize) -> MatrixViewMut<'_> {
+        let (start, len) = window(self.rows, self.cols, self.stride, r0, c0, rows, cols);
+        MatrixViewMut { data: &mut self.data[start..start + len], stride: self.stride, rows, cols }
+    }
+
+    pub fn into_block(self, r0: usize, c0: usize, rows: usize, cols: usize) -> MatrixViewMut<'a> {
+        let (start, len) = window(self.rows, self.cols, self.stride, r0, c0, rows, cols);
+        MatrixViewMut { data: &mut self.data[start..start + len], stride: self.stride, rows, cols }
+    }
+
+    ///
+    /// Rows above r and rows from r on, both writable at once
+    ///
+    pub fn split_at_row(self, r: usize) -> (MatrixViewMut<'a>, MatrixViewMut<'a>) {
+        assert!(r <= self.rows, "split at row {r} of a view with {} rows", self.rows);
+        let (top, bottom) = self.data.split_at_mut((r * self.stride).min(self.data.len()));
+        (MatrixViewMut { data: top, stride: self.stride, rows: r, cols: self.cols },
+         MatrixViewMut { data: bottom, stride: self.stride, rows: self.rows - r, cols: self.cols })
+    }
+
+    pub fn row_mut(&mut self, i: usize) -> &mut [f64] {
+        assert!(i < self.rows, "row {i} of a view with {} rows", self.rows);
+        &mut self.data[i * self.stride..i * self.stride + self.cols]
+    }
+
+    pub fn copy_from(&mut self, src: MatrixView) {
+        assert!(src.rows == self.rows && src.cols == self.cols, "copy of a {}x{} view into {}x{}", src.rows, src.cols, self.rows, self.cols);
+        for i in 0..self.rows {
+            self.row_mut(i).copy_from_slice(src.row(i));
+        }
+    }
+
+    pub fn fill(&mut self, value: f64) {
+        for i in 0..self.rows {
+            self.row_mut(i).fill(value);
+        }
+    }
+}
+
+impl Index<(usize, usize)> for MatrixView<'_> {
+    type Output = f64;
+    fn index(&self, (i, j): (usize, usize)) -> &f64 {
+        &self.row(i)[j]
+    }
+}
+
+impl Index<(usize, usize)> for MatrixViewMut<'_> {
+    type Output = f64;
+    fn index(&self, (i, j): (usize, usize)) -> &f64 {
+        assert!(i < self.rows && j < self.cols, "({i}, {j}) outside a {}x{} view", self.rows, self.cols);
+        &self.data[i * self.stride + j]
+    }
+}
+
+impl IndexMut<(usize, usize)> for MatrixViewMut<'_> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut f64 {
+        &mut self.row_mut(i)[j]
+    }
+}
+
+///
+/// out += a b, the innermost loop along rows of b and out
+///
+fn kernel(a: MatrixView, b: MatrixView, out: &mut MatrixViewMut) {
+    for r in 0..a.rows {
+        let row = out.row_mut(r);
+        for (q, &x) in a.row(r).iter().enumerate() {
+            for (o, y) in row.iter_mut().zip(b.row(q)) {
+                *o += x * y;
+            }
+        }
+    }
+}
+
+fn tiled(a: MatrixView, b: MatrixView, out: &mut MatrixViewMut, tile: usize) {
+    let (m, k, n) = (a.rows, a.cols, b.cols);
+    for i in (0..m).step_by(tile) {
+        let ti = tile.min(m - i);
+        for p in (0..k).step_by(tile) {
+            let tp = tile.min(k - p);
+            for j in (0..n).step_by(tile) {
+                let tj = tile.min(n - j);
+                kernel(a.block(i, p, ti, tp), b.block(p, j, tp, tj), &mut out.block(i, j, ti, tj));
+            }
+        }
+    }
+}
+
+fn recurse(a: MatrixView, b: MatrixView, out: &mut MatrixViewMut, leaf: usize) {
+    let (m, k, n) = (a.rows, a.cols, b.cols);
+    if m.max(k).max(n) <= leaf {
+        kernel(a, b, out);
+        return;
+    }
+    // the inner dimension is split in order, keeping the summation order
+    if m >= k && m >= n {
+        let h = m / 2;
+        recurse(a.block(0, 0, h, k), b, &mut out.block(0, 0, h, n), leaf);
+        recurse(a.block(h, 0, m - h, k), b, &mut out.block(h, 0, m - h, n), leaf);
+    } else if k >= n {
+        let h = k / 2;
+        recurse(a.block(0, 0, m, h), b.block(0, 0, h, n), out, leaf);
+        recurse(a.block(0, h, m, k - h), b.block(h, 0, k - h, n), out, leaf);
+    } else {
+        let h = n / 2;
+        recurse(a, b.block(0, 0, k, h), &mut out.block(0, 0, m, h), leaf);
+        recurse(a, b.block(0, h, k, n - h), &mut out.block(0, h, m, n - h), leaf);
+    }
+}
+
+///
+/// a + sign b, entrywise
+///
+fn combine(a: MatrixView, b: MatrixView, sign: f64) -> Matrix {
+    let data = (0..a.rows).flat_map(|i| a.row(i).iter().zip(b.row(i)).map(move |(x, y)| x + sign * y)).collect();
+    Matrix { rows: a.rows, cols: a.cols, data }
+}
+
+fn strassen(a: MatrixView, b: MatrixView, crossover: usize) -> Matrix {
+    let (m, k, n) = (a.rows, a.cols, b.cols);
+    if m.min(k).min(n) <= crossover {
+        let mut out = Matrix::zeros(m, n);
+        tiled(a, b, &mut out.view_mut(), TILE);
+        return out;
+    }
+    if m % 2 + k % 2 + n % 2 > 0 {
+        let (pa, pb) = (a.padded(m + m % 2, k + k % 2), b.padded(k + k % 2, n + n % 2));
+        return strassen(pa.view(), pb.view(), crossover).block(0, 0, m, n).to_matrix();
+    }
+    let (hm, hk, hn) = (m / 2, k / 2, n / 2);
+    let (a11, a12, a21, a22) = (a.block(0, 0, hm, hk), a.block(0, hk, hm, hk), a.block(hm, 0, hm, hk), a.block(hm, hk, hm, hk));
+    let (b11, b12, b21, b22) = (b.block(0, 0, hk, hn), b.block(0, hn, hk, hn), b.block(hk, 0, hk, hn), b.block(hk, hn, hk, hn));
+
+    let m1 = strassen(combine(a11, a22, 1.0).view(), combine(b11, b22, 1.0).view(), crossover);
+    let m2 = strassen(combine(a21, a22, 1.0).view(), b11, crossover);
+    let m3 = strassen(a11, combine(b12, b22, -1.0).view(), crossover);
+    let m4 = strassen(a22, combine(b21, b11, -1.0).view(), crossover);
+    let m5 = strassen(combine(a11, a12, 1.0).view(), b22, crossover);
+    let m6 = strassen(combine(a21, a11, -1.0).view(), combine(b11, b12, 1.0).view(), crossover);
+    let m7 = strassen(combine(a12, a22, -1.0).view(), combine(b21, b22, 1.0).view(), crossover);
+
+    let quadrants = [
+        combine(combine(combine(m1.view(), m4.view(), 1.0).view(), m5.view(), -1.0).view(), m7.view(), 1.0),
+        combine(m3.view(), m5.view(), 1.0),
+        combine(m2.view(), m4.view(), 1.0),
+        combine(combine(combine(m1.view(), m2.view(), -1.0).view(), m3.view(), 1.0).view(), m6.view(), 1.0),
+    ];
+    let mut out = Matrix::zeros(m, n);
+    for (q, c) in quadrants.iter().enumerate() {
+        out.block_mut((q / 2) * hm, (q % 2) * hn, hm, hn).copy_from(c.view());
+    }
+    out
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = f64;
+    fn index(&self, (i, j): (usize, usize)) -> &f64 {
+        &self.data[i * self.cols + j]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut f64 {
+        &mut self.data[i * self.cols + j]
+    }
+}
+
+///
+/// Packed LU factors (unit lower triangle below the diagonal) and the row
+/// permutation
+///
+#[derive(Clone, Debug)]
+pub struct Lu {
+    a: Matrix,
+    perm: Vec<usize>,
+    /// 1-norm of the factored matrix, for the condition estimate
+    norm: f64,
+}
+
+impl Lu {
+    pub fn solve(&self, b: &[f64]) -> Vec<f64> {
+        let n = self.a.rows;
+        let mut x: Vec<f64> = self.perm.iter().map(|&p| b[p]).collect();
+        for i in 0..n {
+            for j in 0..i {
+                x[i] -= self.a[(i, j)] * x[j];
+            }
+        }
+        for i in (0..n).rev() {
+            for j in (i + 1)..n {
+                x[i] -= self.a[(i, j)] * x[j];
+            }
+            x[i] /= self.a[(i, i)];
+        }
+        x
+    }
+
+    ///
+    /// Solves A^T x = b, as U^T L^T P x = b
+    ///
+    pub fn solve_transpose(&self, b: &[f64]) -> Vec<f64> {
+        let n = self.a.rows;
+        let mut w = b.to_vec();
+        for i in 0..n {
+            for j in 0..i {
+                w[i] -= self.a[(j, i)] * w[j];
+            }
+            w[i] /= self.a[(i, i)];
+        }
+        for i in (0..n).rev() {
+            for j in (i + 1)..n {
+                w[i] -= self.a[(j, i)] * w[j];
+            }
+        }
+        let mut x = vec![0.0; n];
+        for (i, &p) in self.perm.iter().enumerate() {
+            x[p] = w[i];
+        }
+        x
+    }
+
+    ///
+    /// Estimate of the 1-norm condition number |A| |A^-1|
+    ///
+    pub fn condition(&self) -> f64 {
+        self.norm * inverse_norm_estimate(self.a.rows, |b| self.solve(b), |b| self.solve_transpose(b))
+    }
+
+    pub fn determinant(&self) -> f64 {
+        let n = self.a.rows;
+        let mut sign = 1.0;
+        let mut perm = self.perm.clone();
+        for i in 0..n {
+            while perm[i] != i {
+                let j = perm[i];
+                perm.swap(i, j);
+                sign = -sign;
+            }
+        }
+        (0..n).map(|i| self.a[(i, i)]).product::<f64>() * sign
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sqrt_is_within_an_ulp_of_the_float_library() {
+        for x in [1e-310, 1e-300, 2.0, 3.0, 0.5, 1e-8, 12345.678, 1e300, f64::MAX] {
+            let (got, want) = (sqrt(x), x.sqrt());
+            assert!((got - want).abs() <= want * f64::EPSILON, "sqrt({x}) = {got}, want {want}");
+        }
+        assert_eq!(sqrt(0.0), 0.0);
+        assert!(sqrt(-1.0).is_nan());
+        assert_eq!(sqrt(f64::INFINITY), f64::INFINITY);
+    }
+
+    #[test]
+    fn lu_solves_and_gives_the_determinant() {
+        let a = Matrix::from_rows(&[[2.0, 1.0, 1.0], [4.0, -6.0, 0.0], [-2.0, 7.0, 2.0]]);
+        let lu = a.lu().unwrap();
+        let x = lu.solve(&[5.0, -2.0, 9.0]);
+        for (xi, want) in x.iter().zip([1.0, 1.0, 2.0]) {
+            assert!((xi - want).abs() < 1e-14, "{x:?}");
+        }
+        assert!((lu.determinant() + 16.0).abs() < 1e-12);
+        assert!(Matrix::from_rows(&[[1.0, 2.0], [2.0, 4.0]]).lu().is_err());
+    }
+
+    #[test]
+    fn eigenvalues_of_a_rotation_and_a_triangle() {
+        let mut ev = Matrix::from_rows(&[[0.0, -1.0], [1.0, 0.0]]).eigenvalues().unwrap();
+        ev.sort_by(|a, b| a.1.total_cmp(&b.1));
+        assert!((ev[0].0).abs() < 1e-14 && (ev[0].1 + 1.0).abs() < 1e-14, "{ev:?}");
+        assert!((ev[1].0).abs() < 1e-14 && (ev[1].1 - 1.0).abs() < 1e-14, "{ev:?}");
+
+        let mut ev = Matrix::from_rows(&[[3.0, 1.0, 2.0], [0.0, -1.0, 4.0], [0.0, 0.0, 0.5]]).eigenvalues().unwrap();
+        ev.sort_by(|a, b| a.0.total_cmp(&b.0));
+        for ((re, im), want) in ev.iter().zip([-1.0, 0.5, 3.0]) {
+            assert!((re - want).abs() < 1e-12 && im.abs() < 1e-12, "{ev:?}");
+        }
+    }
+
+    #[test]
+    fn least_squares_matches_the_normal_equations() {
+        // a cubic fit on 12 points, well conditioned after column scaling
+        let rows: Vec<[f64; 4]> = (0..12).map(|i| {
+            let x = i as f64 / 11.0;
+            [1.0, x, x * x, x * x * x]
+        }).collect();
+        let b: Vec<f64> = (0..12).map(|i| {
+            let x = i as f64 / 11.0;
+            1.0 - 2.0 * x + 0.5 * x * x + if i % 3 == 0 { 0.01 } else { -0.005 }
+        }).collect();
+        let a = Matrix::from_rows(&rows);
+        let (x, rank) = least_squares(&a, &b, 1e-10).unwrap();
+        assert_eq!(rank, 4);
+
+        let at = a.transpose();
+        let normal = at.mul(&a).solve(&at.mul_vec(&b)).unwrap();
+        for (xi, ni) in x.iter().zip(&normal) {
+            assert!((xi - ni).abs() < 1e-9, "{x:?} vs {normal:?}");
+        }
+    }
+
+    #[test]
+    fn a_dependent_column_is_left_out_of_the_fit() {
+        // the third column is the sum of the first two
+        let rows: Vec<[f64; 3]> = (0..8).map(|i| [1.0, i as f64, 1.0 + i as f64]).collect();
+        let b: Vec<f64> = (0..8).map(|i| 3.0 + 2.0 * i as f64).collect();
+        let (x, rank) = least_squares(&Matrix::from_rows(&rows), &b, 1e-10).unwrap();
+        assert_eq!(rank, 2);
+        assert_eq!(x.iter().filter(|v| **v == 0.0).count(), 1, "{x:?}");
+        let fit: Vec<f64> = Matrix::from_rows(&rows).mul_vec(&x);
+        for (f, want) in fit.iter().zip(&b) {
+            assert!((f - want).abs() < 1e-10, "{fit:?}");
+        }
+        assert!(x.iter().all(|v| v.abs() < 10.0), "{x:?}");
+    }
+
+    #[test]
+    fn qr_diagonal_is_non_increasing() {
+        let a = Matrix::from_rows(&[[1.0, 4.0, 0.1], [2.0, 5.0, 0.2], [3.0, 7.0, 0.1], [1.0, 1.0, 0.3]]);
+        let d = a.qr().diagonal();
+        assert!(d.windows(2).all(|w| w[0] >= w[1]), "{d:?}");
+    }
+
+    fn filled(rows: usize, cols: usize, seed: f64) -> Matrix {
+        let mut m = Matrix::zeros(rows, cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                m[(i, j)] = ((i * 7 + j * 13 + 1) as f64 * seed) % 1.0 - 0.5;
+            }
+        }
+        m
+    }
+
+    fn max_diff(a: &Matrix, b: &Matrix) -> f64 {
+        assert_eq!((a.rows, a.cols), (b.rows, b.cols));
+        (0..a.rows).flat_map(|i| (0..a.cols).map(move |j| (i, j))).fold(0.0, |m, ij| m.max((a[ij] - b[ij]).abs()))
+    }
+
+    #[test]
+    fn strassen_matches_the_plain_product_at_every_crossover() {
+        // odd and unequal sizes pad at some levels of the recursion
+        for (m, k, n) in [(16, 16, 16), (33, 20, 27), (40, 41, 9)] {
+            let (a, b) = (filled(m, k, 0.37), filled(k, n, 0.61));
+            let plain = a.mul_plain(&b);
+            for crossover in [1, 2, 5, 8, 64] {
+                let d = max_diff(&a.mul_strassen(&b, crossover), &plain);
+                assert!(d < 1e-12, "{m}x{k}x{n} crossover {crossover}: {d:.3e}");
+            }
+        }
+    }
+
+    #[test]
+    fn tiled_and_recursive_products_agree_bit_for_bit() {
+        let (a, b) = (filled(37, 29, 0.43), filled(29, 23, 0.29));
+        let plain = a.mul_plain(&b);
+        assert_eq!(a.mul(&b), plain);
+        for size in [1, 4, 7, 64] {
+            assert_eq!(a.mul_tiled(&b, size), plain, "tile {size}");
+            assert_eq!(a.mul_recursive(&b, size), plain, "leaf {size}");
+        }
+    }
+}
@@ -0,0 +1,319 @@
+//!
+//! multistep.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! The lab's AB4 predictor, AM4 corrector on any state size, with a
+//! choice of startup strategy and of evaluation mode, P(EC)^m or
+//! P(EC)^m E. The startup values seed an order 4 Nordsieck array that
+//! carries the history from there; ab4_predict and am4_correct are the
+//! same formulas on raw rate history. The resumable Abm4 stepper and its
+//! dense output sit with the lab's Trajectory, in lab6/multistep.rs
+//!
+
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::nordsieck::{adams_l, Nordsieck};
+use crate::{final_step, integrate, planned_steps, rk4_step, step_count, CorrectorFailure, Limits, Solution, SolveStats};
+
+fn eval<F, const N: usize>(rate: &F, t: f64, y: &[f64; N]) -> [f64; N]
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    let mut d = [0.0; N];
+    rate(t, y, &mut d);
+    d
+}
+
+///
+/// AB4 predictor from y with the rates at the last four grid points,
+/// oldest first
+///
+pub fn ab4_predict<const N: usize>(y: &[f64; N], f: &[[f64; N]; 4], h: f64) -> [f64; N] {
+    core::array::from_fn(|i| y[i] + (h / 24.0) * (55.0 * f[3][i] - 59.0 * f[2][i] + 37.0 * f[1][i] - 9.0 * f[0][i]))
+}
+
+///
+/// AM4 corrector from y, with fp the rate at the predicted (or last
+/// corrected) point
+///
+pub fn am4_correct<const N: usize>(y: &[f64; N], f: &[[f64; N]; 4], fp: &[f64; N], h: f64) -> [f64; N] {
+    core::array::from_fn(|i| y[i] + (h / 24.0) * (9.0 * fp[i] + 19.0 * f[3][i] - 5.0 * f[2][i] + f[1][i]))
+}
+
+///
+/// How the first three steps of AB/AM4 are generated before the
+/// multistep recurrence has enough history
+///
+#[derive(Clone, Copy, Debug)]
+pub enum Startup {
+    /// rk4 at the same dt
+    Rk4,
+    /// rk4 at dt / k, sampled back onto the dt grid
+    Rk4Refined(usize),
+    /// Adams-Bashforth of increasing order AB1 -> AB2 -> AB3
+    Bootstrap,
+}
+
+impl Startup {
+    pub fn name(self) -> String {
+        match self {
+            Startup::Rk4 => "rk4".to_string(),
+            Startup::Rk4Refined(k) => format!("rk4 dt/{k}"),
+            Startup::Bootstrap => "AB1-AB3".to_string(),
+        }
+    }
+}
+
+///
+/// Predictor-corrector evaluation modes. m corrector passes each preceded
+/// by a rate evaluation, P(EC)^m, optionally followed by a final
+/// evaluation, P(EC)^m E, so the stored rate matches the accepted value
+///
+#[derive(Clone, Copy, Debug)]
+pub enum Mode {
+    Pec(usize),
+    Pece(usize),
+}
+
+impl Mode {
+    pub fn name(self) -> String {
+        match self {
+            Mode::Pec(1) => "PEC".to_string(),
+            Mode::Pece(1) => "PECE".to_string(),
+            Mode::Pec(m) => format!("P(EC)^{m}"),
+            Mode::Pece(m) => format!("P(EC)^{m}E"),
+        }
+    }
+
+    pub fn corrections(self) -> usize {
+        match self {
+            Mode::Pec(m) | Mode::Pece(m) => m.max(1),
+        }
+    }
+
+    ///
+    /// Rate evaluations per step once the startup is done
+    ///
+    pub fn evals(self) -> usize {
+        match self {
+            Mode::Pec(_) => self.corrections(),
+            Mode::Pece(_) => self.corrections() + 1,
+        }
+    }
+}
+
+///
+/// First four values y0..y3 on the dt grid from t0 for the chosen strategy
+///
+pub fn startup_values<F, const N: usize>(rate: &F, ic: [f64; N], t0: f64, dt: f64, strategy: Startup)
+    -> Result<[[f64; N]; 4], Box<dyn core::error::Error>>
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    match strategy {
+        Startup::Rk4 | Startup::Rk4Refined(0) => {
+            let (_, y) = integrate(rate, rk4_step, ic, [t0, t0 + 3.0 * dt], dt, Limits::default())?;
+            Ok(core::array::from_fn(|i| y[i]))
+        }
+        Startup::Rk4Refined(k) => {
+            let h = dt / k as f64;
+            let (_, fine) = integrate(rate, rk4_step, ic, [t0, t0 + (3 * k) as f64 * h], h, Limits::default())?;
+            Ok(core::array::from_fn(|i| fine[i * k]))
+        }
+        Startup::Bootstrap => {
+            let mut y = [ic; 4];
+            let f0 = eval(rate, t0, &y[0]);
+            y[1] = core::array::from_fn(|i| y[0][i] + dt * f0[i]);
+            let f1 = eval(rate, t0 + dt, &y[1]);
+            y[2] = core::array::from_fn(|i| y[1][i] + (dt / 2.0) * (3.0 * f1[i] - f0[i]));
+            let f2 = eval(rate, t0 + 2.0 * dt, &y[2]);
+            y[3] = core::array::from_fn(|i| y[2][i] + (dt / 12.0) * (23.0 * f2[i] - 16.0 * f1[i] + 5.0 * f0[i]));
+            Ok(y)
+        }
+    }
+}
+
+///
+/// AB/AM4 over ts on the dt grid in the given startup and evaluation mode,
+/// stepped as the order 4 Nordsieck array built from the startup values.
+/// When dt does not divide the span, the array is rescaled onto the
+/// remainder for one last step that lands on ts[1]; a span under three
+/// steps is too short for the history and is rk4 throughout. A run with
+/// more steps than limits allow, or a trajectory over their memory budget,
+/// is refused before the first step, as is a dt that is not a positive
+/// step; with std the wall clock limit is checked as it runs. A step
+/// whose corrector fails is handled by on_failure as in
+/// Nordsieck::adams_step_with. Also returns the steps taken and the rate
+/// evaluations after startup, including the four that seed the history,
+/// and the corrector failures
+///
+#[allow(clippy::too_many_arguments)]
+pub fn solve_with_mode<F, const N: usize>(
+    rate: &F, ic: [f64; N], ts: [f64; 2], dt: f64, startup: Startup, mode: Mode, on_failure: CorrectorFailure, limits: Limits)
+    -> Result<(Solution<N>, SolveStats), Box<dyn core::error::Error>>
+where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+    planned_steps("ab/am4", ts, dt, limits)?;
+    let (n, last) = (step_count(ts, dt), final_step(ts, dt));
+    if n < 3 {
+        let (t, y) = integrate(rate, rk4_step, ic, ts, dt, limits)?;
+        let stats = SolveStats { accepted: t.len() - 1, rate_evals: 4 * (t.len() - 1), on_failure, ..SolveStats::default() };
+        return Ok(((t, y), stats));
+    }
+    limits.check::<N>("ab/am4", n.saturating_add(usize::from(last.is_some())))?;
+    let mut t: Vec<f64> = Vec::with_capacity(n + 2);
+    let mut y: Vec<[f64; N]> = Vec::with_capacity(n + 2);
+    let mut f = [[0.0; N]; 4];
+    for (i, yi) in startup_values(rate, ic, ts[0], dt, startup)?.iter().enumerate() {
+        t.push(ts[0] + (i as f64) * dt);
+        y.push(*yi);
+        f[i] = eval(rate, t[i], yi);
+    }
+    let mut stats = SolveStats { rate_evals: 4, on_failure, ..SolveStats::default() };
+    let l = adams_l(4);
+    let mut nd = Nordsieck::from_uniform_history(t[3], dt, y[3], &f);
+    let evaluate = matches!(mode, Mode::Pece(_));
+    #[cfg(feature = "std")]
+    let mut guard = limits.start::<N>();
+
+    for i in 4..=n {
+        #[cfg(feature = "std")]
+        guard.step("ab/am4", t[i - 1])?;
+        // the grid time, not the sum of the steps, is what rate sees
+        nd.t = t[i - 1];
+        nd.adams_step_with(rate, &l, mode.corrections(), evaluate, on_failure, &mut stats)?;
+        t.push(ts[0] + (i as f64) * dt);
+        y.push(nd.value());
+    }
+    if let Some(h) = last {
+        nd.t = t[n];
+        nd.rescale(h);
+        nd.adams_step_with(rate, &l, mode.corrections(), evaluate, on_failure, &mut stats)?;
+        t.push(ts[1]);
+        y.push(nd.value());
+    }
+    stats.accepted += t.len() - 1;
+    Ok(((t, y), stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bytes;
+
+    fn decay(_t: f64, y: &[f64; 1], dy: &mut [f64; 1]) {
+        dy[0] = -y[0];
+    }
+
+    fn solve(ts: [f64; 2], dt: f64) -> Solution<1> {
+        solve_with_mode(&decay, [1.0], ts, dt, Startup::Rk4, Mode::Pece(1), CorrectorFailure::Abort, Limits::default()).unwrap().0
+    }
+
+    #[test]
+    fn dividing_dt_ends_on_tf() {
+        let (t, y) = solve([0.0, 0.3], 0.1);
+        assert_eq!(t.len(), 4);
+        assert!((t[3] - 0.3).abs() < 1e-12);
+        assert!((y[3][0] - (-0.3_f64).exp()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn non_dividing_dt_ends_on_tf() {
+        let (t, y) = solve([0.0, 1.05], 0.1);
+        assert_eq!(t.len(), 12);
+        assert_eq!(*t.last().unwrap(), 1.05);
+        assert!((y[11][0] - (-1.05_f64).exp()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn span_under_three_steps_does_not_overshoot() {
+        let (t, y) = solve([0.0, 0.25], 0.1);
+        assert_eq!(t, [0.0, 0.1, 0.2, 0.25]);
+        assert!((y[3][0] - (-0.25_f64).exp()).abs() < 1e-6);
+    }
+
+    fn oscillator(_t: f64, y: &[f64; 2], dy: &mut [f64; 2]) {
+        dy[0] = y[1];
+        dy[1] = 2.0 * y[1] - y[1] * y[1] * y[1] - y[0];
+    }
+
+    ///
+    /// The formulas on raw rate history, as solve_with_mode ran before the
+    /// Nordsieck array
+    ///
+    fn raw_history(ts: [f64; 2], dt: f64, mode: Mode) -> Vec<[f64; 2]> {
+        let mut y = startup_values(&oscillator, [1.0, 0.0], ts[0], dt, Startup::Rk4).unwrap().to_vec();
+        let mut f: [[f64; 2]; 4] = core::array::from_fn(|i| eval(&oscillator, ts[0] + i as f64 * dt, &y[i]));
+        for i in 4..=step_count(ts, dt) {
+            let (w0, ti) = (y[i - 1], ts[0] + i as f64 * dt);
+            let mut w = ab4_predict(&w0, &f, dt);
+            let mut fw = [0.0; 2];
+            for _ in 0..mode.corrections() {
+                fw = eval(&oscillator, ti, &w);
+                w = am4_correct(&w0, &f, &fw, dt);
+            }
+            if let Mode::Pece(_) = mode {
+                fw = eval(&oscillator, ti, &w);
+            }
+            f.rotate_left(1);
+            f[3] = fw;
+            y.push(w);
+        }
+        y
+    }
+
+    #[test]
+    fn nordsieck_steps_match_the_raw_history_formulas() {
+        for mode in [Mode::Pece(1), Mode::Pec(1), Mode::Pec(3), Mode::Pece(2)] {
+            let ((_, y), stats) = solve_with_mode(&oscillator, [1.0, 0.0], [0.0, 10.0], 0.01, Startup::Rk4, mode, CorrectorFailure::Abort, Limits::default()).unwrap();
+            let raw = raw_history([0.0, 10.0], 0.01, mode);
+            assert_eq!(y.len(), raw.len());
+            let gap = y.iter().zip(&raw).fold(0.0_f64, |m, (a, b)| m.max((a[0] - b[0]).abs()).max((a[1] - b[1]).abs()));
+            assert!(gap < 1e-12, "{}: {gap:e}", mode.name());
+            assert_eq!(stats.rate_evals, 4 + 997 * mode.evals());
+        }
+    }
+
+    fn spike(t: f64, y: &[f64; 1], dy: &mut [f64; 1]) {
+        dy[0] = if (0.5..0.6).contains(&t) { -60.0 * y[0] } else { -y[0] };
+    }
+
+    #[test]
+    fn a_diverging_corrector_is_halved_or_accepted_or_refused() {
+        // inside the spike h l_0 lambda = 0.05 * 3 / 8 * 60 > 1, so the
+        // P(EC)^3 iteration diverges on the two grid steps that end there
+        let run = |policy| solve_with_mode(&spike, [1.0], [0.0, 1.0], 0.05, Startup::Rk4, Mode::Pec(3), policy, Limits::default());
+        assert!(run(CorrectorFailure::Abort).is_err());
+        let ((_, y), stats) = run(CorrectorFailure::Halve).unwrap();
+        assert_eq!((stats.corrector_failures, stats.rejected, stats.accepted), (2, 2, 22));
+        assert!(y.last().unwrap()[0].is_finite());
+        let ((_, y), stats) = run(CorrectorFailure::Accept).unwrap();
+        assert_eq!((stats.corrector_failures, stats.rejected, stats.accepted), (2, 0, 20));
+        assert!(y.last().unwrap()[0].is_finite());
+    }
+
+    #[test]
+    fn accept_halves_an_iterate_that_is_not_finite() {
+        for policy in CorrectorFailure::ALL {
+            // the first evaluation at t = 0.5 fails, as a bad iterate would
+            let failed = core::cell::Cell::new(false);
+            let rate = |t: f64, y: &[f64; 1], dy: &mut [f64; 1]| {
+                dy[0] = if t == 0.5 && !failed.replace(true) { f64::NAN } else { -y[0] };
+            };
+            let run = solve_with_mode(&rate, [1.0], [0.0, 1.0], 0.05, Startup::Rk4, Mode::Pece(1), policy, Limits::default());
+            if policy == CorrectorFailure::Abort {
+                assert!(run.is_err());
+                continue;
+            }
+            let ((_, y), stats) = run.unwrap();
+            assert_eq!((stats.corrector_failures, stats.rejected), (1, 1), "{}", policy.name());
+            assert!((y.last().unwrap()[0] - (-1.0_f64).exp()).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn trajectory_over_the_memory_budget_is_refused() {
+        let limits = Limits::default().memory(bytes::<1>(10));
+        let run = |dt| solve_with_mode(&decay, [1.0], [0.0, 1.0], dt, Startup::Rk4, Mode::Pece(1), CorrectorFailure::Abort, limits);
+        assert!(run(0.1).is_ok());
+        assert!(run(0.05).is_err());
+        assert!(run(0.0).is_err());
+    }
+}
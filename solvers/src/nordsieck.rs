@@ -0,0 +1,363 @@
+//!
+//! nordsieck.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Nordsieck history representation for the Adams methods. Instead of past
+//! rates the state carries scaled derivatives z_j = h^j y^(j)(t_n) / j!,
+//! so changing the step is a rescale z_j *= r^j rather than a restart or a
+//! reinterpolation of raw history. Prediction is the Pascal triangle
+//! (Taylor extrapolation), correction adds l * delta for the method's l.
+//! On a uniform grid the order q array predicts with the q step
+//! Adams-Bashforth formula and corrects with the order q Adams-Moulton one,
+//! so AB/AM4 is the order 4 array
+//!
+
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{CorrectorFailure, SolveStats, MAX_HALVINGS};
+
+///
+/// Scaled derivative array at time t for step h, order = z.len() - 1
+///
+#[derive(Clone, Debug)]
+pub struct Nordsieck<const N: usize> {
+    pub t: f64,
+    pub h: f64,
+    pub z: Vec<[f64; N]>,
+}
+
+impl<const N: usize> Nordsieck<N> {
+    ///
+    /// Order one array from a value and its rate
+    ///
+    pub fn new(t: f64, y: [f64; N], f: [f64; N], h: f64) -> Nordsieck<N> {
+        Nordsieck { t, h, z: vec![y, core::array::from_fn(|i| h * f[i])] }
+    }
+
+    ///
+    /// Order q array at t from the value there and the rates at the last q
+    /// points of a uniform grid of step h, oldest first, the last at t.
+    /// The array is the polynomial through y whose derivative interpolates
+    /// the rates, so it is exactly the history the raw Adams formulas use
+    ///
+    pub fn from_uniform_history(t: f64, h: f64, y: [f64; N], f: &[[f64; N]]) -> Nordsieck<N> {
+        let q = f.len();
+        // backward differences at t, diffs[k] = nabla^k f_n
+        let mut table: Vec<[f64; N]> = f.iter().rev().copied().collect();
+        let mut diffs = vec![table[0]];
+        for k in 1..q {
+            for j in 0..q - k {
+                table[j] = core::array::from_fn(|i| table[j][i] - table[j + 1][i]);
+            }
+            diffs.push(table[0]);
+        }
+
+        // Newton backward form in u = (s - t) / h, sum_k nabla^k f_n / k!
+        // u (u + 1) .. (u + k - 1), collected into monomial coefficients
+        let mut coef = vec![[0.0; N]; q];
+        let mut basis = vec![1.0];
+        let mut factorial = 1.0;
+        for (k, dk) in diffs.iter().enumerate() {
+            for (c, b) in coef.iter_mut().zip(&basis) {
+                for i in 0..N {
+                    c[i] += b * dk[i] / factorial;
+                }
+            }
+            basis.push(0.0);
+            for j in (1..basis.len()).rev() {
+                basis[j] = basis[j - 1] + k as f64 * basis[j];
+            }
+            basis[0] *= k as f64;
+            factorial *= (k + 1) as f64;
+        }
+
+        // y(s) = y + h int_0^u p, so z_j is h times the u^(j - 1) coefficient over j
+        let mut z = vec![y];
+        z.extend(coef.iter().enumerate().map(|(j, c)| core::array::from_fn(|i| h * c[i] / (j + 1) as f64)));
+        Nordsieck { t, h, z }
+    }
+
+    pub fn order(&self) -> usize {
+        self.z.len() - 1
+    }
+
+    pub fn value(&self) -> [f64; N] {
+        self.z[0]
+    }
+
+    ///
+    /// Changes the step to h_new by scaling z_j by r^j
+    ///
+    pub fn rescale(&mut self, h_new: f64) {
+        let r = h_new / self.h;
+        let mut rj = 1.0;
+        for zj in &mut self.z {
+            for x in zj.iter_mut() {
+                *x *= rj;
+            }
+            rj *= r;
+        }
+        self.h = h_new;
+    }
+
+    ///
+    /// Taylor extrapolation to t + h via the Pascal triangle
+    ///
+    pub fn predict(&mut self) {
+        let q = self.order();
+        for k in 0..q {
+            for j in (k..q).rev() {
+                let next = self.z[j + 1];
+                for (a, b) in self.z[j].iter_mut().zip(next.iter()) {
+                    *a += b;
+                }
+            }
+        }
+        self.t += self.h;
+    }
+
+    ///
+    /// Undoes predict
+    ///
+    pub fn retract(&mut self) {
+        let q = self.order();
+        for k in 0..q {
+            for j in (k..q).rev() {
+                let next = self.z[j + 1];
+                for (a, b) in self.z[j].iter_mut().zip(next.iter()) {
+                    *a -= b;
+                }
+            }
+        }
+        self.t -= self.h;
+    }
+
+    ///
+    /// z += l delta
+    ///
+    pub fn correct(&mut self, l: &[f64], delta: &[f64; N]) {
+        for (zj, lj) in self.z.iter_mut().zip(l.iter()) {
+            for (x, d) in zj.iter_mut().zip(delta.iter()) {
+                *x += lj * d;
+            }
+        }
+    }
+
+    ///
+    /// The final evaluation of P(EC)^m E: replaces the rate at t with f,
+    /// z_j += l_j (h f - z_1) for j >= 1, leaving the value. The array then
+    /// holds what the raw formulas would with f in their history
+    ///
+    pub fn evaluate(&mut self, l: &[f64], f: &[f64; N]) {
+        let e: [f64; N] = core::array::from_fn(|i| self.h * f[i] - self.z[1][i]);
+        for (zj, lj) in self.z.iter_mut().zip(l.iter()).skip(1) {
+            for (x, d) in zj.iter_mut().zip(e.iter()) {
+                *x += lj * d;
+            }
+        }
+    }
+
+    ///
+    /// One P(EC)^m step to t + h, with the final evaluation when
+    /// `evaluate` is set. Each correction restarts from the predicted
+    /// array, so repeated passes iterate the Adams-Moulton corrector on the
+    /// rate. Returns the predicted value, the last correction, delta =
+    /// h f - z_1 of the prediction, and whether the corrector converged:
+    /// the value stayed finite and, for m >= 2, the last pass moved it less
+    /// than the one before. The step costs m rate evaluations, and one
+    /// more with evaluate
+    ///
+    pub fn adams_step<F>(&mut self, rate: &F, l: &[f64], corrections: usize, evaluate: bool) -> ([f64; N], [f64; N], bool)
+    where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+        self.predict();
+        let (yp, z1) = (self.z[0], self.z[1]);
+        let mut f = [0.0; N];
+        let mut applied = [0.0; N];
+        let mut moved = [f64::INFINITY; 2];
+        for _ in 0..corrections.max(1) {
+            rate(self.t, &self.z[0], &mut f);
+            let delta: [f64; N] = core::array::from_fn(|i| self.h * f[i] - z1[i]);
+            let step: [f64; N] = core::array::from_fn(|i| delta[i] - applied[i]);
+            self.correct(l, &step);
+            moved = [moved[1], step.iter().fold(0.0_f64, |m, x| m.max(x.abs()))];
+            applied = delta;
+        }
+        if evaluate {
+            rate(self.t, &self.z[0], &mut f);
+            self.evaluate(l, &f);
+        }
+        let converged = self.z.iter().all(|zj| zj.iter().all(|x| x.is_finite())) && moved[1] <= moved[0];
+        (yp, applied, converged)
+    }
+
+    ///
+    /// adams_step under a corrector failure policy, its work added to
+    /// stats. Halve restores the array and takes the step as two halves,
+    /// each of which may split again, down to h / 2^MAX_HALVINGS; every
+    /// split counts one rejected and one further accepted step. Accept
+    /// keeps a finite iterate and only counts the failure, and halves one
+    /// that is not; Abort returns an error. The array ends at t + h with
+    /// step h either way
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn adams_step_with<F>(
+        &mut self, rate: &F, l: &[f64], corrections: usize, evaluate: bool, on_failure: CorrectorFailure, stats: &mut SolveStats)
+        -> Result<(), Box<dyn core::error::Error>>
+    where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+        self.halving_step(rate, l, corrections, evaluate, on_failure, stats, 0)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn halving_step<F>(
+        &mut self, rate: &F, l: &[f64], corrections: usize, evaluate: bool, on_failure: CorrectorFailure, stats: &mut SolveStats,
+        depth: usize)
+        -> Result<(), Box<dyn core::error::Error>>
+    where F: Fn(f64, &[f64; N], &mut [f64; N]) {
+        let saved = self.clone();
+        let (_, _, converged) = self.adams_step(rate, l, corrections, evaluate);
+        stats.rate_evals += corrections.max(1) + usize::from(evaluate);
+        if converged {
+            return Ok(());
+        }
+        stats.corrector_failures += 1;
+        let finite = self.z[0].iter().all(|x| x.is_finite());
+        match on_failure {
+            CorrectorFailure::Accept if finite => Ok(()),
+            CorrectorFailure::Abort => Err(format!("ab/am corrector did not converge at t = {}", saved.t).into()),
+            _ if depth < MAX_HALVINGS => {
+                *self = saved;
+                let h = self.h;
+                stats.rejected += 1;
+                stats.accepted += 1;
+                self.rescale(0.5 * h);
+                self.halving_step(rate, l, corrections, evaluate, on_failure, stats, depth + 1)?;
+                self.halving_step(rate, l, corrections, evaluate, on_failure, stats, depth + 1)?;
+                self.rescale(h);
+                Ok(())
+            }
+            _ => Err(format!("ab/am corrector did not converge at t = {} after {MAX_HALVINGS} halvings", saved.t).into()),
+        }
+    }
+
+    ///
+    /// Dense output at t + s h, s in [-1, 0] lies inside the last step
+    ///
+    pub fn interpolate(&self, s: f64) -> [f64; N] {
+        let mut out = [0.0; N];
+        for zj in self.z.iter().rev() {
+            for (o, x) in out.iter_mut().zip(zj.iter()) {
+                *o = *o * s + x;
+            }
+        }
+        out
+    }
+
+    ///
+    /// Raises the order by one, seeding the new entry from the last
+    /// correction as in LSODE
+    ///
+    pub fn raise_order(&mut self, delta: &[f64; N], l_last: f64) {
+        let q = self.order() as f64;
+        self.z.push(core::array::from_fn(|i| l_last * delta[i] / (q + 1.0)));
+    }
+
+    pub fn lower_order(&mut self) {
+        if self.order() > 1 {
+            self.z.pop();
+        }
+    }
+}
+
+///
+/// Adams-Moulton l vector of order q in Nordsieck form: the coefficients
+/// of the correction polynomial L(u), u = (s - t_{n+1}) / h, with L' = 1
+/// at u = 0 and zero at the q - 1 points before, and L = 0 at u = -1
+///
+pub fn adams_l(q: usize) -> Vec<f64> {
+    // L'(u) = prod_{k=1}^{q-1} (u + k) / k in monomials
+    let mut dl = vec![1.0];
+    for k in 1..q {
+        let kf = k as f64;
+        dl.push(0.0);
+        for j in (1..dl.len()).rev() {
+            dl[j] = (dl[j - 1] + kf * dl[j]) / kf;
+        }
+    }
+    let mut l = vec![0.0; q + 1];
+    for (j, c) in dl.iter().enumerate() {
+        l[j + 1] = c / (j + 1) as f64;
+    }
+    // L(-1) = 0 fixes the constant
+    l[0] = -l.iter().enumerate().skip(1).map(|(j, c)| if j % 2 == 0 { *c } else { -c }).sum::<f64>();
+    l
+}
+
+///
+/// Local error constants of the order q Adams-Bashforth and Adams-Moulton
+/// formulas, the error being the constant times h^{q+1} y^{(q+1)}
+///
+pub fn adams_error(q: usize) -> (f64, f64) {
+    // gamma_j + gamma_{j-1} / 2 + .. + gamma_0 / (j + 1) = 1
+    let mut gamma = vec![1.0];
+    for j in 1..=q {
+        let sum: f64 = (0..j).map(|i| gamma[i] / (j + 1 - i) as f64).sum();
+        gamma.push(1.0 - sum);
+    }
+    (gamma[q], gamma[q] - gamma[q - 1])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adams_l_matches_the_tabulated_vectors() {
+        let table: [&[f64]; 4] = [
+            &[1.0, 1.0],
+            &[0.5, 1.0, 0.5],
+            &[5.0 / 12.0, 1.0, 0.75, 1.0 / 6.0],
+            &[3.0 / 8.0, 1.0, 11.0 / 12.0, 1.0 / 3.0, 1.0 / 24.0],
+        ];
+        for (q, want) in table.iter().enumerate() {
+            let got = adams_l(q + 1);
+            assert_eq!(got.len(), want.len());
+            for (a, b) in got.iter().zip(want.iter()) {
+                assert!((a - b).abs() < 1e-15, "order {}: {got:?}", q + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn adams_error_matches_the_tabulated_constants() {
+        assert_eq!(adams_error(1), (0.5, -0.5));
+        let (ab, am) = adams_error(4);
+        assert!((ab - 251.0 / 720.0).abs() < 1e-15);
+        assert!((am + 19.0 / 720.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn uniform_history_is_the_taylor_array_of_a_polynomial() {
+        // y = t^4 has f = 4 t^3, which the order 4 array carries exactly
+        let (t, h) = (1.0, 0.1);
+        let f: Vec<[f64; 1]> = (0..4).map(|k| [4.0 * (t - (3 - k) as f64 * h).powi(3)]).collect();
+        let nd = Nordsieck::from_uniform_history(t, h, [1.0], &f);
+        let want = [1.0, 4.0 * h, 6.0 * h * h, 4.0 * h.powi(3), h.powi(4)];
+        for (z, w) in nd.z.iter().zip(want) {
+            assert!((z[0] - w).abs() < 1e-13, "{:?}", nd.z);
+        }
+    }
+
+    #[test]
+    fn rescale_and_predict_follow_a_polynomial() {
+        let f: Vec<[f64; 1]> = (0..3).map(|k| [3.0 * ((k as f64 - 2.0) * 0.2).powi(2)]).collect();
+        let mut nd = Nordsieck::from_uniform_history(0.0, 0.2, [0.0], &f);
+        nd.rescale(0.05);
+        nd.predict();
+        assert!((nd.value()[0] - 0.05_f64.powi(3)).abs() < 1e-15);
+        nd.retract();
+        assert!(nd.value()[0].abs() < 1e-15);
+        assert!((nd.interpolate(-1.0)[0] + 0.05_f64.powi(3)).abs() < 1e-15);
+    }
+}
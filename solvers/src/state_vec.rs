@@ -0,0 +1,125 @@
+//!
+//! state_vec.rs  Andrew Belles  Oct 16th, 2026
+//!
+//! Lazy arithmetic on fixed size states. Sums, differences and scalar
+//! multiples of &StateVec build a small expression type instead of a new
+//! array; eval() then walks every entry once, so an update such as
+//! y + h / 6 (k1 + 2 k2 + 2 k3 + k4) compiles to the same single loop as
+//! writing it out by index, with no temporaries and the same rounding.
+//! StateVec derefs to [f64; N], so it passes straight to rate functions.
+//! core only, like the rest of the solvers crate
+//!
+
+#![allow(dead_code)]
+
+use core::ops::{Add, Deref, DerefMut, Mul, Sub};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StateVec<const N: usize>(pub [f64; N]);
+
+///
+/// Anything that yields entry i of an N vector
+///
+pub trait Expr<const N: usize>: Copy {
+    fn at(&self, i: usize) -> f64;
+
+    fn eval(self) -> StateVec<N> {
+        StateVec(core::array::from_fn(|i| self.at(i)))
+    }
+}
+
+///
+/// Marks the expression types, so + and - only build expressions from
+/// expressions and a bare StateVec on the right is a compile error
+///
+pub trait Operand {}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Sum<A, B>(A, B);
+
+#[derive(Clone, Copy, Debug)]
+pub struct Difference<A, B>(A, B);
+
+#[derive(Clone, Copy, Debug)]
+pub struct Scaled<A>(f64, A);
+
+impl<const N: usize> StateVec<N> {
+    pub fn zeros() -> StateVec<N> {
+        StateVec([0.0; N])
+    }
+}
+
+impl<const N: usize> From<[f64; N]> for StateVec<N> {
+    fn from(y: [f64; N]) -> StateVec<N> {
+        StateVec(y)
+    }
+}
+
+impl<const N: usize> Deref for StateVec<N> {
+    type Target = [f64; N];
+    fn deref(&self) -> &[f64; N] {
+        &self.0
+    }
+}
+
+impl<const N: usize> DerefMut for StateVec<N> {
+    fn deref_mut(&mut self) -> &mut [f64; N] {
+        &mut self.0
+    }
+}
+
+impl<const N: usize> Expr<N> for &StateVec<N> {
+    fn at(&self, i: usize) -> f64 {
+        self.0[i]
+    }
+}
+
+impl<A: Expr<N>, B: Expr<N>, const N: usize> Expr<N> for Sum<A, B> {
+    fn at(&self, i: usize) -> f64 {
+        self.0.at(i) + self.1.at(i)
+    }
+}
+
+impl<A: Expr<N>, B: Expr<N>, const N: usize> Expr<N> for Difference<A, B> {
+    fn at(&self, i: usize) -> f64 {
+        self.0.at(i) - self.1.at(i)
+    }
+}
+
+impl<A: Expr<N>, const N: usize> Expr<N> for Scaled<A> {
+    fn at(&self, i: usize) -> f64 {
+        self.0 * self.1.at(i)
+    }
+}
+
+///
+/// + and - for every expression type, and f64 * expression
+///
+macro_rules! operators {
+    ($([$($gen:tt)*] $ty:ty),*) => {$(
+        impl<$($gen)*> Operand for $ty {}
+
+        impl<$($gen)*, Rhs: Operand> Add<Rhs> for $ty {
+            type Output = Sum<Self, Rhs>;
+            fn add(self, rhs: Rhs) -> Sum<Self, Rhs> {
+                Sum(self, rhs)
+            }
+        }
+
+        impl<$($gen)*, Rhs: Operand> Sub<Rhs> for $ty {
+            type Output = Difference<Self, Rhs>;
+            fn sub(self, rhs: Rhs) -> Difference<Self, Rhs> {
+                Difference(self, rhs)
+            }
+        }
+
+        impl<$($gen)*> Mul<$ty> for f64 {
+            type Output = Scaled<$ty>;
+            fn mul(self, rhs: $ty) -> Scaled<$ty> {
+                Scaled(self, rhs)
+            }
+        }
+    )*};
+}
+
+operators!(['a, const N: usize] &'a StateVec<N>, [A, B] Sum<A, B>, [A, B] Difference<A, B>, [A] Scaled<A>);